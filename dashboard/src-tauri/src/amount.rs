@@ -0,0 +1,205 @@
+//! Transaction amount parsing.
+//!
+//! Normalizes user-entered amounts ("1.5", "1.5 ETH", "150 gwei",
+//! "1500000000000000000 wei") to the chain's base-unit integer string, which
+//! is what the Go library's ParseAmount expects.
+
+use crate::error::{AppError, AppResult, ErrorCode};
+
+/// A unit an amount can be written in, as a power of ten of the base unit
+struct Unit {
+    name: &'static str,
+    decimals: u32,
+}
+
+/// Units for a chain; the first entry is the default for input without a unit
+fn chain_units(chain_id: &str) -> Option<&'static [Unit]> {
+    const BITCOIN: &[Unit] = &[
+        Unit { name: "btc", decimals: 8 },
+        Unit { name: "sat", decimals: 0 },
+        Unit { name: "sats", decimals: 0 },
+        Unit { name: "satoshi", decimals: 0 },
+    ];
+    const ETHEREUM: &[Unit] = &[
+        Unit { name: "eth", decimals: 18 },
+        Unit { name: "gwei", decimals: 9 },
+        Unit { name: "wei", decimals: 0 },
+    ];
+
+    match chain_id {
+        "bitcoin" | "bitcoin-testnet" | "bitcoin-regtest" => Some(BITCOIN),
+        "ethereum" | "ethereum-goerli" | "ethereum-sepolia" => Some(ETHEREUM),
+        _ => None,
+    }
+}
+
+fn invalid(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCode::FfiInvalidInput, message)
+}
+
+/// Convert an amount to the chain's base unit (satoshi, wei, ...).
+///
+/// - Without a unit the amount is in the chain's main unit, so "1" and "1.0"
+///   are both 1 ETH; base units need the suffix ("1500 wei", "1000 sat").
+/// - An explicit unit suffix sets the unit ("1.5 ETH", "20 gwei").
+/// - Chains without known units only take base-unit integers.
+///
+/// Rejects signs, exponents, NaN/infinity, more decimal places than the
+/// unit allows, and values that overflow u128.
+pub fn to_base_units(chain_id: &str, amount: &str) -> AppResult<String> {
+    let amount = amount.trim();
+    if amount.is_empty() {
+        return Err(invalid("Amount is required"));
+    }
+
+    let (number, unit_name) = match amount.split_once(char::is_whitespace) {
+        Some((number, unit)) => (number, Some(unit.trim().to_ascii_lowercase())),
+        None => (amount, None),
+    };
+
+    let units = chain_units(chain_id);
+
+    let decimals = match (&unit_name, units) {
+        (Some(name), Some(units)) => units
+            .iter()
+            .find(|u| u.name == name)
+            .map(|u| u.decimals)
+            .ok_or_else(|| invalid(format!("Unknown unit '{}' for {}", name, chain_id)))?,
+        (None, Some(units)) => units[0].decimals,
+        // Without a unit table only plain integers can be taken, as base units
+        (None, None) if !number.contains('.') => 0,
+        (Some(_), None) | (None, None) => {
+            return Err(invalid(format!("Cannot convert decimal amounts for {}", chain_id)))
+        }
+    };
+
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(invalid(format!("Invalid amount '{}'", amount)));
+    }
+
+    if fraction.len() > decimals as usize {
+        return Err(invalid(format!(
+            "Amount has too many decimal places (maximum {})",
+            decimals
+        )));
+    }
+
+    // Shift the decimal point right by `decimals` digits
+    let padded = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    let digits = padded.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok("0".to_string());
+    }
+
+    let value: u128 = digits
+        .parse()
+        .map_err(|_| invalid("Amount is too large"))?;
+
+    Ok(value.to_string())
+}
+
+/// Ticker of the chain's main unit ("BTC", "ETH")
+pub fn main_unit_symbol(chain_id: &str) -> Option<String> {
+    chain_units(chain_id).map(|units| units[0].name.to_ascii_uppercase())
+}
+
+/// Format a base-unit integer in the chain's main unit ("150000000" sat ->
+/// "1.5" BTC), without trailing zeros.
+pub fn to_main_units(chain_id: &str, base_units: &str) -> AppResult<String> {
+    let decimals = chain_units(chain_id)
+        .map(|units| units[0].decimals as usize)
+        .ok_or_else(|| invalid(format!("Cannot convert decimal amounts for {}", chain_id)))?;
+
+    if base_units.is_empty() || !base_units.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid(format!("Invalid amount '{}'", base_units)));
+    }
+
+    let digits = base_units.trim_start_matches('0');
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    Ok(if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_btc_decimal_to_satoshi() {
+        assert_eq!(to_base_units("bitcoin", "1.5").unwrap(), "150000000");
+        assert_eq!(to_base_units("bitcoin", "0.00000001").unwrap(), "1");
+        assert_eq!(to_base_units("bitcoin-testnet", "0.001 BTC").unwrap(), "100000");
+        assert_eq!(to_base_units("bitcoin", "2500 sats").unwrap(), "2500");
+        assert_eq!(to_base_units("bitcoin", "2").unwrap(), "200000000");
+    }
+
+    #[test]
+    fn test_whole_and_decimal_amounts_share_a_unit() {
+        for chain_id in ["bitcoin", "ethereum"] {
+            assert_eq!(
+                to_base_units(chain_id, "1").unwrap(),
+                to_base_units(chain_id, "1.0").unwrap(),
+                "{}",
+                chain_id
+            );
+        }
+        assert_eq!(to_base_units("ethereum", "1").unwrap(), "1000000000000000000");
+    }
+
+    #[test]
+    fn test_eth_decimal_to_wei() {
+        assert_eq!(to_base_units("ethereum", "1.5").unwrap(), "1500000000000000000");
+        assert_eq!(to_base_units("ethereum", "1.5 ETH").unwrap(), "1500000000000000000");
+        assert_eq!(to_base_units("ethereum", "1500000000000000000 wei").unwrap(), "1500000000000000000");
+        assert_eq!(to_base_units("ethereum", "20 gwei").unwrap(), "20000000000");
+        assert_eq!(to_base_units("ethereum", ".5").unwrap(), "500000000000000000");
+        assert_eq!(to_base_units("ethereum", "0.0").unwrap(), "0");
+    }
+
+    #[test]
+    fn test_rejects_too_many_decimal_places() {
+        let err = to_base_units("bitcoin", "0.000000001").unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+        assert!(to_base_units("ethereum", "1.5 wei").is_err());
+        assert!(to_base_units("ethereum", "0.0000000000000000001").is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed_amounts() {
+        for amount in ["", "-1", "+1", "NaN", "inf", "1e18", "1.2.3", ".", "1,5", "1.5 DOGE"] {
+            let err = to_base_units("ethereum", amount).unwrap_err();
+            assert_eq!(err.code, ErrorCode::FfiInvalidInput, "{}", amount);
+        }
+    }
+
+    #[test]
+    fn test_rejects_overflow() {
+        let huge = format!("{} wei", "9".repeat(40));
+        assert!(to_base_units("ethereum", &huge).is_err());
+        assert!(to_base_units("ethereum", &format!("{} ETH", "9".repeat(25))).is_err());
+    }
+
+    #[test]
+    fn test_base_units_to_main_units() {
+        assert_eq!(to_main_units("bitcoin", "150000000").unwrap(), "1.5");
+        assert_eq!(to_main_units("bitcoin", "1").unwrap(), "0.00000001");
+        assert_eq!(to_main_units("bitcoin", "0").unwrap(), "0");
+        assert_eq!(to_main_units("ethereum", "2000000000000000000").unwrap(), "2");
+        assert!(to_main_units("solana", "42").is_err());
+    }
+
+    #[test]
+    fn test_unknown_chain_only_accepts_base_units() {
+        assert_eq!(to_base_units("solana", "42").unwrap(), "42");
+        assert!(to_base_units("solana", "4.2").is_err());
+    }
+}
@@ -0,0 +1,318 @@
+//! Short-lived caches for read-only commands.
+//!
+//! Listing wallets or reading library info costs an FFI round-trip (and USB
+//! I/O) each time, while the UI asks for the same data on every screen.
+//! Entries expire after a fixed TTL and are dropped explicitly when a
+//! command changes the underlying data; a cache with a capacity also evicts
+//! its least recently used entry when full. Only non-secret data is cached.
+
+use serde::Serialize;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::commands::diagnostics::LibraryInfo;
+use crate::models::wallet::WalletListResponse;
+
+/// How long a wallet listing stays fresh
+pub const WALLET_LIST_TTL: Duration = Duration::from_secs(30);
+
+/// How long library info stays fresh (it only changes on reload)
+pub const LIBRARY_INFO_TTL: Duration = Duration::from_secs(300);
+
+/// Hit/miss counts of one cache namespace
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct CacheStats {
+    pub namespace: String,
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped to stay within capacity
+    pub evictions: u64,
+    /// Entries currently held (expired ones included until next lookup)
+    pub entries: usize,
+}
+
+struct CacheEntry<V> {
+    inserted: Instant,
+    /// `CacheState::clock` at the last insert or hit
+    last_used: u64,
+    value: V,
+}
+
+struct CacheState<K, V> {
+    entries: HashMap<K, CacheEntry<V>>,
+    /// Logical clock ordering accesses for LRU eviction
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+/// Key-value cache whose entries expire `ttl` after insertion, optionally
+/// bounded to `capacity` entries
+pub struct TtlCache<V, K = String> {
+    namespace: &'static str,
+    ttl: Duration,
+    capacity: Option<usize>,
+    state: Mutex<CacheState<K, V>>,
+}
+
+impl<V: Clone, K: Hash + Eq + Clone> TtlCache<V, K> {
+    pub fn new(namespace: &'static str, ttl: Duration) -> Self {
+        Self {
+            namespace,
+            ttl,
+            capacity: None,
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                clock: 0,
+                hits: 0,
+                misses: 0,
+                evictions: 0,
+            }),
+        }
+    }
+
+    /// Hold at most `capacity` entries (at least one), evicting the least
+    /// recently used
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity.max(1));
+        self
+    }
+
+    /// Fresh value for `key`, counting a hit or a miss
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_at(key, Instant::now())
+    }
+
+    fn get_at<Q>(&self, key: &Q, now: Instant) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.clock += 1;
+        let clock = state.clock;
+
+        let fresh = match state.entries.get_mut(key) {
+            Some(entry) if now.duration_since(entry.inserted) < self.ttl => {
+                entry.last_used = clock;
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                state.entries.remove(key);
+                None
+            }
+            None => None,
+        };
+
+        match fresh {
+            Some(_) => state.hits += 1,
+            None => state.misses += 1,
+        }
+        fresh
+    }
+
+    pub fn insert(&self, key: impl Into<K>, value: V) {
+        self.insert_at(key, value, Instant::now());
+    }
+
+    fn insert_at(&self, key: impl Into<K>, value: V, now: Instant) {
+        let key = key.into();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.clock += 1;
+        let last_used = state.clock;
+        state.entries.insert(key, CacheEntry { inserted: now, last_used, value });
+
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+
+        // Expired entries go first; only then is a live one evicted
+        if state.entries.len() > capacity {
+            let ttl = self.ttl;
+            state.entries.retain(|_, entry| now.duration_since(entry.inserted) < ttl);
+        }
+        while state.entries.len() > capacity {
+            let Some(oldest) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            state.entries.remove(&oldest);
+            state.evictions += 1;
+        }
+    }
+
+    /// Drop the entries whose key matches `predicate` (counts are kept)
+    pub fn invalidate_matching(&self, predicate: impl Fn(&K) -> bool) {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entries
+            .retain(|key, _| !predicate(key));
+    }
+
+    /// Drop every entry (counts are kept)
+    pub fn invalidate_all(&self) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).entries.clear();
+    }
+
+    /// `describe` applied to every entry, with the entry's age and whether
+    /// it has expired; expired entries are reported, not dropped
+    pub fn inspect<T>(&self, describe: impl Fn(&K, &V) -> T) -> Vec<(T, Duration, bool)> {
+        let now = Instant::now();
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .entries
+            .iter()
+            .map(|(key, entry)| {
+                let age = now.duration_since(entry.inserted);
+                (describe(key, &entry.value), age, age >= self.ttl)
+            })
+            .collect()
+    }
+
+    /// Maximum number of entries, if bounded
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        CacheStats {
+            namespace: self.namespace.to_string(),
+            hits: state.hits,
+            misses: state.misses,
+            evictions: state.evictions,
+            entries: state.entries.len(),
+        }
+    }
+}
+
+/// Caches of the read-only commands (Tauri managed state)
+pub struct ReadCache {
+    /// `list_wallets`, keyed by USB path
+    pub wallets: TtlCache<WalletListResponse>,
+    /// `get_library_info` and `list_supported_chains` (single key)
+    pub library_info: TtlCache<LibraryInfo>,
+}
+
+impl Default for ReadCache {
+    fn default() -> Self {
+        Self {
+            wallets: TtlCache::new("wallets", WALLET_LIST_TTL),
+            library_info: TtlCache::new("library_info", LIBRARY_INFO_TTL),
+        }
+    }
+}
+
+impl ReadCache {
+    /// Stats of every namespace
+    pub fn stats(&self) -> Vec<CacheStats> {
+        vec![self.wallets.stats(), self.library_info.stats()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_and_miss_accounting() {
+        let cache: TtlCache<_> = TtlCache::new("test", Duration::from_secs(60));
+
+        assert_eq!(cache.get("a"), None);
+        cache.insert("a", 1);
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("b"), None);
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats { namespace: "test".to_string(), hits: 2, misses: 2, evictions: 0, entries: 1 }
+        );
+
+        cache.invalidate_all();
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.stats().misses, 3);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_inspect_reports_age_without_counting_lookups() {
+        let cache: TtlCache<_> = TtlCache::new("test", Duration::from_secs(30));
+        let start = Instant::now() - Duration::from_secs(40);
+
+        cache.insert_at("old", "abc", start);
+        cache.insert("new", "de");
+
+        let mut entries = cache.inspect(|key, value| (key.clone(), value.len()));
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(entries[0].0, ("new".to_string(), 2));
+        assert!(!entries[0].2);
+        assert_eq!(entries[1].0, ("old".to_string(), 3));
+        assert!(entries[1].1 >= Duration::from_secs(40));
+        assert!(entries[1].2);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (0, 0, 2));
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache: TtlCache<_> = TtlCache::new("test", Duration::from_secs(30));
+        let start = Instant::now();
+
+        cache.insert_at("a", "value", start);
+        assert_eq!(cache.get_at("a", start + Duration::from_secs(29)), Some("value"));
+        assert_eq!(cache.get_at("a", start + Duration::from_secs(30)), None);
+
+        // The expired entry was dropped, not just skipped
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().misses, 1);
+    }
+
+    #[test]
+    fn test_capacity_evicts_least_recently_used() {
+        let cache: TtlCache<_> = TtlCache::new("test", Duration::from_secs(60)).with_capacity(2);
+
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        // Reading "a" makes "b" the least recently used
+        assert_eq!(cache.get("a"), Some(1));
+        cache.insert("c", 3);
+
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("c"), Some(3));
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.stats().entries, 2);
+    }
+
+    #[test]
+    fn test_capacity_drops_expired_entries_first() {
+        let cache: TtlCache<_> = TtlCache::new("test", Duration::from_secs(30)).with_capacity(2);
+        let start = Instant::now();
+
+        cache.insert_at("old", 1, start);
+        cache.insert_at("recent", 2, start + Duration::from_secs(20));
+        // "old" was used most recently, but it has expired by the time "new" arrives
+        assert_eq!(cache.get_at("old", start + Duration::from_secs(25)), Some(1));
+        cache.insert_at("new", 3, start + Duration::from_secs(31));
+
+        assert_eq!(cache.get_at("recent", start + Duration::from_secs(31)), Some(2));
+        assert_eq!(cache.get_at("new", start + Duration::from_secs(31)), Some(3));
+        assert_eq!(cache.stats().evictions, 0);
+    }
+}
@@ -6,8 +6,10 @@
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
+use crate::commands::usb::normalize_usb_path;
 use crate::error::Error;
-use crate::ffi::queue::LazyWalletQueue;
+use crate::ffi::queue::WalletBackend;
+use crate::limits::{check_length, MAX_PASSWORD_LEN, MAX_PATH_LEN};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -68,8 +70,12 @@ pub struct AppConfigResponse {
 #[tauri::command]
 pub async fn is_first_time_setup(
     usb_path: String,
-    queue: State<'_, LazyWalletQueue>,
+    backend: State<'_, WalletBackend>,
 ) -> Result<bool, Error> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue()?;
+
+    check_length("USB path", &usb_path, MAX_PATH_LEN)?;
     tracing::info!("is_first_time_setup: usb_path={}", usb_path);
 
     // Serialize input to JSON for FFI
@@ -84,7 +90,7 @@ pub async fn is_first_time_setup(
         )
     })?;
 
-    tracing::info!("Calling FFI with params: {}", params_json);
+    tracing::info!("Calling FFI with params_json (password hidden)");
 
     // Call FFI through queue
     let result = queue.is_first_time_setup(params_json).await
@@ -96,8 +102,6 @@ pub async fn is_first_time_setup(
             )
         })?;
 
-    tracing::info!("FFI response: {:?}", result);
-
     // Parse response - Go returns {isFirstTime: bool} directly
     let is_first_time = result
         .get("isFirstTime")
@@ -119,21 +123,25 @@ pub async fn is_first_time_setup(
 #[tauri::command]
 pub async fn initialize_app(
     input: InitializeAppInput,
-    queue: State<'_, LazyWalletQueue>,
+    backend: State<'_, WalletBackend>,
 ) -> Result<String, Error> {
+    let queue = backend.queue()?;
+
+    check_length("Password", &input.password, MAX_PASSWORD_LEN)?;
+    check_length("USB path", &input.usb_path, MAX_PATH_LEN)?;
     tracing::info!("initialize_app: usb_path={}", input.usb_path);
 
     // Serialize input to JSON for FFI
     let params_json = serde_json::to_string(&serde_json::json!({
         "password": input.password,
-        "usbPath": input.usb_path,
+        "usbPath": normalize_usb_path(&input.usb_path),
     }))
     .map_err(|e| Error::new(
         crate::error::ErrorCode::SerializationError,
         format!("Failed to serialize input: {}", e)
     ))?;
 
-    tracing::info!("Calling FFI with params: {}", params_json);
+    tracing::info!("Calling FFI with params_json (password hidden)");
 
     // Call FFI through queue
     let result = queue.initialize_app(params_json).await
@@ -145,8 +153,6 @@ pub async fn initialize_app(
             )
         })?;
 
-    tracing::info!("FFI response: {:?}", result);
-
     // Parse response - Go returns {message: string} directly
     let message = result
         .get("message")
@@ -163,14 +169,18 @@ pub async fn initialize_app(
 #[tauri::command]
 pub async fn unlock_app(
     input: UnlockAppInput,
-    queue: State<'_, LazyWalletQueue>,
+    backend: State<'_, WalletBackend>,
 ) -> Result<AppConfig, Error> {
+    let queue = backend.queue()?;
+
+    check_length("Password", &input.password, MAX_PASSWORD_LEN)?;
+    check_length("USB path", &input.usb_path, MAX_PATH_LEN)?;
     tracing::info!("unlock_app: usb_path={}", input.usb_path);
 
     // Serialize input to JSON for FFI
     let params_json = serde_json::to_string(&serde_json::json!({
         "password": input.password,
-        "usbPath": input.usb_path,
+        "usbPath": normalize_usb_path(&input.usb_path),
     }))
     .map_err(|e| {
         tracing::error!("Failed to serialize input: {}", e);
@@ -192,16 +202,15 @@ pub async fn unlock_app(
             )
         })?;
 
-    tracing::info!("FFI response: {:?}", result);
-
-    // Parse config from response - Go returns {config: AppConfig} directly
+    // Parse config from response - Go returns {config: AppConfig} directly.
+    // The config holds provider API keys, so the response is never logged.
     let config_value = result
         .get("config")
         .ok_or_else(|| {
-            tracing::error!("No config in FFI response: {:?}", result);
+            tracing::error!("No config in FFI response");
             Error::new(
                 crate::error::ErrorCode::InternalError,
-                format!("No config in response: {:?}", result)
+                "No config in response",
             )
         })?;
 
@@ -0,0 +1,476 @@
+/**
+ * Wallet bundle commands
+ * Feature: Device migration
+ * Packages a wallet directory (its encrypted files and metadata, never
+ * decrypted) into one tar archive with a checksummed manifest, and restores
+ * such a bundle onto another USB after verifying every file
+ */
+
+use crate::cache::ReadCache;
+use crate::commands::usb::normalize_usb_path;
+use crate::commands::wallet::sanitize_wallet_id;
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::fs_utils::{long_path, write_atomic, write_atomic_with};
+use crate::limits::{check_length, MAX_PATH_LEN};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use tauri::State;
+
+/// Manifest layout version; bumped on incompatible changes
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Archive entry holding the manifest
+const MANIFEST_ENTRY: &str = "manifest.json";
+
+/// Archive directory holding the wallet's files
+const WALLET_ENTRY_DIR: &str = "wallet";
+
+/// Largest bundle accepted for import (a wallet directory is a few hundred KB)
+const MAX_BUNDLE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// One wallet file in a bundle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BundleFile {
+    /// Path relative to the wallet directory, `/`-separated
+    pub path: String,
+    pub size: u64,
+    /// Hex-encoded SHA3-256 of the file contents
+    pub sha3_256: String,
+}
+
+/// Contents of a bundle's manifest.json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BundleManifest {
+    pub format_version: u32,
+    pub wallet_id: String,
+    /// RFC 3339 timestamp of when the bundle was written
+    pub created_at: String,
+    pub files: Vec<BundleFile>,
+}
+
+/// Result of writing or restoring a bundle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WalletBundleResult {
+    pub wallet_id: String,
+    /// Bundle file (export) or restored wallet directory (import)
+    pub path: String,
+    pub file_count: usize,
+}
+
+fn sha3_hex(data: &[u8]) -> String {
+    use sha3::{Digest, Sha3_256};
+
+    Sha3_256::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn corrupted(details: impl Into<String>) -> AppError {
+    AppError::with_details(ErrorCode::DeserializationError, "Wallet bundle is corrupted", details)
+}
+
+/// Regular files under `dir`, keyed by `/`-separated relative path.
+/// Symlinks are skipped so a bundle never reaches outside the wallet.
+fn collect_files(dir: &Path, prefix: &str, files: &mut BTreeMap<String, Vec<u8>>) -> std::io::Result<()> {
+    for entry in fs::read_dir(long_path(dir))? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let relative = if prefix.is_empty() { name } else { format!("{}/{}", prefix, name) };
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            collect_files(&entry.path(), &relative, files)?;
+        } else if file_type.is_file() {
+            files.insert(relative, fs::read(long_path(&entry.path()))?);
+        }
+    }
+    Ok(())
+}
+
+/// `wallet/<relative>` entry name to its relative path; None for anything
+/// that could land outside the wallet directory
+fn wallet_relative_path(entry: &Path) -> Option<String> {
+    let mut components = entry.components();
+    if components.next() != Some(Component::Normal(WALLET_ENTRY_DIR.as_ref())) {
+        return None;
+    }
+
+    let parts = components
+        .map(|component| match component {
+            Component::Normal(part) => part.to_str().map(str::to_string),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+/// Package `{usb_path}/{wallet_id}` into a bundle at `dest_path`.
+///
+/// Files are copied as stored (still encrypted); an existing file at
+/// `dest_path` is never overwritten.
+pub fn write_wallet_bundle(usb_path: &str, wallet_id: &str, dest_path: &str) -> AppResult<WalletBundleResult> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+    check_length("Destination path", dest_path, MAX_PATH_LEN)?;
+
+    let wallet_dir = Path::new(usb_path).join(wallet_id);
+    if !long_path(&wallet_dir.join("wallet.json")).is_file() {
+        return Err(AppError::new(ErrorCode::WalletNotFound, "Wallet not found on USB"));
+    }
+
+    let dest = PathBuf::from(dest_path);
+    if long_path(&dest).exists() {
+        return Err(AppError::with_details(
+            ErrorCode::ExportFailed,
+            "Bundle file already exists",
+            dest_path,
+        ));
+    }
+
+    let mut files = BTreeMap::new();
+    collect_files(&wallet_dir, "", &mut files).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to read wallet files", e.to_string())
+    })?;
+
+    let manifest = BundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        wallet_id: wallet_id.to_string(),
+        created_at: Utc::now().to_rfc3339(),
+        files: files
+            .iter()
+            .map(|(path, data)| BundleFile {
+                path: path.clone(),
+                size: data.len() as u64,
+                sha3_256: sha3_hex(data),
+            })
+            .collect(),
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| AppError::with_details(ErrorCode::SerializationError, "Failed to write bundle manifest", e.to_string()))?;
+
+    write_atomic_with(&dest, |file| {
+        let mut archive = tar::Builder::new(file);
+        let mut append = |name: &str, data: &[u8]| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o600);
+            header.set_cksum();
+            archive.append_data(&mut header, name, data)
+        };
+
+        append(MANIFEST_ENTRY, &manifest_json)?;
+        for (path, data) in &files {
+            append(&format!("{}/{}", WALLET_ENTRY_DIR, path), data)?;
+        }
+        archive.finish()
+    })
+    .map_err(|e| AppError::with_details(ErrorCode::ExportFailed, "Failed to write wallet bundle", e.to_string()))?;
+
+    tracing::info!("Bundled wallet {} ({} files) to {}", wallet_id, files.len(), dest.display());
+
+    Ok(WalletBundleResult {
+        wallet_id: wallet_id.to_string(),
+        path: dest.to_string_lossy().into_owned(),
+        file_count: files.len(),
+    })
+}
+
+/// Read a bundle and check every file against its manifest
+fn read_verified_bundle(bundle_path: &Path) -> AppResult<(BundleManifest, BTreeMap<String, Vec<u8>>)> {
+    let file = fs::File::open(long_path(bundle_path)).map_err(|e| {
+        AppError::with_details(ErrorCode::FfiInvalidInput, "Wallet bundle not found", e.to_string())
+    })?;
+    let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+    if size > MAX_BUNDLE_BYTES {
+        return Err(AppError::with_details(
+            ErrorCode::FfiInvalidInput,
+            "Wallet bundle is too large",
+            format!("{} bytes (limit {})", size, MAX_BUNDLE_BYTES),
+        ));
+    }
+
+    let mut manifest_json = None;
+    let mut files = BTreeMap::new();
+    let mut archive = tar::Archive::new(file.take(MAX_BUNDLE_BYTES));
+    let entries = archive.entries().map_err(|e| corrupted(e.to_string()))?;
+
+    for entry in entries {
+        let mut entry = entry.map_err(|e| corrupted(e.to_string()))?;
+        if entry.header().entry_type() != tar::EntryType::Regular {
+            return Err(corrupted("bundle contains a non-file entry"));
+        }
+
+        let name = entry.path().map_err(|e| corrupted(e.to_string()))?.into_owned();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).map_err(|e| corrupted(e.to_string()))?;
+
+        if name == Path::new(MANIFEST_ENTRY) {
+            manifest_json = Some(data);
+        } else {
+            let path = wallet_relative_path(&name)
+                .ok_or_else(|| corrupted(format!("unexpected entry {}", name.display())))?;
+            files.insert(path, data);
+        }
+    }
+
+    let manifest: BundleManifest = serde_json::from_slice(
+        &manifest_json.ok_or_else(|| corrupted("manifest.json is missing"))?,
+    )
+    .map_err(|e| corrupted(e.to_string()))?;
+
+    if manifest.format_version != BUNDLE_FORMAT_VERSION {
+        return Err(AppError::with_details(
+            ErrorCode::UnsupportedOperation,
+            "Wallet bundle was written by an incompatible version",
+            format!("format version {}", manifest.format_version),
+        ));
+    }
+    sanitize_wallet_id(&manifest.wallet_id)?;
+
+    if manifest.files.len() != files.len() {
+        return Err(corrupted(format!(
+            "manifest lists {} files, bundle holds {}",
+            manifest.files.len(),
+            files.len()
+        )));
+    }
+    for expected in &manifest.files {
+        let data = files
+            .get(&expected.path)
+            .ok_or_else(|| corrupted(format!("{} is missing", expected.path)))?;
+        if data.len() as u64 != expected.size || sha3_hex(data) != expected.sha3_256 {
+            return Err(corrupted(format!("checksum mismatch for {}", expected.path)));
+        }
+    }
+    if !files.contains_key("wallet.json") {
+        return Err(corrupted("wallet.json is missing"));
+    }
+
+    Ok((manifest, files))
+}
+
+/// Point wallet.json's `encryptedMnemonicPath`, an absolute path on the
+/// source USB, at the same file in `wallet_dir`
+fn relocate_mnemonic_path(files: &mut BTreeMap<String, Vec<u8>>, wallet_dir: &Path) -> AppResult<()> {
+    let Some(data) = files.get("wallet.json") else {
+        return Ok(());
+    };
+    let mut wallet: serde_json::Value = serde_json::from_slice(data).map_err(|e| corrupted(e.to_string()))?;
+    let Some(old_path) = wallet.get("encryptedMnemonicPath").and_then(|v| v.as_str()) else {
+        return Ok(());
+    };
+
+    // The source may have been another OS, so split on either separator
+    let file_name = old_path.rsplit(['/', '\\']).next().unwrap_or_default().to_string();
+    if !files.contains_key(&file_name) {
+        return Err(corrupted(format!("{} is missing", file_name)));
+    }
+    wallet["encryptedMnemonicPath"] = wallet_dir.join(&file_name).to_string_lossy().into_owned().into();
+
+    let rewritten = serde_json::to_vec_pretty(&wallet).map_err(|e| corrupted(e.to_string()))?;
+    files.insert("wallet.json".to_string(), rewritten);
+    Ok(())
+}
+
+/// Verify the bundle at `bundle_path` and restore it to `{usb_path}/{wallet_id}`.
+///
+/// Nothing is written unless every checksum matches, and a wallet already on
+/// the USB is never replaced. Files are staged in a hidden directory and
+/// moved into place in one rename. wallet.json is rewritten to find the
+/// encrypted seed on the new USB.
+pub fn restore_wallet_bundle(bundle_path: &str, usb_path: &str) -> AppResult<WalletBundleResult> {
+    check_length("Bundle path", bundle_path, MAX_PATH_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let (manifest, mut files) = read_verified_bundle(Path::new(bundle_path))?;
+
+    let usb_root = Path::new(usb_path);
+    if !long_path(usb_root).is_dir() {
+        return Err(AppError::new(ErrorCode::UsbNotFound, "USB drive not accessible"));
+    }
+
+    let wallet_dir = usb_root.join(&manifest.wallet_id);
+    if long_path(&wallet_dir).exists() {
+        return Err(AppError::with_details(
+            ErrorCode::WalletAlreadyExists,
+            "This wallet is already on the USB",
+            manifest.wallet_id,
+        ));
+    }
+
+    relocate_mnemonic_path(&mut files, &wallet_dir)?;
+
+    let staging = usb_root.join(format!(".{}.restoring", manifest.wallet_id));
+    let io_error = |message: &str, e: std::io::Error| {
+        AppError::with_details(ErrorCode::UsbNotWritable, message, e.to_string())
+    };
+
+    let _ = fs::remove_dir_all(long_path(&staging));
+    let staged = files.iter().try_for_each(|(path, data)| {
+        let target = path.split('/').fold(staging.clone(), |dir, part| dir.join(part));
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(long_path(parent))?;
+        }
+        write_atomic(&target, data)
+    });
+    if let Err(e) = staged.and_then(|_| fs::rename(long_path(&staging), long_path(&wallet_dir))) {
+        let _ = fs::remove_dir_all(long_path(&staging));
+        return Err(io_error("Failed to restore wallet bundle", e));
+    }
+
+    tracing::info!("Restored wallet {} ({} files) from bundle", manifest.wallet_id, files.len());
+
+    Ok(WalletBundleResult {
+        wallet_id: manifest.wallet_id,
+        path: wallet_dir.to_string_lossy().into_owned(),
+        file_count: files.len(),
+    })
+}
+
+/// Package a wallet's encrypted files into a portable bundle for moving it
+/// to another USB
+#[tauri::command]
+pub async fn export_wallet_bundle(
+    wallet_id: String,
+    usb_path: String,
+    dest_path: String,
+) -> Result<WalletBundleResult, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    write_wallet_bundle(&usb_path, &wallet_id, &dest_path).map_err(String::from)
+}
+
+/// Verify a wallet bundle and restore it onto a USB
+#[tauri::command]
+pub async fn import_wallet_bundle(
+    bundle_path: String,
+    usb_path: String,
+    read_cache: State<'_, ReadCache>,
+) -> Result<WalletBundleResult, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let result = restore_wallet_bundle(&bundle_path, &usb_path).map_err(String::from)?;
+
+    read_cache.wallets.invalidate_all();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+
+    fn fake_wallet(usb: &Path) -> PathBuf {
+        let wallet_dir = usb.join(WALLET_ID);
+        fs::create_dir_all(wallet_dir.join("addresses")).unwrap();
+        fs::write(wallet_dir.join("wallet.json"), format!(r#"{{"id":"{}","name":"Savings"}}"#, WALLET_ID)).unwrap();
+        fs::write(wallet_dir.join("mnemonic.enc"), [0u8, 1, 2, 254, 255]).unwrap();
+        fs::write(wallet_dir.join("addresses").join("addresses-20251017-143025.csv"), "a,b\n").unwrap();
+        wallet_dir
+    }
+
+    #[test]
+    fn test_bundle_round_trip_is_byte_for_byte() {
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        let wallet_dir = fake_wallet(source.path());
+        let bundle = out.path().join("wallet.arcsign");
+        let bundle_path = bundle.to_str().unwrap();
+
+        let written = write_wallet_bundle(source.path().to_str().unwrap(), WALLET_ID, bundle_path).unwrap();
+        assert_eq!(written.file_count, 3);
+
+        // Never overwrites an existing bundle
+        let err = write_wallet_bundle(source.path().to_str().unwrap(), WALLET_ID, bundle_path).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ExportFailed);
+
+        let restored = restore_wallet_bundle(bundle_path, target.path().to_str().unwrap()).unwrap();
+        assert_eq!(restored.wallet_id, WALLET_ID);
+        assert_eq!(restored.file_count, 3);
+
+        let mut original = BTreeMap::new();
+        let mut copy = BTreeMap::new();
+        collect_files(&wallet_dir, "", &mut original).unwrap();
+        collect_files(&target.path().join(WALLET_ID), "", &mut copy).unwrap();
+        assert_eq!(original, copy);
+        assert!(!target.path().join(format!(".{}.restoring", WALLET_ID)).exists());
+
+        // A second restore would replace the wallet
+        let err = restore_wallet_bundle(bundle_path, target.path().to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletAlreadyExists);
+    }
+
+    #[test]
+    fn test_restore_points_wallet_at_its_new_location() {
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        let wallet_dir = fake_wallet(source.path());
+        let mnemonic = wallet_dir.join("mnemonic.enc");
+        fs::write(
+            wallet_dir.join("wallet.json"),
+            serde_json::json!({"id": WALLET_ID, "name": "Savings", "encryptedMnemonicPath": mnemonic}).to_string(),
+        )
+        .unwrap();
+        let bundle = out.path().join("wallet.arcsign");
+        write_wallet_bundle(source.path().to_str().unwrap(), WALLET_ID, bundle.to_str().unwrap()).unwrap();
+
+        restore_wallet_bundle(bundle.to_str().unwrap(), target.path().to_str().unwrap()).unwrap();
+
+        // Unlocking reads the seed from this path, which must be on the new USB
+        let restored = target.path().join(WALLET_ID);
+        let wallet: serde_json::Value =
+            serde_json::from_slice(&fs::read(restored.join("wallet.json")).unwrap()).unwrap();
+        let path = PathBuf::from(wallet["encryptedMnemonicPath"].as_str().unwrap());
+        assert_eq!(path, restored.join("mnemonic.enc"));
+        assert_eq!(fs::read(&path).unwrap(), fs::read(&mnemonic).unwrap());
+        assert_eq!(wallet["name"], "Savings");
+    }
+
+    #[test]
+    fn test_tampered_bundle_is_rejected_before_writing() {
+        let source = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        let out = TempDir::new().unwrap();
+        fake_wallet(source.path());
+        let bundle = out.path().join("wallet.arcsign");
+        write_wallet_bundle(source.path().to_str().unwrap(), WALLET_ID, bundle.to_str().unwrap()).unwrap();
+
+        // Flip the encrypted seed's last byte inside the archive
+        let mut archive = tar::Archive::new(fs::File::open(&bundle).unwrap());
+        let mut tampered = tar::Builder::new(Vec::new());
+        for entry in archive.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let name = entry.path().unwrap().into_owned();
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).unwrap();
+            if name == Path::new("wallet/mnemonic.enc") {
+                *data.last_mut().unwrap() ^= 1;
+            }
+            let mut header = entry.header().clone();
+            tampered.append_data(&mut header, &name, data.as_slice()).unwrap();
+        }
+        let tampered_path = out.path().join("tampered.arcsign");
+        fs::File::create(&tampered_path)
+            .unwrap()
+            .write_all(&tampered.into_inner().unwrap())
+            .unwrap();
+
+        let err = restore_wallet_bundle(tampered_path.to_str().unwrap(), target.path().to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::DeserializationError);
+        assert_eq!(fs::read_dir(target.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_entries_outside_the_wallet_are_refused() {
+        assert_eq!(wallet_relative_path(Path::new("wallet/addresses/a.csv")).as_deref(), Some("addresses/a.csv"));
+        assert_eq!(wallet_relative_path(Path::new("wallet/../escape")), None);
+        assert_eq!(wallet_relative_path(Path::new("other/wallet.json")), None);
+        assert_eq!(wallet_relative_path(Path::new("wallet")), None);
+    }
+}
@@ -0,0 +1,669 @@
+/**
+ * Derivation path commands
+ * Feature: User Dashboard for Wallet Management
+ * Shows the BIP44 derivation tree (purpose/coin_type/account/change/index) of a wallet,
+ * parses single paths into their components and finds where an address was derived
+ */
+
+use crate::commands::security::ActivityTracker;
+use crate::commands::usb::normalize_usb_path;
+use crate::commands::wallet::{load_wallet_addresses, AddressCache, UnlockedWallets};
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::ffi::{LazyWalletQueue, WalletBackend};
+use crate::limits::{check_length, MAX_ADDRESS_LEN};
+use crate::models::address::{Address, AddressType};
+use crate::secret::Secret;
+use crate::signing_payload;
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+/// One level of a derivation path (e.g. `44'`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathSegment {
+    /// Child index without the hardened offset
+    pub index: u32,
+
+    /// True if the segment is hardened (`'` or `h` suffix)
+    pub hardened: bool,
+}
+
+/// A BIP44-style path `m/purpose'/coin_type'/account'/change/index`
+/// split into its five levels
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DerivationPath {
+    pub purpose: u32,
+    pub coin_type: u32,
+    pub account: u32,
+    pub change: u32,
+    pub index: u32,
+
+    /// Hardened marker of each level, in path order
+    pub hardened_flags: [bool; 5],
+}
+
+impl DerivationPath {
+    /// Parse a five-level path, accepting `'` and `h`/`H` hardened markers
+    pub fn parse(path: &str) -> AppResult<Self> {
+        // Messages containing '/' are redacted by AppError, so the path goes in details
+        let malformed = |reason: &str| {
+            AppError::with_details(
+                ErrorCode::FfiInvalidInput,
+                format!(
+                    "Malformed derivation path: {} (expected purpose, coin type, account, change and index)",
+                    reason
+                ),
+                path.trim(),
+            )
+        };
+
+        let segments = parse_path_segments(path).ok_or_else(|| malformed("invalid segment"))?;
+        let [purpose, coin_type, account, change, index]: [PathSegment; 5] = segments
+            .try_into()
+            .map_err(|segments: Vec<PathSegment>| {
+                malformed(&format!("{} levels instead of 5", segments.len()))
+            })?;
+
+        Ok(Self {
+            purpose: purpose.index,
+            coin_type: coin_type.index,
+            account: account.index,
+            change: change.index,
+            index: index.index,
+            hardened_flags: [
+                purpose.hardened,
+                coin_type.hardened,
+                account.hardened,
+                change.hardened,
+                index.hardened,
+            ],
+        })
+    }
+}
+
+/// Address attached to a leaf of the derivation tree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DerivationLeaf {
+    /// Blockchain symbol (e.g., "BTC")
+    pub symbol: String,
+
+    /// Human-readable blockchain name
+    pub name: String,
+
+    /// Derived public address
+    pub address: String,
+}
+
+/// Node in the wallet's derivation tree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DerivationNode {
+    /// Segment label as written in the path ("m", "44'", "0")
+    pub segment: String,
+
+    /// Full path from the root to this node (e.g. "m/44'/60'")
+    pub path: String,
+
+    /// Child index (0 for the root)
+    pub index: u32,
+
+    /// True if this segment is hardened
+    pub hardened: bool,
+
+    /// Child nodes, sorted by index
+    pub children: Vec<DerivationNode>,
+
+    /// Addresses derived at exactly this path (leaves only).
+    /// Several chains can share a path, e.g. EVM chains on m/44'/60'/0'/0/0.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub addresses: Vec<DerivationLeaf>,
+}
+
+impl DerivationNode {
+    fn root() -> Self {
+        Self {
+            segment: "m".to_string(),
+            path: "m".to_string(),
+            index: 0,
+            hardened: false,
+            children: Vec::new(),
+            addresses: Vec::new(),
+        }
+    }
+
+    fn child(&self, segment: PathSegment) -> Self {
+        let label = if segment.hardened {
+            format!("{}'", segment.index)
+        } else {
+            segment.index.to_string()
+        };
+
+        Self {
+            path: format!("{}/{}", self.path, label),
+            segment: label,
+            index: segment.index,
+            hardened: segment.hardened,
+            children: Vec::new(),
+            addresses: Vec::new(),
+        }
+    }
+
+    fn sort(&mut self) {
+        self.children.sort_by_key(|child| (child.index, child.hardened));
+        for child in &mut self.children {
+            child.sort();
+        }
+    }
+}
+
+/// Parse a derivation path like `m/44'/0'/0'/0/0` into its segments.
+///
+/// Accepts both `'` and `h`/`H` as hardened markers.
+/// Returns None for malformed paths.
+pub fn parse_path_segments(path: &str) -> Option<Vec<PathSegment>> {
+    let mut parts = path.trim().split('/');
+
+    if parts.next()? != "m" {
+        return None;
+    }
+
+    parts
+        .map(|part| {
+            let (digits, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                Some(digits) => (digits, true),
+                None => (part, false),
+            };
+
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+
+            // Indices must stay below the hardened offset (2^31)
+            let index: u32 = digits.parse().ok()?;
+            if index >= 0x8000_0000 {
+                return None;
+            }
+
+            Some(PathSegment { index, hardened })
+        })
+        .collect()
+}
+
+/// Group addresses into a derivation tree rooted at `m`.
+///
+/// Addresses with malformed paths are skipped (and logged).
+pub fn build_derivation_tree(addresses: &[Address]) -> DerivationNode {
+    let mut root = DerivationNode::root();
+
+    for address in addresses {
+        let segments = match parse_path_segments(&address.derivation_path) {
+            Some(segments) => segments,
+            None => {
+                tracing::warn!(
+                    "Skipping {} in derivation tree: malformed path {}",
+                    address.symbol,
+                    address.derivation_path
+                );
+                continue;
+            }
+        };
+
+        let mut node = &mut root;
+        for segment in segments {
+            let position = node
+                .children
+                .iter()
+                .position(|c| c.index == segment.index && c.hardened == segment.hardened);
+
+            let position = match position {
+                Some(position) => position,
+                None => {
+                    let child = node.child(segment);
+                    node.children.push(child);
+                    node.children.len() - 1
+                }
+            };
+
+            node = &mut node.children[position];
+        }
+
+        node.addresses.push(DerivationLeaf {
+            symbol: address.symbol.clone(),
+            name: address.name.clone(),
+            address: address.address.clone(),
+        });
+    }
+
+    root.sort();
+    root
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivationTreeInput {
+    pub wallet_id: String,
+    pub password: Secret,
+    pub usb_path: String,
+}
+
+/// Get the derivation tree for a wallet's addresses
+/// Reuses the load_addresses data (and its cache) and only reorganizes it
+#[tauri::command]
+pub async fn get_derivation_tree(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+    input: DerivationTreeInput,
+) -> Result<DerivationNode, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    let addresses = load_wallet_addresses(
+        queue,
+        cache.inner(),
+        unlocked.inner(),
+        &input.wallet_id,
+        &input.password,
+        &usb_path,
+        AddressType::default(),
+    )
+    .await
+    .map_err(String::from)?
+    .addresses;
+    Ok(build_derivation_tree(&addresses))
+}
+
+/// Where an address was derived in a wallet, if anywhere
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AddressOwnership {
+    pub belongs: bool,
+
+    /// Blockchain symbol of the matching address (e.g. "BTC")
+    pub chain: Option<String>,
+
+    pub derivation_path: Option<String>,
+}
+
+/// Address types whose derivation sets can contain `address`, most likely
+/// first.
+///
+/// Only a valid Bitcoin native SegWit or P2SH address can come from a BIP84
+/// or BIP49 path. Every address is also searched in the default BIP44 set,
+/// which covers all chains: other chains reuse Bitcoin's prefixes (a
+/// Litecoin P2SH address also starts with '3').
+fn address_types_for(address: &str) -> Vec<AddressType> {
+    let lower = address.to_ascii_lowercase();
+    let bitcoin_segwit = ["bc1", "tb1", "bcrt1"].iter().any(|hrp| lower.starts_with(hrp));
+
+    let segwit_type = match signing_payload::bitcoin_script(address).as_deref() {
+        Some([0x00, 0x14, ..]) if bitcoin_segwit => Some(AddressType::Bip84),
+        Some([0xa9, 0x14, ..]) => Some(AddressType::Bip49),
+        _ => None,
+    };
+    segwit_type.into_iter().chain([AddressType::Bip44]).collect()
+}
+
+/// Find `address` among derived addresses.
+///
+/// Hex (0x) addresses compare case-insensitively, since EIP-55 checksum
+/// casing is optional; other formats are case-sensitive. Chains sharing an
+/// address (EVM networks) report the first match in display order.
+pub(crate) fn find_address(addresses: &[Address], address: &str) -> AddressOwnership {
+    let address = address.trim();
+    let is_hex = address.starts_with("0x") || address.starts_with("0X");
+
+    let matching = addresses
+        .iter()
+        .filter(|a| {
+            if is_hex {
+                a.address.eq_ignore_ascii_case(address)
+            } else {
+                a.address == address
+            }
+        })
+        .min_by_key(|a| a.rank);
+
+    AddressOwnership {
+        belongs: matching.is_some(),
+        chain: matching.map(|a| a.symbol.clone()),
+        derivation_path: matching.map(|a| a.derivation_path.clone()),
+    }
+}
+
+/// Check `address` against the wallet's derived sets of the address types
+/// it could be (each cached, or derived in one pass)
+pub(crate) async fn check_address_ownership(
+    queue: &LazyWalletQueue,
+    cache: &AddressCache,
+    unlocked: &UnlockedWallets,
+    wallet_id: &str,
+    password: &Secret,
+    usb_path: &str,
+    address: &str,
+) -> AppResult<AddressOwnership> {
+    check_length("Address", address, MAX_ADDRESS_LEN)?;
+    if address.trim().is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "Address is required"));
+    }
+
+    for address_type in address_types_for(address.trim()) {
+        let addresses = match load_wallet_addresses(queue, cache, unlocked, wallet_id, password, usb_path, address_type).await {
+            Ok(loaded) => loaded.addresses,
+            // The library can't derive this type, so no address of it is in the wallet
+            Err(e) if e.code == ErrorCode::UnsupportedOperation => continue,
+            Err(e) => return Err(e),
+        };
+        let ownership = find_address(&addresses, address);
+        if ownership.belongs {
+            return Ok(ownership);
+        }
+    }
+
+    Ok(find_address(&[], address))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressOwnershipInput {
+    pub wallet_id: String,
+    pub password: Secret,
+    pub usb_path: String,
+    pub address: String,
+}
+
+/// Check whether an address was derived from this wallet, and where
+#[tauri::command]
+pub async fn address_belongs_to_wallet(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+    input: AddressOwnershipInput,
+) -> Result<AddressOwnership, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    check_address_ownership(
+        queue,
+        cache.inner(),
+        unlocked.inner(),
+        &input.wallet_id,
+        &input.password,
+        &usb_path,
+        &input.address,
+    )
+    .await
+    .map_err(String::from)
+}
+
+/// Split a derivation path into purpose/coin_type/account/change/index
+#[tauri::command]
+pub async fn parse_derivation_path(path: String) -> Result<DerivationPath, String> {
+    DerivationPath::parse(&path).map_err(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::address::{Category, KeyType};
+
+    fn address(symbol: &str, path: &str) -> Address {
+        Address::new(
+            "wallet-1".to_string(),
+            1,
+            symbol.to_string(),
+            symbol.to_lowercase(),
+            0,
+            path.to_string(),
+            format!("{}-address", symbol),
+            Category::Base,
+            KeyType::Secp256k1,
+        )
+    }
+
+    fn leaf_count(node: &DerivationNode) -> usize {
+        if node.children.is_empty() {
+            1
+        } else {
+            node.children.iter().map(leaf_count).sum()
+        }
+    }
+
+    #[test]
+    fn test_parse_path_segments() {
+        let segments = parse_path_segments("m/44'/60'/0'/0/5").unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                PathSegment { index: 44, hardened: true },
+                PathSegment { index: 60, hardened: true },
+                PathSegment { index: 0, hardened: true },
+                PathSegment { index: 0, hardened: false },
+                PathSegment { index: 5, hardened: false },
+            ]
+        );
+
+        // 'h' notation is equivalent to apostrophe
+        assert_eq!(
+            parse_path_segments("m/84h/0h/0h/1/2"),
+            parse_path_segments("m/84'/0'/0'/1/2")
+        );
+    }
+
+    #[test]
+    fn test_parse_path_segments_rejects_malformed() {
+        assert!(parse_path_segments("44'/0'/0'/0/0").is_none());
+        assert!(parse_path_segments("m/44'/x/0").is_none());
+        assert!(parse_path_segments("m//0").is_none());
+        assert!(parse_path_segments("m/2147483648").is_none());
+    }
+
+    #[test]
+    fn test_derivation_path_standard_purposes() {
+        let bip44 = DerivationPath::parse("m/44'/0'/0'/0/0").unwrap();
+        assert_eq!(
+            bip44,
+            DerivationPath {
+                purpose: 44,
+                coin_type: 0,
+                account: 0,
+                change: 0,
+                index: 0,
+                hardened_flags: [true, true, true, false, false],
+            }
+        );
+
+        let bip49 = DerivationPath::parse("m/49'/0'/2'/1/7").unwrap();
+        assert_eq!((bip49.purpose, bip49.account, bip49.change, bip49.index), (49, 2, 1, 7));
+
+        let bip84 = DerivationPath::parse("m/84h/1h/0h/0/3").unwrap();
+        assert_eq!((bip84.purpose, bip84.coin_type, bip84.index), (84, 1, 3));
+        assert_eq!(bip84.hardened_flags, [true, true, true, false, false]);
+    }
+
+    #[test]
+    fn test_derivation_path_malformed() {
+        for path in ["m/44'/0'/0'/0", "m/44'/0'/0'/0/0/0", "44'/0'/0'/0/0", "m/44'/abc/0'/0/0", ""] {
+            let err = DerivationPath::parse(path).unwrap_err();
+            assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+            assert!(err.message.contains("Malformed derivation path"));
+        }
+
+        let err = DerivationPath::parse("m/44'/0'/0'/0").unwrap_err();
+        assert!(err.message.contains("4 levels"));
+    }
+
+    #[test]
+    fn test_tree_leaf_count_and_grouping() {
+        let addresses = vec![
+            address("BTC", "m/44'/0'/0'/0/0"),
+            address("LTC", "m/44'/2'/0'/0/0"),
+            address("BTC2", "m/44'/0'/1'/0/0"),
+            address("BTC3", "m/44'/0'/0'/0/1"),
+        ];
+
+        let tree = build_derivation_tree(&addresses);
+
+        assert_eq!(tree.path, "m");
+        assert_eq!(leaf_count(&tree), 4);
+
+        // Single purpose node with two coin types, sorted by index
+        assert_eq!(tree.children.len(), 1);
+        let purpose = &tree.children[0];
+        assert_eq!(purpose.segment, "44'");
+        let coin_types: Vec<u32> = purpose.children.iter().map(|n| n.index).collect();
+        assert_eq!(coin_types, vec![0, 2]);
+
+        // Bitcoin coin type has two accounts
+        let bitcoin = &purpose.children[0];
+        assert_eq!(bitcoin.path, "m/44'/0'");
+        assert_eq!(bitcoin.children.len(), 2);
+
+        // Address indices under account 0 / change 0 are sorted
+        let external = &bitcoin.children[0].children[0];
+        assert_eq!(external.path, "m/44'/0'/0'/0");
+        let indices: Vec<u32> = external.children.iter().map(|n| n.index).collect();
+        assert_eq!(indices, vec![0, 1]);
+        assert_eq!(external.children[1].addresses[0].symbol, "BTC3");
+    }
+
+    #[test]
+    fn test_tree_shared_path_groups_addresses_on_one_leaf() {
+        let addresses = vec![
+            address("ETH", "m/44'/60'/0'/0/0"),
+            address("MATIC", "m/44'/60'/0'/0/0"),
+            address("BAD", "not-a-path"),
+        ];
+
+        let tree = build_derivation_tree(&addresses);
+
+        assert_eq!(leaf_count(&tree), 1);
+        let leaf = &tree.children[0].children[0].children[0].children[0].children[0];
+        assert_eq!(leaf.path, "m/44'/60'/0'/0/0");
+        assert_eq!(leaf.addresses.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_address_belongs_to_wallet() {
+        use crate::ffi::stub::stub_library;
+        use std::sync::Arc;
+
+        const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        let eth = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+        let mut addresses = vec![address("BTC", "m/44'/0'/0'/0/0"), address("ETH", "m/44'/60'/0'/0/0")];
+        addresses[1].address = eth.to_string();
+        addresses[1].rank = 2;
+
+        // Cached addresses of an unlocked wallet: the (stub) library is never asked to derive
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        let cache = AddressCache::default();
+        cache.0.insert((WALLET_ID.to_string(), AddressType::Bip44), addresses);
+        let password = Secret::new("ValidPassword123".to_string());
+        let unlocked = UnlockedWallets::default();
+        unlocked.mark_unlocked(WALLET_ID, &password, unlocked.lock_generation());
+
+        let found = check_address_ownership(&queue, &cache, &unlocked, WALLET_ID, &password, "/usb", &eth.to_lowercase())
+            .await
+            .unwrap();
+        assert_eq!(
+            found,
+            AddressOwnership {
+                belongs: true,
+                chain: Some("ETH".to_string()),
+                derivation_path: Some("m/44'/60'/0'/0/0".to_string()),
+            }
+        );
+
+        let missing = check_address_ownership(&queue, &cache, &unlocked, WALLET_ID, &password, "/usb", "1BoatSLRHtKNngkdXEeobR76b53LETtpyT")
+            .await
+            .unwrap();
+        assert_eq!(missing, AddressOwnership { belongs: false, chain: None, derivation_path: None });
+    }
+
+    #[tokio::test]
+    async fn test_ownership_searches_bip44_for_segwit_lookalikes() {
+        use crate::ffi::stub::stub_library;
+        use std::sync::Arc;
+
+        const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        // A P2SH address in the BIP44 set, as another chain sharing Bitcoin's prefix
+        let p2sh = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+
+        let mut addresses = vec![address("BTC", "m/44'/0'/0'/0/0"), address("LTC", "m/44'/2'/0'/0/0")];
+        addresses[1].address = p2sh.to_string();
+
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        let cache = AddressCache::default();
+        cache.0.insert((WALLET_ID.to_string(), AddressType::Bip44), addresses);
+        let password = Secret::new("ValidPassword123".to_string());
+        let unlocked = UnlockedWallets::default();
+        unlocked.mark_unlocked(WALLET_ID, &password, unlocked.lock_generation());
+
+        let found = check_address_ownership(&queue, &cache, &unlocked, WALLET_ID, &password, "/usb", p2sh)
+            .await
+            .unwrap();
+        assert_eq!(found.chain.as_deref(), Some("LTC"));
+
+        // BIP84 can't be derived, so a bech32 address is only looked for in BIP44
+        let missing = check_address_ownership(&queue, &cache, &unlocked, WALLET_ID, &password, "/usb", "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+            .await
+            .unwrap();
+        assert!(!missing.belongs);
+    }
+
+    #[tokio::test]
+    async fn test_ownership_rejects_wrong_password_for_cached_wallet() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+        use std::sync::Arc;
+
+        const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        let eth = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"decryption failed"}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        std::fs::create_dir(usb.path().join(WALLET_ID)).unwrap();
+        std::fs::write(usb.path().join(WALLET_ID).join("wallet.json"), "{}").unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        // Warm cache from an earlier load with the right password
+        let mut addresses = vec![address("ETH", "m/44'/60'/0'/0/0")];
+        addresses[0].address = eth.to_string();
+        let cache = AddressCache::default();
+        cache.0.insert((WALLET_ID.to_string(), AddressType::Bip44), addresses);
+        let unlocked = UnlockedWallets::default();
+        unlocked.mark_unlocked(WALLET_ID, &Secret::new("ValidPassword123".to_string()), unlocked.lock_generation());
+
+        let wrong = Secret::new("WrongPassword456".to_string());
+        let err = check_address_ownership(&queue, &cache, &unlocked, WALLET_ID, &wrong, usb_path, eth)
+            .await
+            .unwrap_err();
+        assert_eq!(err.message, "Invalid password");
+    }
+
+    #[test]
+    fn test_address_types_for() {
+        use AddressType::*;
+        assert_eq!(address_types_for("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"), vec![Bip84, Bip44]);
+        assert_eq!(address_types_for("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"), vec![Bip49, Bip44]);
+        assert_eq!(address_types_for("1BoatSLRHtKNngkdXEeobR76b53LETtpyT"), vec![Bip44]);
+        assert_eq!(address_types_for("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"), vec![Bip44]);
+        // Taproot, and a '3' with a bad checksum
+        assert_eq!(
+            address_types_for("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"),
+            vec![Bip44]
+        );
+        assert_eq!(address_types_for("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLz"), vec![Bip44]);
+    }
+}
@@ -0,0 +1,228 @@
+/**
+ * Watch-only descriptor commands
+ * Feature: User Dashboard for Wallet Management
+ * Exports a chain account's public key (Bitcoin output descriptor or xpub)
+ * so a watch-only wallet elsewhere can track balances without private keys
+ *
+ * Depends on an ExportDescriptor export the wallet library doesn't have yet;
+ * `capabilities` reports it as `watchOnlyExport`
+ */
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::usb::normalize_usb_path;
+use crate::commands::wallet::sanitize_wallet_id;
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::ffi::{LazyWalletQueue, WalletBackend};
+use crate::limits::{check_length, MAX_PASSWORD_LEN, MAX_PATH_LEN};
+use crate::secret::{ffi_params, Secret};
+
+/// SLIP-132 prefixes of extended public keys (mainnet and testnet)
+const PUBLIC_KEY_PREFIXES: [&str; 6] = ["xpub", "ypub", "zpub", "tpub", "upub", "vpub"];
+
+/// SLIP-132 prefixes of extended private keys (mainnet and testnet)
+const PRIVATE_KEY_PREFIXES: [&str; 6] = ["xprv", "yprv", "zprv", "tprv", "uprv", "vprv"];
+
+/// Public description of one chain account of a wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchOnlyDescriptor {
+    pub chain_id: String,
+    /// Output descriptor (Bitcoin) or the account xpub (other chains)
+    pub descriptor: String,
+    /// Account-level extended public key
+    pub xpub: String,
+    /// Account derivation path, e.g. "m/84'/0'/0'"
+    pub derivation_path: String,
+}
+
+/// Whether `text` contains an extended private key
+fn contains_private_key(text: &str) -> bool {
+    PRIVATE_KEY_PREFIXES.iter().any(|prefix| text.contains(prefix))
+}
+
+/// Whether `text` starts with an extended public key prefix
+fn is_extended_public_key(text: &str) -> bool {
+    PUBLIC_KEY_PREFIXES.iter().any(|prefix| text.starts_with(prefix))
+}
+
+/// Map an ExportDescriptor FFI error ("CODE: message") to an AppError
+fn descriptor_error(error: String) -> AppError {
+    let code = match error.split_once(':').map(|(code, _)| code.trim()) {
+        // A failed decrypt means the password was wrong
+        Some("INVALID_PASSWORD") | Some("DECRYPTION_ERROR") => ErrorCode::InvalidPassword,
+        Some(code) => AppError::from_ffi_error_code(code),
+        None => ErrorCode::InternalError,
+    };
+
+    let message = match code {
+        ErrorCode::InvalidPassword => "Invalid password",
+        ErrorCode::InternalError => "Failed to export descriptor",
+        _ => AppError::default_message_for_code(code),
+    };
+
+    AppError::with_details(code, message, error)
+}
+
+/// Unlock the wallet and export the public descriptor of `chain_id`.
+///
+/// The library response is checked before it is returned: anything holding
+/// an extended private key, or lacking an extended public key, is rejected.
+pub(crate) async fn export_watch_only(
+    queue: &LazyWalletQueue,
+    wallet_id: &str,
+    password: &Secret,
+    usb_path: &str,
+    chain_id: &str,
+) -> AppResult<WatchOnlyDescriptor> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("Password", password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let params_json = ffi_params(
+        serde_json::json!({
+            "walletId": wallet_id,
+            "usbPath": usb_path,
+            "chainId": chain_id,
+        }),
+        &[("password", password.expose())],
+    )?;
+
+    let result = queue.export_descriptor(params_json).await;
+
+    let data = result.map_err(descriptor_error)?;
+    let field = |name: &str| {
+        data.get(name)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorCode::DeserializationError,
+                    format!("Missing {} in FFI response", name),
+                )
+            })
+    };
+
+    let descriptor = WatchOnlyDescriptor {
+        chain_id: chain_id.to_string(),
+        descriptor: field("descriptor")?,
+        xpub: field("xpub")?,
+        derivation_path: field("derivationPath")?,
+    };
+
+    // Never hand private key material to a watch-only export, whatever the library returned
+    if contains_private_key(&descriptor.descriptor) || contains_private_key(&descriptor.xpub) {
+        tracing::error!("ExportDescriptor returned private key material for {}", chain_id);
+        return Err(AppError::new(
+            ErrorCode::InternalError,
+            "Descriptor contained private key material and was discarded",
+        ));
+    }
+    if !is_extended_public_key(&descriptor.xpub) || !descriptor.descriptor.contains(&descriptor.xpub) {
+        return Err(AppError::new(
+            ErrorCode::DeserializationError,
+            "Descriptor is missing the account extended public key",
+        ));
+    }
+
+    Ok(descriptor)
+}
+
+/// Export a watch-only descriptor (no private keys) for one chain
+#[tauri::command]
+pub async fn export_descriptor(
+    backend: State<'_, WalletBackend>,
+    wallet_id: String,
+    password: Secret,
+    usb_path: String,
+    chain_id: String,
+) -> Result<WatchOnlyDescriptor, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    tracing::info!("export_descriptor: wallet_id={}, chain_id={}", wallet_id, chain_id);
+
+    export_watch_only(queue, &wallet_id, &password, &usb_path, &chain_id)
+        .await
+        .map_err(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::stub::{ffi_string, stub_library};
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+    use std::sync::Arc;
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+    const PASSWORD: &str = "ValidPassword123";
+    const XPUB: &str = "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V";
+
+    unsafe extern "C" fn export_ok(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+
+        if params["password"] != PASSWORD {
+            return ffi_string(
+                r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"decryption failed"}}"#,
+            );
+        }
+        ffi_string(&format!(
+            r#"{{"success":true,"data":{{"descriptor":"wpkh([d34db33f/84h/0h/0h]{}/0/*)#8nxqv4s0","xpub":"{}","derivationPath":"m/84'/0'/0'"}}}}"#,
+            XPUB, XPUB
+        ))
+    }
+
+    /// A broken library build that leaks the account xprv
+    unsafe extern "C" fn export_leaks_xprv(_params: *const c_char) -> *mut c_char {
+        ffi_string(&format!(
+            r#"{{"success":true,"data":{{"descriptor":"wpkh(xprv9s21ZrQH143K/0/*)","xpub":"{}","derivationPath":"m/84'/0'/0'"}}}}"#,
+            XPUB
+        ))
+    }
+
+    fn queue(export: unsafe extern "C" fn(*const c_char) -> *mut c_char) -> LazyWalletQueue {
+        let mut library = stub_library();
+        library.export_descriptor = Some(export);
+        LazyWalletQueue::new(Arc::new(library))
+    }
+
+    #[tokio::test]
+    async fn test_export_descriptor_is_public_only() {
+        let password = Secret::new(PASSWORD.to_string());
+
+        let descriptor = export_watch_only(&queue(export_ok), WALLET_ID, &password, "/usb", "bitcoin")
+            .await
+            .unwrap();
+
+        assert!(descriptor.xpub.starts_with("xpub"));
+        assert!(descriptor.descriptor.contains(XPUB));
+        assert!(!descriptor.descriptor.contains("xprv"));
+        assert_eq!(descriptor.derivation_path, "m/84'/0'/0'");
+    }
+
+    #[tokio::test]
+    async fn test_export_descriptor_rejects_private_key_material() {
+        let password = Secret::new(PASSWORD.to_string());
+
+        let err = export_watch_only(&queue(export_leaks_xprv), WALLET_ID, &password, "/usb", "bitcoin")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::InternalError);
+        assert!(!err.message.contains("xprv9s21"));
+    }
+
+    #[tokio::test]
+    async fn test_export_descriptor_wrong_password() {
+        let password = Secret::new("WrongPassword456".to_string());
+
+        let err = export_watch_only(&queue(export_ok), WALLET_ID, &password, "/usb", "bitcoin")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::InvalidPassword);
+    }
+}
@@ -0,0 +1,634 @@
+/**
+ * Diagnostics commands
+ * Feature: User Dashboard for Wallet Management
+ * Exposes FFI queue metrics for performance monitoring and benchmarking,
+ * a settings summary for support reports, an on-demand library reload,
+ * per-chain support checks, read and address cache statistics, a clock
+ * skew check, a manifest of the optional capabilities of this build and the
+ * level of log lines streamed to the frontend
+ */
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::State;
+
+use crate::cache::{CacheStats, ReadCache, TtlCache};
+use crate::commands::export::is_export_format_supported;
+use crate::commands::security::ActivityTracker;
+use crate::commands::wallet::{AddressCache, AddressCacheDebug, UnlockedWallets};
+use crate::error::{AppError, AppResult, Error, ErrorCode};
+use crate::ffi::queue::{QueueMetricsSnapshot, WalletBackend};
+use crate::ffi::types::VersionInfo;
+use crate::ffi::{LazyWalletQueue, WalletLibrary};
+use crate::log_stream::LogForwarder;
+use crate::models::address::ExportFormat;
+
+/// Runtime settings summary for support reports.
+///
+/// Only non-sensitive values belong here: users paste this into issues.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostics {
+    pub ffi_enabled: bool,
+    /// Path the wallet library was loaded from
+    pub library_path: Option<String>,
+    pub library_version: Option<String>,
+    /// Log output format ("text" for the default fmt subscriber)
+    pub log_format: String,
+    /// Idle time before unlocked wallets are locked; None when auto-lock is off
+    pub auto_lock_secs: Option<u64>,
+    /// Paths searched for the wallet library, in order
+    pub search_paths: Vec<String>,
+    pub os: String,
+    pub arch: String,
+}
+
+/// Build diagnostics from the backend state and the loaded library
+pub(crate) async fn collect_diagnostics(backend: &WalletBackend, activity: &ActivityTracker) -> Diagnostics {
+    let queue = backend.queue().ok();
+
+    let library_version = match queue {
+        Some(queue) => match queue.version_info().await {
+            Ok(info) => Some(info.version),
+            Err(e) => {
+                tracing::warn!("get_diagnostics: version lookup failed: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    Diagnostics {
+        ffi_enabled: queue.is_some(),
+        library_path: queue.and_then(|q| q.library().path().map(str::to_string)),
+        library_version,
+        log_format: "text".to_string(),
+        auto_lock_secs: activity.auto_lock().map(|timeout| timeout.as_secs()),
+        search_paths: WalletLibrary::search_paths(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+    }
+}
+
+/// Get queue metrics, broken down by operation type
+#[tauri::command]
+pub async fn get_queue_metrics(backend: State<'_, WalletBackend>) -> Result<QueueMetricsSnapshot, Error> {
+    Ok(backend.queue()?.metrics_snapshot())
+}
+
+/// Reset queue metrics (e.g. before a benchmarking run)
+#[tauri::command]
+pub async fn reset_queue_metrics(backend: State<'_, WalletBackend>) -> Result<(), Error> {
+    backend.queue()?.reset_metrics();
+    tracing::info!("Queue metrics reset");
+    Ok(())
+}
+
+/// Get a settings summary (library, platform) for support reports
+#[tauri::command]
+pub async fn get_diagnostics(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+) -> Result<Diagnostics, Error> {
+    Ok(collect_diagnostics(&backend, &activity).await)
+}
+
+/// The wallet library now in use
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryInfo {
+    /// Path the library was loaded from
+    pub path: Option<String>,
+    pub version: String,
+    pub build_date: Option<String>,
+    pub git_commit: Option<String>,
+    pub features: Vec<String>,
+    /// Chain IDs the library supports (empty if it doesn't report them)
+    pub supported_chains: Vec<String>,
+}
+
+impl LibraryInfo {
+    fn new(path: Option<String>, version_info: VersionInfo) -> Self {
+        Self {
+            path,
+            version: version_info.version,
+            build_date: version_info.build_date,
+            git_commit: version_info.git_commit,
+            features: version_info.features,
+            supported_chains: version_info.supported_chains,
+        }
+    }
+}
+
+/// Load a library with `load`, check its version and switch `queue` to it.
+///
+/// Nothing changes unless both the load and the version check succeed.
+pub(crate) async fn reload_into(
+    queue: &LazyWalletQueue,
+    load: impl FnOnce() -> Result<WalletLibrary, String>,
+) -> AppResult<LibraryInfo> {
+    let library = load().map_err(|e| {
+        AppError::with_details(ErrorCode::CliNotFound, "Wallet library could not be loaded", e)
+    })?;
+
+    // T040: Same compatibility check as startup
+    let version_info = library.check_version().map_err(|e| {
+        AppError::with_details(
+            ErrorCode::InternalError,
+            "Could not verify the wallet library version. The library may be corrupted.",
+            e,
+        )
+    })?;
+
+    let info = LibraryInfo::new(library.path().map(str::to_string), version_info);
+    queue
+        .swap_library(Arc::new(library))
+        .await
+        .map_err(|e| AppError::with_details(ErrorCode::InternalError, "Failed to switch wallet library", e))?;
+
+    tracing::info!("Reloaded wallet library {}", info.version);
+    Ok(info)
+}
+
+/// Retry the startup library load, e.g. after the user repaired the install
+#[tauri::command]
+pub async fn reload_library(
+    backend: State<'_, WalletBackend>,
+    read_cache: State<'_, ReadCache>,
+) -> Result<LibraryInfo, Error> {
+    let queue = backend.queue()?;
+    let info = reload_into(queue, WalletLibrary::load).await?;
+    read_cache.library_info.insert(LIBRARY_INFO_KEY, info.clone());
+    Ok(info)
+}
+
+/// The library info cache holds a single entry
+const LIBRARY_INFO_KEY: &str = "current";
+
+/// Info about the library in use, served from `cache` while fresh
+pub(crate) async fn cached_library_info(
+    queue: &LazyWalletQueue,
+    cache: &TtlCache<LibraryInfo>,
+) -> AppResult<LibraryInfo> {
+    if let Some(info) = cache.get(LIBRARY_INFO_KEY) {
+        return Ok(info);
+    }
+
+    let version_info = queue.version_info().await.map_err(|e| {
+        AppError::with_details(ErrorCode::InternalError, "Failed to read wallet library version", e)
+    })?;
+    let info = LibraryInfo::new(queue.library().path().map(str::to_string), version_info);
+
+    cache.insert(LIBRARY_INFO_KEY, info.clone());
+    Ok(info)
+}
+
+/// Get the path, version and capabilities of the wallet library in use
+#[tauri::command]
+pub async fn get_library_info(
+    backend: State<'_, WalletBackend>,
+    read_cache: State<'_, ReadCache>,
+) -> Result<LibraryInfo, Error> {
+    cached_library_info(backend.queue()?, &read_cache.library_info).await
+}
+
+/// Chain IDs the wallet library supports (empty if it doesn't report them)
+#[tauri::command]
+pub async fn list_supported_chains(
+    backend: State<'_, WalletBackend>,
+    read_cache: State<'_, ReadCache>,
+) -> Result<Vec<String>, Error> {
+    Ok(cached_library_info(backend.queue()?, &read_cache.library_info)
+        .await?
+        .supported_chains)
+}
+
+/// Hit/miss counts of the read caches, per namespace
+#[tauri::command]
+pub async fn cache_stats(read_cache: State<'_, ReadCache>) -> Result<Vec<CacheStats>, Error> {
+    Ok(read_cache.stats())
+}
+
+/// Entry count, approximate size and age of each AddressCache entry, to
+/// diagnose memory growth and confirm TTL/LRU eviction (no address contents)
+#[tauri::command]
+pub async fn cache_debug(
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+) -> Result<AddressCacheDebug, Error> {
+    Ok(cache.debug_report(unlocked.inner()))
+}
+
+/// Whether the loaded library supports a chain
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainSupport {
+    pub chain_id: String,
+    /// None if the library doesn't report its chains
+    pub supported: Option<bool>,
+    /// Closest supported chain ID when unsupported (e.g. a typo)
+    pub suggestion: Option<String>,
+}
+
+/// Levenshtein distance between two ASCII-case-folded strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_ascii_lowercase().chars().collect();
+    let b: Vec<char> = b.to_ascii_lowercase().chars().collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Check `chain_id` against the chains the library reports.
+///
+/// Support is unknown (None) for libraries that don't report their chains.
+pub(crate) fn chain_support(chain_id: &str, supported_chains: &[String]) -> ChainSupport {
+    let supported = (!supported_chains.is_empty()).then(|| supported_chains.iter().any(|c| c == chain_id));
+
+    let suggestion = if supported != Some(false) {
+        None
+    } else {
+        supported_chains
+            .iter()
+            .min_by_key(|c| edit_distance(chain_id, c))
+            .cloned()
+    };
+
+    ChainSupport {
+        chain_id: chain_id.to_string(),
+        supported,
+        suggestion,
+    }
+}
+
+/// Check whether the loaded library supports `chain_id`, so the UI can
+/// disable unsupported chains up front
+#[tauri::command]
+pub async fn is_chain_supported(
+    backend: State<'_, WalletBackend>,
+    chain_id: String,
+) -> Result<ChainSupport, Error> {
+    let version_info = backend.queue()?.version_info().await.map_err(|e| {
+        AppError::with_details(ErrorCode::InternalError, "Failed to read wallet library version", e)
+    })?;
+
+    Ok(chain_support(&chain_id, &version_info.supported_chains))
+}
+
+/// Optional features available with this build and the loaded library.
+///
+/// Library features follow the optional FFI symbols that resolved at load,
+/// or the features GetVersion reports where a symbol doesn't tell;
+/// everything is false when no library is loaded.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /// Build, sign and broadcast (the library reports "transactions")
+    pub transactions: bool,
+    pub transaction_validation: bool,
+    /// Both SignMessage and VerifyMessage
+    pub message_signing: bool,
+    /// Create and import report per-address derivation progress
+    pub address_progress: bool,
+    pub mnemonic_comparison: bool,
+    pub password_change: bool,
+    pub watch_only_export: bool,
+    pub encrypted_export: bool,
+    /// The library reports a test network, or doesn't restrict chains
+    pub testnet: bool,
+    /// Native SegWit (BIP84) address derivation (the library reports "bip84")
+    pub bip84: bool,
+}
+
+/// Whether a chain ID names a test network
+fn is_testnet_chain(chain_id: &str) -> bool {
+    ["testnet", "regtest", "sepolia", "goerli"]
+        .iter()
+        .any(|network| chain_id.contains(network))
+}
+
+/// Capabilities of `library`, given its GetVersion response (None if that
+/// failed)
+pub(crate) fn library_capabilities(library: &WalletLibrary, version_info: Option<&VersionInfo>) -> Capabilities {
+    let features = version_info.map(|info| info.features.as_slice()).unwrap_or_default();
+    let supported_chains = version_info.map(|info| info.supported_chains.as_slice()).unwrap_or_default();
+    let has_feature = |name: &str| features.iter().any(|feature| feature == name);
+
+    Capabilities {
+        transactions: has_feature("transactions"),
+        transaction_validation: library.validate_transaction.is_some(),
+        message_signing: library.sign_message.is_some() && library.verify_message.is_some(),
+        address_progress: library.create_wallet_with_progress.is_some() && library.import_wallet_with_progress.is_some(),
+        mnemonic_comparison: library.derive_first_address.is_some(),
+        password_change: library.change_wallet_password.is_some(),
+        watch_only_export: library.export_descriptor.is_some(),
+        encrypted_export: is_export_format_supported(ExportFormat::EncryptedJson),
+        testnet: supported_chains.is_empty() || supported_chains.iter().any(|c| is_testnet_chain(c)),
+        bip84: has_feature("bip84"),
+    }
+}
+
+/// Report which optional features the frontend can offer
+#[tauri::command]
+pub async fn capabilities(backend: State<'_, WalletBackend>) -> Result<Capabilities, Error> {
+    let Ok(queue) = backend.queue() else {
+        return Ok(Capabilities::default());
+    };
+
+    let version_info = queue
+        .version_info()
+        .await
+        .inspect_err(|e| tracing::warn!("capabilities: version lookup failed: {}", e))
+        .ok();
+
+    Ok(library_capabilities(&queue.library(), version_info.as_ref()))
+}
+
+/// Skew (seconds, either direction) above which `check_clock` warns
+pub const CLOCK_SKEW_WARNING_SECS: i64 = 120;
+
+/// Local clock compared against a trusted reference time
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClockCheck {
+    /// Local clock reading (RFC 3339, UTC)
+    pub local_time: String,
+    /// Reference time compared against (RFC 3339, UTC), if one was given
+    pub trusted_time: Option<String>,
+    /// Local minus trusted time; positive when the local clock is ahead
+    pub skew_seconds: Option<i64>,
+    /// Whether the skew exceeds `CLOCK_SKEW_WARNING_SECS`
+    pub warning: bool,
+}
+
+/// Compare `local` against an RFC 3339 `trusted_time`.
+///
+/// Without a reference there is nothing to compare, so no skew is reported.
+pub(crate) fn clock_skew(local: DateTime<Utc>, trusted_time: Option<&str>) -> AppResult<ClockCheck> {
+    let trusted = trusted_time
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            DateTime::parse_from_rfc3339(t)
+                .map(|time| time.with_timezone(&Utc))
+                .map_err(|e| {
+                    AppError::with_details(
+                        ErrorCode::FfiInvalidInput,
+                        "Trusted time must be an RFC 3339 timestamp",
+                        e.to_string(),
+                    )
+                })
+        })
+        .transpose()?;
+
+    let skew_seconds = trusted.map(|trusted| (local - trusted).num_seconds());
+
+    Ok(ClockCheck {
+        local_time: local.to_rfc3339(),
+        trusted_time: trusted.map(|t| t.to_rfc3339()),
+        skew_seconds,
+        warning: skew_seconds.is_some_and(|skew| skew.abs() > CLOCK_SKEW_WARNING_SECS),
+    })
+}
+
+/// Check the system clock against a caller-supplied trusted time, since a
+/// wrong clock skews wallet timestamps and export filenames
+#[tauri::command]
+pub async fn check_clock(trusted_time: Option<String>) -> Result<ClockCheck, Error> {
+    let check = clock_skew(Utc::now(), trusted_time.as_deref())?;
+    if check.warning {
+        tracing::warn!("System clock is off by {:?} seconds", check.skew_seconds);
+    }
+    Ok(check)
+}
+
+/// Parse a log stream level: a `tracing` level name, or "off"
+fn parse_log_level(level: &str) -> AppResult<Option<tracing::Level>> {
+    if level.trim().eq_ignore_ascii_case("off") {
+        return Ok(None);
+    }
+    level.trim().parse().map(Some).map_err(|_| {
+        AppError::with_details(
+            ErrorCode::FfiInvalidInput,
+            "Unknown log level (expected error, warn, info, debug, trace or off)",
+            level,
+        )
+    })
+}
+
+/// Choose which log lines reach the frontend as `app-log` events
+#[tauri::command]
+pub async fn set_log_stream_level(level: String, forwarder: State<'_, LogForwarder>) -> Result<(), Error> {
+    forwarder.set_level(parse_log_level(&level)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::stub::{ffi_string, stub_library};
+    use std::os::raw::c_char;
+
+    fn assert_no_secret_fields(value: &serde_json::Value) {
+        const SECRET_WORDS: [&str; 6] = ["password", "mnemonic", "secret", "key", "token", "passphrase"];
+
+        if let Some(object) = value.as_object() {
+            for (name, field) in object {
+                let name = name.to_lowercase();
+                assert!(
+                    !SECRET_WORDS.iter().any(|word| name.contains(word)),
+                    "secret-looking field in diagnostics: {}",
+                    name
+                );
+                assert_no_secret_fields(field);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_report_platform_without_secrets() {
+        let backend = WalletBackend::new(Some(LazyWalletQueue::new(Arc::new(stub_library()))));
+
+        let diagnostics = collect_diagnostics(&backend, &ActivityTracker::new()).await;
+        assert!(diagnostics.ffi_enabled);
+        assert_eq!(diagnostics.library_version.as_deref(), Some("0.0.0-stub"));
+        assert_eq!(diagnostics.auto_lock_secs, Some(15 * 60));
+
+        let json = serde_json::to_value(&diagnostics).unwrap();
+        assert_eq!(json["os"], std::env::consts::OS);
+        assert_eq!(json["arch"], std::env::consts::ARCH);
+        assert_no_secret_fields(&json);
+    }
+
+    unsafe extern "C" fn get_version_v2() -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"version":"0.2.0","gitCommit":"abc123"}}"#)
+    }
+
+    async fn version(queue: &LazyWalletQueue) -> serde_json::Value {
+        queue.get_version().await.unwrap()["version"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_reload_library_switches_queue() {
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        assert_eq!(version(&queue).await, "0.0.0-stub");
+
+        let info = reload_into(&queue, || {
+            let mut library = stub_library();
+            library.get_version = get_version_v2;
+            Ok(library)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(info.version, "0.2.0");
+        assert_eq!(info.git_commit.as_deref(), Some("abc123"));
+        assert_eq!(version(&queue).await, "0.2.0");
+    }
+
+    #[tokio::test]
+    async fn test_reload_library_failure_keeps_current_library() {
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        assert_eq!(version(&queue).await, "0.0.0-stub");
+
+        let err = reload_into(&queue, || {
+            WalletLibrary::load_from(&["/nonexistent/arcsign/libarcsign.so".to_string()])
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::CliNotFound);
+        assert!(err.details.unwrap().contains("/nonexistent/arcsign"));
+        assert_eq!(version(&queue).await, "0.0.0-stub");
+    }
+
+    fn chains(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_chain_support_supported_chain() {
+        let support = chain_support("ethereum", &chains(&["bitcoin", "ethereum", "solana"]));
+
+        assert_eq!(support.supported, Some(true));
+        assert_eq!(support.suggestion, None);
+    }
+
+    #[test]
+    fn test_chain_support_suggests_closest_chain() {
+        let support = chain_support("etherium", &chains(&["bitcoin", "ethereum", "solana"]));
+
+        assert_eq!(support.supported, Some(false));
+        assert_eq!(support.suggestion.as_deref(), Some("ethereum"));
+    }
+
+    #[test]
+    fn test_chain_support_without_reported_chains() {
+        let support = chain_support("dogecoin", &[]);
+        assert_eq!(support.supported, None);
+        assert_eq!(support.suggestion, None);
+    }
+
+    #[tokio::test]
+    async fn test_version_info_reports_supported_chains() {
+        unsafe extern "C" fn get_version_with_chains() -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"version":"0.2.0","supportedChains":["bitcoin","ethereum"]}}"#)
+        }
+
+        let mut library = stub_library();
+        library.get_version = get_version_with_chains;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let info = queue.version_info().await.unwrap();
+        assert_eq!(info.supported_chains, chains(&["bitcoin", "ethereum"]));
+        assert_eq!(chain_support("solana", &info.supported_chains).supported, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_without_ffi() {
+        let activity = ActivityTracker::new();
+        activity.set_auto_lock(None);
+        let diagnostics = collect_diagnostics(&WalletBackend::new(None), &activity).await;
+
+        assert!(!diagnostics.ffi_enabled);
+        assert_eq!(diagnostics.library_path, None);
+        assert_eq!(diagnostics.library_version, None);
+        assert_eq!(diagnostics.auto_lock_secs, None);
+        assert!(!diagnostics.search_paths.is_empty());
+    }
+
+    #[test]
+    fn test_clock_skew_against_reference() {
+        let local = DateTime::parse_from_rfc3339("2025-10-17T14:30:25Z").unwrap().with_timezone(&Utc);
+
+        let check = clock_skew(local, Some("2025-10-17T14:30:00Z")).unwrap();
+        assert_eq!(check.skew_seconds, Some(25));
+        assert!(!check.warning);
+
+        // Local clock ten minutes behind, reference given with an offset
+        let check = clock_skew(local, Some("2025-10-17T16:40:25+02:00")).unwrap();
+        assert_eq!(check.skew_seconds, Some(-600));
+        assert_eq!(check.trusted_time.as_deref(), Some("2025-10-17T14:40:25+00:00"));
+        assert!(check.warning);
+
+        let check = clock_skew(local, None).unwrap();
+        assert_eq!(check.skew_seconds, None);
+        assert!(!check.warning);
+
+        let err = clock_skew(local, Some("yesterday")).unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[test]
+    fn test_capabilities_follow_resolved_symbols() {
+        unsafe extern "C" fn unused(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{}}"#)
+        }
+
+        let mut library = stub_library();
+        library.sign_message = Some(unused);
+        library.derive_first_address = Some(unused);
+
+        let version_info = |features: &[&str], chains: &[&str]| VersionInfo {
+            version: "0.2.0".to_string(),
+            build_date: None,
+            git_commit: None,
+            features: features.iter().map(|f| f.to_string()).collect(),
+            supported_chains: chains.iter().map(|c| c.to_string()).collect(),
+        };
+
+        let info = version_info(&["transactions"], &["bitcoin", "ethereum"]);
+        let capabilities = library_capabilities(&library, Some(&info));
+        assert!(capabilities.transactions);
+        assert!(capabilities.mnemonic_comparison);
+        // Only BIP44 unless the library says otherwise
+        assert!(!capabilities.bip84);
+        // VerifyMessage is missing, so messages can't round-trip
+        assert!(!capabilities.message_signing);
+        assert!(!capabilities.transaction_validation);
+        assert!(!capabilities.watch_only_export);
+        assert!(!capabilities.encrypted_export);
+        assert!(!capabilities.testnet);
+
+        library.verify_message = Some(unused);
+        let info = version_info(&["transactions", "bip84"], &["bitcoin-testnet"]);
+        let capabilities = library_capabilities(&library, Some(&info));
+        assert!(capabilities.message_signing);
+        assert!(capabilities.testnet);
+        assert!(capabilities.bip84);
+
+        // A library that doesn't report its features offers none of them
+        let capabilities = library_capabilities(&library, Some(&version_info(&[], &[])));
+        assert!(!capabilities.transactions);
+        assert!(!library_capabilities(&library, None).transactions);
+    }
+}
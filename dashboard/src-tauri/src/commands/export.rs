@@ -0,0 +1,1625 @@
+/**
+ * Address export file commands
+ * Feature: User Dashboard for Wallet Management
+ * Exports live in {usb_path}/{wallet_id}/addresses/addresses-{YYYYMMDD-HHMMSS}.{ext}
+ */
+
+use crate::commands::security::ActivityTracker;
+use crate::commands::usb::{is_path_writable, normalize_usb_path, validate_usb_path};
+use crate::commands::wallet::{load_wallet_addresses, sanitize_wallet_id, AddressCache, LoadedAddresses, UnlockedWallets};
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::ffi::{LazyWalletQueue, WalletBackend};
+use crate::fs_utils::{long_path, write_atomic, write_new_atomic_with};
+use crate::limits::{check_length, MAX_PATH_LEN};
+use crate::models::address::{Address, AddressType, ExportFormat, ExportOptions, ExportResponse};
+use crate::models::wallet::WalletFileMetadata;
+use crate::secret::Secret;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use tauri::State;
+
+/// Directory (under the wallet directory) that holds address exports
+pub const EXPORT_DIR_NAME: &str = "addresses";
+
+/// Filename prefix of address exports
+const EXPORT_FILE_PREFIX: &str = "addresses-";
+
+/// Timestamp format embedded in export filenames (UTC)
+const EXPORT_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Suffix appended to the extension of compressed exports
+const GZIP_SUFFIX: &str = ".gz";
+
+/// Suffix of the checksum sidecar written next to an export
+pub const CHECKSUM_SUFFIX: &str = ".sha256";
+
+/// CSV header row of address exports
+const CSV_HEADER: &str = "Rank,Symbol,Name,Category,Coin Type,Key Type,Derivation Path,Address,Error";
+
+/// A previously written address export
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExportFileInfo {
+    /// Full path to the export file
+    pub path: String,
+
+    /// Export format, from the file extension ("json", "json.gz", "csv", "pdf", "enc")
+    pub format: String,
+
+    /// Export timestamp parsed from the filename (ISO 8601, UTC)
+    pub created_at: String,
+
+    /// File size in bytes
+    pub size_bytes: u64,
+
+    /// Leftover of an interrupted write (USB pulled, crash); not a usable
+    /// export. Removed by `cleanup_partial_exports`.
+    #[serde(default)]
+    pub incomplete: bool,
+}
+
+/// Filename extension of an export, including the gzip suffix when compressed
+fn export_extension(format: ExportFormat, options: ExportOptions) -> String {
+    if options.compress {
+        format!("{}{}", format.file_extension(), GZIP_SUFFIX)
+    } else {
+        format.file_extension().to_string()
+    }
+}
+
+/// Parse `addresses-YYYYMMDD-HHMMSS.ext` into (timestamp, format).
+/// Returns None for files that aren't address exports.
+fn parse_export_filename(file_name: &str) -> Option<(NaiveDateTime, String)> {
+    let stem_and_ext = file_name.strip_prefix(EXPORT_FILE_PREFIX)?;
+    // The timestamp has no dots, so everything after the first is the extension
+    let (stem, extension) = stem_and_ext.split_once('.')?;
+
+    let format = extension.to_ascii_lowercase();
+    let is_export = ExportFormat::ALL
+        .iter()
+        .any(|f| export_extension(*f, ExportOptions::default()) == format)
+        || format == export_extension(ExportFormat::Json, ExportOptions { compress: true, ..Default::default() });
+    if !is_export {
+        return None;
+    }
+
+    let timestamp = NaiveDateTime::parse_from_str(stem, EXPORT_TIMESTAMP_FORMAT).ok()?;
+    Some((timestamp, format))
+}
+
+/// Suffix of a partially written file some tools leave behind
+const PARTIAL_SUFFIX: &str = ".partial";
+
+/// Name of the export an interrupted write was producing, if `file_name` is
+/// such a leftover: `<name>.partial`, or a `write_atomic` temp file
+/// `.<name>.<pid>-<n>.tmp`. Temp files of this process are skipped, as their
+/// write may still be running.
+fn partial_export_target(file_name: &str) -> Option<&str> {
+    let target = match file_name.strip_suffix(PARTIAL_SUFFIX) {
+        Some(target) => target,
+        None => {
+            let (target, writer) = file_name.strip_prefix('.')?.strip_suffix(".tmp")?.rsplit_once('.')?;
+            let (pid, sequence) = writer.split_once('-')?;
+            if pid.parse::<u32>().ok()? == std::process::id() || sequence.parse::<usize>().is_err() {
+                return None;
+            }
+            target
+        }
+    };
+
+    let export_name = target.strip_suffix(CHECKSUM_SUFFIX).unwrap_or(target);
+    parse_export_filename(export_name).map(|_| target)
+}
+
+/// Scan a wallet's export directory, newest first.
+/// A missing directory means nothing has been exported yet.
+pub fn scan_exports(usb_path: &str, wallet_id: &str) -> AppResult<Vec<ExportFileInfo>> {
+    sanitize_wallet_id(wallet_id)?;
+
+    let export_dir = Path::new(usb_path).join(wallet_id).join(EXPORT_DIR_NAME);
+
+    if !long_path(&export_dir).is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(long_path(&export_dir)).map_err(|e| {
+        AppError::with_details(
+            ErrorCode::ExportFailed,
+            "Failed to read export directory",
+            e.to_string(),
+        )
+    })?;
+
+    let mut exports: Vec<(NaiveDateTime, ExportFileInfo)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+
+            let file_name = entry.file_name();
+            let file_name_str = file_name.to_str()?;
+            let partial_target = partial_export_target(file_name_str);
+            let export_name = partial_target.map_or(file_name_str, |target| {
+                target.strip_suffix(CHECKSUM_SUFFIX).unwrap_or(target)
+            });
+            let (timestamp, format) = parse_export_filename(export_name)?;
+
+            Some((
+                timestamp,
+                ExportFileInfo {
+                    path: export_dir.join(&file_name).to_string_lossy().into_owned(),
+                    format,
+                    created_at: timestamp.and_utc().to_rfc3339(),
+                    size_bytes: metadata.len(),
+                    // An empty export is a name claimed by a write that never finished
+                    incomplete: partial_target.is_some() || metadata.len() == 0,
+                },
+            ))
+        })
+        .collect();
+
+    // Newest first; same-second exports are ordered by path for stability
+    exports.sort_by(|(a_time, a), (b_time, b)| b_time.cmp(a_time).then_with(|| a.path.cmp(&b.path)));
+
+    Ok(exports.into_iter().map(|(_, info)| info).collect())
+}
+
+/// Delete the leftovers of interrupted export writes from a wallet's export
+/// directory; completed exports are never touched. Returns the removed paths.
+pub fn remove_partial_exports(usb_path: &str, wallet_id: &str) -> AppResult<Vec<String>> {
+    let mut removed = Vec::new();
+    for export in scan_exports(usb_path, wallet_id)?.into_iter().filter(|export| export.incomplete) {
+        match fs::remove_file(long_path(Path::new(&export.path))) {
+            Ok(()) => removed.push(export.path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(AppError::with_details(
+                    ErrorCode::ExportFailed,
+                    "Failed to delete incomplete export",
+                    e.to_string(),
+                ))
+            }
+        }
+    }
+
+    if !removed.is_empty() {
+        tracing::info!("Removed {} incomplete export file(s) for wallet {}", removed.len(), wallet_id);
+    }
+    Ok(removed)
+}
+
+/// Resolve `file_path` and confirm it is an export file at
+/// `{usb_path}/{wallet_id}/addresses/addresses-*.{ext}`.
+///
+/// Both paths are canonicalized first, so `..` segments and symlinks can't
+/// point a delete or read outside the USB's export directories.
+fn resolve_export_path(usb_path: &str, file_path: &str) -> AppResult<PathBuf> {
+    let outside_root = || {
+        AppError::new(
+            ErrorCode::ExportFailed,
+            "Only address export files on the wallet USB can be used here",
+        )
+    };
+
+    let usb_root = fs::canonicalize(usb_path).map_err(|e| {
+        AppError::with_details(ErrorCode::UsbNotFound, "USB drive not accessible", e.to_string())
+    })?;
+    let target = fs::canonicalize(file_path).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Export file not found", e.to_string())
+    })?;
+
+    let relative = target.strip_prefix(&usb_root).map_err(|_| outside_root())?;
+    let components: Vec<&str> = relative
+        .components()
+        .map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(outside_root)?;
+
+    // Exactly {wallet_id}/addresses/{export file}
+    match components.as_slice() {
+        [wallet_id, dir, file_name]
+            if sanitize_wallet_id(wallet_id).is_ok()
+                && *dir == EXPORT_DIR_NAME
+                && parse_export_filename(file_name).is_some() =>
+        {
+            Ok(target)
+        }
+        _ => Err(outside_root()),
+    }
+}
+
+/// Delete a single address export after confirming it lives in a wallet's
+/// export directory on the given USB
+pub fn remove_export(usb_path: &str, file_path: &str) -> AppResult<()> {
+    let target = resolve_export_path(usb_path, file_path)?;
+
+    if !target.is_file() {
+        return Err(AppError::new(ErrorCode::ExportFailed, "Export path is not a file"));
+    }
+
+    fs::remove_file(long_path(&target)).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to delete export file", e.to_string())
+    })?;
+    remove_checksum(&target);
+
+    tracing::info!("Deleted address export {}", target.display());
+    Ok(())
+}
+
+/// Chunk size for overwriting an export before deletion
+const WIPE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Overwrite a file's contents with zeros and flush them to the device
+fn overwrite_with_zeros(path: &Path) -> std::io::Result<()> {
+    let mut file = fs::OpenOptions::new().write(true).open(long_path(path))?;
+    let mut remaining = file.metadata()?.len();
+    let zeros = vec![0u8; WIPE_CHUNK_SIZE];
+
+    while remaining > 0 {
+        let chunk = remaining.min(WIPE_CHUNK_SIZE as u64) as usize;
+        file.write_all(&zeros[..chunk])?;
+        remaining -= chunk as u64;
+    }
+
+    file.sync_all()
+}
+
+/// Overwrite an address export with zeros, then delete it.
+///
+/// Same path checks as `remove_export`. This is a best-effort logical
+/// overwrite: flash storage (USB sticks, SSDs) remaps writes through wear
+/// leveling, so the old blocks may survive physically until the controller
+/// reuses them. It still keeps the contents out of reach of undelete tools
+/// that read the filesystem.
+pub fn wipe_export(usb_path: &str, file_path: &str) -> AppResult<()> {
+    let target = resolve_export_path(usb_path, file_path)?;
+
+    if !target.is_file() {
+        return Err(AppError::new(ErrorCode::ExportFailed, "Export path is not a file"));
+    }
+
+    overwrite_with_zeros(&target).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to overwrite export file", e.to_string())
+    })?;
+
+    fs::remove_file(long_path(&target)).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to delete export file", e.to_string())
+    })?;
+    remove_checksum(&target);
+
+    tracing::info!("Securely deleted address export {}", target.display());
+    Ok(())
+}
+
+/// Serialized name of a unit enum variant ("base", "secp256k1", ...)
+fn enum_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Addresses in export order: by rank, then symbol, then derivation path.
+///
+/// The library returns addresses in its own iteration order, which can differ
+/// between runs; sorting keeps exports of the same wallet byte-identical.
+fn export_order(addresses: &[Address]) -> Vec<&Address> {
+    let mut ordered: Vec<&Address> = addresses.iter().collect();
+    ordered.sort_by(|a, b| {
+        (a.rank, &a.symbol, &a.derivation_path).cmp(&(b.rank, &b.symbol, &b.derivation_path))
+    });
+    ordered
+}
+
+/// JSON export: wallet metadata plus every address record
+pub(crate) fn generate_json_export(
+    wallet_id: &str,
+    addresses: &[Address],
+    exported_at: &DateTime<Utc>,
+) -> AppResult<String> {
+    let records: Vec<serde_json::Value> = export_order(addresses)
+        .into_iter()
+        .map(|a| {
+            serde_json::json!({
+                "rank": a.rank,
+                "symbol": a.symbol,
+                "name": a.name,
+                "category": a.category,
+                "coin_type": a.coin_type,
+                "key_type": a.key_type,
+                "derivation_path": a.derivation_path,
+                "address": a.address,
+            })
+        })
+        .collect();
+
+    let export = serde_json::json!({
+        "wallet_id": wallet_id,
+        "exported_at": exported_at.to_rfc3339(),
+        "addresses": records,
+    });
+
+    Ok(serde_json::to_string_pretty(&export)?)
+}
+
+/// One CSV row (without line break) for an address, matching `CSV_HEADER`
+fn csv_row(a: &Address) -> String {
+    let row = [
+        a.rank.to_string(),
+        csv_field(&a.symbol),
+        csv_field(&a.name),
+        enum_name(&a.category),
+        a.coin_type.to_string(),
+        enum_name(&a.key_type),
+        csv_field(&a.derivation_path),
+        csv_field(&a.address),
+        String::new(),
+    ];
+    row.join(",")
+}
+
+/// Split CSV text into records of unquoted fields (the inverse of `csv_field`)
+fn parse_csv_records(csv: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// CSV export: one row per address under `CSV_HEADER`
+pub(crate) fn generate_csv_export(addresses: &[Address]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+
+    for a in export_order(addresses) {
+        csv.push_str(&csv_row(a));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// One wallet's part of a combined export: its addresses, or why they
+/// couldn't be loaded
+pub(crate) struct CombinedWallet {
+    pub wallet_id: String,
+    pub wallet_name: String,
+    pub addresses: Result<LoadedAddresses, String>,
+}
+
+/// Combined CSV: `CSV_HEADER` with leading Wallet ID and Wallet Name columns.
+/// A wallet that failed to load gets a single row with only the Error column
+/// set; a blockchain that failed to derive gets a row with its Name and Error.
+pub(crate) fn generate_combined_csv(wallets: &[CombinedWallet]) -> String {
+    let mut csv = format!("Wallet ID,Wallet Name,{}\n", CSV_HEADER);
+
+    for wallet in wallets {
+        let prefix = format!("{},{}", csv_field(&wallet.wallet_id), csv_field(&wallet.wallet_name));
+
+        match &wallet.addresses {
+            Ok(loaded) => {
+                for a in export_order(&loaded.addresses) {
+                    csv.push_str(&format!("{},{}\n", prefix, csv_row(a)));
+                }
+                for failed in &loaded.failed {
+                    // Rank and Symbol empty, Name, 5 empty columns, then Error
+                    csv.push_str(&format!(
+                        "{},,,{}{}{}\n",
+                        prefix,
+                        csv_field(&failed.blockchain),
+                        ",".repeat(6),
+                        csv_field(&failed.error)
+                    ));
+                }
+            }
+            Err(error) => {
+                // 8 empty address columns, then Error
+                csv.push_str(&format!("{},{}{}\n", prefix, ",".repeat(8), csv_field(error)));
+            }
+        }
+    }
+
+    csv
+}
+
+/// Display name from `{usb_path}/{wallet_id}/wallet.json` (empty if unavailable)
+fn read_wallet_name(usb_path: &str, wallet_id: &str) -> String {
+    let path = Path::new(usb_path).join(wallet_id).join("wallet.json");
+    fs::read_to_string(long_path(&path))
+        .ok()
+        .and_then(|contents| WalletFileMetadata::parse(&contents)?.name)
+        .unwrap_or_default()
+}
+
+/// `<file>.sha256` next to `path`
+fn checksum_path(path: &Path) -> PathBuf {
+    let mut sidecar = path.as_os_str().to_owned();
+    sidecar.push(CHECKSUM_SUFFIX);
+    PathBuf::from(sidecar)
+}
+
+/// Hex SHA-256 of a file's bytes as stored (compressed files are not inflated)
+fn file_sha256(path: &Path) -> std::io::Result<String> {
+    let mut file = fs::File::open(long_path(path))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Hash `path` and write its sidecar in `sha256sum` format ("<hex>  <name>"),
+/// so `sha256sum -c` can check it too. Returns the hash.
+fn write_checksum(path: &Path) -> AppResult<String> {
+    let checksum_failed = |e: std::io::Error| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to write export checksum", e.to_string())
+    };
+
+    let checksum = file_sha256(path).map_err(checksum_failed)?;
+    let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    write_atomic(&checksum_path(path), format!("{}  {}\n", checksum, file_name).as_bytes()).map_err(checksum_failed)?;
+    Ok(checksum)
+}
+
+/// Result of checking an export against its checksum sidecar
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExportVerification {
+    pub file_path: String,
+    /// Hash recorded in the sidecar when the export was written
+    pub expected: String,
+    /// Hash of the file as it is now
+    pub actual: String,
+    pub valid: bool,
+}
+
+/// Recompute the SHA-256 of one of `wallet_id`'s exports on the USB and
+/// compare it with its `.sha256` sidecar
+pub fn verify_export_file(usb_path: &str, wallet_id: &str, file_path: &str) -> AppResult<ExportVerification> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("File path", file_path, MAX_PATH_LEN)?;
+
+    // Resolved to {usb}/{wallet_id}/addresses/{export file}
+    let resolved = resolve_export_path(usb_path, file_path)?;
+    let owner = resolved.parent().and_then(Path::parent).and_then(Path::file_name);
+    if owner.and_then(|name| name.to_str()) != Some(wallet_id) {
+        return Err(AppError::new(ErrorCode::ExportFailed, "This export belongs to another wallet"));
+    }
+    let path = resolved.as_path();
+
+    let sidecar = fs::read_to_string(long_path(&checksum_path(path))).map_err(|e| {
+        AppError::with_details(
+            ErrorCode::ExportFailed,
+            "This export has no checksum file; it was written without the checksum option",
+            e.to_string(),
+        )
+    })?;
+    let expected = sidecar
+        .split_whitespace()
+        .next()
+        .filter(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(str::to_ascii_lowercase)
+        .ok_or_else(|| AppError::new(ErrorCode::ExportFailed, "Export checksum file is malformed"))?;
+
+    let actual = file_sha256(path).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Export file not found", e.to_string())
+    })?;
+
+    Ok(ExportVerification {
+        file_path: file_path.to_string(),
+        valid: actual == expected,
+        expected,
+        actual,
+    })
+}
+
+/// Delete an export's checksum sidecar, if it has one
+fn remove_checksum(path: &Path) {
+    match fs::remove_file(long_path(&checksum_path(path))) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => tracing::warn!("Failed to delete checksum of {}: {}", path.display(), e),
+    }
+}
+
+/// Atomically write `contents` to a new file readable only by the owner
+/// (0600 on unix), gzip-compressed if `compress` is set. Never overwrites an
+/// existing export.
+fn write_private_file(path: &Path, contents: &[u8], compress: bool) -> std::io::Result<()> {
+    write_new_atomic_with(path, |file| {
+        if !compress {
+            return file.write_all(contents);
+        }
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder.write_all(contents)?;
+        encoder.finish().map(|_| ())
+    })
+}
+
+/// Newest CSV export in `export_dir` whose name starts with `wallet_prefix`
+/// (see `wallet_file_prefix`), if any
+fn latest_csv_export(export_dir: &Path, wallet_prefix: &str) -> Option<PathBuf> {
+    fs::read_dir(long_path(export_dir))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let (timestamp, format) = parse_export_filename(file_name.to_str()?.strip_prefix(wallet_prefix)?)?;
+            (format == ExportFormat::Csv.file_extension()).then(|| (timestamp, export_dir.join(&file_name)))
+        })
+        .max()
+        .map(|(_, path)| path)
+}
+
+/// Append rows for `addresses` to an existing CSV export, skipping addresses
+/// it already lists. The file must carry our `CSV_HEADER`.
+fn append_csv_export(
+    path: &Path,
+    addresses: &[Address],
+    exported_at: DateTime<Utc>,
+    checksum: bool,
+) -> AppResult<ExportResponse> {
+    let mut contents = fs::read_to_string(long_path(path)).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to read existing export", e.to_string())
+    })?;
+
+    let records = parse_csv_records(&contents);
+    if records.first().map(|header| header.join(",")).as_deref() != Some(CSV_HEADER) {
+        return Err(AppError::with_details(
+            ErrorCode::InvalidExportFormat,
+            "Existing CSV export has a different header and can't be appended to",
+            path.to_string_lossy().into_owned(),
+        ));
+    }
+
+    let address_column = CSV_HEADER.split(',').position(|column| column == "Address").unwrap_or_default();
+    let mut known: HashSet<String> = records[1..]
+        .iter()
+        .filter_map(|record| record.get(address_column).cloned())
+        .collect();
+
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+
+    let mut appended = 0;
+    for a in export_order(addresses) {
+        if known.insert(a.address.clone()) {
+            contents.push_str(&csv_row(a));
+            contents.push('\n');
+            appended += 1;
+        }
+    }
+
+    // Rewritten atomically rather than opened for append, so the file keeps
+    // its owner-only permissions and is never left half-written
+    write_atomic(path, contents.as_bytes()).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to write export file", e.to_string())
+    })?;
+
+    tracing::info!("Appended {} addresses to {}", appended, path.display());
+
+    // An existing sidecar would no longer match, so it is refreshed as well
+    let checksum = if checksum || long_path(&checksum_path(path)).exists() {
+        Some(write_checksum(path)?)
+    } else {
+        None
+    };
+
+    Ok(ExportResponse {
+        file_path: path.to_string_lossy().into_owned(),
+        exported_count: appended,
+        format: ExportFormat::Csv,
+        exported_at: exported_at.to_rfc3339(),
+        checksum,
+        failed: Vec::new(),
+    })
+}
+
+/// Directory an export is written to: `output_dir` if given (it must already
+/// exist and be writable), else `{usb_path}/{wallet_id}/addresses/`
+fn export_directory(usb_path: &str, wallet_id: &str, output_dir: Option<&str>) -> AppResult<PathBuf> {
+    let Some(output_dir) = output_dir else {
+        let export_dir = Path::new(usb_path).join(wallet_id).join(EXPORT_DIR_NAME);
+        fs::create_dir_all(long_path(&export_dir)).map_err(|e| {
+            AppError::with_details(
+                ErrorCode::ExportFailed,
+                "Failed to create export directory",
+                e.to_string(),
+            )
+        })?;
+        return Ok(export_dir);
+    };
+
+    check_length("Output directory", output_dir, MAX_PATH_LEN)?;
+
+    let export_dir = PathBuf::from(output_dir);
+    if !long_path(&export_dir).is_dir() {
+        return Err(AppError::with_details(
+            ErrorCode::ExportFailed,
+            "Export directory does not exist. Please choose an existing folder.",
+            output_dir,
+        ));
+    }
+
+    // The read-only attribute says nothing about ACLs, mount flags or
+    // another user's directory, so probe with a real file
+    if !is_path_writable(&export_dir) {
+        return Err(AppError::with_details(
+            ErrorCode::ExportFailed,
+            "Export directory is not writable",
+            output_dir,
+        ));
+    }
+
+    Ok(export_dir)
+}
+
+/// Prefix naming the wallet in export file names.
+///
+/// Empty in the wallet's own export directory; a chosen output directory
+/// may hold several wallets' exports, so there names start with
+/// `{wallet_id}-`.
+fn wallet_file_prefix(wallet_id: &str, output_dir: Option<&str>) -> String {
+    match output_dir {
+        Some(_) => format!("{}-", wallet_id),
+        None => String::new(),
+    }
+}
+
+/// Whether this build can write `format` (encrypted and PDF exports aren't
+/// implemented yet)
+pub(crate) fn is_export_format_supported(format: ExportFormat) -> bool {
+    matches!(format, ExportFormat::Json | ExportFormat::Csv)
+}
+
+/// Write an address export to the wallet's export directory on the USB, or
+/// to `output_dir` when given
+pub(crate) fn write_export(
+    usb_path: &str,
+    wallet_id: &str,
+    addresses: &[Address],
+    format: ExportFormat,
+    options: ExportOptions,
+    output_dir: Option<&str>,
+    exported_at: DateTime<Utc>,
+) -> AppResult<ExportResponse> {
+    sanitize_wallet_id(wallet_id)?;
+
+    if options.compress && format != ExportFormat::Json {
+        return Err(AppError::new(
+            ErrorCode::InvalidExportFormat,
+            "Compression is only available for JSON exports",
+        ));
+    }
+    if options.append && format != ExportFormat::Csv {
+        return Err(AppError::new(
+            ErrorCode::InvalidExportFormat,
+            "Appending is only available for CSV exports",
+        ));
+    }
+
+    let contents = match format {
+        ExportFormat::Json => generate_json_export(wallet_id, addresses, &exported_at)?,
+        ExportFormat::Csv => generate_csv_export(addresses),
+        ExportFormat::EncryptedJson | ExportFormat::Pdf => {
+            return Err(AppError::new(
+                ErrorCode::InvalidExportFormat,
+                format!("{} export is not supported yet", enum_name(&format)),
+            ));
+        }
+    };
+
+    let export_dir = export_directory(usb_path, wallet_id, output_dir)?;
+    let wallet_prefix = wallet_file_prefix(wallet_id, output_dir);
+
+    if options.append {
+        if let Some(existing) = latest_csv_export(&export_dir, &wallet_prefix) {
+            return append_csv_export(&existing, addresses, exported_at, options.checksum);
+        }
+    }
+
+    let file_name = format!(
+        "{}{}{}.{}",
+        wallet_prefix,
+        EXPORT_FILE_PREFIX,
+        exported_at.format(EXPORT_TIMESTAMP_FORMAT),
+        export_extension(format, options)
+    );
+    let file_path = export_dir.join(file_name);
+
+    write_private_file(&file_path, contents.as_bytes(), options.compress).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to write export file", e.to_string())
+    })?;
+
+    let checksum = options.checksum.then(|| write_checksum(&file_path)).transpose()?;
+
+    tracing::info!("Exported {} addresses to {}", addresses.len(), file_path.display());
+
+    Ok(ExportResponse {
+        file_path: file_path.to_string_lossy().into_owned(),
+        exported_count: addresses.len(),
+        format,
+        exported_at: exported_at.to_rfc3339(),
+        checksum,
+        failed: Vec::new(),
+    })
+}
+
+/// Unlock each wallet and write all their addresses to one CSV at
+/// `{usb_path}/addresses-combined-{YYYYMMDD-HHMMSS}.csv`.
+///
+/// Wallets that can't be loaded (wrong or missing password, not on the USB)
+/// become error rows instead of aborting the export.
+pub(crate) async fn write_combined_export(
+    queue: &LazyWalletQueue,
+    cache: &AddressCache,
+    unlocked: &UnlockedWallets,
+    usb_path: &str,
+    wallet_ids: &[String],
+    passwords: &HashMap<String, Secret>,
+    exported_at: DateTime<Utc>,
+) -> AppResult<ExportResponse> {
+    validate_usb_path(usb_path)?;
+    for wallet_id in wallet_ids {
+        sanitize_wallet_id(wallet_id)?;
+    }
+
+    let mut wallets = Vec::with_capacity(wallet_ids.len());
+    for wallet_id in wallet_ids {
+        let addresses = match passwords.get(wallet_id) {
+            Some(password) => load_wallet_addresses(queue, cache, unlocked, wallet_id, password, usb_path, AddressType::default())
+                .await
+                .map_err(|e| e.message),
+            None => Err("Password not provided".to_string()),
+        };
+
+        wallets.push(CombinedWallet {
+            wallet_id: wallet_id.clone(),
+            wallet_name: read_wallet_name(usb_path, wallet_id),
+            addresses,
+        });
+    }
+
+    let exported_count = wallets
+        .iter()
+        .filter_map(|w| w.addresses.as_ref().ok())
+        .map(|loaded| loaded.addresses.len())
+        .sum();
+
+    let file_path = Path::new(usb_path).join(format!(
+        "{}combined-{}.{}",
+        EXPORT_FILE_PREFIX,
+        exported_at.format(EXPORT_TIMESTAMP_FORMAT),
+        ExportFormat::Csv.file_extension()
+    ));
+
+    write_private_file(&file_path, generate_combined_csv(&wallets).as_bytes(), false).map_err(|e| {
+        AppError::with_details(ErrorCode::ExportFailed, "Failed to write export file", e.to_string())
+    })?;
+
+    tracing::info!(
+        "Exported {} addresses from {} wallets to {}",
+        exported_count,
+        wallets.len(),
+        file_path.display()
+    );
+
+    Ok(ExportResponse {
+        file_path: file_path.to_string_lossy().into_owned(),
+        exported_count,
+        format: ExportFormat::Csv,
+        exported_at: exported_at.to_rfc3339(),
+        checksum: None,
+        failed: Vec::new(),
+    })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombinedExportInput {
+    pub usb_path: String,
+    pub wallet_ids: Vec<String>,
+    pub password_map: HashMap<String, Secret>,
+}
+
+/// Export addresses of several wallets into one CSV (for accountants/auditors)
+#[tauri::command]
+pub async fn export_combined_csv(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+    input: CombinedExportInput,
+) -> Result<ExportResponse, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    tracing::info!("export_combined_csv: {} wallets", input.wallet_ids.len());
+
+    write_combined_export(
+        queue,
+        cache.inner(),
+        unlocked.inner(),
+        &usb_path,
+        &input.wallet_ids,
+        &input.password_map,
+        Utc::now(),
+    )
+    .await
+    .map_err(String::from)
+}
+
+/// Parse the export format sent by the frontend.
+///
+/// Taken as a string rather than `ExportFormat` so an unknown format fails
+/// with InvalidExportFormat instead of a raw deserialization error.
+pub(crate) fn parse_export_format(format: &str) -> AppResult<ExportFormat> {
+    let format = format.trim();
+    ExportFormat::ALL
+        .into_iter()
+        .find(|f| f.wire_name().eq_ignore_ascii_case(format))
+        .ok_or_else(|| {
+            let supported: Vec<&str> = ExportFormat::ALL.iter().map(|f| f.wire_name()).collect();
+            AppError::new(
+                ErrorCode::InvalidExportFormat,
+                format!(
+                    "Unknown export format '{}'. Supported formats: {}",
+                    format,
+                    supported.join(", ")
+                ),
+            )
+        })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportAddressesInput {
+    pub wallet_id: String,
+    pub password: Secret,
+    pub usb_path: String,
+    pub format: String,
+    pub options: Option<ExportOptions>,
+    pub output_dir: Option<String>,
+}
+
+/// Export a wallet's addresses to a file on the USB
+#[tauri::command]
+pub async fn export_addresses(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+    input: ExportAddressesInput,
+) -> Result<ExportResponse, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let wallet_id = input.wallet_id.as_str();
+    let queue = backend.queue().map_err(String::from)?;
+    let format = parse_export_format(&input.format).map_err(String::from)?;
+
+    tracing::info!("export_addresses: wallet_id={}, format={:?}", wallet_id, format);
+    validate_usb_path(&usb_path).map_err(String::from)?;
+
+    let loaded = load_wallet_addresses(queue, cache.inner(), unlocked.inner(), wallet_id, &input.password, &usb_path, AddressType::default())
+        .await
+        .map_err(String::from)?;
+
+    let options = input.options.unwrap_or_default();
+    let response = write_export(&usb_path, wallet_id, &loaded.addresses, format, options, input.output_dir.as_deref(), Utc::now())
+        .map_err(String::from)?;
+    if !loaded.failed.is_empty() {
+        tracing::warn!("Export of {} is missing {} blockchain(s) that failed to derive", wallet_id, loaded.failed.len());
+    }
+    Ok(ExportResponse { failed: loaded.failed, ..response })
+}
+
+/// List address exports previously written for a wallet
+#[tauri::command]
+pub async fn list_exports(usb_path: String, wallet_id: String) -> Result<Vec<ExportFileInfo>, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let exports = scan_exports(&usb_path, &wallet_id).map_err(String::from)?;
+
+    let incomplete = exports.iter().filter(|export| export.incomplete).count();
+    if incomplete > 0 {
+        tracing::warn!("Wallet {} has {} incomplete export file(s)", wallet_id, incomplete);
+    }
+    Ok(exports)
+}
+
+/// Remove files left by export writes that were interrupted (USB pulled,
+/// crash), which `list_exports` reports as `incomplete`
+#[tauri::command]
+pub async fn cleanup_partial_exports(usb_path: String, wallet_id: String) -> Result<Vec<String>, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    remove_partial_exports(&usb_path, &wallet_id).map_err(String::from)
+}
+
+/// Delete a previously written address export
+#[tauri::command]
+pub async fn delete_export(usb_path: String, file_path: String) -> Result<(), String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    remove_export(&usb_path, &file_path).map_err(String::from)
+}
+
+/// Overwrite an address export file, then delete it
+#[tauri::command]
+pub async fn secure_delete_export(usb_path: String, file_path: String) -> Result<(), String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    wipe_export(&usb_path, &file_path).map_err(String::from)
+}
+
+/// Check an export against the checksum sidecar written with it
+#[tauri::command]
+pub async fn verify_export(usb_path: String, wallet_id: String, file_path: String) -> Result<ExportVerification, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let verification = verify_export_file(&usb_path, &wallet_id, &file_path).map_err(String::from)?;
+    if !verification.valid {
+        tracing::warn!("Export failed checksum verification: {}", file_path);
+    }
+    Ok(verification)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+
+    #[test]
+    fn test_parse_export_filename() {
+        let (timestamp, format) = parse_export_filename("addresses-20251017-143025.json").unwrap();
+        assert_eq!(format, "json");
+        assert_eq!(timestamp.to_string(), "2025-10-17 14:30:25");
+
+        assert!(parse_export_filename("addresses-20251017-143025.txt").is_none());
+        assert!(parse_export_filename("addresses-latest.csv").is_none());
+        assert!(parse_export_filename("wallet.json").is_none());
+    }
+
+    #[test]
+    fn test_parse_export_format() {
+        assert_eq!(parse_export_format("csv").unwrap(), ExportFormat::Csv);
+        assert_eq!(parse_export_format("encrypted_json").unwrap(), ExportFormat::EncryptedJson);
+
+        let err = parse_export_format("xlsx").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidExportFormat);
+        assert!(err.message.contains("'xlsx'"));
+        assert!(err.message.contains("json, csv, encrypted_json, pdf"));
+    }
+
+    fn sample_addresses() -> Vec<Address> {
+        use crate::models::address::{Category, KeyType};
+
+        vec![
+            Address::new(
+                WALLET_ID.to_string(),
+                1,
+                "BTC".to_string(),
+                "Bitcoin".to_string(),
+                0,
+                "m/44'/0'/0'/0/0".to_string(),
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string(),
+                Category::Base,
+                KeyType::Secp256k1,
+            ),
+            Address::new(
+                WALLET_ID.to_string(),
+                2,
+                "XYZ".to_string(),
+                "Chain, \"Quoted\"".to_string(),
+                9999,
+                "m/44'/9999'/0'/0/0".to_string(),
+                "xyz1address".to_string(),
+                Category::AltEvm,
+                KeyType::Ed25519,
+            ),
+        ]
+    }
+
+    fn exported_at() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-10-17T14:30:25Z").unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_generate_csv_export_escapes_fields() {
+        let csv = generate_csv_export(&sample_addresses());
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "1,BTC,Bitcoin,base,0,secp256k1,m/44'/0'/0'/0/0,1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa,");
+        assert_eq!(lines[2], "2,XYZ,\"Chain, \"\"Quoted\"\"\",alt_evm,9999,ed25519,m/44'/9999'/0'/0/0,xyz1address,");
+    }
+
+    #[test]
+    fn test_export_order_is_independent_of_input_order() {
+        let mut addresses = sample_addresses();
+        let mut second_btc = addresses[0].clone();
+        second_btc.derivation_path = "m/44'/0'/0'/0/1".to_string();
+        second_btc.address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2".to_string();
+        addresses.push(second_btc);
+
+        let json = generate_json_export(WALLET_ID, &addresses, &exported_at()).unwrap();
+        let csv = generate_csv_export(&addresses);
+
+        addresses.reverse();
+        addresses.swap(0, 1);
+        assert_eq!(generate_json_export(WALLET_ID, &addresses, &exported_at()).unwrap(), json);
+        assert_eq!(generate_csv_export(&addresses), csv);
+
+        let ranks: Vec<&str> = csv.lines().skip(1).map(|line| &line[..1]).collect();
+        assert_eq!(ranks, ["1", "1", "2"]);
+        assert!(csv.lines().nth(1).unwrap().contains("m/44'/0'/0'/0/0"));
+    }
+
+    #[test]
+    fn test_write_export_uses_format_extension() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let response = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Json, ExportOptions::default(), None, exported_at()).unwrap();
+        assert!(response.file_path.ends_with("addresses-20251017-143025.json"));
+        assert_eq!(response.exported_count, 2);
+
+        let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&response.file_path).unwrap()).unwrap();
+        assert_eq!(json["wallet_id"], WALLET_ID);
+        assert_eq!(json["addresses"][1]["category"], "alt_evm");
+
+        let response = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, ExportOptions::default(), None, exported_at()).unwrap();
+        assert!(response.file_path.ends_with("addresses-20251017-143025.csv"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&response.file_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Both exports are listed
+        assert_eq!(scan_exports(usb_path, WALLET_ID).unwrap().len(), 2);
+
+        let err = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Pdf, ExportOptions::default(), None, exported_at()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidExportFormat);
+    }
+
+    #[test]
+    fn test_write_export_to_output_dir() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        let output_dir = output.path().to_str().unwrap();
+
+        let response = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, ExportOptions::default(), Some(output_dir), exported_at()).unwrap();
+        assert_eq!(
+            Path::new(&response.file_path),
+            output.path().join(format!("{}-addresses-20251017-143025.csv", WALLET_ID))
+        );
+        assert!(!usb.path().join(WALLET_ID).exists(), "default export directory was created");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&response.file_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Missing directories are rejected, not created
+        let missing = output.path().join("missing").join("nested");
+        let err = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, ExportOptions::default(), missing.to_str(), exported_at()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ExportFailed);
+        assert!(!output.path().join("missing").exists());
+    }
+
+    #[test]
+    fn test_append_csv_export_skips_known_addresses() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let addresses = sample_addresses();
+        let append = ExportOptions { append: true, ..Default::default() };
+
+        // Nothing to append to yet: a new file is written
+        let first = write_export(usb_path, WALLET_ID, &addresses[..1], ExportFormat::Csv, append, None, exported_at()).unwrap();
+        assert_eq!(first.exported_count, 1);
+
+        let later = exported_at() + chrono::Duration::hours(1);
+        let second = write_export(usb_path, WALLET_ID, &addresses, ExportFormat::Csv, append, None, later).unwrap();
+        assert_eq!(second.file_path, first.file_path);
+        assert_eq!(second.exported_count, 1);
+
+        let csv = fs::read_to_string(&second.file_path).unwrap();
+        assert_eq!(csv, generate_csv_export(&addresses));
+        assert_eq!(scan_exports(usb_path, WALLET_ID).unwrap().len(), 1);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&second.file_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Appending the same addresses again changes nothing
+        let third = write_export(usb_path, WALLET_ID, &addresses, ExportFormat::Csv, append, None, later).unwrap();
+        assert_eq!(third.exported_count, 0);
+        assert_eq!(fs::read_to_string(&third.file_path).unwrap(), csv);
+
+        let err = write_export(usb_path, WALLET_ID, &addresses, ExportFormat::Json, append, None, later).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidExportFormat);
+    }
+
+    #[test]
+    fn test_append_in_shared_output_dir_keeps_wallets_apart() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        let output_dir = output.path().to_str();
+        let other_wallet = "5b0e7f2a-1c3d-4e5f-8a9b-0c1d2e3f4a5b";
+        let addresses = sample_addresses();
+        let append = ExportOptions { append: true, ..Default::default() };
+
+        let first = write_export(usb_path, WALLET_ID, &addresses[..1], ExportFormat::Csv, append, output_dir, exported_at()).unwrap();
+
+        // Another wallet's newer export in the same folder starts its own file
+        let later = exported_at() + chrono::Duration::hours(1);
+        let other = write_export(usb_path, other_wallet, &addresses[1..], ExportFormat::Csv, append, output_dir, later).unwrap();
+        assert_ne!(other.file_path, first.file_path);
+        assert_eq!(other.exported_count, addresses.len() - 1);
+
+        let again = write_export(usb_path, WALLET_ID, &addresses, ExportFormat::Csv, append, output_dir, later).unwrap();
+        assert_eq!(again.file_path, first.file_path);
+        assert_eq!(fs::read_to_string(&again.file_path).unwrap(), generate_csv_export(&addresses));
+        assert_eq!(fs::read_dir(output.path()).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_checksum_round_trip_detects_tampering() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let checksum = ExportOptions { checksum: true, ..Default::default() };
+
+        for (format, options) in [
+            (ExportFormat::Json, checksum),
+            (ExportFormat::Json, ExportOptions { compress: true, ..checksum }),
+            (ExportFormat::Csv, checksum),
+        ] {
+            let response = write_export(usb_path, WALLET_ID, &sample_addresses(), format, options, None, exported_at()).unwrap();
+            let sidecar = fs::read_to_string(format!("{}{}", response.file_path, CHECKSUM_SUFFIX)).unwrap();
+            let file_name = Path::new(&response.file_path).file_name().unwrap().to_str().unwrap();
+            assert_eq!(sidecar, format!("{}  {}\n", response.checksum.clone().unwrap(), file_name));
+
+            let verification = verify_export_file(usb_path, WALLET_ID, &response.file_path).unwrap();
+            assert!(verification.valid);
+            assert_eq!(Some(verification.actual), response.checksum);
+
+            // Flip one byte
+            let mut bytes = fs::read(&response.file_path).unwrap();
+            bytes[10] ^= 0x01;
+            fs::write(&response.file_path, bytes).unwrap();
+            let verification = verify_export_file(usb_path, WALLET_ID, &response.file_path).unwrap();
+            assert!(!verification.valid);
+            assert_ne!(verification.actual, verification.expected);
+        }
+
+        // Sidecars are not exports, and go away with their export
+        let exports = scan_exports(usb_path, WALLET_ID).unwrap();
+        assert_eq!(exports.len(), 3);
+        for export in &exports {
+            remove_export(usb_path, &export.path).unwrap();
+            assert!(!Path::new(&format!("{}{}", export.path, CHECKSUM_SUFFIX)).exists());
+        }
+
+        // Written without the option: nothing to verify against
+        let plain = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, ExportOptions::default(), None, exported_at()).unwrap();
+        assert_eq!(plain.checksum, None);
+        assert_eq!(verify_export_file(usb_path, WALLET_ID, &plain.file_path).unwrap_err().code, ErrorCode::ExportFailed);
+
+        // Appending keeps an existing sidecar current
+        let later = exported_at() + chrono::Duration::hours(1);
+        fs::remove_file(&plain.file_path).unwrap();
+        let first = write_export(usb_path, WALLET_ID, &sample_addresses()[..1], ExportFormat::Csv, checksum, None, later).unwrap();
+        let append = ExportOptions { append: true, ..Default::default() };
+        let appended = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, append, None, later).unwrap();
+        assert_eq!(appended.file_path, first.file_path);
+        assert_ne!(appended.checksum, first.checksum);
+        assert!(verify_export_file(usb_path, WALLET_ID, &appended.file_path).unwrap().valid);
+
+        // Only this wallet's exports on this USB are read
+        let other_wallet = "5b0e7f2a-1c3d-4e5f-8a9b-0c1d2e3f4a5b";
+        let err = verify_export_file(usb_path, other_wallet, &appended.file_path).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ExportFailed);
+        let elsewhere = tempfile::tempdir().unwrap();
+        let copy = elsewhere.path().join("addresses-20251017-153025.csv");
+        fs::copy(&appended.file_path, &copy).unwrap();
+        fs::copy(format!("{}{}", appended.file_path, CHECKSUM_SUFFIX), format!("{}{}", copy.display(), CHECKSUM_SUFFIX)).unwrap();
+        let err = verify_export_file(usb_path, WALLET_ID, copy.to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ExportFailed);
+    }
+
+    #[test]
+    fn test_append_csv_export_rejects_header_mismatch() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let export_dir = usb.path().join(WALLET_ID).join(EXPORT_DIR_NAME);
+        fs::create_dir_all(&export_dir).unwrap();
+        let existing = export_dir.join("addresses-20251017-143025.csv");
+        fs::write(&existing, "Symbol,Address\nBTC,1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa\n").unwrap();
+
+        let append = ExportOptions { append: true, ..Default::default() };
+        let err = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, append, None, exported_at()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidExportFormat);
+
+        // The existing file is left untouched
+        assert_eq!(
+            fs::read_to_string(&existing).unwrap(),
+            "Symbol,Address\nBTC,1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa\n"
+        );
+    }
+
+    #[test]
+    fn test_write_export_compressed_round_trip() {
+        use flate2::read::GzDecoder;
+        use std::io::Read;
+
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let compress = ExportOptions { compress: true, ..Default::default() };
+
+        let response = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Json, compress, None, exported_at()).unwrap();
+        assert!(response.file_path.ends_with("addresses-20251017-143025.json.gz"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&response.file_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let mut decompressed = String::new();
+        GzDecoder::new(fs::File::open(&response.file_path).unwrap())
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        let expected = generate_json_export(WALLET_ID, &sample_addresses(), &exported_at()).unwrap();
+        assert_eq!(decompressed, expected);
+
+        let exports = scan_exports(usb_path, WALLET_ID).unwrap();
+        assert_eq!(exports.len(), 1);
+        assert_eq!(exports[0].format, "json.gz");
+
+        let err = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, compress, None, exported_at()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidExportFormat);
+    }
+
+    #[tokio::test]
+    async fn test_write_combined_export_concatenates_wallets() {
+        use crate::ffi::stub::stub_library;
+        use std::sync::Arc;
+
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let second_id = "b".repeat(64);
+        let wrong_id = "c".repeat(64);
+
+        let wallet_dir = usb.path().join(WALLET_ID);
+        fs::create_dir_all(&wallet_dir).unwrap();
+        fs::write(wallet_dir.join("wallet.json"), r#"{"id":"x","name":"Savings, main"}"#).unwrap();
+
+        // Cached addresses of unlocked wallets are returned without touching the (stub) library
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        let cache = AddressCache::default();
+        cache.0.insert((WALLET_ID.to_string(), AddressType::Bip44), sample_addresses());
+        cache.0.insert((second_id.clone(), AddressType::Bip44), sample_addresses()[..1].to_vec());
+
+        let mut passwords = HashMap::new();
+        passwords.insert(WALLET_ID.to_string(), Secret::new("ValidPassword123".to_string()));
+        passwords.insert(second_id.clone(), Secret::new("ValidPassword123".to_string()));
+        let unlocked = UnlockedWallets::default();
+        for (wallet_id, password) in &passwords {
+            unlocked.mark_unlocked(wallet_id, password, unlocked.lock_generation());
+        }
+
+        let ids = vec![WALLET_ID.to_string(), second_id.clone()];
+        let response = write_combined_export(&queue, &cache, &unlocked, usb_path, &ids, &passwords, exported_at())
+            .await
+            .unwrap();
+
+        assert!(response.file_path.ends_with("addresses-combined-20251017-143025.csv"));
+        assert_eq!(response.exported_count, 3);
+
+        let csv = fs::read_to_string(&response.file_path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3 + 1);
+        assert!(lines[0].starts_with("Wallet ID,Wallet Name,Rank,"));
+        assert!(lines[1].starts_with(&format!("{},\"Savings, main\",1,BTC,", WALLET_ID)));
+        assert!(lines[3].starts_with(&format!("{},,1,BTC,", second_id)));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&response.file_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // A wallet that can't be unlocked becomes an error row
+        let ids = vec![WALLET_ID.to_string(), wrong_id.clone()];
+        let response = write_combined_export(&queue, &cache, &unlocked, usb_path, &ids, &passwords, Utc::now())
+            .await
+            .unwrap();
+        let csv = fs::read_to_string(&response.file_path).unwrap();
+        let last = csv.lines().last().unwrap();
+        assert_eq!(last, format!("{},,,,,,,,,,Password not provided", wrong_id));
+        assert_eq!(response.exported_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_combined_export_rejects_wrong_password_for_cached_wallet() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+        use std::sync::Arc;
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"decryption failed"}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        fs::create_dir_all(usb.path().join(WALLET_ID)).unwrap();
+        fs::write(usb.path().join(WALLET_ID).join("wallet.json"), r#"{"id":"x","name":"Savings"}"#).unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        // Warm cache from an earlier load with the right password
+        let cache = AddressCache::default();
+        cache.0.insert((WALLET_ID.to_string(), AddressType::Bip44), sample_addresses());
+        let unlocked = UnlockedWallets::default();
+        unlocked.mark_unlocked(WALLET_ID, &Secret::new("ValidPassword123".to_string()), unlocked.lock_generation());
+
+        let mut passwords = HashMap::new();
+        passwords.insert(WALLET_ID.to_string(), Secret::new("WrongPassword456".to_string()));
+        let ids = vec![WALLET_ID.to_string()];
+        let response = write_combined_export(&queue, &cache, &unlocked, usb_path, &ids, &passwords, exported_at())
+            .await
+            .unwrap();
+
+        assert_eq!(response.exported_count, 0);
+        let csv = fs::read_to_string(&response.file_path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1], format!("{},Savings,,,,,,,,,Invalid password", WALLET_ID));
+    }
+
+    #[test]
+    fn test_combined_csv_lists_blockchains_that_failed_to_derive() {
+        use crate::models::address::FailedAddress;
+
+        let wallets = [CombinedWallet {
+            wallet_id: WALLET_ID.to_string(),
+            wallet_name: "Savings".to_string(),
+            addresses: Ok(LoadedAddresses {
+                addresses: sample_addresses()[..1].to_vec(),
+                failed: vec![FailedAddress {
+                    blockchain: "ethereum".to_string(),
+                    error: "key derivation failed".to_string(),
+                }],
+            }),
+        }];
+
+        let csv = generate_combined_csv(&wallets);
+        let records = parse_csv_records(&csv);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2].len(), records[0].len());
+        assert_eq!(records[2][4], "ethereum");
+        assert_eq!(records[2].last().unwrap(), "key derivation failed");
+        assert!(records[2][9].is_empty(), "failed row has an address");
+    }
+
+    #[test]
+    fn test_scan_exports_sorted_newest_first() {
+        let usb = tempfile::tempdir().unwrap();
+        let export_dir = usb.path().join(WALLET_ID).join(EXPORT_DIR_NAME);
+        fs::create_dir_all(&export_dir).unwrap();
+
+        fs::write(export_dir.join("addresses-20251017-143025.json"), "{}").unwrap();
+        fs::write(export_dir.join("addresses-20251018-090000.csv"), "a,b\n").unwrap();
+        fs::write(export_dir.join("addresses-20250101-000000.pdf"), "pdf").unwrap();
+        fs::write(export_dir.join("addresses-20251019-120000.enc"), "enc").unwrap();
+
+        // Not exports: wrong prefix, bad timestamp, unknown extension, directory
+        fs::write(export_dir.join("notes.txt"), "x").unwrap();
+        fs::write(export_dir.join("addresses-backup.json"), "{}").unwrap();
+        fs::write(export_dir.join("addresses-20251017-143025.bak"), "x").unwrap();
+        fs::create_dir(export_dir.join("addresses-20251020-000000.json")).unwrap();
+
+        let exports = scan_exports(usb.path().to_str().unwrap(), WALLET_ID).unwrap();
+
+        let formats: Vec<&str> = exports.iter().map(|e| e.format.as_str()).collect();
+        assert_eq!(formats, vec!["enc", "csv", "json", "pdf"]);
+        assert_eq!(exports[1].size_bytes, 4);
+        assert_eq!(exports[1].created_at, "2025-10-18T09:00:00+00:00");
+        assert!(exports[2].path.ends_with("addresses-20251017-143025.json"));
+    }
+
+    #[test]
+    fn test_partial_exports_are_flagged_and_cleaned() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let checksum = ExportOptions { checksum: true, ..Default::default() };
+        let complete = write_export(usb_path, WALLET_ID, &sample_addresses(), ExportFormat::Csv, checksum, None, exported_at()).unwrap();
+
+        let export_dir = usb.path().join(WALLET_ID).join(EXPORT_DIR_NAME);
+        let other_pid = std::process::id().wrapping_add(1);
+        let leftovers = [
+            format!(".addresses-20251018-090000.json.{}-0.tmp", other_pid),
+            format!(".addresses-20251018-090000.json.sha256.{}-1.tmp", other_pid),
+            "addresses-20251019-120000.csv.partial".to_string(),
+        ];
+        for leftover in &leftovers {
+            fs::write(export_dir.join(leftover), "{\"trunc").unwrap();
+        }
+        let claimed = "addresses-20251021-000000.json";
+        fs::write(export_dir.join(claimed), "").unwrap();
+        // Not ours to delete: a write of this process may still be running,
+        // and other dotfiles aren't export leftovers
+        let running = format!(".addresses-20251020-000000.csv.{}-7.tmp", std::process::id());
+        fs::write(export_dir.join(&running), "a,b").unwrap();
+        fs::write(export_dir.join(".notes.1-2.tmp"), "x").unwrap();
+
+        let exports = scan_exports(usb_path, WALLET_ID).unwrap();
+        let flagged: Vec<(&str, bool)> = exports.iter().map(|e| (e.format.as_str(), e.incomplete)).collect();
+        assert_eq!(flagged, vec![("json", true), ("csv", true), ("json", true), ("json", true), ("csv", false)]);
+
+        let mut removed = remove_partial_exports(usb_path, WALLET_ID).unwrap();
+        removed.sort();
+        let mut expected: Vec<String> =
+            leftovers.iter().map(String::as_str).chain([claimed]).map(|name| export_dir.join(name).to_string_lossy().into_owned()).collect();
+        expected.sort();
+        assert_eq!(removed, expected);
+
+        // Completed exports and their checksums are intact
+        let exports = scan_exports(usb_path, WALLET_ID).unwrap();
+        assert_eq!(exports.len(), 1);
+        assert_eq!(exports[0].path, complete.file_path);
+        assert!(verify_export_file(usb_path, WALLET_ID, &complete.file_path).unwrap().valid);
+        assert!(export_dir.join(&running).exists());
+        assert!(export_dir.join(".notes.1-2.tmp").exists());
+
+        assert!(remove_partial_exports(usb_path, WALLET_ID).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scan_exports_missing_directory_is_empty() {
+        let usb = tempfile::tempdir().unwrap();
+        let exports = scan_exports(usb.path().to_str().unwrap(), WALLET_ID).unwrap();
+        assert!(exports.is_empty());
+    }
+
+    #[test]
+    fn test_scan_exports_rejects_unsafe_wallet_id() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        for wallet_id in ["../../etc", "/etc"] {
+            let err = scan_exports(usb_path, wallet_id).unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidWalletId);
+        }
+    }
+
+    #[test]
+    fn test_remove_export_deletes_file() {
+        let usb = tempfile::tempdir().unwrap();
+        let export_dir = usb.path().join(WALLET_ID).join(EXPORT_DIR_NAME);
+        fs::create_dir_all(&export_dir).unwrap();
+        let export = export_dir.join("addresses-20251017-143025.csv");
+        fs::write(&export, "a,b\n").unwrap();
+
+        remove_export(usb.path().to_str().unwrap(), export.to_str().unwrap()).unwrap();
+        assert!(!export.exists());
+    }
+
+    #[test]
+    fn test_remove_export_rejects_traversal() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().join("usb");
+        let export_dir = usb_path.join(WALLET_ID).join(EXPORT_DIR_NAME);
+        fs::create_dir_all(&export_dir).unwrap();
+
+        // Outside the USB root, reached through ../..
+        let outside = usb.path().join("addresses-20251017-143025.json");
+        fs::write(&outside, "{}").unwrap();
+        let traversal = export_dir.join("..").join("..").join("..").join("addresses-20251017-143025.json");
+
+        let err = remove_export(usb_path.to_str().unwrap(), traversal.to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ExportFailed);
+        assert!(outside.exists());
+
+        // Inside the USB but not in an export directory
+        let wallet_json = usb_path.join(WALLET_ID).join("wallet.json");
+        fs::write(&wallet_json, "{}").unwrap();
+        assert!(remove_export(usb_path.to_str().unwrap(), wallet_json.to_str().unwrap()).is_err());
+        assert!(wallet_json.exists());
+    }
+
+    #[test]
+    fn test_wipe_export_overwrites_and_deletes() {
+        let usb = tempfile::tempdir().unwrap();
+        let export_dir = usb.path().join(WALLET_ID).join(EXPORT_DIR_NAME);
+        fs::create_dir_all(&export_dir).unwrap();
+        let export = export_dir.join("addresses-20251017-143025.csv");
+        fs::write(&export, "Rank,Symbol\n1,BTC\n").unwrap();
+
+        // A second link to the same inode shows what was left in the file
+        #[cfg(unix)]
+        let witness = usb.path().join("witness");
+        #[cfg(unix)]
+        fs::hard_link(&export, &witness).unwrap();
+
+        wipe_export(usb.path().to_str().unwrap(), export.to_str().unwrap()).unwrap();
+        assert!(!export.exists());
+
+        #[cfg(unix)]
+        assert_eq!(fs::read(&witness).unwrap(), vec![0u8; 18]);
+    }
+
+    #[test]
+    fn test_wipe_export_rejects_traversal() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().join("usb");
+        fs::create_dir_all(usb_path.join(WALLET_ID).join(EXPORT_DIR_NAME)).unwrap();
+
+        let wallet_json = usb_path.join(WALLET_ID).join("wallet.json");
+        fs::write(&wallet_json, "{}").unwrap();
+        let traversal = usb_path.join(WALLET_ID).join(EXPORT_DIR_NAME).join("..").join("wallet.json");
+
+        let err = wipe_export(usb_path.to_str().unwrap(), traversal.to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::ExportFailed);
+        assert_eq!(fs::read_to_string(&wallet_json).unwrap(), "{}");
+    }
+}
@@ -0,0 +1,755 @@
+/**
+ * USB maintenance commands
+ * Feature: User Dashboard for Wallet Management
+ * Repairs on-USB layouts left behind by older versions (e.g. wallet
+ * directories keyed by name instead of {wallet_id}) and reports per-wallet
+ * storage usage, a portfolio summary, per-wallet creation provenance and
+ * key-derivation parameters, all without unlocking a wallet
+ */
+
+use crate::commands::usb::{is_housekeeping_entry, normalize_usb_path};
+use crate::commands::wallet::sanitize_wallet_id;
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::fs_utils::long_path;
+use crate::limits::{check_length, MAX_PATH_LEN};
+use crate::models::wallet::WalletFileMetadata;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// Hidden directory (skipped by USB scans) that holds pre-repair copies
+pub const BACKUP_DIR_NAME: &str = ".arcsign-backups";
+
+/// State of a wallet directory whose name doesn't match its wallet.json id
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MismatchStatus {
+    /// Reported only (fix mode off)
+    Found,
+    /// Backed up and moved to {usb_path}/{wallet_id}
+    Moved,
+    /// {usb_path}/{wallet_id} already exists; left untouched
+    Conflict,
+    /// wallet.json id isn't a valid wallet ID; left untouched
+    InvalidId,
+}
+
+/// A wallet directory stored under the wrong name
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DirectoryMismatch {
+    /// Current directory path
+    pub directory: String,
+
+    /// Wallet ID from the directory's wallet.json
+    pub wallet_id: String,
+
+    /// Where the directory belongs ({usb_path}/{wallet_id})
+    pub expected_directory: String,
+
+    pub status: MismatchStatus,
+
+    /// Copy made before moving (fix mode only)
+    pub backup_directory: Option<String>,
+}
+
+/// Read the `id` field of a wallet directory's wallet.json
+fn read_wallet_json_id(dir: &Path) -> Option<String> {
+    let contents = fs::read_to_string(long_path(&dir.join("wallet.json"))).ok()?;
+    WalletFileMetadata::parse(&contents)?.id
+}
+
+/// Recursively copy a directory tree.
+///
+/// Symlinks are skipped rather than followed, so a link planted in a wallet
+/// directory can't pull files from elsewhere into the backup.
+fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(long_path(to))?;
+
+    for entry in fs::read_dir(long_path(from))? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        // DirEntry::file_type doesn't follow symlinks
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() {
+            tracing::warn!("Not backing up symlink {}", entry.path().display());
+        } else if file_type.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            fs::copy(long_path(&entry.path()), long_path(&target))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Back up `dir` under BACKUP_DIR_NAME, then move it to `expected`
+fn move_with_backup(usb_root: &Path, dir: &Path, expected: &Path) -> AppResult<String> {
+    let io_error = |message: &str, e: std::io::Error| {
+        AppError::with_details(ErrorCode::UsbNotWritable, message, e.to_string())
+    };
+
+    let dir_name = dir.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let backup = usb_root
+        .join(BACKUP_DIR_NAME)
+        .join(format!("{}-{}", dir_name, Utc::now().format("%Y%m%d-%H%M%S")));
+
+    if long_path(&backup).exists() {
+        return Err(AppError::new(
+            ErrorCode::UsbNotWritable,
+            format!("Backup directory already exists: {}", backup.display()),
+        ));
+    }
+
+    copy_dir_all(dir, &backup).map_err(|e| io_error("Failed to back up wallet directory", e))?;
+    fs::rename(long_path(dir), long_path(expected))
+        .map_err(|e| io_error("Failed to move wallet directory", e))?;
+
+    tracing::info!(
+        "Moved wallet directory {} -> {} (backup at {})",
+        dir.display(),
+        expected.display(),
+        backup.display()
+    );
+
+    Ok(backup.to_string_lossy().into_owned())
+}
+
+/// Find wallet directories whose name doesn't match their wallet.json id
+/// and, if `fix` is set, move each to `{usb_path}/{wallet_id}` after backing it up.
+///
+/// Directories without a readable wallet.json are not wallets and are ignored.
+pub fn reconcile_directories(usb_path: &str, fix: bool) -> AppResult<Vec<DirectoryMismatch>> {
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let usb_root = Path::new(usb_path);
+    let entries = fs::read_dir(long_path(usb_root)).map_err(|e| {
+        AppError::with_details(ErrorCode::UsbNotFound, "USB drive not accessible", e.to_string())
+    })?;
+
+    let mut directories: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter(|entry| !is_housekeeping_entry(&entry.file_name().to_string_lossy()))
+        .map(|entry| entry.path())
+        .collect();
+    directories.sort();
+
+    let mut mismatches = Vec::new();
+
+    for dir in directories {
+        let Some(wallet_id) = read_wallet_json_id(&dir) else {
+            continue;
+        };
+        if dir.file_name().is_some_and(|name| name == wallet_id.as_str()) {
+            continue;
+        }
+
+        let expected = usb_root.join(&wallet_id);
+        let mut mismatch = DirectoryMismatch {
+            directory: dir.to_string_lossy().into_owned(),
+            wallet_id: wallet_id.clone(),
+            expected_directory: expected.to_string_lossy().into_owned(),
+            status: MismatchStatus::Found,
+            backup_directory: None,
+        };
+
+        // The id is joined into a path below, so it must be a real wallet ID
+        if sanitize_wallet_id(&wallet_id).is_err() {
+            mismatch.status = MismatchStatus::InvalidId;
+        } else if long_path(&expected).exists() {
+            mismatch.status = MismatchStatus::Conflict;
+        } else if fix {
+            mismatch.backup_directory = Some(move_with_backup(usb_root, &dir, &expected)?);
+            mismatch.status = MismatchStatus::Moved;
+        }
+
+        mismatches.push(mismatch);
+    }
+
+    Ok(mismatches)
+}
+
+/// Bytes used by one wallet directory
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WalletStorage {
+    pub wallet_id: String,
+
+    /// Recursive size of {usb_path}/{wallet_id}/, or None if part of it
+    /// couldn't be read
+    pub size_bytes: Option<u64>,
+}
+
+/// Storage used by all wallets on a USB
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StorageUsage {
+    /// Largest first; wallets of unknown size last
+    pub wallets: Vec<WalletStorage>,
+
+    /// Sum of the known wallet sizes
+    pub total_bytes: u64,
+}
+
+/// Recursive size of a directory's files. Symlinks are not followed.
+fn dir_size(dir: &Path) -> std::io::Result<u64> {
+    let mut total = 0;
+
+    for entry in fs::read_dir(long_path(dir))? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            total += dir_size(&entry.path())?;
+        } else if file_type.is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+
+    Ok(total)
+}
+
+/// Measure every `{wallet_id}/` directory on the USB
+pub fn storage_usage(usb_path: &str) -> AppResult<StorageUsage> {
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let entries = fs::read_dir(long_path(Path::new(usb_path))).map_err(|e| {
+        AppError::with_details(ErrorCode::UsbNotFound, "USB drive not accessible", e.to_string())
+    })?;
+
+    let mut wallets: Vec<WalletStorage> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter_map(|entry| {
+            let wallet_id = entry.file_name().to_str()?.to_string();
+            sanitize_wallet_id(&wallet_id).ok()?;
+
+            let size_bytes = match dir_size(&entry.path()) {
+                Ok(size) => Some(size),
+                Err(e) => {
+                    tracing::warn!("Could not measure wallet directory {}: {}", wallet_id, e);
+                    None
+                }
+            };
+
+            Some(WalletStorage { wallet_id, size_bytes })
+        })
+        .collect();
+
+    // Largest first (None sorts below every Some); ties by id for stability
+    wallets.sort_by(|a, b| {
+        b.size_bytes
+            .cmp(&a.size_bytes)
+            .then_with(|| a.wallet_id.cmp(&b.wallet_id))
+    });
+
+    let total_bytes = wallets.iter().filter_map(|w| w.size_bytes).sum();
+
+    Ok(StorageUsage { wallets, total_bytes })
+}
+
+/// One wallet in the portfolio summary
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortfolioWallet {
+    pub id: String,
+
+    /// Display name from wallet.json (the ID if unnamed)
+    pub name: String,
+
+    pub address_count: u32,
+}
+
+/// Wallet and address totals across a USB
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortfolioSummary {
+    pub wallet_count: usize,
+    pub total_addresses: u64,
+
+    /// Sorted by name, then ID
+    pub wallets: Vec<PortfolioWallet>,
+}
+
+/// Number of addresses stored for a wallet directory.
+///
+/// Read from addresses.json (written at creation), falling back to the
+/// address book in wallet.json for older wallets; 0 if neither exists.
+pub(crate) fn stored_address_count(dir: &Path) -> u32 {
+    let count_in = |file: &str, pointer: &str| -> Option<u32> {
+        let contents = fs::read_to_string(long_path(&dir.join(file))).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&contents).ok()?;
+        let count = json.pointer(pointer)?.as_array()?.len();
+        u32::try_from(count).ok()
+    };
+
+    count_in("addresses.json", "/addresses")
+        .or_else(|| count_in("wallet.json", "/addressBook/addresses"))
+        .unwrap_or(0)
+}
+
+/// Summarize every wallet on the USB from its stored metadata
+pub fn portfolio_summary_for(usb_path: &str) -> AppResult<PortfolioSummary> {
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let entries = fs::read_dir(long_path(Path::new(usb_path))).map_err(|e| {
+        AppError::with_details(ErrorCode::UsbNotFound, "USB drive not accessible", e.to_string())
+    })?;
+
+    let mut wallets: Vec<PortfolioWallet> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter_map(|entry| {
+            let id = entry.file_name().to_str()?.to_string();
+            sanitize_wallet_id(&id).ok()?;
+
+            let dir = entry.path();
+            let contents = fs::read_to_string(long_path(&dir.join("wallet.json"))).ok()?;
+            let name = WalletFileMetadata::parse(&contents)
+                .and_then(|metadata| metadata.name)
+                .unwrap_or_else(|| id.clone());
+
+            let address_count = stored_address_count(&dir);
+            Some(PortfolioWallet { id, name, address_count })
+        })
+        .collect();
+
+    wallets.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+    let total_addresses = wallets.iter().map(|w| u64::from(w.address_count)).sum();
+
+    Ok(PortfolioSummary {
+        wallet_count: wallets.len(),
+        total_addresses,
+        wallets,
+    })
+}
+
+/// How a wallet was created, from its wallet.json (nothing secret)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WalletProvenance {
+    pub wallet_id: String,
+    pub created_at: Option<String>,
+
+    /// Library version that created the wallet (not recorded by older libraries)
+    pub created_by_version: Option<String>,
+
+    /// BIP39 mnemonic length in words (not recorded by older libraries)
+    pub mnemonic_word_count: Option<u32>,
+
+    pub uses_passphrase: bool,
+}
+
+/// Read a wallet's creation provenance from its metadata
+pub fn provenance_for(usb_path: &str, wallet_id: &str) -> AppResult<WalletProvenance> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let path = Path::new(usb_path).join(wallet_id).join("wallet.json");
+    let contents = fs::read_to_string(long_path(&path)).map_err(|e| {
+        AppError::with_details(ErrorCode::WalletNotFound, "Wallet not found on USB", e.to_string())
+    })?;
+    let metadata: WalletFileMetadata = serde_json::from_str(&contents).map_err(|e| {
+        AppError::with_details(ErrorCode::DeserializationError, "Wallet metadata is corrupted", e.to_string())
+    })?;
+
+    Ok(WalletProvenance {
+        wallet_id: wallet_id.to_string(),
+        created_at: metadata.created_at,
+        created_by_version: metadata.created_by_version,
+        mnemonic_word_count: metadata.mnemonic_word_count,
+        uses_passphrase: metadata.uses_passphrase,
+    })
+}
+
+/// Encrypted mnemonic file inside a wallet directory
+const MNEMONIC_FILE_NAME: &str = "mnemonic.enc";
+
+/// Plaintext header of `mnemonic.enc`:
+/// [version:1][iterations:4 BE][memory KiB:4 BE][parallelism:1]
+const KDF_HEADER_LEN: usize = 10;
+
+/// Argon2id settings the library uses for new wallets; anything weaker
+/// predates them and unlocks faster than current practice allows
+pub const RECOMMENDED_KDF_ITERATIONS: u32 = 4;
+pub const RECOMMENDED_KDF_MEMORY_KIB: u32 = 256 * 1024;
+
+/// Argon2id parameters a wallet's mnemonic is encrypted with
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KdfParams {
+    pub wallet_id: String,
+    pub algorithm: String,
+    /// Encryption format version from the header
+    pub format_version: u8,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u8,
+    /// At least as strong as the settings used for new wallets
+    pub recommended: bool,
+}
+
+/// Parse the header of an encrypted mnemonic (the salt and ciphertext after
+/// it are never read)
+fn parse_kdf_header(wallet_id: &str, header: &[u8]) -> AppResult<KdfParams> {
+    let header: &[u8; KDF_HEADER_LEN] = header
+        .get(..KDF_HEADER_LEN)
+        .and_then(|h| h.try_into().ok())
+        .ok_or_else(|| AppError::new(ErrorCode::DeserializationError, "Encrypted mnemonic header is truncated"))?;
+
+    let iterations = u32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+    let memory_kib = u32::from_be_bytes([header[5], header[6], header[7], header[8]]);
+    let parallelism = header[9];
+
+    Ok(KdfParams {
+        wallet_id: wallet_id.to_string(),
+        algorithm: "argon2id".to_string(),
+        format_version: header[0],
+        memory_kib,
+        iterations,
+        parallelism,
+        recommended: iterations >= RECOMMENDED_KDF_ITERATIONS
+            && memory_kib >= RECOMMENDED_KDF_MEMORY_KIB
+            && parallelism > 0,
+    })
+}
+
+/// Read the KDF parameters recorded in a wallet's encrypted mnemonic header
+pub fn kdf_params_for(usb_path: &str, wallet_id: &str) -> AppResult<KdfParams> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let path = Path::new(usb_path).join(wallet_id).join(MNEMONIC_FILE_NAME);
+    let file = fs::File::open(long_path(&path)).map_err(|e| {
+        AppError::with_details(ErrorCode::WalletNotFound, "Wallet not found on USB", e.to_string())
+    })?;
+
+    let mut header = Vec::with_capacity(KDF_HEADER_LEN);
+    file.take(KDF_HEADER_LEN as u64).read_to_end(&mut header).map_err(|e| {
+        AppError::with_details(ErrorCode::DeserializationError, "Failed to read encrypted mnemonic", e.to_string())
+    })?;
+
+    parse_kdf_header(wallet_id, &header)
+}
+
+/// Key-derivation settings of a wallet, to explain slow (or too fast) unlocks
+#[tauri::command]
+pub async fn get_kdf_params(wallet_id: String, usb_path: String) -> Result<KdfParams, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    kdf_params_for(&usb_path, &wallet_id).map_err(String::from)
+}
+
+/// Creation provenance of a wallet, for audits
+#[tauri::command]
+pub async fn wallet_provenance(wallet_id: String, usb_path: String) -> Result<WalletProvenance, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    provenance_for(&usb_path, &wallet_id).map_err(String::from)
+}
+
+/// Wallet and address counts on a USB, without unlocking any wallet
+#[tauri::command]
+pub async fn portfolio_summary(usb_path: String) -> Result<PortfolioSummary, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    portfolio_summary_for(&usb_path).map_err(String::from)
+}
+
+/// Per-wallet storage usage on a USB, for the "manage storage" screen
+#[tauri::command]
+pub async fn wallet_storage_usage(usb_path: String) -> Result<StorageUsage, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    storage_usage(&usb_path).map_err(String::from)
+}
+
+/// Report (or with `fix`, repair) wallet directories not named by their wallet ID
+#[tauri::command]
+pub async fn reconcile_wallet_directories(
+    usb_path: String,
+    fix: bool,
+) -> Result<Vec<DirectoryMismatch>, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    tracing::info!("reconcile_wallet_directories: fix={}", fix);
+    reconcile_directories(&usb_path, fix).map_err(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+
+    /// Legacy layout: wallet stored under its display name
+    fn legacy_usb() -> tempfile::TempDir {
+        let usb = tempfile::tempdir().unwrap();
+        let legacy = usb.path().join("My Wallet");
+        fs::create_dir_all(legacy.join("addresses")).unwrap();
+        fs::write(legacy.join("wallet.json"), format!(r#"{{"id":"{}","name":"My Wallet"}}"#, WALLET_ID)).unwrap();
+        fs::write(legacy.join("addresses").join("addresses-20251017-143025.csv"), "a,b\n").unwrap();
+
+        // Correctly keyed wallet and unrelated directory: not reported
+        let ok_id = "a".repeat(64);
+        fs::create_dir(usb.path().join(&ok_id)).unwrap();
+        fs::write(usb.path().join(&ok_id).join("wallet.json"), format!(r#"{{"id":"{}"}}"#, ok_id)).unwrap();
+        fs::create_dir(usb.path().join("Photos")).unwrap();
+
+        usb
+    }
+
+    #[test]
+    fn test_reconcile_reports_mismatch_without_moving() {
+        let usb = legacy_usb();
+
+        let mismatches = reconcile_directories(usb.path().to_str().unwrap(), false).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].wallet_id, WALLET_ID);
+        assert_eq!(mismatches[0].status, MismatchStatus::Found);
+        assert!(mismatches[0].directory.ends_with("My Wallet"));
+        assert!(usb.path().join("My Wallet").exists());
+        assert!(!usb.path().join(WALLET_ID).exists());
+    }
+
+    #[test]
+    fn test_reconcile_fix_moves_after_backup() {
+        let usb = legacy_usb();
+
+        let mismatches = reconcile_directories(usb.path().to_str().unwrap(), true).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].status, MismatchStatus::Moved);
+        assert!(!usb.path().join("My Wallet").exists());
+
+        let export = Path::new("addresses").join("addresses-20251017-143025.csv");
+        assert!(usb.path().join(WALLET_ID).join("wallet.json").is_file());
+        assert!(usb.path().join(WALLET_ID).join(&export).is_file());
+
+        let backup = Path::new(mismatches[0].backup_directory.as_ref().unwrap());
+        assert!(backup.starts_with(usb.path().join(BACKUP_DIR_NAME)));
+        assert!(backup.join("wallet.json").is_file());
+        assert!(backup.join(&export).is_file());
+
+        // Nothing left to reconcile
+        assert!(reconcile_directories(usb.path().to_str().unwrap(), true).unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reconcile_fix_backup_skips_symlinks() {
+        let usb = legacy_usb();
+        let outside = tempfile::tempdir().unwrap();
+        fs::write(outside.path().join("secret.txt"), "not wallet data").unwrap();
+        let addresses = usb.path().join("My Wallet").join("addresses");
+        std::os::unix::fs::symlink(outside.path().join("secret.txt"), addresses.join("linked.csv")).unwrap();
+        std::os::unix::fs::symlink(outside.path(), addresses.join("linked-dir")).unwrap();
+
+        let mismatches = reconcile_directories(usb.path().to_str().unwrap(), true).unwrap();
+
+        assert_eq!(mismatches[0].status, MismatchStatus::Moved);
+        let backup = Path::new(mismatches[0].backup_directory.as_ref().unwrap()).join("addresses");
+        assert!(backup.join("addresses-20251017-143025.csv").is_file());
+        assert!(fs::symlink_metadata(backup.join("linked.csv")).is_err());
+        assert!(fs::symlink_metadata(backup.join("linked-dir")).is_err());
+    }
+
+    #[test]
+    fn test_reconcile_fix_skips_conflicts_and_invalid_ids() {
+        let usb = legacy_usb();
+        fs::create_dir(usb.path().join(WALLET_ID)).unwrap();
+
+        let traversal = usb.path().join("Other");
+        fs::create_dir(&traversal).unwrap();
+        fs::write(traversal.join("wallet.json"), r#"{"id":"../../etc"}"#).unwrap();
+
+        let mismatches = reconcile_directories(usb.path().to_str().unwrap(), true).unwrap();
+        let statuses: Vec<MismatchStatus> = mismatches.iter().map(|m| m.status).collect();
+
+        assert_eq!(statuses, vec![MismatchStatus::Conflict, MismatchStatus::InvalidId]);
+        assert!(usb.path().join("My Wallet").exists());
+        assert!(traversal.exists());
+        assert!(!usb.path().join(BACKUP_DIR_NAME).exists());
+    }
+
+    #[test]
+    fn test_storage_usage_sorted_by_size() {
+        let usb = tempfile::tempdir().unwrap();
+        let small = "b".repeat(64);
+        let large = WALLET_ID;
+
+        fs::create_dir_all(usb.path().join(&small)).unwrap();
+        fs::write(usb.path().join(&small).join("wallet.json"), vec![0u8; 100]).unwrap();
+
+        let exports = usb.path().join(large).join("addresses");
+        fs::create_dir_all(&exports).unwrap();
+        fs::write(usb.path().join(large).join("wallet.json"), vec![0u8; 200]).unwrap();
+        fs::write(exports.join("addresses-20251017-143025.json"), vec![0u8; 1000]).unwrap();
+
+        // Not wallets: not counted
+        fs::create_dir(usb.path().join("Photos")).unwrap();
+        fs::write(usb.path().join("Photos").join("a.jpg"), vec![0u8; 5000]).unwrap();
+
+        let usage = storage_usage(usb.path().to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            usage.wallets,
+            vec![
+                WalletStorage { wallet_id: large.to_string(), size_bytes: Some(1200) },
+                WalletStorage { wallet_id: small, size_bytes: Some(100) },
+            ]
+        );
+        assert_eq!(usage.total_bytes, 1300);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_storage_usage_unreadable_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Root can read regardless of permissions
+        if unsafe { libc::geteuid() } == 0 {
+            return;
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let locked = usb.path().join(WALLET_ID).join("locked");
+        fs::create_dir_all(&locked).unwrap();
+        fs::write(usb.path().join(WALLET_ID).join("wallet.json"), "{}").unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+
+        let usage = storage_usage(usb.path().to_str().unwrap());
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let usage = usage.unwrap();
+        assert_eq!(usage.wallets[0].size_bytes, None);
+        assert_eq!(usage.total_bytes, 0);
+    }
+
+    #[test]
+    fn test_portfolio_summary_counts_addresses() {
+        let usb = tempfile::tempdir().unwrap();
+        let savings = "b".repeat(64);
+
+        // Current layout: addresses.json next to wallet.json
+        let trading = usb.path().join(WALLET_ID);
+        fs::create_dir_all(&trading).unwrap();
+        fs::write(trading.join("wallet.json"), format!(r#"{{"id":"{}","name":"Trading"}}"#, WALLET_ID)).unwrap();
+        fs::write(
+            trading.join("addresses.json"),
+            r#"{"total_count":3,"addresses":[{"symbol":"BTC"},{"symbol":"ETH"},{"symbol":"LTC"}]}"#,
+        )
+        .unwrap();
+
+        // Older wallet: address book inside wallet.json
+        fs::create_dir_all(usb.path().join(&savings)).unwrap();
+        fs::write(
+            usb.path().join(&savings).join("wallet.json"),
+            format!(r#"{{"id":"{}","name":"Savings","addressBook":{{"addresses":[{{}},{{}}]}}}}"#, savings),
+        )
+        .unwrap();
+
+        // Not wallets: no wallet.json, or not a wallet ID
+        fs::create_dir(usb.path().join("c".repeat(64))).unwrap();
+        fs::create_dir(usb.path().join("Photos")).unwrap();
+
+        let summary = portfolio_summary_for(usb.path().to_str().unwrap()).unwrap();
+
+        assert_eq!(summary.wallet_count, 2);
+        assert_eq!(summary.total_addresses, 5);
+        assert_eq!(
+            summary.wallets,
+            vec![
+                PortfolioWallet { id: savings, name: "Savings".to_string(), address_count: 2 },
+                PortfolioWallet { id: WALLET_ID.to_string(), name: "Trading".to_string(), address_count: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wallet_provenance_with_recorded_version() {
+        let usb = tempfile::tempdir().unwrap();
+        let dir = usb.path().join(WALLET_ID);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("wallet.json"),
+            format!(
+                r#"{{"id":"{}","createdAt":"2025-10-17T14:30:25Z","encryptedMnemonicPath":"/usb/mnemonic.enc","usesPassphrase":true,"mnemonicWordCount":24,"createdByVersion":"0.2.0"}}"#,
+                WALLET_ID
+            ),
+        )
+        .unwrap();
+
+        let provenance = provenance_for(usb.path().to_str().unwrap(), WALLET_ID).unwrap();
+        assert_eq!(
+            provenance,
+            WalletProvenance {
+                wallet_id: WALLET_ID.to_string(),
+                created_at: Some("2025-10-17T14:30:25Z".to_string()),
+                created_by_version: Some("0.2.0".to_string()),
+                mnemonic_word_count: Some(24),
+                uses_passphrase: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_wallet_provenance_without_recorded_version() {
+        let usb = tempfile::tempdir().unwrap();
+        let dir = usb.path().join(WALLET_ID);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("wallet.json"),
+            format!(r#"{{"id":"{}","createdAt":"2025-10-17T14:30:25Z","usesPassphrase":false}}"#, WALLET_ID),
+        )
+        .unwrap();
+
+        let provenance = provenance_for(usb.path().to_str().unwrap(), WALLET_ID).unwrap();
+        assert_eq!(provenance.created_by_version, None);
+        assert_eq!(provenance.mnemonic_word_count, None);
+        assert!(!provenance.uses_passphrase);
+
+        let err = provenance_for(usb.path().to_str().unwrap(), &"b".repeat(64)).unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletNotFound);
+    }
+
+    /// mnemonic.enc as the library writes it: header, salt, nonce, ciphertext
+    fn write_mnemonic_file(usb: &Path, iterations: u32, memory_kib: u32, parallelism: u8) {
+        let mut data = vec![1u8];
+        data.extend_from_slice(&iterations.to_be_bytes());
+        data.extend_from_slice(&memory_kib.to_be_bytes());
+        data.push(parallelism);
+        data.extend_from_slice(&[0xAA; 16 + 12 + 48]);
+
+        fs::create_dir_all(usb.join(WALLET_ID)).unwrap();
+        fs::write(usb.join(WALLET_ID).join(MNEMONIC_FILE_NAME), data).unwrap();
+    }
+
+    #[test]
+    fn test_kdf_params_from_mnemonic_header() {
+        let usb = tempfile::tempdir().unwrap();
+        write_mnemonic_file(usb.path(), 4, 262_144, 4);
+
+        let params = kdf_params_for(usb.path().to_str().unwrap(), WALLET_ID).unwrap();
+        assert_eq!(
+            params,
+            KdfParams {
+                wallet_id: WALLET_ID.to_string(),
+                algorithm: "argon2id".to_string(),
+                format_version: 1,
+                memory_kib: 262_144,
+                iterations: 4,
+                parallelism: 4,
+                recommended: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_weaker_kdf_params_are_not_recommended() {
+        let usb = tempfile::tempdir().unwrap();
+        write_mnemonic_file(usb.path(), 3, 65_536, 1);
+
+        let params = kdf_params_for(usb.path().to_str().unwrap(), WALLET_ID).unwrap();
+        assert_eq!((params.iterations, params.memory_kib, params.parallelism), (3, 65_536, 1));
+        assert!(!params.recommended);
+
+        let err = parse_kdf_header(WALLET_ID, &[1, 0, 0, 0, 4]).unwrap_err();
+        assert_eq!(err.code, ErrorCode::DeserializationError);
+
+        let err = kdf_params_for(usb.path().to_str().unwrap(), "7f1c2d3e-4b5a-4c6d-8e9f-0a1b2c3d4e5f").unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletNotFound);
+    }
+}
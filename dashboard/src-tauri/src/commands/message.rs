@@ -0,0 +1,305 @@
+/**
+ * Message signing commands
+ * Feature: User Dashboard for Wallet Management
+ * Proves ownership of a wallet address by signing an arbitrary message
+ * (Bitcoin BIP-137 message signing, Ethereum EIP-191 personal_sign),
+ * and verifies such proofs from others
+ *
+ * Both depend on SignMessage/VerifyMessage exports the wallet library
+ * doesn't have yet; `capabilities` reports them as `messageSigning`
+ */
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::transaction::{is_evm_chain, is_bitcoin_chain, normalize_recipient_address};
+use crate::commands::usb::normalize_usb_path;
+use crate::commands::wallet::sanitize_wallet_id;
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::ffi::{LazyWalletQueue, WalletBackend};
+use crate::limits::{
+    check_length, MAX_MESSAGE_LEN, MAX_PASSWORD_LEN, MAX_PATH_LEN, MAX_SIGNATURE_LEN,
+};
+use crate::secret::{ffi_params, Secret};
+
+/// A message signed by one of the wallet's addresses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedMessage {
+    pub chain_id: String,
+    pub address: String,
+    pub message: String,
+    /// Base64 (BIP-137) or 0x-hex (EIP-191) signature
+    pub signature: String,
+    /// "bip137" or "eip191"
+    pub scheme: String,
+}
+
+/// Message signing scheme for a chain.
+///
+/// Both schemes hash a chain-specific prefix with the message, which keeps
+/// message signatures from being valid transaction signatures.
+pub(crate) fn signing_scheme(chain_id: &str) -> AppResult<&'static str> {
+    if is_bitcoin_chain(chain_id) {
+        Ok("bip137")
+    } else if is_evm_chain(chain_id) {
+        Ok("eip191")
+    } else {
+        Err(AppError::new(
+            ErrorCode::FfiInvalidBlockchain,
+            format!("Message signing is not supported for {}", chain_id),
+        ))
+    }
+}
+
+/// Map a SignMessage FFI error ("CODE: message") to an AppError
+fn sign_error(error: String) -> AppError {
+    let code = match error.split_once(':').map(|(code, _)| code.trim()) {
+        // A failed decrypt means the password was wrong
+        Some("INVALID_PASSWORD") | Some("DECRYPTION_ERROR") => ErrorCode::InvalidPassword,
+        Some(code) => AppError::from_ffi_error_code(code),
+        None => ErrorCode::InternalError,
+    };
+
+    let message = match code {
+        ErrorCode::InvalidPassword => "Invalid password",
+        ErrorCode::InternalError => "Failed to sign message",
+        _ => AppError::default_message_for_code(code),
+    };
+
+    AppError::with_details(code, message, error)
+}
+
+/// Unlock the wallet and sign `message` with the key of `address`
+pub(crate) async fn sign_with_wallet(
+    queue: &LazyWalletQueue,
+    wallet_id: &str,
+    password: &Secret,
+    usb_path: &str,
+    chain_id: &str,
+    address: &str,
+    message: &str,
+) -> AppResult<SignedMessage> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("Password", password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+    check_length("Message", message, MAX_MESSAGE_LEN)?;
+
+    if message.is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "Message is required"));
+    }
+
+    let scheme = signing_scheme(chain_id)?;
+    let address = normalize_recipient_address(chain_id, address)?;
+
+    let params_json = ffi_params(
+        serde_json::json!({
+            "walletId": wallet_id,
+            "usbPath": usb_path,
+            "chainId": chain_id,
+            "address": address,
+            "message": message,
+            "scheme": scheme,
+        }),
+        &[("password", password.expose())],
+    )?;
+
+    let result = queue.sign_message(params_json).await;
+
+    let signature = result
+        .map_err(sign_error)?
+        .get("signature")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            AppError::new(ErrorCode::DeserializationError, "Missing signature in FFI response")
+        })?;
+
+    Ok(SignedMessage {
+        chain_id: chain_id.to_string(),
+        address,
+        message: message.to_string(),
+        signature,
+        scheme: scheme.to_string(),
+    })
+}
+
+/// Sign a message with one of the wallet's addresses to prove ownership
+/// Fails with UnsupportedOperation until the library exports SignMessage
+#[tauri::command]
+pub async fn sign_message(
+    backend: State<'_, WalletBackend>,
+    wallet_id: String,
+    password: Secret,
+    usb_path: String,
+    chain_id: String,
+    address: String,
+    message: String,
+) -> Result<SignedMessage, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    tracing::info!("sign_message: wallet_id={}, chain_id={}", wallet_id, chain_id);
+
+    sign_with_wallet(queue, &wallet_id, &password, &usb_path, &chain_id, &address, &message)
+        .await
+        .map_err(String::from)
+}
+
+/// Check that `signature` over `message` was produced by `address`
+pub(crate) async fn verify_signature(
+    queue: &LazyWalletQueue,
+    chain_id: &str,
+    address: &str,
+    message: &str,
+    signature: &str,
+) -> AppResult<bool> {
+    check_length("Message", message, MAX_MESSAGE_LEN)?;
+    check_length("Signature", signature, MAX_SIGNATURE_LEN)?;
+
+    let signature = signature.trim();
+    if signature.is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "Signature is required"));
+    }
+
+    let scheme = signing_scheme(chain_id)?;
+    let address = normalize_recipient_address(chain_id, address)?;
+
+    let params_json = serde_json::to_string(&serde_json::json!({
+        "chainId": chain_id,
+        "address": address,
+        "message": message,
+        "signature": signature,
+        "scheme": scheme,
+    }))?;
+
+    let result = queue.verify_message(params_json).await.map_err(|e| {
+        let code = e
+            .split_once(':')
+            .map(|(code, _)| AppError::from_ffi_error_code(code.trim()))
+            .unwrap_or(ErrorCode::InternalError);
+        AppError::with_details(code, "Failed to verify message", e)
+    })?;
+
+    result.get("valid").and_then(|v| v.as_bool()).ok_or_else(|| {
+        AppError::new(ErrorCode::DeserializationError, "Missing valid flag in FFI response")
+    })
+}
+
+/// Verify a signed message from any address (no wallet needed)
+/// Fails with UnsupportedOperation until the library exports VerifyMessage
+#[tauri::command]
+pub async fn verify_message(
+    backend: State<'_, WalletBackend>,
+    chain_id: String,
+    address: String,
+    message: String,
+    signature: String,
+) -> Result<bool, String> {
+    let queue = backend.queue().map_err(String::from)?;
+
+    verify_signature(queue, &chain_id, &address, &message, &signature)
+        .await
+        .map_err(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::stub::{ffi_string, stub_library};
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+    use std::sync::Arc;
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+    const PASSWORD: &str = "ValidPassword123";
+    const ETH_ADDRESS: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    /// Signs only with the right password and an EIP-191 scheme
+    unsafe extern "C" fn sign_ok(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+
+        if params["password"] != PASSWORD {
+            return ffi_string(
+                r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"decryption failed"}}"#,
+            );
+        }
+        assert_eq!(params["scheme"], "eip191");
+        ffi_string(r#"{"success":true,"data":{"signature":"0xabc123"}}"#)
+    }
+
+    /// Accepts only the signature sign_ok produces for "I own this"
+    unsafe extern "C" fn verify_stub(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+
+        let valid = params["message"] == "I own this"
+            && params["signature"] == "0xabc123"
+            && params["address"] == ETH_ADDRESS;
+        ffi_string(&format!(r#"{{"success":true,"data":{{"valid":{}}}}}"#, valid))
+    }
+
+    fn queue() -> LazyWalletQueue {
+        let mut library = stub_library();
+        library.sign_message = Some(sign_ok);
+        library.verify_message = Some(verify_stub);
+        LazyWalletQueue::new(Arc::new(library))
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_returns_signature() {
+        let password = Secret::new(PASSWORD.to_string());
+        let address = ETH_ADDRESS.to_lowercase();
+
+        let signed = sign_with_wallet(&queue(), WALLET_ID, &password, "/usb", "ethereum", &address, "I own this")
+            .await
+            .unwrap();
+
+        assert_eq!(signed.signature, "0xabc123");
+        assert_eq!(signed.scheme, "eip191");
+        assert_eq!(signed.address, ETH_ADDRESS);
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_rejects_wrong_password() {
+        let password = Secret::new("WrongPassword456".to_string());
+
+        let err = sign_with_wallet(&queue(), WALLET_ID, &password, "/usb", "ethereum", ETH_ADDRESS, "I own this")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::InvalidPassword);
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_validates_input() {
+        let password = Secret::new(PASSWORD.to_string());
+
+        let err = sign_with_wallet(&queue(), WALLET_ID, &password, "/usb", "ethereum", ETH_ADDRESS, "")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+
+        let err = sign_with_wallet(&queue(), WALLET_ID, &password, "/usb", "solana", ETH_ADDRESS, "hi")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidBlockchain);
+    }
+
+    #[tokio::test]
+    async fn test_verify_message_valid_signature() {
+        let valid = verify_signature(&queue(), "ethereum", ETH_ADDRESS, "I own this", "0xabc123")
+            .await
+            .unwrap();
+        assert!(valid);
+    }
+
+    #[tokio::test]
+    async fn test_verify_message_tampered_message() {
+        let valid = verify_signature(&queue(), "ethereum", ETH_ADDRESS, "I own this!", "0xabc123")
+            .await
+            .unwrap();
+        assert!(!valid);
+    }
+}
@@ -4,7 +4,17 @@
  */
 
 pub mod app;
+pub mod bundle;
+pub mod derivation;
+pub mod descriptor;
+pub mod diagnostics;
+pub mod export;
+pub mod maintenance;
+pub mod message;
 pub mod provider;
+pub mod ranking;
+pub mod receive;
 pub mod security;
+pub mod transaction;
 pub mod usb;
 pub mod wallet;
@@ -0,0 +1,228 @@
+/**
+ * Address ranking preferences
+ * Feature: User Dashboard for Wallet Management
+ * Lets a user pin favorite chains to the top of a wallet's address list.
+ * Stored per wallet on the USB next to wallet.json; purely presentational,
+ * derivation and the library's own ranks are untouched
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::usb::normalize_usb_path;
+use crate::commands::wallet::{ensure_wallet_exists, sanitize_wallet_id};
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::fs_utils::{long_path, write_atomic};
+use crate::limits::{check_length, MAX_PATH_LEN};
+use crate::models::address::Address;
+
+/// Preference file inside a wallet directory
+pub const RANKING_FILE_NAME: &str = "address-ranking.json";
+
+/// More than every supported chain, so a full ordering always fits
+const MAX_PREFERRED_SYMBOLS: usize = 64;
+
+/// Longest accepted chain symbol
+const MAX_SYMBOL_LEN: usize = 16;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RankingFile {
+    preferred_symbols: Vec<String>,
+}
+
+fn ranking_path(usb_path: &str, wallet_id: &str) -> PathBuf {
+    Path::new(usb_path).join(wallet_id).join(RANKING_FILE_NAME)
+}
+
+/// Uppercase, trim and de-duplicate symbols, keeping the first occurrence
+fn normalize_symbols(symbols: &[String]) -> AppResult<Vec<String>> {
+    if symbols.len() > MAX_PREFERRED_SYMBOLS {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidInput,
+            format!("At most {} preferred symbols are allowed", MAX_PREFERRED_SYMBOLS),
+        ));
+    }
+
+    let mut normalized: Vec<String> = Vec::with_capacity(symbols.len());
+    for symbol in symbols {
+        let symbol = symbol.trim().to_ascii_uppercase();
+        if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LEN || !symbol.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(AppError::new(
+                ErrorCode::FfiInvalidInput,
+                format!("Invalid chain symbol '{}'", symbol),
+            ));
+        }
+        if !normalized.contains(&symbol) {
+            normalized.push(symbol);
+        }
+    }
+    Ok(normalized)
+}
+
+/// Store a wallet's preferred symbol order; an empty list removes it
+pub fn save_ranking(usb_path: &str, wallet_id: &str, preferred_symbols: &[String]) -> AppResult<Vec<String>> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+    let preferred_symbols = normalize_symbols(preferred_symbols)?;
+    ensure_wallet_exists(usb_path, wallet_id)?;
+
+    let path = ranking_path(usb_path, wallet_id);
+    if preferred_symbols.is_empty() {
+        match fs::remove_file(long_path(&path)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(AppError::with_details(
+                    ErrorCode::UsbNotWritable,
+                    "Failed to clear address ranking",
+                    e.to_string(),
+                ))
+            }
+        }
+        return Ok(preferred_symbols);
+    }
+
+    let contents = serde_json::to_vec_pretty(&RankingFile { preferred_symbols: preferred_symbols.clone() })?;
+    write_atomic(&path, &contents).map_err(|e| {
+        AppError::with_details(ErrorCode::UsbNotWritable, "Failed to save address ranking", e.to_string())
+    })?;
+
+    Ok(preferred_symbols)
+}
+
+/// A wallet's preferred symbol order (empty if none is stored)
+pub fn load_ranking(usb_path: &str, wallet_id: &str) -> AppResult<Vec<String>> {
+    let path = ranking_path(usb_path, wallet_id);
+    let contents = match fs::read(long_path(&path)) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(AppError::with_details(
+                ErrorCode::DeserializationError,
+                "Failed to read address ranking",
+                e.to_string(),
+            ))
+        }
+    };
+
+    let file: RankingFile = serde_json::from_slice(&contents).map_err(|e| {
+        AppError::with_details(ErrorCode::DeserializationError, "Address ranking is corrupted", e.to_string())
+    })?;
+    normalize_symbols(&file.preferred_symbols)
+}
+
+/// Move addresses of `preferred_symbols` to the front, in that order; the
+/// rest keep their relative order. Ranks are renumbered from 1 with the
+/// library's rank kept as `default_rank`.
+pub fn apply_ranking(addresses: &mut [Address], preferred_symbols: &[String]) {
+    if preferred_symbols.is_empty() {
+        return;
+    }
+
+    // Stable sort: equal keys (all non-preferred chains) stay in input order
+    addresses.sort_by_key(|a| {
+        preferred_symbols
+            .iter()
+            .position(|symbol| symbol.eq_ignore_ascii_case(&a.symbol))
+            .unwrap_or(preferred_symbols.len())
+    });
+
+    for (index, address) in addresses.iter_mut().enumerate() {
+        address.default_rank = Some(address.default_rank.unwrap_or(address.rank));
+        address.rank = index as u32 + 1;
+    }
+}
+
+/// Pin chains to the top of a wallet's address list (Tauri command)
+/// Returns the stored order; an empty list restores the default ranking
+#[tauri::command]
+pub async fn set_address_ranking(
+    wallet_id: String,
+    preferred_symbols: Vec<String>,
+    usb_path: String,
+) -> Result<Vec<String>, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+
+    tracing::info!("set_address_ranking: wallet_id={}, symbols={}", wallet_id, preferred_symbols.len());
+    save_ranking(&usb_path, &wallet_id, &preferred_symbols).map_err(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::address::{Category, KeyType};
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+
+    fn addresses(symbols: &[&str]) -> Vec<Address> {
+        symbols
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| {
+                Address::new(
+                    WALLET_ID.to_string(),
+                    index as u32 + 1,
+                    symbol.to_string(),
+                    symbol.to_lowercase(),
+                    0,
+                    "m/44'/0'/0'/0/0".to_string(),
+                    format!("{}-address", symbol),
+                    Category::Base,
+                    KeyType::Secp256k1,
+                )
+            })
+            .collect()
+    }
+
+    fn order(addresses: &[Address]) -> Vec<(&str, u32, Option<u32>)> {
+        addresses.iter().map(|a| (a.symbol.as_str(), a.rank, a.default_rank)).collect()
+    }
+
+    #[test]
+    fn test_preferred_symbols_move_to_front_and_rest_keep_order() {
+        let mut list = addresses(&["BTC", "ETH", "USDT", "BNB", "SOL", "XRP"]);
+        apply_ranking(&mut list, &["SOL".to_string(), "eth".to_string(), "DOGE".to_string()]);
+
+        assert_eq!(
+            order(&list),
+            vec![
+                ("SOL", 1, Some(5)),
+                ("ETH", 2, Some(2)),
+                ("BTC", 3, Some(1)),
+                ("USDT", 4, Some(3)),
+                ("BNB", 5, Some(4)),
+                ("XRP", 6, Some(6)),
+            ]
+        );
+
+        // No preference: the library's order and ranks are untouched
+        let mut list = addresses(&["BTC", "ETH"]);
+        apply_ranking(&mut list, &[]);
+        assert_eq!(order(&list), vec![("BTC", 1, None), ("ETH", 2, None)]);
+    }
+
+    #[test]
+    fn test_ranking_round_trip_on_usb() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        fs::create_dir(usb.path().join(WALLET_ID)).unwrap();
+        fs::write(usb.path().join(WALLET_ID).join("wallet.json"), "{}").unwrap();
+
+        assert!(load_ranking(usb_path, WALLET_ID).unwrap().is_empty());
+
+        let stored = save_ranking(usb_path, WALLET_ID, &[" sol".to_string(), "BTC".to_string(), "SOL".to_string()])
+            .unwrap();
+        assert_eq!(stored, vec!["SOL".to_string(), "BTC".to_string()]);
+        assert_eq!(load_ranking(usb_path, WALLET_ID).unwrap(), stored);
+
+        save_ranking(usb_path, WALLET_ID, &[]).unwrap();
+        assert!(!usb.path().join(WALLET_ID).join(RANKING_FILE_NAME).exists());
+
+        let err = save_ranking(usb_path, WALLET_ID, &["BTC/../x".to_string()]).unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+        let err = save_ranking(usb_path, "7f1c2d3e-4b5a-4c6d-8e9f-0a1b2c3d4e5f", &["BTC".to_string()]).unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletNotFound);
+    }
+}
@@ -0,0 +1,134 @@
+/**
+ * Receive payload commands
+ * Feature: User Dashboard for Wallet Management
+ * Builds the payment URI shown as a QR code when receiving funds
+ * (Bitcoin BIP-21, Ethereum EIP-681), which is what wallet scanners expect
+ * instead of a bare address
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::amount;
+use crate::commands::transaction::{is_bitcoin_chain, is_evm_chain, normalize_recipient_address};
+use crate::error::{AppError, AppResult, ErrorCode};
+
+/// Payment request for a receive QR code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivePayload {
+    pub chain_id: String,
+    /// Validated address (EIP-55 checksummed on EVM chains), for display
+    pub address: String,
+    /// BIP-21 or EIP-681 URI to encode in the QR code
+    pub uri: String,
+}
+
+/// EIP-155 chain ID for EVM networks other than mainnet
+pub(crate) fn evm_network_id(chain_id: &str) -> Option<u64> {
+    match chain_id {
+        "ethereum-goerli" => Some(5),
+        "ethereum-sepolia" => Some(11_155_111),
+        _ => None,
+    }
+}
+
+/// Build the payment URI for `address`, optionally requesting `amount`.
+///
+/// `amount` is parsed like a transaction amount ("1", "0.5 BTC", "20 gwei"),
+/// so a bare number is in the chain's main unit, then written as BTC for BIP-21 `amount` and as wei for EIP-681 `value`.
+pub(crate) fn receive_payload(chain_id: &str, address: &str, amount: Option<&str>) -> AppResult<ReceivePayload> {
+    let amount = amount.map(str::trim).filter(|a| !a.is_empty());
+
+    let (address, uri) = if is_bitcoin_chain(chain_id) {
+        let address = normalize_recipient_address(chain_id, address)?;
+        let uri = match amount {
+            Some(amount) => {
+                let satoshi = amount::to_base_units(chain_id, amount)?;
+                format!("bitcoin:{}?amount={}", address, amount::to_main_units(chain_id, &satoshi)?)
+            }
+            None => format!("bitcoin:{}", address),
+        };
+        (address, uri)
+    } else if is_evm_chain(chain_id) {
+        let address = normalize_recipient_address(chain_id, address)?;
+        let network = evm_network_id(chain_id)
+            .map(|id| format!("@{}", id))
+            .unwrap_or_default();
+        let uri = match amount {
+            Some(amount) => format!(
+                "ethereum:{}{}?value={}",
+                address,
+                network,
+                amount::to_base_units(chain_id, amount)?
+            ),
+            None => format!("ethereum:{}{}", address, network),
+        };
+        (address, uri)
+    } else {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidBlockchain,
+            format!("Payment requests are not supported for {}", chain_id),
+        ));
+    };
+
+    Ok(ReceivePayload {
+        chain_id: chain_id.to_string(),
+        address,
+        uri,
+    })
+}
+
+/// Get the payment URI to show as a receive QR code
+#[tauri::command]
+pub async fn get_receive_payload(
+    chain_id: String,
+    address: String,
+    amount: Option<String>,
+) -> Result<ReceivePayload, String> {
+    receive_payload(&chain_id, &address, amount.as_deref()).map_err(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BTC_ADDRESS: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+    const ETH_ADDRESS: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    #[test]
+    fn test_bitcoin_uri() {
+        let payload = receive_payload("bitcoin", BTC_ADDRESS, Some("0.015")).unwrap();
+        assert_eq!(payload.uri, format!("bitcoin:{}?amount=0.015", BTC_ADDRESS));
+
+        let payload = receive_payload("bitcoin", BTC_ADDRESS, Some("2500 sats")).unwrap();
+        assert_eq!(payload.uri, format!("bitcoin:{}?amount=0.000025", BTC_ADDRESS));
+
+        let payload = receive_payload("bitcoin", BTC_ADDRESS, Some("1")).unwrap();
+        assert_eq!(payload.uri, format!("bitcoin:{}?amount=1", BTC_ADDRESS));
+
+        let payload = receive_payload("bitcoin", BTC_ADDRESS, None).unwrap();
+        assert_eq!(payload.uri, format!("bitcoin:{}", BTC_ADDRESS));
+    }
+
+    #[test]
+    fn test_ethereum_uri() {
+        let payload = receive_payload("ethereum", &ETH_ADDRESS.to_lowercase(), Some("1.5")).unwrap();
+        assert_eq!(payload.address, ETH_ADDRESS);
+        assert_eq!(payload.uri, format!("ethereum:{}?value=1500000000000000000", ETH_ADDRESS));
+
+        let payload = receive_payload("ethereum-sepolia", ETH_ADDRESS, Some("")).unwrap();
+        assert_eq!(payload.uri, format!("ethereum:{}@11155111", ETH_ADDRESS));
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        let err = receive_payload("bitcoin", "not-an-address", None).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidRecipientAddress);
+
+        let err = receive_payload("ethereum", ETH_ADDRESS, Some("-1")).unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+
+        let err = receive_payload("solana", "anything", None).unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidBlockchain);
+    }
+}
@@ -5,7 +5,93 @@
  * Generated: 2025-10-17
  */
 
-use tauri::{Manager, Window};
+use crate::commands::wallet::UnlockedWallets;
+use crate::ffi::queue::env_number;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{Manager, State, Window};
+
+/// Idle time before unlocked wallets are locked, matching the frontend's
+/// inactivity logout (SEC-006)
+pub const DEFAULT_AUTO_LOCK: Duration = Duration::from_secs(15 * 60);
+
+/// How often the auto-lock check runs
+pub const AUTO_LOCK_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Event emitted after an auto-lock, so the frontend can drop wallet views
+pub const AUTO_LOCK_EVENT: &str = "wallets-auto-locked";
+
+/// Time of the last user interaction, for auto-lock idle detection.
+///
+/// Lock-free so the frequent heartbeats never wait on wallet work.
+pub struct ActivityTracker {
+    started: Instant,
+    /// Milliseconds from `started` to the last activity
+    last_activity_ms: AtomicU64,
+    /// Idle seconds before wallets are locked (0 = never)
+    auto_lock_secs: AtomicU64,
+}
+
+impl ActivityTracker {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            last_activity_ms: AtomicU64::new(0),
+            auto_lock_secs: AtomicU64::new(DEFAULT_AUTO_LOCK.as_secs()),
+        }
+    }
+
+    /// `new`, with the auto-lock timeout overridden by ARCSIGN_AUTO_LOCK_SECS
+    /// when it is set (0 turns auto-lock off)
+    pub fn from_env() -> Self {
+        let tracker = Self::new();
+        if let Some(secs) = env_number::<u64>("ARCSIGN_AUTO_LOCK_SECS") {
+            tracker.set_auto_lock(Some(Duration::from_secs(secs)).filter(|timeout| !timeout.is_zero()));
+        }
+        tracker
+    }
+
+    /// Record activity now; returns how long the user had been idle
+    pub fn touch(&self) -> Duration {
+        let now_ms = self.started.elapsed().as_millis() as u64;
+        let previous_ms = self.last_activity_ms.swap(now_ms, Ordering::Relaxed);
+        Duration::from_millis(now_ms.saturating_sub(previous_ms))
+    }
+
+    /// Time since the last activity
+    pub fn idle(&self) -> Duration {
+        let now_ms = self.started.elapsed().as_millis() as u64;
+        let last_ms = self.last_activity_ms.load(Ordering::Relaxed);
+        Duration::from_millis(now_ms.saturating_sub(last_ms))
+    }
+
+    /// Idle time before wallets are locked; None when auto-lock is off
+    pub fn auto_lock(&self) -> Option<Duration> {
+        match self.auto_lock_secs.load(Ordering::Relaxed) {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    pub fn set_auto_lock(&self, timeout: Option<Duration>) {
+        let secs = timeout.map_or(0, |timeout| timeout.as_secs().max(1));
+        self.auto_lock_secs.store(secs, Ordering::Relaxed);
+    }
+}
+
+impl Default for ActivityTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reset the idle timer on user interaction (mouse/keyboard), so a user
+/// who is reading but not issuing commands isn't auto-locked
+#[tauri::command]
+pub async fn heartbeat(activity: State<'_, ActivityTracker>) -> Result<(), String> {
+    activity.touch();
+    Ok(())
+}
 
 /// Enable screenshot protection (SEC-004)
 /// Prevents screen capture during sensitive operations (mnemonic display)
@@ -53,7 +139,35 @@ pub async fn disable_screenshot_protection(window: Window) -> Result<(), String>
 /// Clear sensitive data from memory (SEC-003)
 /// Attempts to zero out memory containing sensitive data
 #[tauri::command]
-pub async fn clear_sensitive_memory() -> Result<(), String> {
+pub async fn clear_sensitive_memory(unlocked: State<'_, UnlockedWallets>) -> Result<(), String> {
+    clear_session(unlocked.inner())
+}
+
+/// Lock every wallet once the user has been idle for the auto-lock timeout.
+/// Returns true if wallets were locked (false with none unlocked, so an
+/// idle app isn't re-locked on every check).
+pub(crate) fn auto_lock_if_idle(activity: &ActivityTracker, unlocked: &UnlockedWallets) -> bool {
+    let Some(timeout) = activity.auto_lock() else {
+        return false;
+    };
+
+    let idle = activity.idle();
+    if idle < timeout || unlocked.wallet_ids().is_empty() {
+        return false;
+    }
+
+    tracing::info!("Auto-locking wallets after {}s idle", idle.as_secs());
+    match clear_session(unlocked) {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::warn!("Auto-lock failed: {}", e);
+            false
+        }
+    }
+}
+
+/// Body of `clear_sensitive_memory`
+pub(crate) fn clear_session(unlocked: &UnlockedWallets) -> Result<(), String> {
     // Note: Full implementation would use secure memory zeroing libraries
     // like zeroize or secrecy crates
 
@@ -62,6 +176,9 @@ pub async fn clear_sensitive_memory() -> Result<(), String> {
 
     tracing::info!("Clearing sensitive memory");
 
+    // Locking: the next address load verifies the password again
+    unlocked.clear();
+
     // Placeholder: Would zero mnemonic and password memory here
     Ok(())
 }
@@ -190,8 +307,53 @@ mod tests {
 
     #[tokio::test]
     async fn test_clear_sensitive_memory() {
-        let result = clear_sensitive_memory().await;
+        let unlocked = UnlockedWallets::default();
+        let password = crate::secret::Secret::new("ValidPassword123".to_string());
+        unlocked.mark_unlocked("wallet-1", &password, unlocked.lock_generation());
+
+        let result = clear_session(&unlocked);
         assert!(result.is_ok());
+        assert!(!unlocked.is_unlocked("wallet-1", &password));
+    }
+
+    #[test]
+    fn test_touch_resets_idle_time() {
+        let activity = ActivityTracker::new();
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert!(activity.idle() >= Duration::from_millis(30));
+        assert!(activity.touch() >= Duration::from_millis(30));
+        assert!(activity.touch() < Duration::from_millis(30));
+        assert!(activity.idle() < Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_auto_lock_locks_idle_wallets_once() {
+        let activity = ActivityTracker::new();
+        let unlocked = UnlockedWallets::default();
+        let password = crate::secret::Secret::new("ValidPassword123".to_string());
+        unlocked.mark_unlocked("wallet-1", &password, unlocked.lock_generation());
+
+        // Not idle long enough
+        assert!(!auto_lock_if_idle(&activity, &unlocked));
+        assert!(unlocked.is_unlocked("wallet-1", &password));
+
+        // Auto-lock off
+        activity.set_auto_lock(None);
+        std::thread::sleep(Duration::from_millis(1100));
+        assert!(!auto_lock_if_idle(&activity, &unlocked));
+
+        activity.set_auto_lock(Some(Duration::from_secs(1)));
+        assert!(auto_lock_if_idle(&activity, &unlocked));
+        assert!(!unlocked.is_unlocked("wallet-1", &password));
+        // Nothing left to lock
+        assert!(!auto_lock_if_idle(&activity, &unlocked));
+
+        // Activity restarts the timer
+        unlocked.mark_unlocked("wallet-1", &password, unlocked.lock_generation());
+        activity.touch();
+        assert!(!auto_lock_if_idle(&activity, &unlocked));
+        assert!(unlocked.is_unlocked("wallet-1", &password));
     }
 
     // Note: Window-based tests require Tauri runtime
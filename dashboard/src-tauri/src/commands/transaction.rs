@@ -0,0 +1,2116 @@
+/**
+ * Transaction commands
+ * Feature: 006-chain-adapter - ChainAdapter Transaction FFI
+ * Validates transaction input client-side before handing it to the Go library
+ */
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::amount;
+use crate::commands::receive;
+use crate::commands::usb::normalize_usb_path;
+use crate::error::{AppError, AppResult, Error, ErrorCode};
+use crate::eth_address;
+use crate::ffi::queue::{LazyWalletQueue, WalletBackend};
+use crate::limits::{check_length, MAX_PASSWORD_LEN, MAX_PATH_LEN, MAX_SIGNED_TX_LEN};
+use crate::secret::{ffi_params, Secret};
+use crate::signing_payload;
+
+/// Base58 alphabet (no 0, O, I, l)
+const BASE58_CHARSET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Bech32 data-part alphabet (BIP173)
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildTransactionInput {
+    pub chain_id: String,
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub asset: String,
+    pub amount: String,
+    #[serde(default = "default_fee_speed")]
+    pub fee_speed: String,
+    /// Explicit fee rate for the "custom" tier (sat/vB or gwei)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_config: Option<String>,
+    /// Account nonce (EVM chains); fetched from the node when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+}
+
+fn default_fee_speed() -> String {
+    "normal".to_string()
+}
+
+/// Fee tiers the library maps to its own rate estimates
+const NAMED_FEE_SPEEDS: &[&str] = &["slow", "normal", "fast"];
+
+/// Tier for a caller-supplied fee rate
+const CUSTOM_FEE_SPEED: &str = "custom";
+
+/// Sane range for custom fee rates on a chain
+struct FeeRateBounds {
+    unit: &'static str,
+    /// Lowest rate nodes will relay
+    min: f64,
+    /// Anti-fat-finger ceiling
+    max: f64,
+}
+
+fn fee_rate_bounds(chain_id: &str) -> Option<FeeRateBounds> {
+    if bitcoin_format(chain_id).is_some() {
+        Some(FeeRateBounds { unit: "sat/vB", min: 1.0, max: 1_000.0 })
+    } else if is_evm_chain(chain_id) {
+        Some(FeeRateBounds { unit: "gwei", min: 0.001, max: 1_000.0 })
+    } else {
+        None
+    }
+}
+
+/// Validate the fee tier and, for "custom", the explicit rate.
+/// Returns the fee rate to pass to the library (None for named tiers).
+pub fn validate_fee(chain_id: &str, fee_speed: &str, fee_rate: Option<&str>) -> Result<Option<String>, AppError> {
+    if NAMED_FEE_SPEEDS.contains(&fee_speed) {
+        return Ok(None);
+    }
+
+    if fee_speed != CUSTOM_FEE_SPEED {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidInput,
+            "Fee speed must be slow, normal, fast, or custom",
+        ));
+    }
+
+    let raw = fee_rate.map(str::trim).filter(|r| !r.is_empty()).ok_or_else(|| {
+        AppError::new(ErrorCode::FfiInvalidInput, "A fee rate is required for the custom fee tier")
+    })?;
+
+    let bounds = fee_rate_bounds(chain_id).ok_or_else(|| {
+        AppError::new(
+            ErrorCode::FfiInvalidBlockchain,
+            format!("Custom fee rates are not supported for {}", chain_id),
+        )
+    })?;
+
+    // Plain decimals only: reject signs, exponents, NaN and infinity
+    let rate: f64 = raw
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.')
+        .then(|| raw.parse().ok())
+        .flatten()
+        .ok_or_else(|| AppError::new(ErrorCode::FfiInvalidInput, format!("Invalid fee rate '{}'", raw)))?;
+
+    if rate < bounds.min {
+        return Err(AppError::new(
+            ErrorCode::FeeTooLow,
+            format!("Fee rate must be at least {} {}", bounds.min, bounds.unit),
+        ));
+    }
+
+    if rate > bounds.max {
+        return Err(AppError::new(
+            ErrorCode::FeeTooHigh,
+            format!("Fee rate above {} {} is not allowed", bounds.max, bounds.unit),
+        ));
+    }
+
+    Ok(Some(raw.to_string()))
+}
+
+/// Address formats accepted by a chain
+struct AddressFormat {
+    /// Leading characters of legacy base58 addresses (P2PKH/P2SH)
+    base58_prefixes: &'static [char],
+    /// Human-readable part of segwit addresses (without the "1" separator)
+    bech32_hrp: &'static str,
+}
+
+fn bitcoin_format(chain_id: &str) -> Option<AddressFormat> {
+    match chain_id {
+        "bitcoin" => Some(AddressFormat {
+            base58_prefixes: &['1', '3'],
+            bech32_hrp: "bc",
+        }),
+        "bitcoin-testnet" => Some(AddressFormat {
+            base58_prefixes: &['m', 'n', '2'],
+            bech32_hrp: "tb",
+        }),
+        "bitcoin-regtest" => Some(AddressFormat {
+            base58_prefixes: &['m', 'n', '2'],
+            bech32_hrp: "bcrt",
+        }),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_bitcoin_chain(chain_id: &str) -> bool {
+    bitcoin_format(chain_id).is_some()
+}
+
+pub(crate) fn is_evm_chain(chain_id: &str) -> bool {
+    matches!(chain_id, "ethereum" | "ethereum-goerli" | "ethereum-sepolia")
+}
+
+fn is_valid_base58_address(address: &str, prefixes: &[char]) -> bool {
+    (26..=35).contains(&address.len())
+        && address.starts_with(prefixes)
+        && address.chars().all(|c| BASE58_CHARSET.contains(c))
+}
+
+fn is_valid_bech32_address(address: &str, hrp: &str) -> bool {
+    // Bech32 is case-insensitive but must not mix cases
+    let lower = address.to_ascii_lowercase();
+    if address != lower && address != address.to_ascii_uppercase() {
+        return false;
+    }
+
+    let data = match lower.strip_prefix(hrp).and_then(|rest| rest.strip_prefix('1')) {
+        Some(data) => data,
+        None => return false,
+    };
+
+    // v0 P2WPKH has the shortest data part (39 chars); BIP173 caps the total at 90
+    (hrp.len() + 1 + data.len()) <= 90
+        && data.len() >= 39
+        && data.chars().all(|c| BECH32_CHARSET.contains(c))
+}
+
+/// Check that `to` is well-formed for `chain_id` and return it normalized.
+///
+/// Bitcoin addresses are checked for format only (prefix, length, charset);
+/// their checksums are left to the Go library. EVM addresses are verified
+/// against their EIP-55 checksum when mixed-case and returned checksummed.
+/// Unknown chains are passed through for the library to report.
+pub fn normalize_recipient_address(chain_id: &str, to: &str) -> Result<String, AppError> {
+    let to = to.trim();
+
+    if is_evm_chain(chain_id) {
+        return eth_address::normalize(to);
+    }
+
+    let valid = match bitcoin_format(chain_id) {
+        Some(format) => {
+            is_valid_base58_address(to, format.base58_prefixes)
+                || is_valid_bech32_address(to, format.bech32_hrp)
+        }
+        None => true,
+    };
+
+    if valid {
+        Ok(to.to_string())
+    } else {
+        Err(AppError::new(
+            ErrorCode::InvalidRecipientAddress,
+            format!("Recipient address is not a valid {} address", chain_id),
+        ))
+    }
+}
+
+/// Map a transaction FFI error ("CODE: message") to an AppError.
+///
+/// The library wraps chain errors, e.g.
+/// "TRANSACTION_BUILD_FAILED: Failed to build transaction: ERR_FEE_TOO_LOW: ...",
+/// so the innermost chain error code wins over the outer one.
+fn transaction_error(error: String, fallback: ErrorCode) -> AppError {
+    let chain_code = error
+        .split(|c: char| !(c.is_ascii_uppercase() || c == '_'))
+        .rev()
+        .find(|token| token.starts_with("ERR_"));
+    let outer_code = error.split_once(':').map(|(code, _)| code.trim());
+
+    let code = chain_code
+        .or(outer_code)
+        .map(AppError::from_ffi_error_code)
+        .filter(|code| *code != ErrorCode::InternalError)
+        .unwrap_or(fallback);
+
+    AppError::with_details(code, AppError::default_message_for_code(code), error)
+}
+
+/// Pick the nonce of an EVM transaction.
+///
+/// Without an explicit nonce, the account's next nonce (pending transactions
+/// included) is fetched. An explicit nonce is checked against it: a lower
+/// one is already taken (NonceConflict), a higher one leaves a gap the
+/// transaction would be stuck behind. Libraries without GetNonce predate
+/// nonce support in BuildTransaction: they pick the nonce themselves (None)
+/// and an explicit one is refused, as it would be silently ignored.
+async fn resolve_nonce(queue: &LazyWalletQueue, input: &BuildTransactionInput) -> AppResult<Option<u64>> {
+    if !is_evm_chain(&input.chain_id) {
+        return match input.nonce {
+            Some(_) => Err(AppError::new(
+                ErrorCode::FfiInvalidInput,
+                format!("{} does not use account nonces", input.chain_id),
+            )),
+            None => Ok(None),
+        };
+    }
+
+    let params_json = serde_json::to_string(&serde_json::json!({
+        "chainId": input.chain_id,
+        "address": input.from,
+        "rpcConfig": input.rpc_config.as_deref().unwrap_or_default(),
+    }))?;
+    let next = match queue.get_nonce(params_json).await {
+        Ok(data) => data
+            .get("nonce")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| AppError::new(ErrorCode::DeserializationError, "Nonce missing from response"))?,
+        Err(e) if e.starts_with("UNSUPPORTED_OPERATION") => {
+            if input.nonce.is_some() {
+                return Err(AppError::new(
+                    ErrorCode::UnsupportedOperation,
+                    "The installed wallet library cannot build with an explicit nonce; please update the application",
+                ));
+            }
+            tracing::debug!("GetNonce unavailable; the library picks the nonce");
+            return Ok(None);
+        }
+        Err(e) => return Err(transaction_error(e, ErrorCode::InternalError)),
+    };
+
+    match input.nonce {
+        None => Ok(Some(next)),
+        Some(nonce) if nonce < next => Err(AppError::with_details(
+            ErrorCode::NonceConflict,
+            AppError::default_message_for_code(ErrorCode::NonceConflict),
+            format!("Nonce {} is already used; the next nonce is {}", nonce, next),
+        )),
+        Some(nonce) if nonce > next => Err(AppError::new(
+            ErrorCode::FfiInvalidInput,
+            format!("Nonce {} skips ahead of the next nonce {}; the transaction would never confirm", nonce, next),
+        )),
+        Some(nonce) => Ok(Some(nonce)),
+    }
+}
+
+/// Body of `build_transaction`
+pub(crate) async fn build_single(
+    queue: &LazyWalletQueue,
+    input: &BuildTransactionInput,
+) -> AppResult<serde_json::Value> {
+    // Reject malformed recipients before the FFI round-trip
+    let to = normalize_recipient_address(&input.chain_id, &input.to)?;
+
+    // The library expects an integer in the chain's base unit (wei, satoshi, ...)
+    let amount = amount::to_base_units(&input.chain_id, &input.amount)?;
+
+    let fee_rate = validate_fee(&input.chain_id, &input.fee_speed, input.fee_rate.as_deref())?;
+
+    let nonce = resolve_nonce(queue, input).await?;
+
+    let mut params = serde_json::json!({
+        "chainId": input.chain_id,
+        "from": input.from,
+        "to": to,
+        "asset": input.asset,
+        "amount": amount,
+        "feeSpeed": input.fee_speed,
+        "feeRate": fee_rate.unwrap_or_default(),
+        "memo": input.memo.as_deref().unwrap_or_default(),
+        "rpcConfig": input.rpc_config.as_deref().unwrap_or_default(),
+    });
+    if let Some(nonce) = nonce {
+        params["nonce"] = nonce.into();
+    }
+    let params_json = serde_json::to_string(&params)
+    .map_err(|e| Error::new(
+        ErrorCode::SerializationError,
+        format!("Failed to serialize transaction request: {}", e)
+    ))?;
+
+    queue
+        .build_transaction(params_json)
+        .await
+        .map_err(|e| transaction_error(e, ErrorCode::InternalError))
+}
+
+/// Build an unsigned transaction (Tauri command)
+#[tauri::command]
+pub async fn build_transaction(
+    input: BuildTransactionInput,
+    backend: State<'_, WalletBackend>,
+) -> Result<serde_json::Value, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("build_transaction: chain_id={}, nonce={:?}", input.chain_id, input.nonce);
+
+    build_single(queue, &input).await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildMultiTransactionInput {
+    pub chain_id: String,
+    pub from: String,
+    #[serde(default)]
+    pub asset: String,
+    pub outputs: Vec<BatchRecipient>,
+    #[serde(default = "default_fee_speed")]
+    pub fee_speed: String,
+    /// Explicit fee rate for the "custom" tier (sat/vB or gwei)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_config: Option<String>,
+}
+
+/// Build an unsigned transaction paying every output.
+///
+/// Only UTXO chains can pay several outputs in one transaction; elsewhere a
+/// single output is built as a regular transfer and more are rejected.
+pub(crate) async fn build_multi_output(
+    queue: &LazyWalletQueue,
+    input: &BuildMultiTransactionInput,
+) -> AppResult<serde_json::Value> {
+    let payments = validate_payments(&input.chain_id, &input.outputs)?;
+
+    if payments.len() > 1 && !is_bitcoin_chain(&input.chain_id) {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidBlockchain,
+            format!(
+                "{} transactions have a single recipient; send {} separate transactions instead",
+                input.chain_id,
+                payments.len()
+            ),
+        ));
+    }
+
+    let fee_rate = validate_fee(&input.chain_id, &input.fee_speed, input.fee_rate.as_deref())?;
+    let total = sum_base_units(payments.iter().map(|(_, amount)| amount.as_str()))?;
+
+    let mut params = serde_json::json!({
+        "chainId": input.chain_id,
+        "from": input.from,
+        "asset": input.asset,
+        "amount": total.to_string(),
+        "feeSpeed": input.fee_speed,
+        "feeRate": fee_rate.unwrap_or_default(),
+        "memo": input.memo.clone().unwrap_or_default(),
+        "rpcConfig": input.rpc_config.clone().unwrap_or_default(),
+    });
+
+    let result = if let [(to, _)] = payments.as_slice() {
+        params["to"] = serde_json::json!(to);
+        queue.build_transaction(serde_json::to_string(&params)?).await
+    } else {
+        params["outputs"] = payments
+            .iter()
+            .map(|(to, amount)| serde_json::json!({ "to": to, "amount": amount }))
+            .collect();
+        queue.build_transaction_multi(serde_json::to_string(&params)?).await
+    };
+
+    result.map_err(|e| transaction_error(e, ErrorCode::InternalError))
+}
+
+/// Build an unsigned transaction with several outputs (Tauri command)
+/// A single output goes through BuildTransaction; more need the library's
+/// BuildTransactionMulti export, which no release has yet
+#[tauri::command]
+pub async fn build_transaction_multi(
+    input: BuildMultiTransactionInput,
+    backend: State<'_, WalletBackend>,
+) -> Result<serde_json::Value, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("build_transaction_multi: chain_id={}, outputs={}", input.chain_id, input.outputs.len());
+    build_multi_output(queue, &input).await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateTransactionInput {
+    pub chain_id: String,
+    pub signed_tx: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_config: Option<String>,
+}
+
+/// Result of a dry-run broadcast
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TransactionValidation {
+    /// True if the node would accept the transaction into its mempool
+    pub acceptable: bool,
+
+    /// Node's rejection reason (e.g. "min relay fee not met")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryStatusInput {
+    pub chain_id: String,
+    pub tx_hash: String,
+    /// Unlocks the provider config store
+    pub password: Secret,
+    pub usb_path: String,
+}
+
+/// One provider's answer to a status query
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderTransactionStatus {
+    pub provider_type: String,
+    pub priority: i64,
+    /// None if the query failed
+    pub status: Option<String>,
+    pub confirmations: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// A transaction's status as seen by every enabled provider of its chain
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidatedTransactionStatus {
+    pub chain_id: String,
+    pub tx_hash: String,
+    /// Answer of the highest-priority provider that responded
+    pub status: Option<String>,
+    /// Providers that answered reported different statuses (e.g. one
+    /// confirmed, one pending): one of them is lagging or misconfigured
+    pub disagreement: bool,
+    /// Highest priority first
+    pub providers: Vec<ProviderTransactionStatus>,
+}
+
+/// Enabled providers configured for a chain, highest priority first
+async fn enabled_providers(
+    queue: &LazyWalletQueue,
+    chain_id: &str,
+    password: &str,
+    usb_path: &str,
+) -> AppResult<Vec<(String, i64)>> {
+    let params_json = ffi_params(
+        serde_json::json!({
+            "chainId": chain_id,
+            "usbPath": usb_path,
+        }),
+        &[("password", password)],
+    )?;
+    let result = queue.list_provider_configs(params_json).await;
+
+    let listing = result.map_err(|e| transaction_error(e, ErrorCode::FfiStorageError))?;
+    let mut providers: Vec<(String, i64)> = listing
+        .get("providers")
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter(|p| p.get("enabled").and_then(|v| v.as_bool()).unwrap_or(false))
+        .filter_map(|p| {
+            let provider_type = p.get("providerType")?.as_str()?.to_string();
+            let priority = p.get("priority").and_then(|v| v.as_i64()).unwrap_or_default();
+            Some((provider_type, priority))
+        })
+        .collect();
+    providers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    Ok(providers)
+}
+
+/// Query a transaction through each enabled provider of its chain and
+/// compare the answers.
+///
+/// Providers are queried one after another (the library is single-threaded),
+/// each through its own endpoint passed as `rpcConfig`; one provider failing
+/// doesn't stop the others. Libraries that can't resolve provider endpoints
+/// would answer every query from one endpoint, so they are rejected.
+pub(crate) async fn query_status_all_providers(
+    queue: &LazyWalletQueue,
+    input: &QueryStatusInput,
+) -> AppResult<ConsolidatedTransactionStatus> {
+    check_length("Password", input.password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", &input.usb_path, MAX_PATH_LEN)?;
+    if input.tx_hash.trim().is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "Transaction hash is required"));
+    }
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let providers = enabled_providers(queue, &input.chain_id, input.password.expose(), &usb_path).await?;
+    if providers.is_empty() {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidInput,
+            format!("No enabled providers are configured for {}", input.chain_id),
+        ));
+    }
+
+    let mut answers = Vec::with_capacity(providers.len());
+    for (provider_type, priority) in providers {
+        let endpoint =
+            provider_endpoint(queue, &input.chain_id, &provider_type, input.password.expose(), &usb_path).await;
+        let result = match endpoint {
+            Ok(endpoint) => {
+                let params_json = ffi_params(
+                    serde_json::json!({
+                        "chainId": input.chain_id,
+                        "txHash": input.tx_hash.trim(),
+                    }),
+                    &[("rpcConfig", endpoint.expose())],
+                )?;
+                queue.query_transaction_status(params_json).await
+            }
+            Err(e) if e.starts_with("UNSUPPORTED_OPERATION") => {
+                return Err(AppError::new(
+                    ErrorCode::UnsupportedOperation,
+                    "This wallet library can't query individual providers",
+                ));
+            }
+            Err(e) => Err(e),
+        };
+
+        let answer = match result {
+            Ok(data) => match data.get("status").and_then(|v| v.as_str()) {
+                Some(status) => ProviderTransactionStatus {
+                    provider_type,
+                    priority,
+                    status: Some(status.to_string()),
+                    confirmations: data.get("confirmations").and_then(|v| v.as_u64()),
+                    error: None,
+                },
+                None => ProviderTransactionStatus {
+                    provider_type,
+                    priority,
+                    status: None,
+                    confirmations: None,
+                    error: Some("Transaction status missing from response".to_string()),
+                },
+            },
+            Err(e) => {
+                tracing::warn!("Provider {} failed to query {}: {}", provider_type, input.tx_hash, e);
+                ProviderTransactionStatus {
+                    provider_type,
+                    priority,
+                    status: None,
+                    confirmations: None,
+                    error: Some(AppError::sanitize_message(e)),
+                }
+            }
+        };
+        answers.push(answer);
+    }
+
+    let mut statuses = answers.iter().filter_map(|a| a.status.as_deref());
+    let status = statuses.next().map(str::to_string);
+    let disagreement = statuses.any(|other| Some(other) != status.as_deref());
+    if disagreement {
+        tracing::warn!("Providers disagree on the status of {} on {}", input.tx_hash, input.chain_id);
+    }
+
+    Ok(ConsolidatedTransactionStatus {
+        chain_id: input.chain_id.clone(),
+        tx_hash: input.tx_hash.trim().to_string(),
+        status,
+        disagreement,
+        providers: answers,
+    })
+}
+
+/// Query a transaction through every enabled provider of its chain (Tauri command)
+/// Flags providers that disagree, to spot one that is lagging or misconfigured
+#[tauri::command]
+pub async fn query_transaction_status_all(
+    input: QueryStatusInput,
+    backend: State<'_, WalletBackend>,
+) -> Result<ConsolidatedTransactionStatus, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("query_transaction_status_all: chain_id={}, tx_hash={}", input.chain_id, input.tx_hash);
+
+    query_status_all_providers(queue, &input).await
+}
+
+/// Chain error codes that blame the provider (unreachable, overloaded)
+/// rather than the transaction, so another provider may still succeed
+const PROVIDER_ERROR_CODES: &[&str] = &["ERR_RPC_TIMEOUT", "ERR_RPC_UNAVAILABLE", "ERR_NETWORK_CONGESTION"];
+
+/// Whether a broadcast/query error is worth retrying on the next provider.
+///
+/// Chain errors only fail over if they blame the provider. A bare
+/// TRANSACTION_BROADCAST_FAILED / TRANSACTION_QUERY_FAILED without a chain
+/// code is a transport failure (connection refused, bad response).
+fn is_provider_error(error: &str) -> bool {
+    let chain_code = error
+        .split(|c: char| !(c.is_ascii_uppercase() || c == '_'))
+        .find(|token| token.starts_with("ERR_"));
+    match chain_code {
+        Some(code) => PROVIDER_ERROR_CODES.contains(&code),
+        None => matches!(
+            error.split_once(':').map(|(code, _)| code.trim()),
+            Some("TRANSACTION_BROADCAST_FAILED" | "TRANSACTION_QUERY_FAILED")
+        ),
+    }
+}
+
+/// A provider that was tried and failed before the one that answered
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderFailure {
+    pub provider_type: String,
+    pub error: String,
+}
+
+/// Library call that can be retried on another provider
+#[derive(Debug, Clone, Copy)]
+enum ProviderCall {
+    Broadcast,
+    QueryStatus,
+}
+
+impl ProviderCall {
+    async fn run(self, queue: &LazyWalletQueue, params_json: String) -> Result<serde_json::Value, String> {
+        match self {
+            ProviderCall::Broadcast => queue.broadcast_transaction(params_json).await,
+            ProviderCall::QueryStatus => queue.query_transaction_status(params_json).await,
+        }
+    }
+
+    /// Whether a retry's error means an earlier attempt went through: a
+    /// provider that failed mid-broadcast may still have relayed the
+    /// transaction, so the next one already knows it
+    fn already_done(self, error: &str) -> bool {
+        matches!(self, ProviderCall::Broadcast) && error.contains("ERR_TX_ALREADY_BROADCAST")
+    }
+
+    fn fallback_code(self) -> ErrorCode {
+        match self {
+            ProviderCall::Broadcast => ErrorCode::BroadcastFailed,
+            ProviderCall::QueryStatus => ErrorCode::InternalError,
+        }
+    }
+}
+
+/// JSON-RPC endpoint of a configured provider, API key included.
+/// Errors are the library's "CODE: message" strings.
+async fn provider_endpoint(
+    queue: &LazyWalletQueue,
+    chain_id: &str,
+    provider_type: &str,
+    password: &str,
+    usb_path: &str,
+) -> Result<Secret, String> {
+    let params_json = ffi_params(
+        serde_json::json!({
+            "chainId": chain_id,
+            "providerType": provider_type,
+            "usbPath": usb_path,
+        }),
+        &[("password", password)],
+    )
+    .map_err(|e| format!("INVALID_INPUT: {}", e))?;
+
+    let mut data = queue.resolve_provider_endpoint(params_json).await?;
+    match data.get_mut("rpcEndpoint").map(serde_json::Value::take) {
+        Some(serde_json::Value::String(endpoint)) if !endpoint.is_empty() => Ok(Secret::new(endpoint)),
+        _ => Err(format!("STORAGE_ERROR: Provider {} has no endpoint", provider_type)),
+    }
+}
+
+/// Run `call` against the enabled providers of `chain_id`, highest priority
+/// first, moving on to the next one only on provider errors.
+///
+/// The library's transaction functions don't read the provider store, so
+/// each provider's endpoint is resolved and passed as `rpcConfig`. A library
+/// that can't resolve endpoints gets a single attempt on its default one.
+///
+/// Returns the response with the provider that produced it (None: the
+/// library's default endpoint, used when no provider is configured) and the
+/// providers that failed before it.
+async fn call_with_failover(
+    queue: &LazyWalletQueue,
+    call: ProviderCall,
+    chain_id: &str,
+    password: &str,
+    usb_path: &str,
+    params: serde_json::Value,
+) -> AppResult<(serde_json::Value, Option<String>, Vec<ProviderFailure>)> {
+    let providers = enabled_providers(queue, chain_id, password, usb_path).await?;
+    let mut attempts: Vec<Option<String>> = if providers.is_empty() {
+        vec![None]
+    } else {
+        providers.into_iter().map(|(provider_type, _)| Some(provider_type)).collect()
+    };
+
+    let mut failures = Vec::new();
+    // A provider that failed after the library sent it the call
+    let mut reached_provider = false;
+    let mut index = 0;
+    let (result, provider_type) = loop {
+        let provider_type = attempts[index].clone();
+        index += 1;
+        let last_attempt = index == attempts.len();
+
+        let endpoint = match &provider_type {
+            Some(name) => provider_endpoint(queue, chain_id, name, password, usb_path).await,
+            None => Ok(Secret::new(String::new())),
+        };
+        let (result, retryable) = match endpoint {
+            Ok(endpoint) => {
+                let params_json = ffi_params(params.clone(), &[("rpcConfig", endpoint.expose())])?;
+                let result = call.run(queue, params_json).await;
+                let retryable = matches!(&result, Err(e) if is_provider_error(e));
+                (result, retryable)
+            }
+            Err(e) if e.starts_with("UNSUPPORTED_OPERATION") => {
+                tracing::warn!("Library can't resolve provider endpoints; using its default endpoint for {}", chain_id);
+                attempts = vec![None];
+                failures.clear();
+                index = 0;
+                continue;
+            }
+            // This provider can't be reached at all; the next one might be
+            Err(e) => (Err(e), true),
+        };
+
+        match result {
+            Ok(data) => break (Ok(data), provider_type),
+            Err(e) if reached_provider && call.already_done(&e) => {
+                tracing::info!("An earlier provider for {} already relayed the call before failing", chain_id);
+                break (Ok(serde_json::json!({})), provider_type);
+            }
+            Err(e) if retryable && !last_attempt => {
+                reached_provider |= is_provider_error(&e);
+                let provider_type = provider_type.unwrap_or_default();
+                tracing::warn!("Provider {} failed for {}, trying the next one: {}", provider_type, chain_id, e);
+                failures.push(ProviderFailure {
+                    provider_type,
+                    error: AppError::sanitize_message(e),
+                });
+            }
+            Err(e) => break (Err(e), provider_type),
+        }
+    };
+
+    let data = result.map_err(|e| transaction_error(e, call.fallback_code()))?;
+    Ok((data, provider_type, failures))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastTransactionInput {
+    pub chain_id: String,
+    /// Signed transaction as returned by the signer
+    pub signed_tx: serde_json::Value,
+    /// Unlocks the provider config store
+    pub password: Secret,
+    pub usb_path: String,
+}
+
+/// A submitted transaction and the provider that accepted it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastReceipt {
+    pub tx_hash: String,
+    pub chain_id: String,
+    pub status: String,
+    pub submitted_at: Option<String>,
+    pub status_url: Option<String>,
+    /// None: the library's default endpoint (no provider configured)
+    pub provider_type: Option<String>,
+    /// Providers that failed first, highest priority first
+    pub failed_providers: Vec<ProviderFailure>,
+}
+
+/// A transaction's status and the provider that reported it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatusReport {
+    pub tx_hash: String,
+    pub status: String,
+    pub confirmations: Option<u64>,
+    pub block_number: Option<u64>,
+    /// None: the library's default endpoint (no provider configured)
+    pub provider_type: Option<String>,
+    /// Providers that failed first, highest priority first
+    pub failed_providers: Vec<ProviderFailure>,
+}
+
+fn response_str(data: &serde_json::Value, field: &str) -> Option<String> {
+    data.get(field).and_then(|v| v.as_str()).map(str::to_string)
+}
+
+/// Broadcast a signed transaction, failing over to lower-priority providers.
+/// A provider that timed out may still have relayed the transaction, so a
+/// later provider reporting it as already broadcast counts as success.
+pub(crate) async fn broadcast_with_failover(
+    queue: &LazyWalletQueue,
+    input: &BroadcastTransactionInput,
+) -> AppResult<BroadcastReceipt> {
+    check_length("Password", input.password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", &input.usb_path, MAX_PATH_LEN)?;
+    check_length("Signed transaction", &input.signed_tx.to_string(), MAX_SIGNED_TX_LEN)?;
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let params = serde_json::json!({
+        "chainId": input.chain_id,
+        "signedTx": input.signed_tx,
+    });
+    let (data, provider_type, failed_providers) =
+        call_with_failover(queue, ProviderCall::Broadcast, &input.chain_id, input.password.expose(), &usb_path, params)
+            .await?;
+
+    Ok(BroadcastReceipt {
+        tx_hash: response_str(&data, "txHash")
+            .or_else(|| response_str(&input.signed_tx, "txHash"))
+            .ok_or_else(|| AppError::new(ErrorCode::DeserializationError, "Transaction hash missing from response"))?,
+        chain_id: response_str(&data, "chainId").unwrap_or_else(|| input.chain_id.clone()),
+        status: response_str(&data, "status").unwrap_or_else(|| "pending".to_string()),
+        submitted_at: response_str(&data, "submittedAt"),
+        status_url: response_str(&data, "statusUrl").filter(|url| !url.is_empty()),
+        provider_type,
+        failed_providers,
+    })
+}
+
+/// Query a transaction's status, failing over to lower-priority providers
+pub(crate) async fn query_status_with_failover(
+    queue: &LazyWalletQueue,
+    input: &QueryStatusInput,
+) -> AppResult<TransactionStatusReport> {
+    check_length("Password", input.password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", &input.usb_path, MAX_PATH_LEN)?;
+    if input.tx_hash.trim().is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "Transaction hash is required"));
+    }
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let params = serde_json::json!({
+        "chainId": input.chain_id,
+        "txHash": input.tx_hash.trim(),
+    });
+    let (data, provider_type, failed_providers) =
+        call_with_failover(queue, ProviderCall::QueryStatus, &input.chain_id, input.password.expose(), &usb_path, params)
+            .await?;
+
+    Ok(TransactionStatusReport {
+        tx_hash: response_str(&data, "txHash").unwrap_or_else(|| input.tx_hash.trim().to_string()),
+        status: response_str(&data, "status")
+            .ok_or_else(|| AppError::new(ErrorCode::DeserializationError, "Transaction status missing from response"))?,
+        confirmations: data.get("confirmations").and_then(|v| v.as_u64()),
+        block_number: data.get("blockNumber").and_then(|v| v.as_u64()),
+        provider_type,
+        failed_providers,
+    })
+}
+
+/// Broadcast a signed transaction (Tauri command)
+/// Tries the chain's enabled providers by priority until one accepts it
+#[tauri::command]
+pub async fn broadcast_transaction(
+    input: BroadcastTransactionInput,
+    backend: State<'_, WalletBackend>,
+) -> Result<BroadcastReceipt, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("broadcast_transaction: chain_id={}", input.chain_id);
+
+    broadcast_with_failover(queue, &input).await
+}
+
+/// Query a transaction's status (Tauri command)
+/// Tries the chain's enabled providers by priority until one answers
+#[tauri::command]
+pub async fn query_transaction_status(
+    input: QueryStatusInput,
+    backend: State<'_, WalletBackend>,
+) -> Result<TransactionStatusReport, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("query_transaction_status: chain_id={}, tx_hash={}", input.chain_id, input.tx_hash);
+
+    query_status_with_failover(queue, &input).await
+}
+
+/// Dry-run a signed transaction against the node without broadcasting it
+pub(crate) async fn check_transaction(
+    queue: &LazyWalletQueue,
+    input: &ValidateTransactionInput,
+) -> AppResult<TransactionValidation> {
+    check_length("Signed transaction", &input.signed_tx, MAX_SIGNED_TX_LEN)?;
+    if input.signed_tx.trim().is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "Signed transaction is required"));
+    }
+
+    let params_json = serde_json::to_string(&serde_json::json!({
+        "chainId": input.chain_id,
+        "signedTx": input.signed_tx.trim(),
+        "rpcConfig": input.rpc_config.as_deref().unwrap_or_default(),
+    }))?;
+
+    let result = queue
+        .validate_transaction(params_json)
+        .await
+        .map_err(|e| transaction_error(e, ErrorCode::InternalError))?;
+
+    let validation: TransactionValidation = serde_json::from_value(result)?;
+
+    // The library may send an empty reason for accepted transactions
+    Ok(TransactionValidation {
+        reason: validation.reason.filter(|r| !r.is_empty()),
+        ..validation
+    })
+}
+
+/// Check whether the network would accept a signed transaction (Tauri command)
+/// Nothing is broadcast. Depends on the library's ValidateTransaction export,
+/// which no release has yet; `capabilities` reports `transactionValidation`.
+#[tauri::command]
+pub async fn validate_transaction(
+    input: ValidateTransactionInput,
+    backend: State<'_, WalletBackend>,
+) -> Result<TransactionValidation, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("validate_transaction: chain_id={}", input.chain_id);
+    check_transaction(queue, &input).await
+}
+
+/// Virtual size the library's Bitcoin estimate assumes (1 P2WPKH input,
+/// recipient and change outputs)
+const BITCOIN_BASE_TX_VBYTES: u128 = 140;
+
+/// Virtual size of each additional P2WPKH output
+const BITCOIN_OUTPUT_VBYTES: u128 = 31;
+
+/// Upper bound on recipients of one batch estimate
+const MAX_BATCH_RECIPIENTS: usize = 100;
+
+/// One payment of a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchRecipient {
+    pub to: String,
+    pub amount: String,
+}
+
+/// Fee estimate in the chain's base unit, as returned by the library
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeEstimate {
+    pub min_fee: String,
+    pub recommended_fee: String,
+    pub max_fee: String,
+    pub confidence: u32,
+    pub estimated_blocks: u32,
+}
+
+/// Total fee of paying every recipient of a batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchFeeEstimate {
+    pub chain_id: String,
+    /// True for a single multi-output transaction (Bitcoin); false when
+    /// each recipient gets its own transaction
+    pub combined: bool,
+    pub min_fee: String,
+    pub recommended_fee: String,
+    pub max_fee: String,
+    /// Lowest confidence of the underlying estimates
+    pub confidence: u32,
+    /// Slowest of the underlying estimates
+    pub estimated_blocks: u32,
+    /// Per-recipient estimates, in input order (empty when combined)
+    pub recipients: Vec<FeeEstimate>,
+}
+
+fn parse_base_units(value: &str) -> AppResult<u128> {
+    value.parse().map_err(|_| {
+        AppError::new(ErrorCode::DeserializationError, format!("Invalid base-unit amount '{}'", value))
+    })
+}
+
+/// Sum base-unit integers (fees or amounts)
+fn sum_base_units<'a>(values: impl IntoIterator<Item = &'a str>) -> AppResult<u128> {
+    let mut total: u128 = 0;
+    for value in values {
+        total = total
+            .checked_add(parse_base_units(value)?)
+            .ok_or_else(|| AppError::new(ErrorCode::FfiInvalidInput, "Total amount is too large"))?;
+    }
+    Ok(total)
+}
+
+/// Scale a single-output Bitcoin fee to a transaction paying `outputs`
+/// recipients (plus change)
+fn scale_bitcoin_fee(fee: &str, outputs: usize) -> AppResult<String> {
+    let vbytes = BITCOIN_BASE_TX_VBYTES + BITCOIN_OUTPUT_VBYTES * (outputs as u128 - 1);
+    parse_base_units(fee)?
+        .checked_mul(vbytes)
+        .map(|scaled| (scaled / BITCOIN_BASE_TX_VBYTES).to_string())
+        .ok_or_else(|| AppError::new(ErrorCode::InternalError, "Fee estimate overflows"))
+}
+
+/// Validate every recipient and amount of a batch, returning the normalized
+/// address and base-unit amount of each
+fn validate_payments(chain_id: &str, recipients: &[BatchRecipient]) -> AppResult<Vec<(String, String)>> {
+    if recipients.is_empty() {
+        return Err(AppError::new(ErrorCode::FfiInvalidInput, "At least one recipient is required"));
+    }
+    if recipients.len() > MAX_BATCH_RECIPIENTS {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidInput,
+            format!("At most {} recipients can be paid at once", MAX_BATCH_RECIPIENTS),
+        ));
+    }
+
+    recipients
+        .iter()
+        .map(|recipient| {
+            let to = normalize_recipient_address(chain_id, &recipient.to)?;
+            let amount = amount::to_base_units(chain_id, &recipient.amount)?;
+            Ok((to, amount))
+        })
+        .collect()
+}
+
+async fn estimate_single_fee(
+    queue: &LazyWalletQueue,
+    chain_id: &str,
+    from: &str,
+    to: &str,
+    amount: &str,
+    rpc_config: &str,
+) -> AppResult<FeeEstimate> {
+    let params_json = serde_json::to_string(&serde_json::json!({
+        "chainId": chain_id,
+        "from": from,
+        "to": to,
+        "asset": "",
+        "amount": amount,
+        "rpcConfig": rpc_config,
+    }))?;
+
+    let result = queue
+        .estimate_fee(params_json)
+        .await
+        .map_err(|e| transaction_error(e, ErrorCode::InternalError))?;
+
+    Ok(serde_json::from_value(result)?)
+}
+
+/// Estimate the total fee of paying several recipients.
+///
+/// Every recipient is validated before anything is estimated. Bitcoin pays
+/// them all from one multi-output transaction, so its estimate is taken once
+/// for the total and scaled by the extra outputs; account-model chains need
+/// one transaction per recipient, so their estimates are summed.
+pub(crate) async fn estimate_batch(
+    queue: &LazyWalletQueue,
+    chain_id: &str,
+    from: &str,
+    recipients: &[BatchRecipient],
+    rpc_config: &str,
+) -> AppResult<BatchFeeEstimate> {
+    let payments = validate_payments(chain_id, recipients)?;
+
+    if is_bitcoin_chain(chain_id) {
+        let total = sum_base_units(payments.iter().map(|(_, amount)| amount.as_str()))?;
+
+        let (first_to, _) = &payments[0];
+        let estimate =
+            estimate_single_fee(queue, chain_id, from, first_to, &total.to_string(), rpc_config).await?;
+
+        return Ok(BatchFeeEstimate {
+            chain_id: chain_id.to_string(),
+            combined: true,
+            min_fee: scale_bitcoin_fee(&estimate.min_fee, payments.len())?,
+            recommended_fee: scale_bitcoin_fee(&estimate.recommended_fee, payments.len())?,
+            max_fee: scale_bitcoin_fee(&estimate.max_fee, payments.len())?,
+            confidence: estimate.confidence,
+            estimated_blocks: estimate.estimated_blocks,
+            recipients: Vec::new(),
+        });
+    }
+
+    let mut estimates = Vec::with_capacity(payments.len());
+    for (to, amount) in &payments {
+        estimates.push(estimate_single_fee(queue, chain_id, from, to, amount, rpc_config).await?);
+    }
+
+    Ok(BatchFeeEstimate {
+        chain_id: chain_id.to_string(),
+        combined: false,
+        min_fee: sum_base_units(estimates.iter().map(|e| e.min_fee.as_str()))?.to_string(),
+        recommended_fee: sum_base_units(estimates.iter().map(|e| e.recommended_fee.as_str()))?.to_string(),
+        max_fee: sum_base_units(estimates.iter().map(|e| e.max_fee.as_str()))?.to_string(),
+        confidence: estimates.iter().map(|e| e.confidence).min().unwrap_or_default(),
+        estimated_blocks: estimates.iter().map(|e| e.estimated_blocks).max().unwrap_or_default(),
+        recipients: estimates,
+    })
+}
+
+/// Estimate the total fee of a "pay many" batch (Tauri command)
+#[tauri::command]
+pub async fn estimate_batch_fee(
+    chain_id: String,
+    from: String,
+    recipients: Vec<BatchRecipient>,
+    rpc_config: Option<String>,
+    backend: State<'_, WalletBackend>,
+) -> Result<BatchFeeEstimate, Error> {
+    let queue = backend.queue()?;
+
+    tracing::info!("estimate_batch_fee: chain_id={}, recipients={}", chain_id, recipients.len());
+    estimate_batch(queue, &chain_id, &from, &recipients, rpc_config.as_deref().unwrap_or_default()).await
+}
+
+/// One payment of a transaction summary
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryOutput {
+    pub to: String,
+    /// Main units ("0.5")
+    pub amount: String,
+}
+
+/// What an unsigned transaction does, in main units, for the confirmation
+/// screen ("Send X to Y, fee Z, total T")
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSummary {
+    pub chain: String,
+    /// Unit of amount, fee and total ("BTC", "ETH")
+    pub symbol: String,
+    pub from: String,
+    /// The recipient, or the first of several (see `outputs`)
+    pub to: String,
+    pub outputs: Vec<SummaryOutput>,
+    /// Sum of the outputs
+    pub amount: String,
+    pub fee: String,
+    pub total: String,
+    pub nonce: Option<u64>,
+}
+
+/// Base-unit integer field, sent as a string or a number
+fn base_units_field(unsigned_tx: &serde_json::Value, field: &str) -> AppResult<u128> {
+    match unsigned_tx.get(field) {
+        Some(serde_json::Value::String(value)) => parse_base_units(value.trim()),
+        Some(serde_json::Value::Number(value)) => parse_base_units(&value.to_string()),
+        _ => Err(AppError::new(
+            ErrorCode::DeserializationError,
+            format!("Unsigned transaction has no '{}'", field),
+        )),
+    }
+}
+
+fn invalid_payload(message: impl Into<String>) -> AppError {
+    AppError::new(ErrorCode::DeserializationError, message)
+}
+
+/// Check that a Bitcoin signing payload (the serialized transaction) pays
+/// exactly `payments`, apart from change back to `from` and memo outputs.
+///
+/// Input values are not part of the payload, so the fee is checked against
+/// the selected UTXOs the library reported.
+fn verify_bitcoin_payload(
+    unsigned_tx: &serde_json::Value,
+    payments: &[(String, u128)],
+    fee: u128,
+    payload: &[u8],
+) -> AppResult<()> {
+    let script = |address: &str| {
+        signing_payload::bitcoin_script(address)
+            .ok_or_else(|| invalid_payload(format!("'{}' is not a valid Bitcoin address", address)))
+    };
+    let mut outputs = signing_payload::bitcoin_outputs(payload)
+        .ok_or_else(|| invalid_payload("Signing payload is not a Bitcoin transaction"))?;
+    let paid: u128 = outputs.iter().map(|output| output.value as u128).sum();
+
+    for (to, value) in payments {
+        let script = script(to)?;
+        let index = outputs
+            .iter()
+            .position(|output| output.script == script && output.value as u128 == *value)
+            .ok_or_else(|| invalid_payload(format!("Signing payload does not pay {} to {}", value, to)))?;
+        outputs.remove(index);
+    }
+
+    let change_script = script(unsigned_tx["from"].as_str().unwrap_or_default().trim())?;
+    if let Some(output) = outputs
+        .iter()
+        .find(|output| !(output.script == change_script || output.is_null_data() && output.value == 0))
+    {
+        return Err(invalid_payload(format!(
+            "Signing payload sends {} satoshis to an output not shown in the transaction",
+            output.value
+        )));
+    }
+
+    if let Some(utxos) = unsigned_tx.pointer("/chainSpecific/utxos").and_then(|v| v.as_array()) {
+        let spent: u128 = utxos.iter().filter_map(|utxo| utxo["Amount"].as_u64()).map(u128::from).sum();
+        if spent.checked_sub(paid) != Some(fee) {
+            return Err(invalid_payload("Fee does not match the transaction's inputs and outputs"));
+        }
+    }
+    Ok(())
+}
+
+/// Check that an Ethereum signing payload (the EIP-1559 signing hash) is the
+/// hash of a plain transfer of `value` to `to` with the reported gas
+/// parameters, and return its nonce.
+fn verify_ethereum_payload(
+    chain_id: &str,
+    unsigned_tx: &serde_json::Value,
+    to: &str,
+    value: u128,
+    fee: u128,
+    payload: &[u8],
+) -> AppResult<u64> {
+    let params = unsigned_tx.get("chainSpecific").filter(|v| v.is_object()).ok_or_else(|| {
+        AppError::new(
+            ErrorCode::UnsupportedOperation,
+            "The installed wallet library does not report transaction parameters; please update the application",
+        )
+    })?;
+    let uint = |name: &str| -> AppResult<u64> {
+        u64::try_from(base_units_field(params, name)?)
+            .map_err(|_| invalid_payload(format!("'{}' is out of range", name)))
+    };
+    let data = match params.get("data") {
+        Some(serde_json::Value::String(encoded)) => signing_payload::decode_base64(encoded)
+            .ok_or_else(|| invalid_payload("Transaction data is not valid base64"))?,
+        _ => Vec::new(),
+    };
+
+    let transaction = signing_payload::Eip1559Transaction {
+        chain_id: uint("chain_id")?,
+        nonce: uint("nonce")?,
+        max_priority_fee_per_gas: base_units_field(params, "max_priority_fee_per_gas")?,
+        max_fee_per_gas: base_units_field(params, "max_fee_per_gas")?,
+        gas_limit: uint("gas_limit")?,
+        to: signing_payload::ethereum_address_bytes(to)
+            .ok_or_else(|| invalid_payload(format!("'{}' is not a valid Ethereum address", to)))?,
+        value,
+        data: &data,
+    };
+
+    let expected_chain_id = receive::evm_network_id(chain_id).unwrap_or(1);
+    if transaction.chain_id != expected_chain_id {
+        return Err(invalid_payload(format!(
+            "Transaction is for chain ID {}, not {} ({})",
+            transaction.chain_id, chain_id, expected_chain_id
+        )));
+    }
+    if transaction.signing_hash()[..] != *payload {
+        return Err(invalid_payload("Signing payload does not match the transaction's recipient, amount or gas"));
+    }
+    if transaction.max_fee_per_gas.checked_mul(transaction.gas_limit.into()) != Some(fee) {
+        return Err(invalid_payload("Fee does not match the transaction's gas limit and fee cap"));
+    }
+    Ok(transaction.nonce)
+}
+
+/// Summarize an unsigned transaction as returned by `build_transaction` or
+/// `build_transaction_multi`.
+///
+/// The recipients, amounts and fee are checked against the signing payload
+/// rather than trusted: a Bitcoin payload is decoded, an Ethereum payload
+/// (a hash) recomputed from the transaction's parameters. Token transfers
+/// are rejected, as their amount is not in the chain's native unit.
+pub fn decode_unsigned(chain_id: &str, unsigned_tx: &serde_json::Value) -> AppResult<TransactionSummary> {
+    let symbol = amount::main_unit_symbol(chain_id).ok_or_else(|| {
+        AppError::new(ErrorCode::FfiInvalidBlockchain, format!("Cannot summarize {} transactions", chain_id))
+    })?;
+    let field = |name: &str| unsigned_tx.get(name).and_then(|v| v.as_str()).map(str::trim).unwrap_or_default();
+    let main_units = |value: u128| amount::to_main_units(chain_id, &value.to_string());
+
+    let asset = field("asset");
+    if !asset.is_empty() && !asset.eq_ignore_ascii_case(&symbol) {
+        return Err(AppError::new(
+            ErrorCode::UnsupportedOperation,
+            format!("Cannot summarize {} transfers on {}", asset, chain_id),
+        ));
+    }
+
+    let mut payments = Vec::new();
+    match unsigned_tx.get("outputs").and_then(|v| v.as_array()) {
+        Some(outputs) => {
+            for output in outputs {
+                let to = output.get("to").and_then(|v| v.as_str()).unwrap_or_default().trim();
+                payments.push((to.to_string(), base_units_field(output, "amount")?));
+            }
+        }
+        None => payments.push((field("to").to_string(), base_units_field(unsigned_tx, "amount")?)),
+    }
+    if payments.is_empty() || payments.iter().any(|(to, _)| to.is_empty()) || field("from").is_empty() {
+        return Err(invalid_payload("Unsigned transaction is missing its sender or recipient"));
+    }
+
+    let amount = base_units_field(unsigned_tx, "amount")?;
+    let fee = base_units_field(unsigned_tx, "fee")?;
+    let paid = payments.iter().try_fold(0u128, |sum, (_, value)| sum.checked_add(*value));
+    if paid != Some(amount) {
+        return Err(invalid_payload("Transaction outputs do not add up to its amount"));
+    }
+    let total = amount
+        .checked_add(fee)
+        .ok_or_else(|| invalid_payload("Transaction total overflows"))?;
+
+    let payload = signing_payload::decode_base64(field("signingPayload"))
+        .filter(|payload| !payload.is_empty())
+        .ok_or_else(|| invalid_payload("Unsigned transaction has no valid signing payload"))?;
+    let nonce = if is_evm_chain(chain_id) {
+        if payments.len() != 1 {
+            return Err(invalid_payload("Ethereum transactions have a single recipient"));
+        }
+        Some(verify_ethereum_payload(chain_id, unsigned_tx, &payments[0].0, amount, fee, &payload)?)
+    } else {
+        verify_bitcoin_payload(unsigned_tx, &payments, fee, &payload)?;
+        None
+    };
+
+    let mut outputs = Vec::new();
+    for (to, value) in payments {
+        outputs.push(SummaryOutput { to, amount: main_units(value)? });
+    }
+    Ok(TransactionSummary {
+        chain: chain_id.to_string(),
+        symbol,
+        from: field("from").to_string(),
+        to: outputs[0].to.clone(),
+        outputs,
+        amount: main_units(amount)?,
+        fee: main_units(fee)?,
+        total: main_units(total)?,
+        nonce,
+    })
+}
+
+/// Summarize an unsigned transaction for review before signing (Tauri command)
+#[tauri::command]
+pub async fn decode_transaction(
+    chain_id: String,
+    unsigned_tx: serde_json::Value,
+) -> Result<TransactionSummary, Error> {
+    tracing::info!("decode_transaction: chain_id={}", chain_id);
+
+    decode_unsigned(&chain_id, &unsigned_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::stub::{ffi_string, stub_library};
+    use std::os::raw::c_char;
+    use std::sync::Arc;
+
+    unsafe extern "C" fn status_confirmed(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"txHash":"abc","status":"confirmed","confirmations":3}}"#)
+    }
+
+    unsafe extern "C" fn status_pending(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"txHash":"abc","status":"pending","confirmations":0}}"#)
+    }
+
+    unsafe extern "C" fn validate_accepted(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"acceptable":true,"reason":""}}"#)
+    }
+
+    unsafe extern "C" fn validate_rejected(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"acceptable":false,"reason":"min relay fee not met"}}"#)
+    }
+
+    unsafe extern "C" fn estimate_by_amount(params: *const c_char) -> *mut c_char {
+        // Fee grows with the amount, so the test can tell which request was made
+        let params: serde_json::Value =
+            serde_json::from_str(std::ffi::CStr::from_ptr(params).to_str().unwrap()).unwrap();
+        let amount: u128 = params["amount"].as_str().unwrap().parse().unwrap();
+        let fee = 1400 + amount / 1000;
+        ffi_string(&format!(
+            r#"{{"success":true,"data":{{"chainId":"{}","minFee":"{}","recommendedFee":"{}","maxFee":"{}","confidence":{},"estimatedBlocks":{},"timestamp":"2025-11-04T15:40:00Z"}}}}"#,
+            params["chainId"].as_str().unwrap(),
+            fee,
+            fee * 2,
+            fee * 3,
+            if amount > 1_000_000 { 70 } else { 90 },
+            if amount > 1_000_000 { 6 } else { 3 },
+        ))
+    }
+
+    unsafe extern "C" fn build_multi_echo(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(std::ffi::CStr::from_ptr(params).to_str().unwrap()).unwrap();
+        let response = serde_json::json!({
+            "success": true,
+            "data": {
+                "id": "tx-1",
+                "chainId": params["chainId"],
+                "from": params["from"],
+                "outputs": params["outputs"],
+                "amount": params["amount"],
+                "fee": "5000",
+            },
+        });
+        ffi_string(&response.to_string())
+    }
+
+    unsafe extern "C" fn three_providers(_params: *const c_char) -> *mut c_char {
+        ffi_string(
+            r#"{"success":true,"data":{"providers":[
+                {"providerType":"infura","chainId":"ethereum","priority":50,"enabled":true},
+                {"providerType":"quicknode","chainId":"ethereum","priority":80,"enabled":false},
+                {"providerType":"alchemy","chainId":"ethereum","priority":100,"enabled":true}
+            ],"count":3}}"#,
+        )
+    }
+
+    unsafe extern "C" fn infura_only(_params: *const c_char) -> *mut c_char {
+        ffi_string(
+            r#"{"success":true,"data":{"providers":[
+                {"providerType":"infura","chainId":"ethereum","priority":50,"enabled":true}
+            ],"count":1}}"#,
+        )
+    }
+
+    /// Alchemy has seen the block, Infura lags behind
+    unsafe extern "C" fn status_by_provider(params: *const c_char) -> *mut c_char {
+        match routed_provider(params).as_str() {
+            "alchemy" => status_confirmed(std::ptr::null()),
+            "infura" => status_pending(std::ptr::null()),
+            _ => ffi_string(r#"{"success":false,"error":{"code":"TRANSACTION_QUERY_FAILED","message":"timeout"}}"#),
+        }
+    }
+
+    /// Endpoint named after the provider, e.g. "https://alchemy.example/key"
+    unsafe extern "C" fn endpoint_by_provider(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(std::ffi::CStr::from_ptr(params).to_str().unwrap()).unwrap();
+        let provider_type = params["providerType"].as_str().unwrap();
+        ffi_string(
+            &serde_json::json!({
+                "success": true,
+                "data": {"providerType": provider_type, "rpcEndpoint": format!("https://{}.example/key", provider_type)},
+            })
+            .to_string(),
+        )
+    }
+
+    /// Provider a call was routed to, from its rpcConfig endpoint
+    unsafe fn routed_provider(params: *const c_char) -> String {
+        let params: serde_json::Value =
+            serde_json::from_str(std::ffi::CStr::from_ptr(params).to_str().unwrap()).unwrap();
+        let endpoint = params["rpcConfig"].as_str().unwrap();
+        endpoint.trim_start_matches("https://").split('.').next().unwrap().to_string()
+    }
+
+    /// Alchemy (top priority) is unreachable, Infura works
+    unsafe extern "C" fn broadcast_alchemy_down(params: *const c_char) -> *mut c_char {
+        match routed_provider(params).as_str() {
+            "alchemy" => ffi_string(
+                r#"{"success":false,"error":{"code":"TRANSACTION_BROADCAST_FAILED","message":"Failed to broadcast transaction: ERR_RPC_UNAVAILABLE: 503 Service Unavailable"}}"#,
+            ),
+            _ => ffi_string(
+                r#"{"success":true,"data":{"txHash":"0xabc","chainId":"ethereum","submittedAt":"2025-11-04T15:30:00Z","status":"pending","statusUrl":""}}"#,
+            ),
+        }
+    }
+
+    unsafe extern "C" fn status_alchemy_down(params: *const c_char) -> *mut c_char {
+        match routed_provider(params).as_str() {
+            "alchemy" => ffi_string(
+                r#"{"success":false,"error":{"code":"TRANSACTION_QUERY_FAILED","message":"Failed to query transaction status: connection refused"}}"#,
+            ),
+            _ => status_pending(std::ptr::null()),
+        }
+    }
+
+    /// Alchemy times out after relaying the transaction, so Infura already knows it
+    unsafe extern "C" fn broadcast_relayed_before_timeout(params: *const c_char) -> *mut c_char {
+        match routed_provider(params).as_str() {
+            "alchemy" => ffi_string(
+                r#"{"success":false,"error":{"code":"TRANSACTION_BROADCAST_FAILED","message":"Failed to broadcast transaction: ERR_RPC_TIMEOUT: request timed out"}}"#,
+            ),
+            _ => ffi_string(
+                r#"{"success":false,"error":{"code":"TRANSACTION_BROADCAST_FAILED","message":"Failed to broadcast transaction: ERR_TX_ALREADY_BROADCAST: transaction already in mempool"}}"#,
+            ),
+        }
+    }
+
+    /// Only reachable through the library's default endpoint
+    unsafe extern "C" fn broadcast_default_endpoint_only(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(std::ffi::CStr::from_ptr(params).to_str().unwrap()).unwrap();
+        match params["rpcConfig"].as_str() {
+            Some("") => ffi_string(
+                r#"{"success":true,"data":{"txHash":"0xabc","chainId":"ethereum","status":"pending","statusUrl":""}}"#,
+            ),
+            _ => ffi_string(r#"{"success":false,"error":{"code":"INVALID_INPUT","message":"unexpected rpcConfig"}}"#),
+        }
+    }
+
+    static REJECTED_BROADCASTS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    unsafe extern "C" fn broadcast_insufficient_funds(_params: *const c_char) -> *mut c_char {
+        REJECTED_BROADCASTS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        ffi_string(
+            r#"{"success":false,"error":{"code":"TRANSACTION_BROADCAST_FAILED","message":"Failed to broadcast transaction: ERR_INSUFFICIENT_FUNDS: balance too low"}}"#,
+        )
+    }
+
+    unsafe extern "C" fn next_nonce_seven(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"nonce":7}}"#)
+    }
+
+    /// Echoes the params, so tests can see what reached the library
+    unsafe extern "C" fn build_echo(params: *const c_char) -> *mut c_char {
+        let params: serde_json::Value =
+            serde_json::from_str(std::ffi::CStr::from_ptr(params).to_str().unwrap()).unwrap();
+        ffi_string(&serde_json::json!({"success": true, "data": params}).to_string())
+    }
+
+    fn multi_input(chain_id: &str, outputs: Vec<BatchRecipient>) -> BuildMultiTransactionInput {
+        BuildMultiTransactionInput {
+            chain_id: chain_id.to_string(),
+            from: "bc1qsender".to_string(),
+            asset: String::new(),
+            outputs,
+            fee_speed: "normal".to_string(),
+            fee_rate: None,
+            memo: None,
+            rpc_config: None,
+        }
+    }
+
+    fn eth_transfer(nonce: Option<u64>) -> BuildTransactionInput {
+        BuildTransactionInput {
+            chain_id: "ethereum".to_string(),
+            from: "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359".to_string(),
+            to: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            asset: String::new(),
+            amount: "0.1".to_string(),
+            fee_speed: "normal".to_string(),
+            fee_rate: None,
+            memo: None,
+            rpc_config: None,
+            nonce,
+        }
+    }
+
+    #[test]
+    fn test_bitcoin_recipient_validation() {
+        // Legacy, P2SH and native segwit mainnet addresses
+        assert!(normalize_recipient_address("bitcoin", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").is_ok());
+        assert!(normalize_recipient_address("bitcoin", "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy").is_ok());
+        assert!(normalize_recipient_address("bitcoin", "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq").is_ok());
+        assert!(normalize_recipient_address("bitcoin", "BC1QAR0SRRR7XFKVY5L643LYDNW9RE59GTZZWF5MDQ").is_ok());
+        assert!(normalize_recipient_address("bitcoin-testnet", "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx").is_ok());
+
+        // Wrong network, bad charset (0/O/I/l), mixed case, too short
+        let err = normalize_recipient_address("bitcoin", "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidRecipientAddress);
+        assert!(normalize_recipient_address("bitcoin", "1A1zP1eP5QGefi2DMPTfTL5SLmv7Div0Oa").is_err());
+        assert!(normalize_recipient_address("bitcoin", "bc1qAr0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq").is_err());
+        assert!(normalize_recipient_address("bitcoin", "1A1zP1").is_err());
+    }
+
+    #[test]
+    fn test_ethereum_recipient_validation() {
+        assert!(normalize_recipient_address("ethereum", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+        assert_eq!(
+            normalize_recipient_address("ethereum-sepolia", "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359").unwrap(),
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+        );
+
+        let err = normalize_recipient_address("ethereum", "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidRecipientAddress);
+        assert!(normalize_recipient_address("ethereum", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe").is_err());
+        assert!(normalize_recipient_address("ethereum", "0xZZAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+
+        let err = normalize_recipient_address("ethereum", "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap_err();
+        assert_eq!(err.code, ErrorCode::AddressChecksumMismatch);
+    }
+
+    #[test]
+    fn test_unknown_chain_is_passed_through() {
+        assert!(normalize_recipient_address("solana", "anything").is_ok());
+    }
+
+    #[test]
+    fn test_transaction_error_prefers_chain_error_code() {
+        let err = transaction_error(
+            "TRANSACTION_BUILD_FAILED: Failed to build transaction: ERR_INSUFFICIENT_FUNDS: balance too low".to_string(),
+            ErrorCode::InternalError,
+        );
+        assert_eq!(err.code, ErrorCode::InsufficientBalance);
+
+        let err = transaction_error("Queue channel closed".to_string(), ErrorCode::InternalError);
+        assert_eq!(err.code, ErrorCode::InternalError);
+    }
+
+    #[test]
+    fn test_named_fee_tiers() {
+        for speed in ["slow", "normal", "fast"] {
+            assert_eq!(validate_fee("bitcoin", speed, None).unwrap(), None);
+            // A stray rate is ignored for named tiers
+            assert_eq!(validate_fee("ethereum", speed, Some("5")).unwrap(), None);
+        }
+
+        let err = validate_fee("bitcoin", "turbo", None).unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[test]
+    fn test_custom_fee_rate_bounds() {
+        assert_eq!(validate_fee("bitcoin", "custom", Some("12.5")).unwrap().as_deref(), Some("12.5"));
+        assert_eq!(validate_fee("ethereum", "custom", Some(" 30 ")).unwrap().as_deref(), Some("30"));
+
+        assert_eq!(validate_fee("bitcoin", "custom", Some("0")).unwrap_err().code, ErrorCode::FeeTooLow);
+        assert_eq!(validate_fee("ethereum", "custom", Some("0.0")).unwrap_err().code, ErrorCode::FeeTooLow);
+        assert_eq!(validate_fee("bitcoin", "custom", Some("5000")).unwrap_err().code, ErrorCode::FeeTooHigh);
+        assert_eq!(validate_fee("ethereum", "custom", Some("2000")).unwrap_err().code, ErrorCode::FeeTooHigh);
+
+        for rate in [None, Some(""), Some("-5"), Some("1e3"), Some("NaN"), Some("inf"), Some("1.2.3")] {
+            let err = validate_fee("bitcoin", "custom", rate).unwrap_err();
+            assert_eq!(err.code, ErrorCode::FfiInvalidInput, "{:?}", rate);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_all_flags_disagreeing_providers() {
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.resolve_provider_endpoint = Some(endpoint_by_provider);
+        library.query_transaction_status = status_by_provider;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let input = QueryStatusInput {
+            chain_id: "ethereum".to_string(),
+            tx_hash: "abc".to_string(),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: "/media/usb".to_string(),
+        };
+
+        let result = query_status_all_providers(&queue, &input).await.unwrap();
+        assert!(result.disagreement);
+        assert_eq!(result.status.as_deref(), Some("confirmed"));
+
+        let answers: Vec<(&str, Option<&str>, Option<u64>)> = result
+            .providers
+            .iter()
+            .map(|p| (p.provider_type.as_str(), p.status.as_deref(), p.confirmations))
+            .collect();
+        assert_eq!(
+            answers,
+            vec![("alchemy", Some("confirmed"), Some(3)), ("infura", Some("pending"), Some(0))]
+        );
+
+        // Providers that agree are not flagged
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.resolve_provider_endpoint = Some(endpoint_by_provider);
+        library.query_transaction_status = status_pending;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let result = query_status_all_providers(&queue, &input).await.unwrap();
+        assert!(!result.disagreement);
+        assert_eq!(result.status.as_deref(), Some("pending"));
+
+        // Without endpoint resolution every answer would come from one endpoint
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.query_transaction_status = status_pending;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let err = query_status_all_providers(&queue, &input).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::UnsupportedOperation);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_and_query_fail_over_to_next_provider() {
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.resolve_provider_endpoint = Some(endpoint_by_provider);
+        library.broadcast_transaction = broadcast_alchemy_down;
+        library.query_transaction_status = status_alchemy_down;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let input = BroadcastTransactionInput {
+            chain_id: "ethereum".to_string(),
+            signed_tx: serde_json::json!({"txHash": "0xabc", "serializedTx": "AQID"}),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: "/media/usb".to_string(),
+        };
+        let receipt = broadcast_with_failover(&queue, &input).await.unwrap();
+        assert_eq!(receipt.tx_hash, "0xabc");
+        assert_eq!(receipt.provider_type.as_deref(), Some("infura"));
+        assert_eq!(receipt.status_url, None);
+        assert_eq!(receipt.failed_providers.len(), 1);
+        assert_eq!(receipt.failed_providers[0].provider_type, "alchemy");
+
+        let input = QueryStatusInput {
+            chain_id: "ethereum".to_string(),
+            tx_hash: "abc".to_string(),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: "/media/usb".to_string(),
+        };
+        let report = query_status_with_failover(&queue, &input).await.unwrap();
+        assert_eq!(report.status, "pending");
+        assert_eq!(report.provider_type.as_deref(), Some("infura"));
+        assert_eq!(report.failed_providers[0].provider_type, "alchemy");
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_retry_of_relayed_transaction_succeeds() {
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.resolve_provider_endpoint = Some(endpoint_by_provider);
+        library.broadcast_transaction = broadcast_relayed_before_timeout;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let input = BroadcastTransactionInput {
+            chain_id: "ethereum".to_string(),
+            signed_tx: serde_json::json!({"txHash": "0xabc", "serializedTx": "AQID"}),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: "/media/usb".to_string(),
+        };
+
+        let receipt = broadcast_with_failover(&queue, &input).await.unwrap();
+        assert_eq!(receipt.tx_hash, "0xabc");
+        assert_eq!(receipt.status, "pending");
+        assert_eq!(receipt.provider_type.as_deref(), Some("infura"));
+        assert_eq!(receipt.failed_providers[0].provider_type, "alchemy");
+
+        // On a first attempt, "already broadcast" is a real duplicate
+        let mut library = stub_library();
+        library.list_provider_configs = infura_only;
+        library.resolve_provider_endpoint = Some(endpoint_by_provider);
+        library.broadcast_transaction = broadcast_relayed_before_timeout;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let err = broadcast_with_failover(&queue, &input).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::NonceConflict);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_without_endpoint_resolution_uses_default_endpoint() {
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.broadcast_transaction = broadcast_default_endpoint_only;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let input = BroadcastTransactionInput {
+            chain_id: "ethereum".to_string(),
+            signed_tx: serde_json::json!({"txHash": "0xabc"}),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: "/media/usb".to_string(),
+        };
+
+        let receipt = broadcast_with_failover(&queue, &input).await.unwrap();
+        assert_eq!(receipt.provider_type, None);
+        assert!(receipt.failed_providers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_does_not_fail_over_on_transaction_errors() {
+        let mut library = stub_library();
+        library.list_provider_configs = three_providers;
+        library.resolve_provider_endpoint = Some(endpoint_by_provider);
+        library.broadcast_transaction = broadcast_insufficient_funds;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let input = BroadcastTransactionInput {
+            chain_id: "ethereum".to_string(),
+            signed_tx: serde_json::json!({"txHash": "0xabc"}),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: "/media/usb".to_string(),
+        };
+
+        let err = broadcast_with_failover(&queue, &input).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::InsufficientBalance);
+        assert_eq!(REJECTED_BROADCASTS.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_build_fetches_or_checks_nonce() {
+        let mut library = stub_library();
+        library.build_transaction = build_echo;
+        library.get_nonce = Some(next_nonce_seven);
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let built = build_single(&queue, &eth_transfer(None)).await.unwrap();
+        assert_eq!(built["nonce"], 7);
+        let built = build_single(&queue, &eth_transfer(Some(7))).await.unwrap();
+        assert_eq!(built["nonce"], 7);
+
+        let err = build_single(&queue, &eth_transfer(Some(5))).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::NonceConflict);
+        let err = build_single(&queue, &eth_transfer(Some(9))).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_explicit_nonce_needs_get_nonce() {
+        let mut library = stub_library();
+        library.build_transaction = build_echo;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        // Such a library would ignore the nonce
+        let err = build_single(&queue, &eth_transfer(Some(42))).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::UnsupportedOperation);
+        // No nonce and no GetNonce: left to the library
+        let built = build_single(&queue, &eth_transfer(None)).await.unwrap();
+        assert!(built.get("nonce").is_none());
+
+        let mut bitcoin = eth_transfer(Some(1));
+        bitcoin.chain_id = "bitcoin".to_string();
+        bitcoin.to = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq".to_string();
+        let err = build_single(&queue, &bitcoin).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[test]
+    fn test_decode_bitcoin_and_ethereum_transactions() {
+        // Serialized transaction paying 150000 sat to the recipient and
+        // 47180 sat of change back to the sender
+        let bitcoin = serde_json::json!({
+            "id": "5f1c",
+            "chainId": "bitcoin",
+            "from": "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            "to": "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            "asset": "BTC",
+            "amount": "150000",
+            "fee": "2820",
+            "signingPayload": "AgAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/////wLwSQIAAAAAABYAFHUedugZkZbUVJQcRdGzoyPxQzvWTLgAAAAAAAAZdqkUd7/yDGDlIt+qM1DDmwMKXQBOg5qIrAAAAAA=",
+            "humanReadable": "{\n  \"amount\": 150000 satoshis,\n  \"fee\": 2820 satoshis\n}",
+            "chainSpecific": {"utxos": [{"TxID": "00", "Vout": 0, "Amount": 200000}], "change_amount": 47180},
+        });
+        let summary = decode_unsigned("bitcoin", &bitcoin).unwrap();
+        assert_eq!(
+            (summary.symbol.as_str(), summary.amount.as_str(), summary.fee.as_str(), summary.total.as_str()),
+            ("BTC", "0.0015", "0.0000282", "0.0015282")
+        );
+        assert_eq!(summary.to, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+        assert_eq!(summary.outputs.len(), 1);
+        assert_eq!(summary.nonce, None);
+
+        // EIP-1559 signing hash of nonce 7, 1 gwei fee caps, 21000 gas
+        let ethereum = serde_json::json!({
+            "id": "0xabc",
+            "chainId": "ethereum-1",
+            "from": "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "to": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "asset": "ETH",
+            "amount": "100000000000000000",
+            "fee": "21000000000000",
+            "signingPayload": "8iGfsJnJ/qD4XZ8WDmV8wnuz0VhLW3rSRdbSGdwQDic=",
+            "chainSpecific": {
+                "chain_id": 1,
+                "nonce": 7,
+                "gas_limit": 21000,
+                "max_fee_per_gas": "1000000000",
+                "max_priority_fee_per_gas": "1000000000",
+                "data": null,
+            },
+        });
+        let summary = decode_unsigned("ethereum", &ethereum).unwrap();
+        assert_eq!(
+            (summary.symbol.as_str(), summary.amount.as_str(), summary.fee.as_str(), summary.total.as_str()),
+            ("ETH", "0.1", "0.000021", "0.100021")
+        );
+        assert_eq!(summary.from, "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359");
+        assert_eq!(summary.nonce, Some(7));
+
+        let mut missing_fee = ethereum.clone();
+        missing_fee.as_object_mut().unwrap().remove("fee");
+        assert_eq!(decode_unsigned("ethereum", &missing_fee).unwrap_err().code, ErrorCode::DeserializationError);
+    }
+
+    #[test]
+    fn test_decode_rejects_fields_the_payload_does_not_match() {
+        let bitcoin = serde_json::json!({
+            "from": "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            "to": "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            "amount": "150000",
+            "fee": "2820",
+            "signingPayload": "AgAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/////wLwSQIAAAAAABYAFHUedugZkZbUVJQcRdGzoyPxQzvWTLgAAAAAAAAZdqkUd7/yDGDlIt+qM1DDmwMKXQBOg5qIrAAAAAA=",
+        });
+        let tampered = |field: &str, value: serde_json::Value| {
+            let mut tx = bitcoin.clone();
+            tx[field] = value;
+            decode_unsigned("bitcoin", &tx).unwrap_err().code
+        };
+        assert_eq!(tampered("to", "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy".into()), ErrorCode::DeserializationError);
+        assert_eq!(tampered("amount", "15000".into()), ErrorCode::DeserializationError);
+        // The change goes somewhere the summary does not show
+        assert_eq!(
+            tampered("signingPayload", "AgAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/////wLwSQIAAAAAABYAFHUedugZkZbUVJQcRdGzoyPxQzvWTLgAAAAAAAAWABR1HnboGZGW1FSUHEXRs6Mj8UM71gAAAAA=".into()),
+            ErrorCode::DeserializationError
+        );
+        // Outputs that do not add up to the amount
+        let split = serde_json::json!([
+            {"to": "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", "amount": "100000"},
+            {"to": "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", "amount": "10000"},
+        ]);
+        assert_eq!(tampered("outputs", split), ErrorCode::DeserializationError);
+
+        let ethereum = serde_json::json!({
+            "from": "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "to": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "amount": "100000000000000000",
+            "fee": "21000000000000",
+            "signingPayload": "8iGfsJnJ/qD4XZ8WDmV8wnuz0VhLW3rSRdbSGdwQDic=",
+            "chainSpecific": {
+                "chain_id": 1,
+                "nonce": 7,
+                "gas_limit": 21000,
+                "max_fee_per_gas": "1000000000",
+                "max_priority_fee_per_gas": "1000000000",
+            },
+        });
+        let tampered = |field: &str, value: serde_json::Value| {
+            let mut tx = ethereum.clone();
+            tx[field] = value;
+            decode_unsigned("ethereum", &tx).unwrap_err().code
+        };
+        assert_eq!(tampered("to", "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359".into()), ErrorCode::DeserializationError);
+        assert_eq!(tampered("amount", "1000000000000000000".into()), ErrorCode::DeserializationError);
+        assert_eq!(tampered("fee", "21".into()), ErrorCode::DeserializationError);
+        // Signed for mainnet, presented as Sepolia
+        assert_eq!(decode_unsigned("ethereum-sepolia", &ethereum).unwrap_err().code, ErrorCode::DeserializationError);
+        // Token amounts are not wei
+        assert_eq!(tampered("asset", "USDC".into()), ErrorCode::UnsupportedOperation);
+        // A library too old to report the parameters behind the hash
+        assert_eq!(tampered("chainSpecific", serde_json::Value::Null), ErrorCode::UnsupportedOperation);
+    }
+
+    fn validate_input() -> ValidateTransactionInput {
+        ValidateTransactionInput {
+            chain_id: "bitcoin".to_string(),
+            signed_tx: "0200000001abcdef".to_string(),
+            rpc_config: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_transaction_acceptable() {
+        let mut library = stub_library();
+        library.validate_transaction = Some(validate_accepted);
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let validation = check_transaction(&queue, &validate_input()).await.unwrap();
+        assert_eq!(validation, TransactionValidation { acceptable: true, reason: None });
+    }
+
+    #[tokio::test]
+    async fn test_validate_transaction_rejected() {
+        let mut library = stub_library();
+        library.validate_transaction = Some(validate_rejected);
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let validation = check_transaction(&queue, &validate_input()).await.unwrap();
+        assert!(!validation.acceptable);
+        assert_eq!(validation.reason.as_deref(), Some("min relay fee not met"));
+    }
+
+    fn recipient(to: &str, amount: &str) -> BatchRecipient {
+        BatchRecipient { to: to.to_string(), amount: amount.to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_bitcoin_batch_is_one_multi_output_estimate() {
+        let mut library = stub_library();
+        library.estimate_fee = estimate_by_amount;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let recipients = [
+            recipient("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", "100000 sat"),
+            recipient("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", "200000 sat"),
+            recipient("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", "0.001"),
+        ];
+        let estimate = estimate_batch(&queue, "bitcoin", "bc1qsender", &recipients, "").await.unwrap();
+
+        // One estimate for 400000 sat (fee 1800), scaled from 140 to 202 vbytes
+        assert!(estimate.combined);
+        assert!(estimate.recipients.is_empty());
+        assert_eq!(estimate.min_fee, "2597");
+        assert_eq!(estimate.recommended_fee, "5194");
+        assert_eq!(estimate.max_fee, "7791");
+        assert_eq!(estimate.confidence, 90);
+    }
+
+    #[tokio::test]
+    async fn test_ethereum_batch_sums_per_recipient_estimates() {
+        let mut library = stub_library();
+        library.estimate_fee = estimate_by_amount;
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let recipients = [
+            recipient("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "1000000 wei"),
+            recipient("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359", "2000000 wei"),
+        ];
+        let estimate = estimate_batch(&queue, "ethereum", "0xsender", &recipients, "").await.unwrap();
+
+        assert!(!estimate.combined);
+        assert_eq!(estimate.recipients.len(), 2);
+        assert_eq!(estimate.recipients[0].recommended_fee, "4800");
+        assert_eq!(estimate.recipients[1].recommended_fee, "6800");
+        assert_eq!(estimate.min_fee, "5800");
+        assert_eq!(estimate.recommended_fee, "11600");
+        assert_eq!(estimate.max_fee, "17400");
+        assert_eq!(estimate.confidence, 70);
+        assert_eq!(estimate.estimated_blocks, 6);
+    }
+
+    #[tokio::test]
+    async fn test_batch_rejects_any_invalid_recipient_before_estimating() {
+        // The default stub fails every estimate, so reaching the library errors differently
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+
+        let recipients = [
+            recipient("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "1000000"),
+            recipient("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "1000000"),
+        ];
+        let err = estimate_batch(&queue, "ethereum", "0xsender", &recipients, "").await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::AddressChecksumMismatch);
+
+        let err = estimate_batch(&queue, "ethereum", "0xsender", &[], "").await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_build_two_output_bitcoin_transaction() {
+        let mut library = stub_library();
+        library.build_transaction_multi = Some(build_multi_echo);
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let input = multi_input(
+            "bitcoin",
+            vec![
+                recipient(" bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq ", "0.5"),
+                recipient("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", "25000 sat"),
+            ],
+        );
+        let tx = build_multi_output(&queue, &input).await.unwrap();
+
+        assert_eq!(tx["amount"], "50025000");
+        assert_eq!(
+            tx["outputs"],
+            serde_json::json!([
+                {"to": "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", "amount": "50000000"},
+                {"to": "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", "amount": "25000"},
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multi_output_rejected_on_single_output_chain() {
+        let mut library = stub_library();
+        library.build_transaction_multi = Some(build_multi_echo);
+        let queue = LazyWalletQueue::new(Arc::new(library));
+
+        let input = multi_input(
+            "ethereum",
+            vec![
+                recipient("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "1000000"),
+                recipient("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359", "1000000"),
+            ],
+        );
+        let err = build_multi_output(&queue, &input).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidBlockchain);
+        assert!(err.message.contains("single recipient"));
+    }
+}
@@ -5,6 +5,10 @@
  * Generated: 2025-10-17
  */
 
+use crate::commands::wallet::sanitize_wallet_id;
+use crate::error::{AppError, AppResult, ErrorCode};
+use crate::fs_utils::long_path;
+use crate::limits::{check_length, MAX_PATH_LEN};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -24,9 +28,244 @@ pub struct UsbDevice {
     pub available_space: u64,
 }
 
+/// What a USB drive holds, from a read-only scan of its top level
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UsbWalletScan {
+    /// Wallet directories that contain a wallet.json
+    pub wallet_count: usize,
+
+    /// At least one wallet directory ({wallet_id}/) exists, even if incomplete
+    pub has_arcsign_structure: bool,
+
+    /// Nothing on the drive apart from hidden and OS housekeeping entries
+    pub is_empty: bool,
+}
+
+/// Entries created by operating systems on freshly formatted drives
+pub(crate) fn is_housekeeping_entry(name: &str) -> bool {
+    name.starts_with('.') || name == "System Volume Information" || name == "$RECYCLE.BIN"
+}
+
 /// Minimum required space for wallet storage (10MB)
 const MIN_REQUIRED_SPACE: u64 = 10 * 1024 * 1024;
 
+/// Addresses derived per wallet (all supported blockchains)
+const ADDRESSES_PER_WALLET: u64 = 54;
+
+/// Fixed on-USB footprint of one wallet: metadata, encrypted seed,
+/// address cache and audit log
+const WALLET_OVERHEAD_BYTES: u64 = 64 * 1024;
+
+/// Average size of one exported address record in CSV, the most compact
+/// format; other formats are expressed as multiples of it
+const EXPORT_BYTES_PER_ADDRESS: u64 = 256;
+
+/// Operation the UI wants a space pre-flight check for
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    CreateWallet,
+    ImportWallet,
+    ExportCsv,
+    ExportJson,
+    ExportEncrypted,
+    ExportPdf,
+}
+
+impl OperationKind {
+    /// (per-file overhead, per-address size multiplier) for export formats
+    fn export_profile(self) -> Option<(u64, u64)> {
+        match self {
+            OperationKind::CreateWallet | OperationKind::ImportWallet => None,
+            OperationKind::ExportCsv => Some((1024, 1)),
+            OperationKind::ExportJson => Some((1024, 2)),
+            // Encrypted JSON: base64 plus encryption envelope
+            OperationKind::ExportEncrypted => Some((4 * 1024, 3)),
+            // PDF: embedded fonts, plus a QR code image per address
+            OperationKind::ExportPdf => Some((256 * 1024, 32)),
+        }
+    }
+}
+
+/// Estimate the bytes `operation` will write for `wallet_count` wallets
+pub fn required_space(operation: OperationKind, wallet_count: usize) -> u64 {
+    let per_wallet = match operation.export_profile() {
+        Some((overhead, multiplier)) => {
+            overhead + EXPORT_BYTES_PER_ADDRESS * ADDRESSES_PER_WALLET * multiplier
+        }
+        None => WALLET_OVERHEAD_BYTES,
+    };
+
+    per_wallet.saturating_mul(wallet_count as u64)
+}
+
+/// Estimate the USB space needed for an operation, for pre-flight warnings
+#[tauri::command]
+pub async fn estimate_required_space(operation: OperationKind, wallet_count: usize) -> Result<u64, String> {
+    Ok(required_space(operation, wallet_count))
+}
+
+/// Separator to normalize to, and the one to replace
+#[cfg(windows)]
+const SEPARATORS: (char, char) = ('\\', '/');
+#[cfg(not(windows))]
+const SEPARATORS: (char, char) = ('/', '\\');
+
+/// Canonical spelling of a USB path, so one drive referenced two ways
+/// ("/media/usb/", "/media/usb") shares cache keys.
+///
+/// Separators are unified, repeated and trailing separators dropped, and a
+/// path that exists is resolved to its absolute canonical form. Paths that
+/// don't exist keep their (cleaned) spelling so errors still show them.
+pub fn normalize_usb_path(path: &str) -> String {
+    let (separator, other) = SEPARATORS;
+    let unified = path.trim().replace(other, &separator.to_string());
+
+    // Keep a leading double separator (Windows UNC share)
+    let unc = cfg!(windows) && unified.starts_with(r"\\");
+    let mut cleaned = String::with_capacity(unified.len());
+    for c in unified.chars() {
+        if c == separator && cleaned.ends_with(separator) && !(unc && cleaned.len() == 1) {
+            continue;
+        }
+        cleaned.push(c);
+    }
+
+    // Strip trailing separators, but never reduce a root ("/", "C:\\") further
+    while cleaned.len() > 1 && cleaned.ends_with(separator) && !cleaned.ends_with(&format!(":{}", separator)) {
+        cleaned.pop();
+    }
+
+    match std::fs::canonicalize(long_path(Path::new(&cleaned))) {
+        Ok(canonical) => {
+            let canonical = canonical.to_string_lossy().into_owned();
+            // canonicalize returns the verbatim form on Windows; drop it for drive paths
+            match canonical.strip_prefix(r"\\?\") {
+                Some(rest) if !rest.starts_with("UNC") => rest.to_string(),
+                _ => canonical,
+            }
+        }
+        Err(_) => cleaned,
+    }
+}
+
+/// Check that `usb_path` is an existing directory before handing it to the
+/// FFI, so a typo or file path fails with UsbNotFound instead of a storage
+/// error from deep inside the wallet library
+pub fn validate_usb_path(usb_path: &str) -> AppResult<()> {
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let path = Path::new(usb_path);
+    if usb_path.is_empty() || !long_path(path).exists() {
+        return Err(AppError::new(ErrorCode::UsbNotFound, "USB drive not found"));
+    }
+    if !long_path(path).is_dir() {
+        return Err(AppError::new(
+            ErrorCode::UsbNotFound,
+            "USB path is not a directory. Please select the drive itself.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// What a write pre-flight found out about a USB mount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UsbWriteProbe {
+    writable: bool,
+    /// Free bytes, or None if the filesystem couldn't be queried
+    available_space: Option<u64>,
+}
+
+/// Map a probe to the error the UI can give remediation for
+fn check_write_probe(probe: UsbWriteProbe, required_bytes: u64) -> AppResult<()> {
+    if !probe.writable {
+        return Err(AppError::new(
+            ErrorCode::UsbNotWritable,
+            AppError::default_message_for_code(ErrorCode::UsbNotWritable),
+        ));
+    }
+
+    match probe.available_space {
+        Some(available) if available < required_bytes => Err(AppError::with_details(
+            ErrorCode::UsbInsufficientSpace,
+            AppError::default_message_for_code(ErrorCode::UsbInsufficientSpace),
+            format!("{} bytes free, {} bytes required", available, required_bytes),
+        )),
+        // Unknown free space isn't a reason to refuse: the write itself will tell
+        _ => Ok(()),
+    }
+}
+
+/// Check that a wallet can be written to `usb_path` before calling the FFI.
+///
+/// Distinguishes a missing or non-directory path (UsbNotFound), a read-only
+/// drive (UsbNotWritable) and a full one (UsbInsufficientSpace), which the
+/// library would all report as the same storage error.
+pub async fn preflight_usb_write(usb_path: &str, operation: OperationKind) -> AppResult<()> {
+    validate_usb_path(usb_path)?;
+
+    let path = Path::new(usb_path);
+    let writable = is_path_writable(path);
+    let available_space = match get_available_space(path).await {
+        Ok(available) => Some(available),
+        Err(e) => {
+            tracing::warn!("Could not read free space of USB: {}", e);
+            None
+        }
+    };
+
+    let required_bytes = required_space(operation, 1).max(MIN_REQUIRED_SPACE);
+    check_write_probe(UsbWriteProbe { writable, available_space }, required_bytes)
+}
+
+/// Count the arcSign wallets on a USB without unlocking anything.
+///
+/// A wallet is a top-level directory named by a valid wallet ID that holds a
+/// `wallet.json`; everything else counts as unrelated content.
+pub fn scan_usb_wallets(usb_path: &str) -> AppResult<UsbWalletScan> {
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let entries = std::fs::read_dir(long_path(Path::new(usb_path))).map_err(|e| {
+        AppError::with_details(ErrorCode::UsbNotFound, "USB drive not accessible", e.to_string())
+    })?;
+
+    let mut scan = UsbWalletScan {
+        wallet_count: 0,
+        has_arcsign_structure: false,
+        is_empty: true,
+    };
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let file_name = entry.file_name();
+        let name = file_name.to_string_lossy();
+        if is_housekeeping_entry(&name) {
+            continue;
+        }
+        scan.is_empty = false;
+
+        let is_wallet_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false)
+            && sanitize_wallet_id(&name).is_ok();
+        if !is_wallet_dir {
+            continue;
+        }
+
+        scan.has_arcsign_structure = true;
+        if long_path(&entry.path().join("wallet.json")).is_file() {
+            scan.wallet_count += 1;
+        }
+    }
+
+    Ok(scan)
+}
+
+/// Check whether a USB already holds arcSign wallets (before offering to create one)
+#[tauri::command]
+pub async fn usb_has_wallets(usb_path: String) -> Result<UsbWalletScan, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    scan_usb_wallets(&usb_path).map_err(String::from)
+}
+
 /// Detect available USB storage devices
 #[tauri::command]
 pub async fn detect_usb() -> Result<Vec<UsbDevice>, String> {
@@ -183,12 +422,11 @@ async fn detect_windows_drives() -> Result<Vec<UsbDevice>, String> {
     Ok(devices)
 }
 
-/// Check if path is writable and get available space
-async fn check_path_writable_and_space(path: &Path) -> Result<(bool, u64), String> {
+/// Check if path is writable by attempting to create a test file
+pub(crate) fn is_path_writable(path: &Path) -> bool {
     use std::fs;
 
-    // Check if path is writable by attempting to create a test file
-    let test_file = path.join(".arcsign_write_test");
+    let test_file = long_path(&path.join(".arcsign_write_test"));
     let is_writable = fs::write(&test_file, b"test").is_ok();
 
     // Clean up test file
@@ -196,6 +434,13 @@ async fn check_path_writable_and_space(path: &Path) -> Result<(bool, u64), Strin
         let _ = fs::remove_file(&test_file);
     }
 
+    is_writable
+}
+
+/// Check if path is writable and get available space
+async fn check_path_writable_and_space(path: &Path) -> Result<(bool, u64), String> {
+    let is_writable = is_path_writable(path);
+
     // Get available space using platform-specific methods
     let available_space = get_available_space(path).await?;
 
@@ -229,7 +474,7 @@ async fn get_available_space(path: &Path) -> Result<u64, String> {
         use std::os::windows::ffi::OsStrExt;
         use winapi::um::fileapi::GetDiskFreeSpaceExW;
 
-        let wide_path: Vec<u16> = path
+        let wide_path: Vec<u16> = long_path(path)
             .as_os_str()
             .encode_wide()
             .chain(std::iter::once(0))
@@ -253,11 +498,201 @@ async fn get_available_space(path: &Path) -> Result<u64, String> {
     }
 }
 
+/// What a USB filesystem can enforce, for the security notice in the UI
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UsbSecurityReport {
+    /// Filesystem type as reported by the OS (e.g. "vfat", "exfat", "apfs"),
+    /// or None if it couldn't be determined
+    pub filesystem: Option<String>,
+    /// Whether owner-only (0600) file permissions are kept; None if unknown
+    pub preserves_permissions: Option<bool>,
+    /// Whether file names differing only in case are distinct; None if unknown
+    pub case_sensitive: Option<bool>,
+    /// Messages for the UI to show, empty if nothing to warn about
+    pub warnings: Vec<String>,
+}
+
+/// (preserves Unix permissions, case-sensitive) for a filesystem type name,
+/// or None for types whose behaviour depends on mount options
+fn filesystem_capabilities(fs_type: &str) -> Option<(bool, bool)> {
+    match fs_type.to_ascii_lowercase().as_str() {
+        "ext2" | "ext3" | "ext4" | "xfs" | "btrfs" | "f2fs" | "zfs" | "jfs" | "tmpfs" => Some((true, true)),
+        // Default APFS/HFS+ volumes are case-insensitive
+        "apfs" | "hfs" | "hfsplus" => Some((true, false)),
+        "vfat" | "fat" | "fat12" | "fat16" | "fat32" | "msdos" | "msdosfs" | "exfat" => Some((false, false)),
+        "ntfs" | "ntfs3" => Some((false, false)),
+        _ => None,
+    }
+}
+
+/// Build the report for a detected (or undetected) filesystem type
+fn security_report(fs_type: Option<String>) -> UsbSecurityReport {
+    let capabilities = fs_type.as_deref().and_then(filesystem_capabilities);
+    let preserves_permissions = capabilities.map(|(permissions, _)| permissions);
+    let case_sensitive = capabilities.map(|(_, case_sensitive)| case_sensitive);
+
+    let mut warnings = Vec::new();
+    match preserves_permissions {
+        Some(true) => {}
+        Some(false) => warnings.push(
+            "This drive cannot restrict file permissions. Anyone with access to the drive can read exported files."
+                .to_string(),
+        ),
+        None => warnings.push(
+            "Could not determine whether this drive can restrict file permissions.".to_string(),
+        ),
+    }
+    if case_sensitive == Some(false) {
+        warnings.push(
+            "This drive ignores letter case in file names, so names differing only in case refer to the same file."
+                .to_string(),
+        );
+    }
+
+    UsbSecurityReport {
+        filesystem: fs_type,
+        preserves_permissions,
+        case_sensitive,
+        warnings,
+    }
+}
+
+/// Filesystem type of the mount holding `path`: the longest matching mount
+/// point in a /proc/mounts listing
+#[cfg(any(target_os = "linux", test))]
+fn fs_type_from_mounts(mounts: &str, path: &Path) -> Option<String> {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            // Spaces in mount points are escaped as \040
+            let mount_point = fields.next()?.replace("\\040", " ");
+            let fs_type = fields.next()?;
+            path.starts_with(&mount_point).then(|| (mount_point.len(), fs_type.to_string()))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, fs_type)| fs_type)
+}
+
+/// Filesystem type of the volume holding `path`, if the OS reports it
+fn filesystem_type(path: &Path) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        let path = std::fs::canonicalize(path).ok()?;
+        let mounts = std::fs::read_to_string("/proc/self/mounts").ok()?;
+        fs_type_from_mounts(&mounts, &path)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use std::ffi::{CStr, CString};
+        use std::os::unix::ffi::OsStrExt;
+
+        let path_cstr = CString::new(path.as_os_str().as_bytes()).ok()?;
+        let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statfs(path_cstr.as_ptr(), &mut stat) } != 0 {
+            return None;
+        }
+        let name = unsafe { CStr::from_ptr(stat.f_fstypename.as_ptr()) };
+        Some(name.to_string_lossy().into_owned())
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+        use winapi::um::fileapi::{GetVolumeInformationW, GetVolumePathNameW};
+
+        let wide_path: Vec<u16> = long_path(path)
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        let mut root = [0u16; 261];
+        if unsafe { GetVolumePathNameW(wide_path.as_ptr(), root.as_mut_ptr(), root.len() as u32) } == 0 {
+            return None;
+        }
+
+        let mut fs_name = [0u16; 261];
+        if unsafe {
+            GetVolumeInformationW(
+                root.as_ptr(),
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                fs_name.as_mut_ptr(),
+                fs_name.len() as u32,
+            )
+        } == 0
+        {
+            return None;
+        }
+
+        let len = fs_name.iter().position(|&c| c == 0).unwrap_or(fs_name.len());
+        Some(String::from_utf16_lossy(&fs_name[..len]))
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// Report whether the USB's filesystem keeps owner-only permissions and
+/// distinguishes file names by case, with warnings for the UI
+#[tauri::command]
+pub async fn check_usb_security(usb_path: String) -> Result<UsbSecurityReport, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    validate_usb_path(&usb_path).map_err(String::from)?;
+
+    let report = security_report(filesystem_type(Path::new(&usb_path)));
+    if !report.warnings.is_empty() {
+        tracing::warn!("USB filesystem {:?}: {}", report.filesystem, report.warnings.join(" "));
+    }
+    Ok(report)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_filesystem_capabilities() {
+        assert_eq!(filesystem_capabilities("ext4"), Some((true, true)));
+        assert_eq!(filesystem_capabilities("apfs"), Some((true, false)));
+        assert_eq!(filesystem_capabilities("vfat"), Some((false, false)));
+        assert_eq!(filesystem_capabilities("exFAT"), Some((false, false)));
+        assert_eq!(filesystem_capabilities("FAT32"), Some((false, false)));
+        assert_eq!(filesystem_capabilities("NTFS"), Some((false, false)));
+        // ntfs-3g and other FUSE mounts depend on mount options
+        assert_eq!(filesystem_capabilities("fuseblk"), None);
+
+        let fat = security_report(Some("exfat".to_string()));
+        assert_eq!(fat.preserves_permissions, Some(false));
+        assert_eq!(fat.case_sensitive, Some(false));
+        assert!(fat.warnings[0].starts_with("This drive cannot restrict file permissions"));
+
+        assert!(security_report(Some("ext4".to_string())).warnings.is_empty());
+        assert_eq!(security_report(None).warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_fs_type_from_mounts_uses_longest_mount_point() {
+        let mounts = "/dev/sda1 / ext4 rw,relatime 0 0\n\
+                      /dev/sdb1 /media/alice/MY\\040USB vfat rw,nosuid 0 0\n";
+
+        assert_eq!(
+            fs_type_from_mounts(mounts, Path::new("/media/alice/MY USB/wallets")).as_deref(),
+            Some("vfat")
+        );
+        assert_eq!(fs_type_from_mounts(mounts, Path::new("/home/alice")).as_deref(), Some("ext4"));
+    }
+
     #[tokio::test]
     async fn test_check_path_writable() {
         let temp_dir = TempDir::new().unwrap();
@@ -277,6 +712,171 @@ mod tests {
         assert!(space > MIN_REQUIRED_SPACE, "Should have at least 10MB free");
     }
 
+    #[test]
+    fn test_required_space_pdf_exceeds_csv() {
+        let csv = required_space(OperationKind::ExportCsv, 1);
+        let json = required_space(OperationKind::ExportJson, 1);
+        let pdf = required_space(OperationKind::ExportPdf, 1);
+
+        assert!(csv < json);
+        assert!(pdf > csv);
+        assert!(pdf > required_space(OperationKind::ExportEncrypted, 1));
+    }
+
+    #[test]
+    fn test_required_space_scales_with_wallet_count() {
+        let one = required_space(OperationKind::ExportJson, 1);
+        assert_eq!(required_space(OperationKind::ExportJson, 5), one * 5);
+        assert_eq!(required_space(OperationKind::CreateWallet, 3), WALLET_OVERHEAD_BYTES * 3);
+        assert_eq!(required_space(OperationKind::ExportPdf, 0), 0);
+    }
+
+    const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+
+    #[test]
+    fn test_validate_usb_path() {
+        let usb = TempDir::new().unwrap();
+        assert!(validate_usb_path(usb.path().to_str().unwrap()).is_ok());
+
+        let file = usb.path().join("wallet.json");
+        std::fs::write(&file, "{}").unwrap();
+        let err = validate_usb_path(file.to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::UsbNotFound);
+        assert!(err.message.contains("not a directory"));
+
+        for missing in [usb.path().join("missing").to_str().unwrap(), ""] {
+            let err = validate_usb_path(missing).unwrap_err();
+            assert_eq!(err.code, ErrorCode::UsbNotFound);
+        }
+    }
+
+    #[test]
+    fn test_normalize_usb_path_spellings_agree() {
+        let temp_dir = TempDir::new().unwrap();
+        let usb = temp_dir.path().join("USB");
+        std::fs::create_dir(&usb).unwrap();
+        let usb_path = usb.to_str().unwrap();
+
+        let canonical = normalize_usb_path(usb_path);
+        assert_eq!(normalize_usb_path(&format!("{}/", usb_path)), canonical);
+        assert_eq!(normalize_usb_path(&format!("{}//", usb_path)), canonical);
+        assert_eq!(normalize_usb_path(&format!("  {}  ", usb_path)), canonical);
+
+        // Mixed separators resolve to the same drive
+        let mixed = format!("{}\\{}", temp_dir.path().to_str().unwrap(), "USB\\");
+        assert_eq!(normalize_usb_path(&mixed), canonical);
+
+        // A missing path is cleaned but not resolved
+        #[cfg(not(windows))]
+        {
+            assert_eq!(normalize_usb_path("/media/missing-usb//"), "/media/missing-usb");
+            assert_eq!(normalize_usb_path("\\media\\missing-usb\\"), "/media/missing-usb");
+            assert_eq!(normalize_usb_path("/"), "/");
+        }
+    }
+
+    #[test]
+    fn test_check_write_probe_branches() {
+        let ok = UsbWriteProbe { writable: true, available_space: Some(MIN_REQUIRED_SPACE) };
+        assert!(check_write_probe(ok, MIN_REQUIRED_SPACE).is_ok());
+
+        let read_only = UsbWriteProbe { writable: false, available_space: Some(u64::MAX) };
+        let err = check_write_probe(read_only, MIN_REQUIRED_SPACE).unwrap_err();
+        assert_eq!(err.code, ErrorCode::UsbNotWritable);
+
+        let full = UsbWriteProbe { writable: true, available_space: Some(MIN_REQUIRED_SPACE - 1) };
+        let err = check_write_probe(full, MIN_REQUIRED_SPACE).unwrap_err();
+        assert_eq!(err.code, ErrorCode::UsbInsufficientSpace);
+
+        // Read-only wins over full: making room wouldn't help
+        let both = UsbWriteProbe { writable: false, available_space: Some(0) };
+        assert_eq!(check_write_probe(both, MIN_REQUIRED_SPACE).unwrap_err().code, ErrorCode::UsbNotWritable);
+
+        let unknown = UsbWriteProbe { writable: true, available_space: None };
+        assert!(check_write_probe(unknown, MIN_REQUIRED_SPACE).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_preflight_usb_write() {
+        let usb = TempDir::new().unwrap();
+        preflight_usb_write(usb.path().to_str().unwrap(), OperationKind::CreateWallet)
+            .await
+            .unwrap();
+        assert!(!usb.path().join(".arcsign_write_test").exists());
+
+        let file = usb.path().join("wallet.json");
+        std::fs::write(&file, "{}").unwrap();
+        let err = preflight_usb_write(file.to_str().unwrap(), OperationKind::CreateWallet)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::UsbNotFound);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_preflight_usb_write_read_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Root can write regardless of permissions
+        if unsafe { libc::geteuid() } == 0 {
+            return;
+        }
+
+        let usb = TempDir::new().unwrap();
+        std::fs::set_permissions(usb.path(), std::fs::Permissions::from_mode(0o555)).unwrap();
+        let result = preflight_usb_write(usb.path().to_str().unwrap(), OperationKind::ImportWallet).await;
+        std::fs::set_permissions(usb.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert_eq!(result.unwrap_err().code, ErrorCode::UsbNotWritable);
+    }
+
+    #[test]
+    fn test_scan_usb_wallets_empty_drive() {
+        let usb = TempDir::new().unwrap();
+        std::fs::create_dir(usb.path().join(".Trashes")).unwrap();
+        std::fs::write(usb.path().join(".DS_Store"), "").unwrap();
+
+        let scan = scan_usb_wallets(usb.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            scan,
+            UsbWalletScan { wallet_count: 0, has_arcsign_structure: false, is_empty: true }
+        );
+    }
+
+    #[test]
+    fn test_scan_usb_wallets_counts_wallets() {
+        let usb = TempDir::new().unwrap();
+        let wallet_dir = usb.path().join(WALLET_ID);
+        std::fs::create_dir(&wallet_dir).unwrap();
+        std::fs::write(wallet_dir.join("wallet.json"), "{}").unwrap();
+
+        // Incomplete wallet: directory without wallet.json
+        std::fs::create_dir(usb.path().join("a".repeat(64))).unwrap();
+
+        let scan = scan_usb_wallets(usb.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            scan,
+            UsbWalletScan { wallet_count: 1, has_arcsign_structure: true, is_empty: false }
+        );
+    }
+
+    #[test]
+    fn test_scan_usb_wallets_unrelated_files() {
+        let usb = TempDir::new().unwrap();
+        std::fs::create_dir(usb.path().join("Photos")).unwrap();
+        std::fs::write(usb.path().join("Photos").join("wallet.json"), "{}").unwrap();
+        std::fs::write(usb.path().join(WALLET_ID), "not a directory").unwrap();
+
+        let scan = scan_usb_wallets(usb.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            scan,
+            UsbWalletScan { wallet_count: 0, has_arcsign_structure: false, is_empty: false }
+        );
+
+        let err = scan_usb_wallets(usb.path().join("missing").to_str().unwrap()).unwrap_err();
+        assert_eq!(err.code, ErrorCode::UsbNotFound);
+    }
+
     #[tokio::test]
     async fn test_detect_usb_filters_insufficient_space() {
         // This is a conceptual test - actual implementation would mock filesystem
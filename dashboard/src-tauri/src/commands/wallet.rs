@@ -5,16 +5,34 @@
  * Updated: 2025-10-25 - T032.1: Migrated to FFI queue
  */
 
+use crate::cache::{ReadCache, TtlCache};
+use crate::commands::derivation::parse_path_segments;
+use crate::commands::maintenance::stored_address_count;
+use crate::commands::ranking;
+use crate::commands::security::ActivityTracker;
+use crate::commands::usb::{normalize_usb_path, preflight_usb_write, OperationKind};
 use crate::error::{AppError, AppResult, ErrorCode};
-use crate::ffi::LazyWalletQueue; // T032: Add FFI queue import (using LazyWalletQueue for deferred initialization)
-use crate::models::address::{Address, AddressListResponse, Category, KeyType};
-use crate::models::wallet::{Wallet, WalletCreateResponse, WalletImportResponse};
+use crate::ffi::queue::env_number;
+use crate::ffi::{LazyWalletQueue, WalletBackend}; // T032: Add FFI queue import (using LazyWalletQueue for deferred initialization)
+use crate::limits::{
+    check_length, check_optional_length, MAX_MNEMONIC_LEN, MAX_PASSPHRASE_LEN, MAX_PASSWORD_LEN,
+    MAX_PATH_LEN, MAX_WALLET_NAME_LEN,
+};
+use crate::models::address::{
+    Address, AddressListResponse, AddressType, Category, FailedAddress, GenerateAddressesRequest,
+    GroupedAddressResponse, KeyType,
+};
+use crate::models::wallet::{
+    Wallet, WalletCreateResponse, WalletFileMetadata, WalletImportResponse, WalletListResponse, WalletPage,
+    WalletRenameResponse, WalletSortKey, DEFAULT_WALLET_PAGE_SIZE,
+};
+use crate::secret::{ffi_params, Secret}; // T037: Zeroized on drop
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Mutex;
-use std::time::Instant; // T038: Performance logging
+use std::time::{Duration, Instant}; // T038: Performance logging
 use tauri::State;
-use zeroize::Zeroize; // T037: Secure memory zeroing
 
 /// Validate password complexity
 /// Requirements: 12+ chars, uppercase, lowercase, number
@@ -54,25 +72,62 @@ fn validate_password(password: &str) -> AppResult<()> {
     Ok(())
 }
 
+/// Reject oversized create/import arguments before any other work.
+/// The wallet name is checked trimmed, matching `Wallet::validate_name`.
+fn check_wallet_input_lengths(
+    password: &str,
+    passphrase: Option<&str>,
+    mnemonic: Option<&str>,
+    name: Option<&str>,
+    usb_path: &str,
+) -> AppResult<()> {
+    check_length("Password", password, MAX_PASSWORD_LEN)?;
+    check_optional_length("Passphrase", passphrase, MAX_PASSPHRASE_LEN)?;
+    check_optional_length("Mnemonic", mnemonic, MAX_MNEMONIC_LEN)?;
+    check_optional_length("Wallet name", name.map(str::trim), MAX_WALLET_NAME_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)
+}
+
+/// Command duration for responses, rounded up so fast operations don't
+/// report 0 ms
+fn duration_ms(elapsed: Duration) -> u64 {
+    elapsed.as_micros().div_ceil(1000) as u64
+}
+
 /// Create new HD wallet (T032.1 - Updated to use FFI queue)
 /// Requirements: FR-001 (Wallet creation), FR-004 (BIP39 generation), FR-024 (USB storage)
 /// Note: Using camelCase parameter names to match JavaScript/TypeScript convention
 #[tauri::command]
 pub async fn create_wallet(
-    queue: State<'_, LazyWalletQueue>, // T032.1: Accept LazyWalletQueue from Tauri state
-    mut password: String, // T037: Make mutable for zeroize
+    backend: State<'_, WalletBackend>, // T032.1: Accept WalletBackend from Tauri state
+    read_cache: State<'_, ReadCache>,
+    password: Secret,
     #[allow(non_snake_case)]
     usbPath: String,
     name: Option<String>,
-    mut passphrase: Option<String>, // T037: Make mutable for zeroize
+    passphrase: Option<Secret>,
     #[allow(non_snake_case)]
     mnemonicLength: Option<usize>,
 ) -> Result<WalletCreateResponse, String> {
+    #[allow(non_snake_case)]
+    let usbPath = normalize_usb_path(&usbPath);
+    let queue = backend.queue().map_err(String::from)?;
+
     // T038: Start performance timer
     let start = Instant::now();
 
+    check_wallet_input_lengths(
+        password.expose(),
+        passphrase.as_ref().map(Secret::expose),
+        None,
+        name.as_deref(),
+        &usbPath,
+    )
+    .map_err(String::from)?;
+    preflight_usb_write(&usbPath, OperationKind::CreateWallet).await.map_err(String::from)?;
+
     // T050: Validate password
-    validate_password(&password).map_err(String::from)?;
+    validate_password(password.expose()).map_err(String::from)?;
 
     // Validate mnemonic length
     let length = mnemonicLength.unwrap_or(24);
@@ -105,19 +160,19 @@ pub async fn create_wallet(
 
     // T032.1: Build JSON params for FFI call
     // Go will generate the mnemonic based on wordCount
-    let mut params = json!({
+    let params = json!({
         "walletName": wallet_name,
-        "password": password,
         "usbPath": usbPath,
         "wordCount": length,
     });
 
     // Add passphrase if provided
+    let mut secrets = vec![("password", password.expose())];
     if let Some(ref pp) = passphrase {
-        params["passphrase"] = json!(pp);
+        secrets.push(("passphrase", pp.expose()));
     }
 
-    let params_json = serde_json::to_string(&params)
+    let params_json = ffi_params(params, &secrets)
         .map_err(|e| format!("Failed to serialize params: {}", e))?;
 
     // T032.1: Call FFI queue
@@ -150,15 +205,8 @@ pub async fn create_wallet(
             }
         })?;
 
-    // T037: Zero sensitive data from memory
-    password.zeroize();
-    if let Some(ref mut pp) = passphrase {
-        pp.zeroize();
-    }
-
-    // T052: Parse FFI JSON response
+    // T052: Parse FFI JSON response (not logged: it carries the new mnemonic)
     // Expected format: {"walletId": "...", "walletName": "...", "createdAt": "...", "note": "..."}
-    tracing::info!("Wallet creation FFI response: {:?}", ffi_response);
 
     let wallet_id = ffi_response
         .get("walletId")
@@ -176,14 +224,7 @@ pub async fn create_wallet(
         .to_string();
 
     // Extract mnemonic from FFI response (Go generates it)
-    let mnemonic = ffi_response
-        .get("mnemonic")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| AppError::new(
-            ErrorCode::DeserializationError,
-            "Missing mnemonic in FFI response",
-        ))?
-        .to_string();
+    let mnemonic = created_wallet_mnemonic(&usbPath, &wallet_id, &ffi_response, length)?;
 
     // T053: Convert to domain model and return via Tauri IPC
     let wallet = Wallet {
@@ -195,22 +236,83 @@ pub async fn create_wallet(
         address_count: 54, // All 54 addresses will be generated
     };
 
+    // T038: Log performance metrics
+    let elapsed = start.elapsed();
     let response = WalletCreateResponse {
         wallet,
         mnemonic,
+        duration_ms: Some(duration_ms(elapsed)),
     };
 
-    // T038: Log performance metrics
-    let elapsed = start.elapsed();
     tracing::info!(
         "Wallet created successfully: {} (took {:?})",
         wallet_name,
         elapsed
     );
 
+    read_cache.wallets.invalidate_all();
     Ok(response)
 }
 
+/// Extract the mnemonic Go generated, checking it has the requested word count.
+///
+/// A library that ignores `wordCount` would otherwise hand the user a
+/// phrase of a different length than they chose without any warning.
+fn generated_mnemonic(ffi_response: &serde_json::Value, requested_words: usize) -> AppResult<String> {
+    let mnemonic = ffi_response
+        .get("mnemonic")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| AppError::new(
+            ErrorCode::DeserializationError,
+            "Missing mnemonic in FFI response",
+        ))?;
+
+    let word_count = mnemonic.split_whitespace().count();
+    if word_count != requested_words {
+        tracing::error!(
+            "Wallet library generated a {}-word mnemonic, {} words requested",
+            word_count,
+            requested_words
+        );
+        return Err(AppError::new(
+            ErrorCode::InternalError,
+            format!(
+                "Wallet library generated a {}-word recovery phrase instead of the requested {} words",
+                word_count, requested_words
+            ),
+        ));
+    }
+
+    Ok(mnemonic.to_string())
+}
+
+/// The mnemonic of the wallet Go just created and saved as
+/// `{usb_path}/{wallet_id}`, checked by `generated_mnemonic`.
+///
+/// On failure the saved wallet is deleted again: its phrase is never shown,
+/// so it could not be backed up and would only clutter the wallet list.
+fn created_wallet_mnemonic(
+    usb_path: &str,
+    wallet_id: &str,
+    ffi_response: &serde_json::Value,
+    requested_words: usize,
+) -> AppResult<String> {
+    use crate::fs_utils::long_path;
+    use std::path::Path;
+
+    generated_mnemonic(ffi_response, requested_words).inspect_err(|_| {
+        // wallet_id comes from the library; never let it name another directory
+        if sanitize_wallet_id(wallet_id).is_err() {
+            return;
+        }
+        let wallet_dir = Path::new(usb_path).join(wallet_id);
+        match std::fs::remove_dir_all(long_path(&wallet_dir)) {
+            Ok(()) => tracing::warn!("Deleted wallet {} created with the wrong recovery phrase", wallet_id),
+            Err(e) => tracing::error!("Failed to delete rejected wallet {}: {}", wallet_id, e),
+        }
+    })
+}
+
 /// Normalize mnemonic phrase (T068)
 /// Requirements: FR-030 (Whitespace normalization)
 fn normalize_mnemonic(mnemonic: &str) -> String {
@@ -221,6 +323,41 @@ fn normalize_mnemonic(mnemonic: &str) -> String {
         .to_lowercase()
 }
 
+/// Map an ImportWallet FFI error ("CODE: message") to an AppError.
+///
+/// Word-count problems are caught before the FFI call, so the library's
+/// mnemonic errors never map to InvalidMnemonicLength.
+fn import_error(e: String) -> AppError {
+    // Check for duplicate wallet error (FR-031)
+    if e.contains("already exists") || e.contains("DUPLICATE") || e.contains("WALLET_ALREADY_EXISTS") {
+        AppError::new(
+            ErrorCode::WalletAlreadyExists,
+            "Wallet with this mnemonic already exists on USB",
+        )
+    } else if e.contains("INVALID_CHECKSUM") {
+        AppError::new(
+            ErrorCode::InvalidMnemonicChecksum,
+            AppError::default_message_for_code(ErrorCode::InvalidMnemonicChecksum),
+        )
+    } else if e.contains("INVALID_MNEMONIC") {
+        AppError::new(
+            ErrorCode::InvalidMnemonic,
+            "Invalid BIP39 mnemonic phrase",
+        )
+    } else if e.contains("USB_NOT_FOUND") || e.contains("STORAGE_ERROR") {
+        AppError::new(
+            ErrorCode::UsbNotFound,
+            "USB device not found",
+        )
+    } else {
+        AppError::with_details(
+            ErrorCode::CliExecutionFailed,
+            "Failed to import wallet",
+            e,
+        )
+    }
+}
+
 /// Validate mnemonic word count
 fn validate_mnemonic_length(mnemonic: &str) -> AppResult<()> {
     let word_count = mnemonic.split_whitespace().count();
@@ -233,25 +370,62 @@ fn validate_mnemonic_length(mnemonic: &str) -> AppResult<()> {
     Ok(())
 }
 
+/// Derivation path of the address DeriveFirstAddress returns
+const FIRST_ADDRESS_PATH: &str = "m/44'/0'/0'/0/0";
+
+/// What duplicate detection compares for a stored wallet: its first Bitcoin
+/// address and whether it was created with a passphrase.
+///
+/// Read from addresses.json, falling back to the address book in wallet.json
+/// for older wallets; None if the wallet has no stored first address.
+fn stored_wallet_fingerprint(dir: &std::path::Path, metadata: &serde_json::Value) -> Option<(String, bool)> {
+    use crate::fs_utils::long_path;
+
+    let first_address_in = |addresses: &serde_json::Value| -> Option<String> {
+        addresses
+            .as_array()?
+            .iter()
+            .find(|entry| entry.get("derivationPath").and_then(|v| v.as_str()) == Some(FIRST_ADDRESS_PATH))?
+            .get("address")?
+            .as_str()
+            .map(str::to_string)
+    };
+
+    let from_addresses_json = std::fs::read_to_string(long_path(&dir.join("addresses.json")))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .and_then(|json| first_address_in(json.get("addresses")?));
+    let first_address = from_addresses_json
+        .or_else(|| first_address_in(metadata.pointer("/addressBook/addresses")?))?;
+
+    Some((first_address, WalletFileMetadata::from(metadata).uses_passphrase))
+}
+
 /// Check for duplicate wallet by deriving Bitcoin address (T092-T094)
-/// Returns Some((wallet_id, name)) if duplicate found, None otherwise
+/// Returns Some((wallet_id, name, created_at)) if duplicate found, None otherwise
+///
+/// A stored wallet is a duplicate only if both its first address and its
+/// passphrase flag match, so a passphrase wallet is never mistaken for its
+/// passphrase-less sibling (the passphrase changes every derived address).
+/// Without DeriveFirstAddress in the library, detection is left to the FFI.
 async fn check_duplicate_wallet(
+    queue: &LazyWalletQueue,
     mnemonic: &str,
     passphrase: Option<&str>,
     usb_path: &str,
 ) -> AppResult<Option<(String, String, String)>> {
+    use crate::fs_utils::long_path;
     use std::fs;
     use std::path::Path;
 
-    // T093: Read all addresses.json files from USB
-    // TODO: Implement actual duplicate detection by deriving Bitcoin address and comparing
+    // T093: Read every wallet's stored first address from the USB
     let usb_dir = Path::new(usb_path);
 
     if !usb_dir.exists() {
         return Ok(None);
     }
 
-    let entries = fs::read_dir(usb_dir).map_err(|e| {
+    let entries = fs::read_dir(long_path(usb_dir)).map_err(|e| {
         AppError::with_details(
             ErrorCode::CliExecutionFailed,
             "Failed to read USB directory for duplicate detection",
@@ -259,8 +433,7 @@ async fn check_duplicate_wallet(
         )
     })?;
 
-    // For now, we'll use a simpler approach: check if any wallet exists
-    // In production, we should derive the Bitcoin address and compare
+    let mut stored = Vec::new();
     for entry in entries {
         let entry = entry.map_err(|e| {
             AppError::with_details(
@@ -275,61 +448,454 @@ async fn check_duplicate_wallet(
             continue;
         }
 
-        // Check if wallet.json exists
-        let wallet_json_path = path.join("wallet.json");
-        if wallet_json_path.exists() {
-            // Read wallet metadata
-            if let Ok(wallet_json) = fs::read_to_string(&wallet_json_path) {
-                if let Ok(wallet_meta) = serde_json::from_str::<serde_json::Value>(&wallet_json) {
-                    let wallet_id = wallet_meta.get("id")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("unknown")
-                        .to_string();
-
-                    let wallet_name = wallet_meta.get("name")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or(&wallet_id)
-                        .to_string();
-
-                    let created_at = wallet_meta.get("createdAt")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("unknown")
-                        .to_string();
-
-                    // TODO: Implement actual duplicate detection by comparing Bitcoin addresses
-                    // For now, we'll mark as non-duplicate and let the CLI handle it
-                    // The CLI will return WALLET_EXISTS error if mnemonic is duplicate
+        let Some(wallet_meta) = fs::read_to_string(long_path(&path.join("wallet.json")))
+            .ok()
+            .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        else {
+            continue;
+        };
+        let Some(fingerprint) = stored_wallet_fingerprint(&path, &wallet_meta) else {
+            continue;
+        };
+
+        let metadata = WalletFileMetadata::from(&wallet_meta);
+        let wallet_id = metadata.id.unwrap_or_else(|| "unknown".to_string());
+        let wallet_name = metadata.name.unwrap_or_else(|| wallet_id.clone());
+        let created_at = metadata.created_at.unwrap_or_else(|| "unknown".to_string());
+
+        stored.push((fingerprint, (wallet_id, wallet_name, created_at)));
+    }
+
+    // No wallet to compare against: skip the FFI round-trip
+    if stored.is_empty() {
+        return Ok(None);
+    }
+
+    // T092: Derive the first address of the phrase being imported
+    let first_address = match first_address_fingerprint(queue, mnemonic, passphrase).await {
+        Ok(address) => address,
+        Err(e) if e.code == ErrorCode::UnsupportedOperation => {
+            tracing::warn!("DeriveFirstAddress unavailable; leaving duplicate detection to the library");
+            return Ok(None);
+        }
+        Err(e) => return Err(e),
+    };
+    let uses_passphrase = passphrase.is_some_and(|p| !p.is_empty());
+
+    // T094: Same first address and same passphrase flag
+    Ok(stored
+        .into_iter()
+        .find(|((address, stored_passphrase), _)| *address == first_address && *stored_passphrase == uses_passphrase)
+        .map(|(_, wallet)| wallet))
+}
+
+/// First address (m/44'/0'/0'/0/0) of a normalized mnemonic and passphrase,
+/// which identifies the wallet the phrase restores
+async fn first_address_fingerprint(
+    queue: &LazyWalletQueue,
+    mnemonic: &str,
+    passphrase: Option<&str>,
+) -> AppResult<String> {
+    first_chain_address(queue, mnemonic, passphrase, "bitcoin").await
+}
+
+/// First receiving address of `chain_id` for a mnemonic and passphrase.
+/// Derived in memory only; nothing is written anywhere.
+async fn first_chain_address(
+    queue: &LazyWalletQueue,
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    chain_id: &str,
+) -> AppResult<String> {
+    check_length("Mnemonic", mnemonic, MAX_MNEMONIC_LEN)?;
+    check_optional_length("Passphrase", passphrase, MAX_PASSPHRASE_LEN)?;
+
+    let normalized = Secret::new(normalize_mnemonic(mnemonic));
+    validate_mnemonic_length(normalized.expose())?;
+
+    let params_json = ffi_params(
+        json!({ "chainId": chain_id }),
+        &[("mnemonic", normalized.expose()), ("passphrase", passphrase.unwrap_or(""))],
+    )?;
+
+    let result = queue.derive_first_address(params_json).await;
+
+    let result = result.map_err(|e| {
+        let code = e
+            .split_once(':')
+            .map(|(code, _)| AppError::from_ffi_error_code(code.trim()))
+            .unwrap_or(ErrorCode::InternalError);
+        AppError::with_details(code, AppError::default_message_for_code(code), e)
+    })?;
+
+    result
+        .get("address")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| AppError::new(ErrorCode::DeserializationError, "Missing address in FFI response"))
+}
+
+/// Whether two mnemonics restore the same wallet.
+///
+/// Compares derived first addresses rather than the phrases, so whitespace
+/// and case differences don't matter but a different passphrase does.
+pub(crate) async fn mnemonics_match(
+    queue: &LazyWalletQueue,
+    a: &Secret,
+    passphrase_a: Option<&Secret>,
+    b: &Secret,
+    passphrase_b: Option<&Secret>,
+) -> AppResult<bool> {
+    let first = first_address_fingerprint(queue, a.expose(), passphrase_a.map(Secret::expose)).await?;
+    let second = first_address_fingerprint(queue, b.expose(), passphrase_b.map(Secret::expose)).await?;
+    Ok(first == second)
+}
+
+/// Check that a re-entered backup phrase restores the same wallet
+#[tauri::command]
+pub async fn mnemonics_equivalent(
+    backend: State<'_, WalletBackend>,
+    a: Secret,
+    b: Secret,
+    passphrase_a: Option<Secret>,
+    passphrase_b: Option<Secret>,
+) -> Result<bool, String> {
+    let queue = backend.queue().map_err(String::from)?;
+
+    mnemonics_match(queue, &a, passphrase_a.as_ref(), &b, passphrase_b.as_ref())
+        .await
+        .map_err(String::from)
+}
+
+/// Address a backup phrase would restore, for checking a paper backup
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorePreview {
+    pub chain_id: String,
+    pub address: String,
+}
+
+/// Derive the first address a mnemonic and passphrase would restore on
+/// `chain_id`, without creating a wallet or touching the USB.
+pub(crate) async fn preview_wallet_restore(
+    queue: &LazyWalletQueue,
+    mnemonic: &Secret,
+    passphrase: Option<&Secret>,
+    chain_id: &str,
+) -> AppResult<RestorePreview> {
+    let address = first_chain_address(queue, mnemonic.expose(), passphrase.map(Secret::expose), chain_id).await?;
+
+    // Older libraries ignore chainId and always derive Bitcoin; never show
+    // that as another chain's address
+    let address = crate::commands::transaction::normalize_recipient_address(chain_id, &address).map_err(|_| {
+        AppError::new(
+            ErrorCode::UnsupportedOperation,
+            format!("This wallet library cannot derive {} addresses for a preview", chain_id),
+        )
+    })?;
+
+    Ok(RestorePreview { chain_id: chain_id.to_string(), address })
+}
+
+/// Show the address a backup phrase restores, to match against a known one.
+/// Offline: nothing is persisted and the USB is never written.
+#[tauri::command]
+pub async fn preview_restore(
+    backend: State<'_, WalletBackend>,
+    mnemonic: Secret,
+    passphrase: Option<Secret>,
+    chain_id: String,
+) -> Result<RestorePreview, String> {
+    let queue = backend.queue().map_err(String::from)?;
+
+    tracing::info!("preview_restore: chain_id={}", chain_id);
+
+    preview_wallet_restore(queue, &mnemonic, passphrase.as_ref(), &chain_id)
+        .await
+        .map_err(String::from)
+}
+
+/// Layout of a mnemonic shown for backup
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MnemonicStyle {
+    /// Words on one line, separated by spaces
+    #[default]
+    Plain,
+    /// One word per line: "1. abandon"
+    Numbered,
+    /// Rows of MNEMONIC_GROUP_SIZE numbered words
+    Grouped,
+}
+
+/// Words per row in `MnemonicStyle::Grouped`
+pub const MNEMONIC_GROUP_SIZE: usize = 4;
+
+/// Lay out a (normalized) mnemonic for display; the words and their order
+/// are unchanged
+pub(crate) fn format_mnemonic_words(mnemonic: &Secret, style: MnemonicStyle) -> AppResult<Secret> {
+    use std::fmt::Write;
+
+    check_length("Mnemonic", mnemonic.expose(), MAX_MNEMONIC_LEN)?;
+    let normalized = Secret::new(normalize_mnemonic(mnemonic.expose()));
+    validate_mnemonic_length(normalized.expose())?;
+
+    let words: Vec<&str> = normalized.expose().split(' ').collect();
+    // Sized up front so the buffer never reallocates (and leaves stale copies)
+    let mut formatted = String::with_capacity(normalized.expose().len() + words.len() * 16);
+
+    match style {
+        MnemonicStyle::Plain => formatted.push_str(normalized.expose()),
+        MnemonicStyle::Numbered => {
+            for (i, word) in words.iter().enumerate() {
+                if i > 0 {
+                    formatted.push('\n');
+                }
+                let _ = write!(formatted, "{}. {}", i + 1, word);
+            }
+        }
+        MnemonicStyle::Grouped => {
+            for (row, group) in words.chunks(MNEMONIC_GROUP_SIZE).enumerate() {
+                if row > 0 {
+                    formatted.push('\n');
+                }
+                for (column, word) in group.iter().enumerate() {
+                    if column > 0 {
+                        formatted.push_str("  ");
+                    }
+                    let _ = write!(formatted, "{:>2}. {:<8}", row * MNEMONIC_GROUP_SIZE + column + 1, word);
                 }
+                formatted.truncate(formatted.trim_end().len());
             }
         }
     }
 
-    // T094: Return None (no duplicate found) - let CLI handle actual detection
-    Ok(None)
+    Ok(Secret::new(formatted))
+}
+
+/// A formatted mnemonic returned for display. `Secret` itself can't be
+/// serialized, so this is the one place the phrase is written out; the
+/// buffer is still zeroized when the response is dropped.
+#[derive(Debug)]
+pub struct DisplayedMnemonic(Secret);
+
+impl Serialize for DisplayedMnemonic {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.expose())
+    }
+}
+
+/// Format a mnemonic for the backup screen (plain, numbered or grouped)
+#[tauri::command]
+pub async fn format_mnemonic(mnemonic: Secret, style: MnemonicStyle) -> Result<DisplayedMnemonic, String> {
+    format_mnemonic_words(&mnemonic, style).map(DisplayedMnemonic).map_err(String::from)
+}
+
+/// Reject wallet IDs that could escape `{usb_path}` when joined as a path
+/// Accepts the two known formats: UUID v4 (Go wallet service) and 64-char hex (Wallet::validate_id),
+/// neither of which can contain separators, `..`, or drive prefixes
+pub(crate) fn sanitize_wallet_id(wallet_id: &str) -> AppResult<()> {
+    let is_uuid = wallet_id.len() == 36
+        && wallet_id.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        });
+
+    if is_uuid || Wallet::validate_id(wallet_id) {
+        Ok(())
+    } else {
+        Err(AppError::new(
+            ErrorCode::InvalidWalletId,
+            "Invalid wallet ID format",
+        ))
+    }
+}
+
+/// Check that `{usb_path}/{wallet_id}/wallet.json` exists before unlocking
+/// Gives a deterministic WalletNotFound instead of relying on the FFI error text
+pub(crate) fn ensure_wallet_exists(usb_path: &str, wallet_id: &str) -> AppResult<()> {
+    use crate::fs_utils::long_path;
+    use std::path::Path;
+
+    let wallet_dir = Path::new(usb_path).join(wallet_id);
+
+    if !long_path(&wallet_dir).is_dir() {
+        return Err(AppError::new(
+            ErrorCode::WalletNotFound,
+            "Wallet not found on this USB drive. Please check that the correct drive is inserted.",
+        ));
+    }
+
+    if !long_path(&wallet_dir.join("wallet.json")).is_file() {
+        return Err(AppError::new(
+            ErrorCode::WalletNotFound,
+            "Wallet data is incomplete (wallet.json is missing) on this USB drive.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// How long a failed import can be retried and recognized as the same import
+pub const IMPORT_RETRY_WINDOW: Duration = Duration::from_secs(600);
+
+/// An import sent to the library this session
+struct ImportAttempt {
+    /// Keccak-256 of the normalized mnemonic and passphrase; the phrase itself
+    /// is never kept
+    fingerprint: [u8; 32],
+    usb_path: String,
+    wallet_name: String,
+    started_at: chrono::DateTime<chrono::Utc>,
+    at: Instant,
+}
+
+/// Imports attempted this session, so a retry of an import whose response
+/// was lost isn't reported as an accidental duplicate. In memory only.
+#[derive(Default)]
+pub struct RecentImports(Mutex<Vec<ImportAttempt>>);
+
+impl RecentImports {
+    fn record(&self, attempt: ImportAttempt) {
+        let mut attempts = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        attempts.retain(|a| a.at.elapsed() < IMPORT_RETRY_WINDOW && a.fingerprint != attempt.fingerprint);
+        attempts.push(attempt);
+    }
+
+    fn forget(&self, fingerprint: &[u8; 32]) {
+        let mut attempts = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        attempts.retain(|a| &a.fingerprint != fingerprint);
+    }
+
+    /// (wallet name, start time) of a recent import of the same phrase to the same USB
+    fn find(&self, fingerprint: &[u8; 32], usb_path: &str) -> Option<(String, chrono::DateTime<chrono::Utc>)> {
+        let attempts = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        attempts
+            .iter()
+            .rev()
+            .find(|a| &a.fingerprint == fingerprint && a.usb_path == usb_path && a.at.elapsed() < IMPORT_RETRY_WINDOW)
+            .map(|a| (a.wallet_name.clone(), a.started_at))
+    }
+}
+
+/// Fingerprint of a normalized mnemonic and passphrase for `RecentImports`
+fn import_fingerprint(mnemonic: &str, passphrase: Option<&str>) -> [u8; 32] {
+    use sha3::{Digest, Keccak256};
+
+    let mut hasher = Keccak256::new();
+    hasher.update(mnemonic.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(passphrase.unwrap_or("").as_bytes());
+    hasher.finalize().into()
+}
+
+/// Wallet named `wallet_name` created on the USB at or after `since`: what an
+/// earlier attempt of this import left behind
+fn find_imported_wallet(usb_path: &str, wallet_name: &str, since: chrono::DateTime<chrono::Utc>) -> Option<Wallet> {
+    use crate::fs_utils::long_path;
+    use std::fs;
+    use std::path::Path;
+
+    // Go writes createdAt with second precision
+    let since = since - chrono::Duration::seconds(1);
+
+    fs::read_dir(long_path(Path::new(usb_path)))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let contents = fs::read_to_string(long_path(&entry.path().join("wallet.json"))).ok()?;
+            let meta = WalletFileMetadata::parse(&contents)?;
+            let created_at = meta.created_at?;
+            let created = chrono::DateTime::parse_from_rfc3339(&created_at).ok()?;
+
+            if meta.name.as_deref() != Some(wallet_name) || created < since {
+                return None;
+            }
+
+            Some(Wallet {
+                id: meta.id?,
+                name: wallet_name.to_string(),
+                created_at: created_at.clone(),
+                updated_at: created_at,
+                has_passphrase: meta.uses_passphrase,
+                address_count: stored_address_count(&entry.path()),
+            })
+        })
+        .next()
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportWalletInput {
+    pub mnemonic: Secret,
+    pub password: Secret,
+    pub usb_path: String,
+    pub passphrase: Option<Secret>,
+    pub name: Option<String>,
 }
 
 /// Import/restore wallet from mnemonic (T067, T095-T098)
 /// Requirements: FR-006 (BIP39 import), FR-029 (validation), FR-031 (duplicate detection)
 #[tauri::command]
 pub async fn import_wallet(
-    queue: State<'_, LazyWalletQueue>, // T032.2: Accept LazyWalletQueue from Tauri state
-    mut mnemonic: String, // T037: Make mutable for zeroize
-    mut password: String, // T037: Make mutable for zeroize
-    usb_path: String,
-    mut passphrase: Option<String>, // T037: Make mutable for zeroize
-    name: Option<String>,
+    backend: State<'_, WalletBackend>, // T032.2: Accept WalletBackend from Tauri state
+    activity: State<'_, ActivityTracker>,
+    read_cache: State<'_, ReadCache>,
+    recent_imports: State<'_, RecentImports>,
+    cache: State<'_, AddressCache>,
+    mut input: ImportWalletInput,
 ) -> Result<WalletImportResponse, String> {
+    activity.touch();
+
+    input.usb_path = normalize_usb_path(&input.usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    let response = import_into(queue, recent_imports.inner(), cache.inner(), &input)
+        .await
+        .map_err(String::from)?;
+
+    read_cache.wallets.invalidate_all();
+    Ok(response)
+}
+
+/// Body of `import_wallet`; `input.usb_path` must already be normalized.
+///
+/// A duplicate of an import attempted in the last `IMPORT_RETRY_WINDOW` is a
+/// retry whose first response was lost: it succeeds with the existing
+/// wallet and `already_imported` set instead of reporting a duplicate. Any
+/// addresses cached for that wallet are dropped.
+pub(crate) async fn import_into(
+    queue: &LazyWalletQueue,
+    recent_imports: &RecentImports,
+    cache: &AddressCache,
+    input: &ImportWalletInput,
+) -> AppResult<WalletImportResponse> {
+    let mnemonic = &input.mnemonic;
+    let password = &input.password;
+    let usb_path = input.usb_path.as_str();
+    let passphrase = input.passphrase.as_ref();
+    let name = input.name.clone();
+
     // T038: Start performance timer
     let start = Instant::now();
 
+    check_wallet_input_lengths(
+        password.expose(),
+        passphrase.map(Secret::expose),
+        Some(mnemonic.expose()),
+        name.as_deref(),
+        usb_path,
+    )?;
+    preflight_usb_write(usb_path, OperationKind::ImportWallet).await?;
+
     // Validate password
-    validate_password(&password).map_err(String::from)?;
+    validate_password(password.expose())?;
 
     // Normalize mnemonic (FR-030)
-    let normalized_mnemonic = normalize_mnemonic(&mnemonic);
+    let normalized_mnemonic = Secret::new(normalize_mnemonic(mnemonic.expose()));
 
     // Validate mnemonic length
-    validate_mnemonic_length(&normalized_mnemonic).map_err(String::from)?;
+    validate_mnemonic_length(normalized_mnemonic.expose())?;
 
     // Validate wallet name if provided
     if let Some(ref n) = name {
@@ -337,28 +903,44 @@ pub async fn import_wallet(
             return Err(AppError::new(
                 ErrorCode::InvalidWalletId,
                 "Wallet name must be 1-50 characters",
-            )
-            .into());
+            ));
         }
     }
 
-    // T096: Check for duplicate wallet before FFI invocation
     let has_passphrase = passphrase.is_some();
-    let passphrase_ref = passphrase.as_deref();
+    let passphrase_ref = passphrase.map(Secret::expose);
+    let fingerprint = import_fingerprint(normalized_mnemonic.expose(), passphrase_ref);
+    let earlier_attempt = recent_imports.find(&fingerprint, usb_path);
+
+    // A retry of an import that did land on the USB
+    let already_imported = |start: Instant| {
+        let (wallet_name, started_at) = earlier_attempt.as_ref()?;
+        let wallet = find_imported_wallet(usb_path, wallet_name, *started_at)?;
+        tracing::info!("Import retry found wallet {} from the earlier attempt", wallet.id);
+        // Whatever was cached for the ID predates this import
+        cache.invalidate_wallet(&wallet.id);
+        Some(WalletImportResponse {
+            wallet,
+            is_duplicate: false,
+            already_imported: true,
+            duration_ms: Some(duration_ms(start.elapsed())),
+        })
+    };
+
+    // Don't import twice when the earlier attempt's wallet is already there
+    if let Some(response) = already_imported(start) {
+        return Ok(response);
+    }
+
+    // T096: Check for duplicate wallet before FFI invocation
     if let Some((dup_id, dup_name, dup_created)) = check_duplicate_wallet(
-        &normalized_mnemonic,
+        queue,
+        normalized_mnemonic.expose(),
         passphrase_ref,
-        &usb_path,
-    ).await.map_err(String::from)? {
+        usb_path,
+    ).await? {
         tracing::warn!("Duplicate wallet detected: {} ({})", dup_name, dup_id);
 
-        // T037: Zero sensitive data before returning
-        mnemonic.zeroize();
-        password.zeroize();
-        if let Some(ref mut pp) = passphrase {
-            pp.zeroize();
-        }
-
         // Return early with duplicate flag
         let wallet = Wallet {
             id: dup_id.clone(),
@@ -372,66 +954,51 @@ pub async fn import_wallet(
         return Ok(WalletImportResponse {
             wallet,
             is_duplicate: true,
+            already_imported: false,
+            duration_ms: Some(duration_ms(start.elapsed())),
         });
     }
 
-    // Check if passphrase is provided (before moving it)
-    let has_passphrase = passphrase.is_some();
-
-    let wallet_name = name.clone().unwrap_or_else(|| {
-        format!("Imported Wallet {}", chrono::Local::now().format("%Y-%m-%d"))
-    });
+    // A retry keeps the name of the first attempt (the default name is dated)
+    let wallet_name = match &earlier_attempt {
+        Some((wallet_name, _)) if name.is_none() => wallet_name.clone(),
+        _ => name.clone().unwrap_or_else(|| {
+            format!("Imported Wallet {}", chrono::Local::now().format("%Y-%m-%d"))
+        }),
+    };
 
     // T032.2: Build JSON params for FFI call
-    let params = json!({
-        "walletName": wallet_name,
-        "mnemonic": normalized_mnemonic,
-        "password": password,
-        "usbPath": usb_path,
+    let params_json = ffi_params(
+        json!({
+            "walletName": wallet_name,
+            "usbPath": usb_path,
+        }),
+        &[("mnemonic", normalized_mnemonic.expose()), ("password", password.expose())],
+    )?;
+
+    recent_imports.record(ImportAttempt {
+        fingerprint,
+        usb_path: usb_path.to_string(),
+        wallet_name: wallet_name.clone(),
+        started_at: earlier_attempt.as_ref().map_or_else(chrono::Utc::now, |(_, started_at)| *started_at),
+        at: Instant::now(),
     });
 
-    let params_json = serde_json::to_string(&params)
-        .map_err(|e| format!("Failed to serialize params: {}", e))?;
-
     // T032.2: Call FFI queue
-    let ffi_response = queue
-        .import_wallet(params_json)
-        .await
-        .map_err(|e| {
-            // Check for duplicate wallet error (FR-031)
-            if e.contains("already exists") || e.contains("DUPLICATE") || e.contains("WALLET_ALREADY_EXISTS") {
-                AppError::new(
-                    ErrorCode::WalletAlreadyExists,
-                    "Wallet with this mnemonic already exists on USB",
-                )
-            } else if e.contains("INVALID_MNEMONIC") {
-                AppError::new(
-                    ErrorCode::InvalidMnemonicLength,
-                    "Invalid BIP39 mnemonic phrase",
-                )
-            } else if e.contains("USB_NOT_FOUND") || e.contains("STORAGE_ERROR") {
-                AppError::new(
-                    ErrorCode::UsbNotFound,
-                    "USB device not found",
-                )
-            } else {
-                AppError::with_details(
-                    ErrorCode::CliExecutionFailed,
-                    "Failed to import wallet",
-                    e,
-                )
-            }
-        })?;
+    let result = queue.import_wallet(params_json).await;
 
-    // T037: Zero sensitive data from memory
-    mnemonic.zeroize();
-    password.zeroize();
-    if let Some(ref mut pp) = passphrase {
-        pp.zeroize();
-    }
+    let ffi_response = match result.map_err(import_error) {
+        Ok(response) => response,
+        Err(e) if e.code == ErrorCode::WalletAlreadyExists => {
+            return already_imported(start).ok_or(e);
+        }
+        Err(e) => return Err(e),
+    };
+
+    // The caller got this response, so importing the phrase again is a real duplicate
+    recent_imports.forget(&fingerprint);
 
     // T098: Parse FFI JSON response and extract wallet metadata
-    tracing::info!("Wallet import FFI response: {:?}", ffi_response);
 
     // Extract wallet metadata from FFI response
     let wallet_id = ffi_response
@@ -459,13 +1026,15 @@ pub async fn import_wallet(
         address_count: 0, // Will be populated when addresses are loaded
     };
 
+    // T038: Log performance metrics
+    let elapsed = start.elapsed();
     let response = WalletImportResponse {
         wallet,
         is_duplicate: false, // If we got here, it's not a duplicate
+        already_imported: false,
+        duration_ms: Some(duration_ms(elapsed)),
     };
 
-    // T038: Log performance metrics
-    let elapsed = start.elapsed();
     tracing::info!(
         "Wallet imported successfully: {} (took {:?})",
         wallet_name,
@@ -475,92 +1044,566 @@ pub async fn import_wallet(
     Ok(response)
 }
 
-/// Address cache state (wallet_id -> addresses)
-pub struct AddressCache(pub Mutex<HashMap<String, Vec<Address>>>);
-
-/// Load wallet addresses (T052)
-/// Caches results in Tauri State to avoid re-loading (T046)
-#[tauri::command]
-pub async fn load_addresses(
-    queue: State<'_, LazyWalletQueue>, // T033: Accept LazyWalletQueue from Tauri state
-    wallet_id: String,
-    mut password: String, // T037: Make mutable for zeroize
-    usb_path: String,
-    cache: State<'_, AddressCache>,
-) -> Result<AddressListResponse, String> {
-    // T038: Start performance timer
-    let start = Instant::now();
-
-    // Check cache first
-    {
-        let cache_lock = cache.0.lock().unwrap();
-        if let Some(cached_addresses) = cache_lock.get(&wallet_id) {
-            tracing::info!("Returning cached addresses for wallet {}", wallet_id);
+/// How long derived addresses stay cached
+pub const ADDRESS_CACHE_TTL: Duration = Duration::from_secs(30 * 60);
 
-            // T037: Zero password even on cache hit
-            password.zeroize();
+/// Default number of (wallet, address type) entries kept in the AddressCache
+pub const DEFAULT_ADDRESS_CACHE_CAPACITY: usize = 16;
 
-            return Ok(AddressListResponse::new(
-                wallet_id.clone(),
-                cached_addresses.clone(),
-            ));
+/// AddressCache capacity, overridable via `ARCSIGN_ADDRESS_CACHE_SIZE`
+pub fn address_cache_capacity_from_env() -> usize {
+    match env_number::<usize>("ARCSIGN_ADDRESS_CACHE_SIZE") {
+        Some(0) => {
+            tracing::warn!("Ignoring ARCSIGN_ADDRESS_CACHE_SIZE=0");
+            DEFAULT_ADDRESS_CACHE_CAPACITY
         }
+        Some(capacity) => capacity,
+        None => DEFAULT_ADDRESS_CACHE_CAPACITY,
     }
+}
 
-    // STEP 1: Unlock wallet with password (verify password and decrypt wallet)
-    tracing::info!("Unlocking wallet {} with password", wallet_id);
-    let unlock_params = json!({
-        "walletId": wallet_id,
-        "password": password,
-        "usbPath": usb_path,
-    });
+/// Address cache state ((wallet_id, address type) -> addresses), bounded in
+/// both age and size so loading many wallets doesn't grow it without limit
+pub struct AddressCache(pub TtlCache<Vec<Address>, (String, AddressType)>);
 
-    let unlock_params_json = serde_json::to_string(&unlock_params)
-        .map_err(|e| format!("Failed to serialize unlock params: {}", e))?;
+impl AddressCache {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(TtlCache::new("addresses", ADDRESS_CACHE_TTL).with_capacity(capacity))
+    }
 
-    let unlock_response = queue
-        .unlock_wallet(unlock_params_json)
-        .await
-        .map_err(|e| {
-            if e.contains("INVALID_PASSWORD") || e.contains("DECRYPTION_ERROR") {
-                AppError::new(
-                    ErrorCode::PasswordTooWeak,
-                    "Invalid password",
-                )
-            } else if e.contains("WALLET_NOT_FOUND") {
-                AppError::new(
-                    ErrorCode::WalletNotFound,
-                    "Wallet not found on USB",
-                )
-            } else if e.contains("USB_NOT_FOUND") || e.contains("STORAGE_ERROR") {
-                AppError::new(
-                    ErrorCode::UsbNotFound,
-                    "USB device not found",
-                )
-            } else {
-                AppError::with_details(
-                    ErrorCode::CliExecutionFailed,
-                    "Failed to unlock wallet",
-                    e,
-                )
+    /// Drop every address type cached for one wallet
+    pub fn invalidate_wallet(&self, wallet_id: &str) {
+        self.0.invalidate_matching(|(id, _)| id == wallet_id);
+    }
+
+    /// Counts, sizes and ages of the cached entries, for diagnostics. An
+    /// entry is orphaned when its wallet is no longer unlocked: nothing can
+    /// read it until the wallet is unlocked again.
+    pub fn debug_report(&self, unlocked: &UnlockedWallets) -> AddressCacheDebug {
+        let unlocked_ids = unlocked.wallet_ids();
+
+        let mut entries: Vec<AddressCacheEntryDebug> = self
+            .0
+            .inspect(|(wallet_id, address_type), addresses| (
+                wallet_id.clone(),
+                *address_type,
+                addresses.len(),
+                addresses.iter().map(approx_address_bytes).sum::<usize>(),
+            ))
+            .into_iter()
+            .map(|((wallet_id, address_type, address_count, approx_bytes), age, expired)| {
+                AddressCacheEntryDebug {
+                    orphaned: !unlocked_ids.contains(&wallet_id),
+                    wallet_id,
+                    address_type,
+                    address_count,
+                    approx_bytes,
+                    age_secs: age.as_secs(),
+                    expired,
+                }
+            })
+            .collect();
+        entries.sort_by(|a, b| b.age_secs.cmp(&a.age_secs).then_with(|| a.wallet_id.cmp(&b.wallet_id)));
+
+        let mut wallet_ids: Vec<&str> = entries.iter().map(|e| e.wallet_id.as_str()).collect();
+        wallet_ids.sort_unstable();
+        wallet_ids.dedup();
+
+        AddressCacheDebug {
+            wallet_count: wallet_ids.len(),
+            entry_count: entries.len(),
+            capacity: self.0.capacity(),
+            approx_bytes: entries.iter().map(|e| e.approx_bytes).sum(),
+            orphaned_entries: entries.iter().filter(|e| e.orphaned).count(),
+            entries,
+        }
+    }
+}
+
+/// Approximate memory held by one cached address: the struct plus its strings
+fn approx_address_bytes(address: &Address) -> usize {
+    std::mem::size_of::<Address>()
+        + address.wallet_id.capacity()
+        + address.symbol.capacity()
+        + address.name.capacity()
+        + address.derivation_path.capacity()
+        + address.address.capacity()
+}
+
+/// One AddressCache entry as reported by `cache_debug`; never includes the
+/// addresses themselves
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct AddressCacheEntryDebug {
+    pub wallet_id: String,
+    pub address_type: AddressType,
+    pub address_count: usize,
+    pub approx_bytes: usize,
+    /// Seconds since the entry was stored
+    pub age_secs: u64,
+    /// Past the TTL; dropped on the next lookup or insert
+    pub expired: bool,
+    /// The wallet is not unlocked
+    pub orphaned: bool,
+}
+
+/// Memory usage of the AddressCache, oldest entries first
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct AddressCacheDebug {
+    pub wallet_count: usize,
+    pub entry_count: usize,
+    pub capacity: Option<usize>,
+    pub approx_bytes: usize,
+    pub orphaned_entries: usize,
+    pub entries: Vec<AddressCacheEntryDebug>,
+}
+
+impl Default for AddressCache {
+    fn default() -> Self {
+        Self::with_capacity(address_cache_capacity_from_env())
+    }
+}
+
+/// Wallets whose password UnlockWallet verified this session, with a
+/// fingerprint of that password, so repeat loads skip the KDF pass.
+///
+/// A different password still goes through UnlockWallet. Cleared on lock
+/// (`clear_sensitive_memory`, or `lock_wallet` for one wallet) and whenever
+/// a wallet's password changes.
+#[derive(Default)]
+pub struct UnlockedWallets(Mutex<UnlockState>);
+
+#[derive(Default)]
+struct UnlockState {
+    fingerprints: HashMap<String, [u8; 32]>,
+    /// Bumped whenever a wallet is locked, see `lock_generation`
+    generation: u64,
+}
+
+/// HMAC-SHA256 of the concatenated `message` parts
+fn hmac_sha256(key: &[u8], message: &[&[u8]]) -> [u8; 32] {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in message {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+impl UnlockedWallets {
+    /// HMAC of the password under a random key drawn once per process, so a
+    /// fingerprint found in memory can't be checked against guesses offline
+    fn password_fingerprint(wallet_id: &str, password: &Secret) -> [u8; 32] {
+        static KEY: std::sync::OnceLock<[u8; 32]> = std::sync::OnceLock::new();
+        let key = KEY.get_or_init(|| {
+            let mut key = [0u8; 32];
+            getrandom::getrandom(&mut key).expect("OS random number generator unavailable");
+            key
+        });
+
+        hmac_sha256(key, &[wallet_id.as_bytes(), &[0u8], password.expose().as_bytes()])
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, UnlockState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether `password` already unlocked `wallet_id` this session
+    pub fn is_unlocked(&self, wallet_id: &str, password: &Secret) -> bool {
+        self.state().fingerprints.get(wallet_id) == Some(&Self::password_fingerprint(wallet_id, password))
+    }
+
+    pub fn forget(&self, wallet_id: &str) {
+        let mut state = self.state();
+        state.fingerprints.remove(wallet_id);
+        state.generation += 1;
+    }
+
+    /// Ids of the wallets currently unlocked, sorted
+    pub fn wallet_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.state().fingerprints.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Lock every wallet
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.fingerprints.clear();
+        state.generation += 1;
+    }
+
+    /// Counter bumped by every `forget` and `clear`.
+    ///
+    /// A load takes it before unlocking; if it changed by the time the
+    /// addresses are derived, a lock ran in between and the load must not
+    /// mark the wallet unlocked or cache what it derived.
+    pub fn lock_generation(&self) -> u64 {
+        self.state().generation
+    }
+
+    /// Record that `password` unlocked `wallet_id`, unless a wallet was
+    /// locked since `generation`. Returns whether the wallet was marked.
+    pub fn mark_unlocked(&self, wallet_id: &str, password: &Secret, generation: u64) -> bool {
+        let mut state = self.state();
+        if state.generation != generation {
+            return false;
+        }
+        state
+            .fingerprints
+            .insert(wallet_id.to_string(), Self::password_fingerprint(wallet_id, password));
+        true
+    }
+
+    /// Run `f` unless a wallet was locked since `generation`, holding the
+    /// unlock state meanwhile so no lock can land between the check and `f`.
+    /// Returns whether `f` ran.
+    pub fn run_unless_locked_since(&self, generation: u64, f: impl FnOnce()) -> bool {
+        let state = self.state();
+        if state.generation != generation {
+            return false;
+        }
+        f();
+        true
+    }
+}
+
+/// A wallet and when its addresses were last loaded
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RecentWallet {
+    pub wallet_id: String,
+    /// RFC 3339 timestamp of the last access
+    pub last_accessed: String,
+}
+
+/// Wallet access history, oldest first.
+///
+/// In memory only: nothing about wallet usage is written to the USB, and the
+/// history starts empty on every launch.
+#[derive(Default)]
+pub struct RecentWallets(pub Mutex<Vec<RecentWallet>>);
+
+impl RecentWallets {
+    /// Mark `wallet_id` as accessed now
+    pub fn record(&self, wallet_id: &str) {
+        let mut recent = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        recent.retain(|entry| entry.wallet_id != wallet_id);
+        recent.push(RecentWallet {
+            wallet_id: wallet_id.to_string(),
+            last_accessed: chrono::Utc::now().to_rfc3339(),
+        });
+    }
+
+    /// Up to `limit` wallets, most recently accessed first
+    pub fn most_recent(&self, limit: usize) -> Vec<RecentWallet> {
+        let recent = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        recent.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+/// List recently accessed wallets, most recent first (this session only)
+#[tauri::command]
+pub async fn recent_wallets(
+    recent: State<'_, RecentWallets>,
+    limit: usize,
+) -> Result<Vec<RecentWallet>, String> {
+    Ok(recent.most_recent(limit))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadAddressesInput {
+    pub wallet_id: String,
+    pub password: Secret,
+    pub usb_path: String,
+    pub address_type: Option<AddressType>,
+    pub apply_ranking: Option<bool>,
+}
+
+/// Load wallet addresses (T052)
+/// Caches results in Tauri State to avoid re-loading (T046)
+#[tauri::command]
+pub async fn load_addresses(
+    backend: State<'_, WalletBackend>, // T033: Accept WalletBackend from Tauri state
+    activity: State<'_, ActivityTracker>,
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+    recent: State<'_, RecentWallets>,
+    input: LoadAddressesInput,
+) -> Result<AddressListResponse, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let wallet_id = input.wallet_id;
+    let queue = backend.queue().map_err(String::from)?;
+
+    let mut response = load_address_list(
+        queue,
+        cache.inner(),
+        unlocked.inner(),
+        &wallet_id,
+        &input.password,
+        &usb_path,
+        input.address_type.unwrap_or_default(),
+    )
+    .await
+    .map_err(String::from)?;
+
+    // Presentation only: the cache keeps the library's order
+    if input.apply_ranking.unwrap_or(false) {
+        match ranking::load_ranking(&usb_path, &wallet_id) {
+            Ok(preferred) => ranking::apply_ranking(&mut response.addresses, &preferred),
+            Err(e) => tracing::warn!("Ignoring address ranking of {}: {}", wallet_id, e.message),
+        }
+    }
+
+    recent.record(&wallet_id);
+    Ok(response)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadAddressesGroupedInput {
+    pub wallet_id: String,
+    pub password: Secret,
+    pub usb_path: String,
+}
+
+/// Load a wallet's addresses grouped by category (Tauri command)
+/// Groups follow `Category::ALL`; empty categories are still returned
+#[tauri::command]
+pub async fn load_addresses_grouped(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    cache: State<'_, AddressCache>,
+    unlocked: State<'_, UnlockedWallets>,
+    recent: State<'_, RecentWallets>,
+    input: LoadAddressesGroupedInput,
+) -> Result<GroupedAddressResponse, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&input.usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    let response = load_address_list(
+        queue,
+        cache.inner(),
+        unlocked.inner(),
+        &input.wallet_id,
+        &input.password,
+        &usb_path,
+        AddressType::default(),
+    )
+    .await
+    .map_err(String::from)?;
+
+    recent.record(&input.wallet_id);
+    Ok(GroupedAddressResponse::from_list(response))
+}
+
+/// `load_wallet_addresses` as a timed `load_addresses` response
+pub(crate) async fn load_address_list(
+    queue: &LazyWalletQueue,
+    cache: &AddressCache,
+    unlocked: &UnlockedWallets,
+    wallet_id: &str,
+    password: &Secret,
+    usb_path: &str,
+    address_type: AddressType,
+) -> AppResult<AddressListResponse> {
+    let start = Instant::now();
+
+    let loaded = load_wallet_addresses(queue, cache, unlocked, wallet_id, password, usb_path, address_type).await?;
+
+    let mut response = AddressListResponse::new(wallet_id.to_string(), loaded.addresses).with_failed(loaded.failed);
+    response.duration_ms = Some(duration_ms(start.elapsed()));
+    Ok(response)
+}
+
+/// Addresses derived by `load_wallet_addresses`
+#[derive(Debug)]
+pub(crate) struct LoadedAddresses {
+    pub addresses: Vec<Address>,
+    /// Entries the library returned without an address; never cached
+    pub failed: Vec<FailedAddress>,
+}
+
+/// Split a GenerateAddresses `addresses` array into derived addresses and
+/// failures. Ranks follow the library's order, failures included.
+fn parse_generated_addresses(wallet_id: &str, addresses_array: &[serde_json::Value]) -> LoadedAddresses {
+    let mut addresses = Vec::with_capacity(addresses_array.len());
+    let mut failed = Vec::new();
+
+    for (idx, addr_data) in addresses_array.iter().enumerate() {
+        let blockchain = addr_data
+            .get("blockchain")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+
+        let address = match addr_data.get("address").and_then(|v| v.as_str()) {
+            Some(address) if !address.is_empty() => address.to_string(),
+            _ => {
+                let error = addr_data
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Address missing from library response");
+                failed.push(FailedAddress {
+                    blockchain: blockchain.to_string(),
+                    error: error.to_string(),
+                });
+                continue;
             }
+        };
+
+        let derivation_path = addr_data
+            .get("derivationPath")
+            .and_then(|v| v.as_str())
+            .unwrap_or("m/44'/0'/0'/0/0")
+            .to_string();
+
+        // Older libraries report only the blockchain name
+        let symbol = addr_data
+            .get("symbol")
+            .and_then(|v| v.as_str())
+            .unwrap_or(blockchain)
+            .to_uppercase();
+
+        // Coin type level of m/purpose'/coin_type'/..., else the reported one
+        let coin_type = parse_path_segments(&derivation_path)
+            .and_then(|segments| segments.get(1).map(|segment| segment.index))
+            .or_else(|| {
+                addr_data
+                    .get("coinType")
+                    .and_then(|v| v.as_u64())
+                    .and_then(|v| u32::try_from(v).ok())
+            })
+            .unwrap_or(0);
+
+        // The library's category only for chains the table doesn't know
+        let category = Category::of_symbol(&symbol)
+            .or_else(|| addr_data.get("category").and_then(|v| v.as_str()).map(parse_category))
+            .unwrap_or(Category::Specialized);
+
+        addresses.push(Address::new(
+            wallet_id.to_string(),
+            (idx + 1) as u32, // rank
+            symbol,
+            blockchain.to_string(), // name
+            coin_type,
+            derivation_path,
+            address,
+            category,
+            KeyType::Secp256k1, // Default key type
+        ));
+    }
+
+    LoadedAddresses { addresses, failed }
+}
+
+/// Unlock a wallet and derive its addresses, using the AddressCache when possible.
+///
+/// Shared by `load_addresses` and the commands that reorganize its data
+/// (e.g. the derivation tree). Results with failed addresses are not cached,
+/// so the next call retries the derivation.
+pub(crate) async fn load_wallet_addresses(
+    queue: &LazyWalletQueue,
+    cache: &AddressCache,
+    unlocked: &UnlockedWallets,
+    wallet_id: &str,
+    password: &Secret,
+    usb_path: &str,
+    address_type: AddressType,
+) -> AppResult<LoadedAddresses> {
+    // T038: Start performance timer
+    let start = Instant::now();
+
+    // wallet_id is joined into filesystem paths below
+    sanitize_wallet_id(wallet_id)?;
+    check_length("Password", password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+
+    let cache_key = (wallet_id.to_string(), address_type);
+    // Rejects address types the library can't derive, before unlocking
+    let params = GenerateAddressesRequest::all_chains(wallet_id, usb_path, address_type).to_params()?;
+
+    // Check cache first, but only for the password that unlocked the wallet:
+    // any other password goes through UnlockWallet like an uncached load
+    if unlocked.is_unlocked(wallet_id, password) {
+        if let Some(cached_addresses) = cache.0.get(&cache_key) {
+            tracing::info!("Returning cached addresses for wallet {}", wallet_id);
+            return Ok(LoadedAddresses {
+                addresses: cached_addresses,
+                failed: Vec::new(),
+            });
+        }
+    }
+
+    // Fail fast if the wallet isn't on this USB (avoids an FFI round-trip)
+    ensure_wallet_exists(usb_path, wallet_id)?;
+
+    // A lock while the library works must win over this load (see below)
+    let lock_generation = unlocked.lock_generation();
+
+    // STEP 1: Unlock wallet with password (verify password and decrypt wallet),
+    // unless this password already unlocked it this session
+    if unlocked.is_unlocked(wallet_id, password) {
+        tracing::info!("Wallet {} already unlocked; skipping unlock", wallet_id);
+    } else {
+        tracing::info!("Unlocking wallet {} with password", wallet_id);
+        let unlock_params = json!({
+            "walletId": wallet_id,
+            "usbPath": usb_path,
+        });
+
+        let unlock_params_json = ffi_params(unlock_params, &[("password", password.expose())]).map_err(|e| {
+            AppError::new(
+                ErrorCode::SerializationError,
+                format!("Failed to serialize unlock params: {}", e),
+            )
         })?;
 
-    tracing::info!("Wallet unlocked successfully: {:?}", unlock_response);
+        queue
+            .unlock_wallet(unlock_params_json)
+            .await
+            .map_err(|e| {
+                if e.contains("INVALID_PASSWORD") || e.contains("DECRYPTION_ERROR") {
+                    AppError::new(
+                        ErrorCode::PasswordTooWeak,
+                        "Invalid password",
+                    )
+                } else if e.contains("WALLET_NOT_FOUND") {
+                    AppError::new(
+                        ErrorCode::WalletNotFound,
+                        "Wallet not found on USB",
+                    )
+                } else if e.contains("USB_NOT_FOUND") || e.contains("STORAGE_ERROR") {
+                    AppError::new(
+                        ErrorCode::UsbNotFound,
+                        "USB device not found",
+                    )
+                } else {
+                    AppError::with_details(
+                        ErrorCode::CliExecutionFailed,
+                        "Failed to unlock wallet",
+                        e,
+                    )
+                }
+            })?;
+
+        tracing::info!("Wallet unlocked successfully: {}", wallet_id);
 
-    // T037: Zero sensitive data from memory immediately after unlock
-    password.zeroize();
+        unlocked.mark_unlocked(wallet_id, password, lock_generation);
+    }
 
     // STEP 2: Generate addresses (wallet is now unlocked in memory)
     tracing::info!("Generating addresses for wallet {}", wallet_id);
-    let params = json!({
-        "walletId": wallet_id,
-        "usbPath": usb_path, // Pass USB path so Go can load wallet metadata
-        "blockchains": [], // Empty array means generate all supported blockchains
-    });
-
-    let params_json = serde_json::to_string(&params)
-        .map_err(|e| format!("Failed to serialize params: {}", e))?;
+    let params_json = serde_json::to_string(&params).map_err(|e| {
+        AppError::new(
+            ErrorCode::SerializationError,
+            format!("Failed to serialize params: {}", e),
+        )
+    })?;
 
     // T033: Call FFI queue (generate_addresses)
     let ffi_response = queue
@@ -568,6 +1611,8 @@ pub async fn load_addresses(
         .await
         .map_err(|e| {
             if e.contains("WALLET_NOT_FOUND") || e.contains("WALLET_NOT_UNLOCKED") {
+                // The library no longer considers it unlocked; verify again next time
+                unlocked.forget(wallet_id);
                 AppError::new(
                     ErrorCode::WalletNotFound,
                     "Wallet not found or not unlocked",
@@ -589,7 +1634,7 @@ pub async fn load_addresses(
     tracing::info!("Generate addresses FFI response: {:?}", ffi_response);
 
     // T033: Parse FFI JSON response
-    // Expected format: {"addresses": [{"blockchain": "...", "address": "...", "derivationPath": "..."}], "generatedAt": "..."}
+    // Expected format: {"addresses": [{"blockchain": "...", "symbol": "...", "address": "...", "derivationPath": "...", "coinType": 0, "category": "..."}], "generatedAt": "..."}
     let addresses_array = ffi_response
         .get("addresses")
         .and_then(|v| v.as_array())
@@ -599,67 +1644,103 @@ pub async fn load_addresses(
         ))?;
 
     // Convert FFI addresses to domain model
-    let addresses: Vec<Address> = addresses_array
-        .iter()
-        .enumerate()
-        .map(|(idx, addr_data)| {
-            let blockchain = addr_data
-                .get("blockchain")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown");
-
-            let address = addr_data
-                .get("address")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown")
-                .to_string();
-
-            let derivation_path = addr_data
-                .get("derivationPath")
-                .and_then(|v| v.as_str())
-                .unwrap_or("m/44'/0'/0'/0/0")
-                .to_string();
-
-            // TODO: Map blockchain names to proper symbols, coin_types, etc.
-            Address::new(
-                wallet_id.clone(),
-                (idx + 1) as u32, // rank
-                blockchain.to_uppercase(), // symbol
-                blockchain.to_string(), // name
-                0, // coin_type (TODO: derive from derivation path)
-                derivation_path,
-                address,
-                Category::Layer2, // Default category (Layer1 doesn't exist, using Layer2)
-                KeyType::Secp256k1, // Default key type
-            )
-        })
-        .collect();
-
-    // Cache the addresses
-    {
-        let mut cache_lock = cache.0.lock().unwrap();
-        cache_lock.insert(wallet_id.clone(), addresses.clone());
+    let loaded = parse_generated_addresses(wallet_id, addresses_array);
+
+    if loaded.failed.is_empty() {
+        // lock_single_wallet forgets the wallet before invalidating the cache,
+        // so either the lock comes later and clears this entry, or it came
+        // first and the entry is never written
+        let cached = unlocked.run_unless_locked_since(lock_generation, || {
+            cache.0.insert(cache_key, loaded.addresses.clone());
+        });
+        if !cached {
+            tracing::info!("Wallet {} was locked while loading; not caching", wallet_id);
+        }
+    } else {
+        tracing::warn!(
+            "{} of {} addresses failed to derive for wallet {}; not caching",
+            loaded.failed.len(),
+            addresses_array.len(),
+            wallet_id
+        );
     }
 
     // T038: Log performance metrics
     let elapsed = start.elapsed();
     tracing::info!(
-        "Loaded and cached {} addresses for wallet {} (took {:?})",
-        addresses.len(),
+        "Loaded {} addresses for wallet {} (took {:?})",
+        loaded.addresses.len(),
         wallet_id,
         elapsed
     );
 
-    Ok(AddressListResponse::new(wallet_id, addresses))
+    Ok(loaded)
 }
 
 /// List all wallets on USB
 /// Directly scans USB directory for wallet folders (CLI list command not yet implemented)
 #[tauri::command]
 pub async fn list_wallets(
-    queue: State<'_, LazyWalletQueue>, // T035: Accept LazyWalletQueue from Tauri state
+    backend: State<'_, WalletBackend>, // T035: Accept WalletBackend from Tauri state
+    read_cache: State<'_, ReadCache>,
+    usb_path: String,
+) -> Result<WalletListResponse, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    list_usb_wallets_cached(queue, &read_cache.wallets, &usb_path)
+        .await
+        .map_err(String::from)
+}
+
+/// List one page of the wallets on USB, sorted by name or creation time
+/// The FFI returns every wallet, so paging happens over the cached listing
+#[tauri::command]
+pub async fn list_wallets_page(
+    backend: State<'_, WalletBackend>,
+    read_cache: State<'_, ReadCache>,
     usb_path: String,
-) -> Result<Vec<Wallet>, String> {
+    offset: Option<usize>,
+    limit: Option<usize>,
+    sort_by: Option<WalletSortKey>,
+) -> Result<WalletPage, String> {
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    let listing = list_usb_wallets_cached(queue, &read_cache.wallets, &usb_path)
+        .await
+        .map_err(String::from)?;
+
+    let mut page = WalletPage::from_list(
+        listing.wallets,
+        sort_by.unwrap_or_default(),
+        offset.unwrap_or(0),
+        limit.unwrap_or(DEFAULT_WALLET_PAGE_SIZE),
+    );
+    page.duration_ms = listing.duration_ms;
+    Ok(page)
+}
+
+/// `list_usb_wallets`, served from `cache` while the listing is fresh
+pub(crate) async fn list_usb_wallets_cached(
+    queue: &LazyWalletQueue,
+    cache: &TtlCache<WalletListResponse>,
+    usb_path: &str,
+) -> AppResult<WalletListResponse> {
+    let start = Instant::now();
+
+    if let Some(mut response) = cache.get(usb_path) {
+        response.duration_ms = Some(duration_ms(start.elapsed()));
+        return Ok(response);
+    }
+
+    let response = list_usb_wallets(queue, usb_path).await?;
+    cache.insert(usb_path, response.clone());
+    Ok(response)
+}
+
+/// List the wallets on the USB through the FFI library
+pub(crate) async fn list_usb_wallets(queue: &LazyWalletQueue, usb_path: &str) -> AppResult<WalletListResponse> {
     // T038: Start performance timer
     let start = Instant::now();
 
@@ -668,8 +1749,7 @@ pub async fn list_wallets(
         "usbPath": usb_path,
     });
 
-    let params_json = serde_json::to_string(&params)
-        .map_err(|e| format!("Failed to serialize params: {}", e))?;
+    let params_json = serde_json::to_string(&params)?;
 
     // T035: Call FFI queue
     let ffi_response = queue
@@ -704,30 +1784,20 @@ pub async fn list_wallets(
 
     let mut wallets = Vec::new();
     for wallet_data in wallets_array {
-        let wallet_id = wallet_data
-            .get("walletId")
-            .and_then(|v| v.as_str())
-            .unwrap_or("unknown")
-            .to_string();
-
-        let name = wallet_data
-            .get("walletName")
-            .and_then(|v| v.as_str())
-            .unwrap_or(&wallet_id)
-            .to_string();
-
-        let created_at = wallet_data
-            .get("createdAt")
-            .and_then(|v| v.as_str())
-            .unwrap_or("unknown")
-            .to_string();
+        let metadata = WalletFileMetadata::from(wallet_data);
+        let wallet_id = metadata.id.unwrap_or_else(|| "unknown".to_string());
+        let name = metadata.name.unwrap_or_else(|| wallet_id.clone());
+        let created_at = metadata.created_at.unwrap_or_else(|| "unknown".to_string());
 
         // Use created_at as updated_at for now (actual implementation would track this)
         let updated_at = created_at.clone();
 
         // Default values (actual implementation would read from wallet metadata)
         let has_passphrase = false;
-        let address_count = 0;
+        let address_count = match sanitize_wallet_id(&wallet_id) {
+            Ok(()) => stored_address_count(&std::path::Path::new(usb_path).join(&wallet_id)),
+            Err(_) => 0,
+        };
 
         wallets.push(Wallet {
             id: wallet_id,
@@ -749,29 +1819,34 @@ pub async fn list_wallets(
         elapsed
     );
 
-    Ok(wallets)
+    Ok(WalletListResponse {
+        wallets,
+        duration_ms: Some(duration_ms(elapsed)),
+    })
 }
 
 /// Rename wallet (T036 - Updated to use FFI queue)
 /// Requirements: FR-019 (Wallet rename functionality)
 #[tauri::command]
 pub async fn rename_wallet(
-    queue: State<'_, LazyWalletQueue>, // T036: Accept LazyWalletQueue from Tauri state
+    backend: State<'_, WalletBackend>, // T036: Accept WalletBackend from Tauri state
+    activity: State<'_, ActivityTracker>,
+    read_cache: State<'_, ReadCache>,
     wallet_id: String,
     new_name: String,
     usb_path: String,
-) -> Result<Wallet, String> {
+) -> Result<WalletRenameResponse, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
     // T038: Start performance timer
     let start = Instant::now();
 
-    // Validate wallet ID format
-    if !Wallet::validate_id(&wallet_id) {
-        return Err(AppError::new(
-            ErrorCode::InvalidWalletId,
-            "Invalid wallet ID format",
-        )
-        .into());
-    }
+    // Validate wallet ID format (also guards the Go-side path join)
+    sanitize_wallet_id(&wallet_id).map_err(String::from)?;
+    check_length("USB path", &usb_path, MAX_PATH_LEN).map_err(String::from)?;
 
     // Validate new name
     if !Wallet::validate_name(&new_name) {
@@ -849,7 +1924,7 @@ pub async fn rename_wallet(
         created_at: "unknown".to_string(), // Actual implementation would preserve this
         updated_at: renamed_at,
         has_passphrase: false, // Actual implementation would preserve this
-        address_count: 0, // Actual implementation would preserve this
+        address_count: stored_address_count(&std::path::Path::new(&usb_path).join(&wallet_id)),
     };
 
     // T038: Log performance metrics
@@ -860,18 +1935,271 @@ pub async fn rename_wallet(
         elapsed
     );
 
-    Ok(wallet)
+    read_cache.wallets.invalidate_all();
+    Ok(WalletRenameResponse {
+        wallet,
+        duration_ms: Some(duration_ms(elapsed)),
+    })
+}
+
+/// Map a ChangeWalletPassword FFI error ("CODE: message") to an AppError
+fn change_password_error(error: String) -> AppError {
+    let code = match error.split_once(':').map(|(code, _)| code.trim()) {
+        // A failed decrypt means the old password was wrong
+        Some("INVALID_PASSWORD") | Some("DECRYPTION_ERROR") => ErrorCode::InvalidPassword,
+        Some(code) => AppError::from_ffi_error_code(code),
+        None => ErrorCode::InternalError,
+    };
+
+    let message = match code {
+        ErrorCode::InvalidPassword => "Current password is incorrect",
+        ErrorCode::InternalError => "Failed to change wallet password",
+        _ => AppError::default_message_for_code(code),
+    };
+
+    AppError::with_details(code, message, error)
+}
+
+/// Re-encrypt one wallet under `new_password`.
+///
+/// The new password's strength is the caller's to check, so a bulk change
+/// validates it once.
+async fn reencrypt_wallet(
+    queue: &LazyWalletQueue,
+    wallet_id: &str,
+    old_password: &Secret,
+    new_password: &Secret,
+    usb_path: &str,
+) -> AppResult<()> {
+    sanitize_wallet_id(wallet_id)?;
+
+    let params_json = ffi_params(
+        json!({
+            "walletId": wallet_id,
+            "usbPath": usb_path,
+        }),
+        &[("oldPassword", old_password.expose()), ("newPassword", new_password.expose())],
+    )?;
+
+    let result = queue.change_wallet_password(params_json).await;
+
+    result.map(|_| ()).map_err(change_password_error)
+}
+
+/// Check both passwords' lengths and the new password's strength
+fn check_password_change(old_password: &Secret, new_password: &Secret, usb_path: &str) -> AppResult<()> {
+    check_length("Password", old_password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("Password", new_password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+    validate_password(new_password.expose())
+}
+
+/// Change the password protecting a wallet
+#[tauri::command]
+pub async fn change_wallet_password(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    wallet_id: String,
+    old_password: Secret,
+    new_password: Secret,
+    usb_path: String,
+    unlocked: State<'_, UnlockedWallets>,
+) -> Result<(), String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    tracing::info!("change_wallet_password: wallet_id={}", wallet_id);
+
+    check_password_change(&old_password, &new_password, &usb_path).map_err(String::from)?;
+    unlocked.forget(&wallet_id);
+    reencrypt_wallet(queue, &wallet_id, &old_password, &new_password, &usb_path)
+        .await
+        .map_err(String::from)
+}
+
+/// Outcome of re-encrypting one wallet in a bulk password change
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletReencryptResult {
+    pub wallet_id: String,
+    pub name: String,
+    pub success: bool,
+    /// Why this wallet was left unchanged
+    pub error: Option<AppError>,
+}
+
+/// Re-encrypt every wallet on the USB, continuing past failures
+pub(crate) async fn reencrypt_usb_wallets(
+    queue: &LazyWalletQueue,
+    usb_path: &str,
+    old_password: &Secret,
+    new_password: &Secret,
+) -> AppResult<Vec<WalletReencryptResult>> {
+    check_password_change(old_password, new_password, usb_path)?;
+
+    let wallets = list_usb_wallets(queue, usb_path).await?.wallets;
+
+    let mut results = Vec::with_capacity(wallets.len());
+    for wallet in wallets {
+        let outcome = reencrypt_wallet(queue, &wallet.id, old_password, new_password, usb_path).await;
+        if let Err(ref e) = outcome {
+            tracing::warn!("Could not re-encrypt wallet {}: {}", wallet.id, e.message);
+        }
+
+        results.push(WalletReencryptResult {
+            wallet_id: wallet.id,
+            name: wallet.name,
+            success: outcome.is_ok(),
+            error: outcome.err(),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Change the password of every wallet on a USB that `old_password` unlocks
+#[tauri::command]
+pub async fn reencrypt_all_wallets(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    usb_path: String,
+    old_password: Secret,
+    new_password: Secret,
+    unlocked: State<'_, UnlockedWallets>,
+) -> Result<Vec<WalletReencryptResult>, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    unlocked.clear();
+
+    let results = reencrypt_usb_wallets(queue, &usb_path, &old_password, &new_password)
+        .await
+        .map_err(String::from)?;
+
+    tracing::info!(
+        "reencrypt_all_wallets: {} of {} wallet(s) re-encrypted",
+        results.iter().filter(|r| r.success).count(),
+        results.len()
+    );
+
+    Ok(results)
+}
+
+/// Wallets currently held unlocked in memory (Tauri command)
+#[tauri::command]
+pub async fn list_unlocked_wallets(unlocked: State<'_, UnlockedWallets>) -> Result<Vec<String>, String> {
+    Ok(unlocked.wallet_ids())
+}
+
+/// Lock one wallet: forget its unlock state and cached addresses. Other
+/// wallets stay unlocked.
+///
+/// Locking is entirely on this side: the library's UnlockWallet only checks
+/// the password and keeps nothing in memory, so there is nothing to release
+/// there. What makes a wallet "unlocked" is its password fingerprint in
+/// UnlockedWallets and the addresses in the AddressCache.
+pub(crate) fn lock_single_wallet(unlocked: &UnlockedWallets, cache: &AddressCache, wallet_id: &str) -> AppResult<()> {
+    sanitize_wallet_id(wallet_id)?;
+
+    unlocked.forget(wallet_id);
+    cache.invalidate_wallet(wallet_id);
+    Ok(())
+}
+
+/// Lock a single wallet without affecting the others (Tauri command)
+#[tauri::command]
+pub async fn lock_wallet(
+    wallet_id: String,
+    unlocked: State<'_, UnlockedWallets>,
+    cache: State<'_, AddressCache>,
+) -> Result<(), String> {
+    tracing::info!("lock_wallet: wallet_id={}", wallet_id);
+
+    lock_single_wallet(&unlocked, &cache, &wallet_id).map_err(String::from)
+}
+
+/// Result of verify_password
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct PasswordCheck {
+    pub valid: bool,
+}
+
+/// Check `password` against a wallet through UnlockWallet.
+///
+/// UnlockWallet decrypts the wallet and discards the mnemonic right away, so
+/// nothing stays unlocked; unlike `load_wallet_addresses` no addresses are
+/// derived or cached. A wrong password is a result, not an error.
+pub(crate) async fn check_wallet_password(
+    queue: &LazyWalletQueue,
+    wallet_id: &str,
+    password: &Secret,
+    usb_path: &str,
+) -> AppResult<PasswordCheck> {
+    sanitize_wallet_id(wallet_id)?;
+    check_length("Password", password.expose(), MAX_PASSWORD_LEN)?;
+    check_length("USB path", usb_path, MAX_PATH_LEN)?;
+    ensure_wallet_exists(usb_path, wallet_id)?;
+
+    let params_json = ffi_params(
+        json!({
+            "walletId": wallet_id,
+            "usbPath": usb_path,
+        }),
+        &[("password", password.expose())],
+    )?;
+
+    let result = queue.unlock_wallet(params_json).await;
+
+    match result {
+        Ok(_) => Ok(PasswordCheck { valid: true }),
+        Err(e) => match e.split_once(':').map(|(code, _)| code.trim()) {
+            Some("INVALID_PASSWORD") | Some("DECRYPTION_ERROR") => Ok(PasswordCheck { valid: false }),
+            Some(code) => {
+                let code = AppError::from_ffi_error_code(code);
+                Err(AppError::with_details(code, AppError::default_message_for_code(code), e))
+            }
+            None => Err(AppError::with_details(ErrorCode::InternalError, "Failed to verify password", e)),
+        },
+    }
 }
 
-/// Parse category string to Category enum
+/// Check a wallet password without loading its addresses ("confirm password")
+#[tauri::command]
+pub async fn verify_password(
+    backend: State<'_, WalletBackend>,
+    activity: State<'_, ActivityTracker>,
+    wallet_id: String,
+    password: Secret,
+    usb_path: String,
+) -> Result<PasswordCheck, String> {
+    activity.touch();
+
+    let usb_path = normalize_usb_path(&usb_path);
+    let queue = backend.queue().map_err(String::from)?;
+
+    tracing::info!("verify_password: wallet_id={}", wallet_id);
+
+    check_wallet_password(queue, &wallet_id, &password, &usb_path)
+        .await
+        .map_err(String::from)
+}
+
+/// Parse category string to Category enum.
+///
+/// Accepts the dashboard names as well as the library's chain categories
+/// ("UTXO", "EVM_Mainnet", "Layer2", "Cosmos_SDK", "Substrate", "Custom").
+/// The library's are coarse, so `Category::of_symbol` takes precedence.
 fn parse_category(s: &str) -> Category {
-    match s {
-        "base" => Category::Base,
+    match s.to_ascii_lowercase().as_str() {
+        "base" | "utxo" | "evm_mainnet" => Category::Base,
         "layer2" => Category::Layer2,
         "regional" => Category::Regional,
-        "cosmos" => Category::Cosmos,
+        "cosmos" | "cosmos_sdk" => Category::Cosmos,
         "alt_evm" => Category::AltEvm,
-        "specialized" => Category::Specialized,
+        "specialized" | "substrate" | "custom" => Category::Specialized,
         _ => Category::Specialized, // Default fallback
     }
 }
@@ -924,4 +2252,1099 @@ mod tests {
         assert!(validate_password("ValidPassword123@").is_ok());
         assert!(validate_password("ValidPassword123#").is_ok());
     }
+
+    #[test]
+    fn test_sanitize_wallet_id() {
+        assert!(sanitize_wallet_id("3c3e0aba-91e1-44d4-8b29-ec066d5acf0b").is_ok());
+        assert!(sanitize_wallet_id(&"a".repeat(64)).is_ok());
+
+        for wallet_id in ["../../etc", "/etc/passwd", "C:\\Windows", "..", "", "3c3e0aba/91e1-44d4-8b29-ec066d5acf0b"] {
+            let err = sanitize_wallet_id(wallet_id).unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidWalletId, "{}", wallet_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_wallet_addresses_rejects_traversal_before_fs_access() {
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(crate::ffi::stub::stub_library()));
+        let cache = AddressCache::default();
+        let unlocked = UnlockedWallets::default();
+
+        // usb_path doesn't exist: a WalletNotFound here would mean the fs was touched
+        for wallet_id in ["../../etc", "/etc"] {
+            let password = Secret::new("ValidPassword123".to_string());
+            let err = load_wallet_addresses(&queue, &cache, &unlocked, wallet_id, &password, "/nonexistent-usb", AddressType::Bip44)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidWalletId);
+        }
+    }
+
+    #[test]
+    fn test_wallet_input_lengths() {
+        let huge = "a".repeat(1 << 20);
+        let mnemonic = "abandon ".repeat(23) + "art";
+
+        assert!(check_wallet_input_lengths("ValidPassword123", None, Some(&mnemonic), Some("  My Wallet  "), "/media/usb").is_ok());
+
+        let oversized = [
+            check_wallet_input_lengths(&huge, None, None, None, "/media/usb"),
+            check_wallet_input_lengths("ValidPassword123", Some(&huge), None, None, "/media/usb"),
+            check_wallet_input_lengths("ValidPassword123", None, Some(&huge), None, "/media/usb"),
+            check_wallet_input_lengths("ValidPassword123", None, None, Some(&"n".repeat(51)), "/media/usb"),
+            check_wallet_input_lengths("ValidPassword123", None, None, None, &huge),
+        ];
+        for result in oversized {
+            assert_eq!(result.unwrap_err().code, ErrorCode::FfiInvalidInput);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_wallet_addresses_rejects_oversized_password_before_fs_access() {
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(crate::ffi::stub::stub_library()));
+        let cache = AddressCache::default();
+        let unlocked = UnlockedWallets::default();
+
+        // usb_path doesn't exist: a WalletNotFound here would mean the fs was touched
+        let password = Secret::new("a".repeat(MAX_PASSWORD_LEN + 1));
+        let err = load_wallet_addresses(
+            &queue,
+            &cache,
+            &unlocked,
+            "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b",
+            &password,
+            "/nonexistent-usb",
+            AddressType::Bip44,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_load_wallet_addresses_rejects_bip84_before_unlocking() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static UNLOCK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            UNLOCK_CALLS.fetch_add(1, Ordering::SeqCst);
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        std::fs::create_dir(usb.path().join(wallet_id)).unwrap();
+        std::fs::write(usb.path().join(wallet_id).join("wallet.json"), "{}").unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let cache = AddressCache::default();
+        let unlocked = UnlockedWallets::default();
+        let password = Secret::new("ValidPassword123".to_string());
+
+        let err = load_wallet_addresses(&queue, &cache, &unlocked, wallet_id, &password, usb_path, AddressType::Bip84)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::UnsupportedOperation);
+        assert_eq!(UNLOCK_CALLS.load(Ordering::SeqCst), 0);
+        assert_eq!(cache.0.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_second_load_of_unlocked_wallet_skips_unlock() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static UNLOCK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            UNLOCK_CALLS.fetch_add(1, Ordering::SeqCst);
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        unsafe extern "C" fn generate(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"addresses":[{"blockchain":"bitcoin","address":"bc1qexample","derivationPath":"m/44'/0'/0'/0/0"}]}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        std::fs::create_dir(usb.path().join(wallet_id)).unwrap();
+        std::fs::write(usb.path().join(wallet_id).join("wallet.json"), "{}").unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        library.generate_addresses = generate;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let unlocked = UnlockedWallets::default();
+        let password = Secret::new("ValidPassword123".to_string());
+
+        // A fresh cache each time, so only the unlock state is carried over
+        for _ in 0..2 {
+            let cache = AddressCache::default();
+            load_wallet_addresses(&queue, &cache, &unlocked, wallet_id, &password, usb_path, AddressType::Bip44)
+                .await
+                .unwrap();
+        }
+        assert_eq!(UNLOCK_CALLS.load(Ordering::SeqCst), 1);
+
+        // A different password is verified again
+        let other = Secret::new("OtherPassword456".to_string());
+        load_wallet_addresses(&queue, &AddressCache::default(), &unlocked, wallet_id, &other, usb_path, AddressType::Bip44)
+            .await
+            .unwrap();
+        assert_eq!(UNLOCK_CALLS.load(Ordering::SeqCst), 2);
+
+        // Locking clears the unlock state
+        unlocked.clear();
+        load_wallet_addresses(&queue, &AddressCache::default(), &unlocked, wallet_id, &other, usb_path, AddressType::Bip44)
+            .await
+            .unwrap();
+        assert_eq!(UNLOCK_CALLS.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_grouped_addresses_follow_library_categories() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        // GenerateAddresses entries as the library writes them
+        unsafe extern "C" fn generate(_params: *const c_char) -> *mut c_char {
+            ffi_string(
+                r#"{"success":true,"data":{"addresses":[
+                    {"blockchain":"Bitcoin","symbol":"BTC","address":"1abc","derivationPath":"m/44'/0'/0'/0/0","coinType":0,"category":"UTXO"},
+                    {"blockchain":"Arbitrum","symbol":"ARB","address":"0xabc","derivationPath":"m/44'/60'/0'/0/0","coinType":60,"category":"Layer2"},
+                    {"blockchain":"Klaytn","symbol":"KLAY","address":"0xdef","derivationPath":"m/44'/8217'/0'/0/0","coinType":8217,"category":"Custom"},
+                    {"blockchain":"Fantom","symbol":"FTM","address":"0x123","derivationPath":"m/44'/60'/0'/0/0","coinType":60,"category":"EVM_Mainnet"},
+                    {"blockchain":"Kusama","symbol":"KSM","address":"Fabc","derivationPath":"m/44'/434'/0'/0/0","coinType":434,"category":"Substrate"},
+                    {"blockchain":"Tezos","symbol":"XTZ","address":"tz1abc","derivationPath":"m/44'/1729'/0'/0/0","coinType":1729,"category":"Custom"}
+                ]}}"#,
+            )
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        std::fs::create_dir(usb.path().join(wallet_id)).unwrap();
+        std::fs::write(usb.path().join(wallet_id).join("wallet.json"), "{}").unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        library.generate_addresses = generate;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let password = Secret::new("ValidPassword123".to_string());
+
+        let list = load_address_list(
+            &queue,
+            &AddressCache::default(),
+            &UnlockedWallets::default(),
+            wallet_id,
+            &password,
+            usb.path().to_str().unwrap(),
+            AddressType::Bip44,
+        )
+        .await
+        .unwrap();
+        let grouped = GroupedAddressResponse::from_list(list);
+
+        let groups: Vec<(Category, Vec<&str>)> = grouped
+            .groups
+            .iter()
+            .map(|g| (g.category.clone(), g.addresses.iter().map(|a| a.symbol.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (Category::Base, vec!["BTC"]),
+                (Category::Layer2, vec!["ARB"]),
+                (Category::Regional, vec!["KLAY"]),
+                (Category::Cosmos, vec![]),
+                (Category::AltEvm, vec!["FTM"]),
+                (Category::Specialized, vec!["KSM", "XTZ"]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cached_addresses_need_the_unlocking_password() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+
+        const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+
+        unsafe extern "C" fn unlock(params: *const c_char) -> *mut c_char {
+            let params = std::ffi::CStr::from_ptr(params).to_string_lossy();
+            if params.contains("ValidPassword123") {
+                ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+            } else {
+                ffi_string(r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"wrong password"}}"#)
+            }
+        }
+
+        unsafe extern "C" fn generate(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"addresses":[{"blockchain":"bitcoin","address":"bc1qexample","derivationPath":"m/44'/0'/0'/0/0"}]}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        std::fs::create_dir(usb.path().join(WALLET_ID)).unwrap();
+        std::fs::write(usb.path().join(WALLET_ID).join("wallet.json"), "{}").unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        library.generate_addresses = generate;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let cache = AddressCache::default();
+        let unlocked = UnlockedWallets::default();
+
+        // Warm the cache with the right password
+        let password = Secret::new("ValidPassword123".to_string());
+        load_wallet_addresses(&queue, &cache, &unlocked, WALLET_ID, &password, usb_path, AddressType::Bip44)
+            .await
+            .unwrap();
+        assert!(cache.0.get(&(WALLET_ID.to_string(), AddressType::Bip44)).is_some());
+
+        for wrong in ["WrongPassword456", ""] {
+            let err = load_wallet_addresses(&queue, &cache, &unlocked, WALLET_ID, &Secret::new(wrong.to_string()), usb_path, AddressType::Bip44)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code, ErrorCode::PasswordTooWeak);
+        }
+
+        // The right password is still served
+        let loaded = load_wallet_addresses(&queue, &cache, &unlocked, WALLET_ID, &password, usb_path, AddressType::Bip44)
+            .await
+            .unwrap();
+        assert_eq!(loaded.addresses.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_lock_during_load_is_not_undone() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+        use std::sync::OnceLock;
+
+        const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        static UNLOCKED: OnceLock<UnlockedWallets> = OnceLock::new();
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        // The wallet gets locked while the library derives its addresses
+        unsafe extern "C" fn generate(_params: *const c_char) -> *mut c_char {
+            UNLOCKED.get().unwrap().forget(WALLET_ID);
+            ffi_string(r#"{"success":true,"data":{"addresses":[{"blockchain":"bitcoin","address":"bc1qexample","derivationPath":"m/44'/0'/0'/0/0"}]}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        std::fs::create_dir(usb.path().join(WALLET_ID)).unwrap();
+        std::fs::write(usb.path().join(WALLET_ID).join("wallet.json"), "{}").unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        library.generate_addresses = generate;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let unlocked = UNLOCKED.get_or_init(UnlockedWallets::default);
+        let cache = AddressCache::default();
+        let password = Secret::new("ValidPassword123".to_string());
+
+        let loaded = load_wallet_addresses(&queue, &cache, unlocked, WALLET_ID, &password, usb_path, AddressType::Bip44)
+            .await
+            .unwrap();
+
+        // The caller still gets what was derived, but the lock stands
+        assert_eq!(loaded.addresses.len(), 1);
+        assert!(cache.0.get(&(WALLET_ID.to_string(), AddressType::Bip44)).is_none());
+        assert!(!unlocked.is_unlocked(WALLET_ID, &password));
+
+        // Without a lock in between, the load caches and marks as before
+        let generation = unlocked.lock_generation();
+        assert!(unlocked.run_unless_locked_since(generation, || ()));
+        unlocked.clear();
+        assert!(!unlocked.run_unless_locked_since(generation, || ()));
+        assert!(!unlocked.mark_unlocked(WALLET_ID, &password, generation));
+    }
+
+    #[test]
+    fn test_password_fingerprint_is_keyed_hmac() {
+        // RFC 4231 test cases 1 and 2
+        let hex = |bytes: [u8; 32]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        assert_eq!(
+            hex(hmac_sha256(&[0x0b; 20], &[b"Hi There"])),
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+        );
+        assert_eq!(
+            hex(hmac_sha256(b"Jefe", &[b"what do ya want ", b"for nothing?"])),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // Test case 6: a key longer than a block is hashed first
+        assert_eq!(
+            hex(hmac_sha256(&[0xaa; 131], &[b"Test Using Larger Than Block-Size Key - Hash Key First"])),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        let password = Secret::new("ValidPassword123".to_string());
+        let fingerprint = UnlockedWallets::password_fingerprint(wallet_id, &password);
+        assert_eq!(fingerprint, UnlockedWallets::password_fingerprint(wallet_id, &password));
+
+        // Not the unkeyed hash an attacker could compute from a guess
+        let unkeyed: [u8; 32] = {
+            use sha2::{Digest, Sha256};
+            Sha256::digest(format!("{}\0{}", wallet_id, password.expose())).into()
+        };
+        assert_ne!(fingerprint, unkeyed);
+        assert_ne!(fingerprint, hmac_sha256(&[], &[wallet_id.as_bytes(), &[0u8], password.expose().as_bytes()]));
+    }
+
+    #[test]
+    fn test_locking_one_wallet_leaves_others_unlocked() {
+        let first = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        let second = "7f1c2d3e-4b5a-4c6d-8e9f-0a1b2c3d4e5f";
+        let password = Secret::new("ValidPassword123".to_string());
+        let unlocked = UnlockedWallets::default();
+        let cache = AddressCache::default();
+        for wallet_id in [first, second] {
+            unlocked.mark_unlocked(wallet_id, &password, unlocked.lock_generation());
+            cache.0.insert((wallet_id.to_string(), AddressType::Bip44), Vec::new());
+            cache.0.insert((wallet_id.to_string(), AddressType::Bip84), Vec::new());
+        }
+        assert_eq!(unlocked.wallet_ids(), vec![first.to_string(), second.to_string()]);
+
+        lock_single_wallet(&unlocked, &cache, first).unwrap();
+
+        assert_eq!(unlocked.wallet_ids(), vec![second.to_string()]);
+        assert!(unlocked.is_unlocked(second, &password));
+        assert!(cache.0.get(&(first.to_string(), AddressType::Bip44)).is_none());
+        assert!(cache.0.get(&(first.to_string(), AddressType::Bip84)).is_none());
+        assert!(cache.0.get(&(second.to_string(), AddressType::Bip44)).is_some());
+
+        lock_single_wallet(&unlocked, &cache, second).unwrap();
+        assert!(unlocked.wallet_ids().is_empty());
+
+        let err = lock_single_wallet(&unlocked, &cache, "../etc").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidWalletId);
+    }
+
+    #[tokio::test]
+    async fn test_load_wallet_addresses_reports_failed_and_skips_cache() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        /// Ethereum and Solana come back without an address
+        unsafe extern "C" fn generate(_params: *const c_char) -> *mut c_char {
+            ffi_string(
+                r#"{"success":true,"data":{"addresses":[
+                    {"blockchain":"bitcoin","address":"bc1qexample","derivationPath":"m/44'/0'/0'/0/0"},
+                    {"blockchain":"ethereum","derivationPath":"m/44'/60'/0'/0/0","error":"key derivation failed"},
+                    {"blockchain":"solana","address":""}
+                ]}}"#,
+            )
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        std::fs::create_dir(usb.path().join(wallet_id)).unwrap();
+        std::fs::write(usb.path().join(wallet_id).join("wallet.json"), "{}").unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        library.generate_addresses = generate;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let cache = AddressCache::default();
+        let unlocked = UnlockedWallets::default();
+        let password = Secret::new("ValidPassword123".to_string());
+
+        let loaded = load_wallet_addresses(
+            &queue,
+            &cache,
+            &unlocked,
+            wallet_id,
+            &password,
+            usb.path().to_str().unwrap(),
+            AddressType::Bip44,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(loaded.addresses.len(), 1);
+        assert_eq!(loaded.addresses[0].address, "bc1qexample");
+        assert_eq!(
+            loaded.failed,
+            vec![
+                FailedAddress {
+                    blockchain: "ethereum".to_string(),
+                    error: "key derivation failed".to_string(),
+                },
+                FailedAddress {
+                    blockchain: "solana".to_string(),
+                    error: "Address missing from library response".to_string(),
+                },
+            ]
+        );
+        assert_eq!(cache.0.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_wallets_reports_duration() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+
+        unsafe extern "C" fn list(_params: *const c_char) -> *mut c_char {
+            ffi_string(
+                r#"{"success":true,"data":{"wallets":[{"walletId":"w1","walletName":"Savings","createdAt":"2025-10-17T12:00:00Z"}],"count":1}}"#,
+            )
+        }
+
+        let mut library = stub_library();
+        library.list_wallets = list;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+
+        let response = list_usb_wallets(&queue, "/usb").await.unwrap();
+
+        assert_eq!(response.wallets.len(), 1);
+        assert_eq!(response.wallets[0].name, "Savings");
+        assert!(response.duration_ms.unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_load_addresses_reports_duration() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+
+        unsafe extern "C" fn unlock(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        unsafe extern "C" fn generate(_params: *const c_char) -> *mut c_char {
+            ffi_string(
+                r#"{"success":true,"data":{"addresses":[{"blockchain":"bitcoin","address":"bc1qexample","derivationPath":"m/44'/0'/0'/0/0"}]}}"#,
+            )
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        std::fs::create_dir(usb.path().join(wallet_id)).unwrap();
+        std::fs::write(usb.path().join(wallet_id).join("wallet.json"), "{}").unwrap();
+
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        library.generate_addresses = generate;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let cache = AddressCache::default();
+        let unlocked = UnlockedWallets::default();
+        let password = Secret::new("ValidPassword123".to_string());
+
+        let response = load_address_list(
+            &queue,
+            &cache,
+            &unlocked,
+            wallet_id,
+            &password,
+            usb.path().to_str().unwrap(),
+            AddressType::Bip44,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.total_count, 1);
+        assert!(response.duration_ms.unwrap() > 0);
+    }
+
+    #[test]
+    fn test_address_cache_debug_reports_counts_and_sizes() {
+        fn cached(wallet_id: &str, count: u32) -> Vec<Address> {
+            (1..=count)
+                .map(|rank| {
+                    Address::new(
+                        wallet_id.to_string(),
+                        rank,
+                        "BTC".to_string(),
+                        "bitcoin".to_string(),
+                        0,
+                        format!("m/44'/0'/0'/0/{}", rank),
+                        "1BoatSLRHtKNngkdXEeobR76b53LETtpyT".to_string(),
+                        Category::Base,
+                        KeyType::Secp256k1,
+                    )
+                })
+                .collect()
+        }
+
+        let cache = AddressCache::with_capacity(4);
+        let unlocked = UnlockedWallets::default();
+        unlocked.mark_unlocked("wallet-a", &Secret::new("ValidPassword123".to_string()), unlocked.lock_generation());
+
+        cache.0.insert(("wallet-a".to_string(), AddressType::Bip44), cached("wallet-a", 3));
+        cache.0.insert(("wallet-a".to_string(), AddressType::Bip84), cached("wallet-a", 1));
+        cache.0.insert(("wallet-b".to_string(), AddressType::Bip44), cached("wallet-b", 2));
+
+        let report = cache.debug_report(&unlocked);
+        assert_eq!(report.wallet_count, 2);
+        assert_eq!(report.entry_count, 3);
+        assert_eq!(report.capacity, Some(4));
+        assert_eq!(report.orphaned_entries, 1);
+        assert!(report.approx_bytes > 6 * std::mem::size_of::<Address>());
+        assert_eq!(report.approx_bytes, report.entries.iter().map(|e| e.approx_bytes).sum::<usize>());
+
+        let orphan = report.entries.iter().find(|e| e.orphaned).unwrap();
+        assert_eq!((orphan.wallet_id.as_str(), orphan.address_count), ("wallet-b", 2));
+        assert!(report.entries.iter().all(|e| !e.expired && e.approx_bytes > 0));
+
+        // Only counts and sizes: no address, path or symbol leaks out
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(!json.contains("1BoatSLRHtKNngkdXEeobR76b53LETtpyT"));
+        assert!(!json.contains("m/44'"));
+    }
+
+    #[test]
+    fn test_duration_ms_rounds_up() {
+        assert_eq!(duration_ms(Duration::from_micros(1)), 1);
+        assert_eq!(duration_ms(Duration::from_millis(1200)), 1200);
+        assert_eq!(duration_ms(Duration::from_micros(1_200_001)), 1201);
+    }
+
+    #[test]
+    fn test_recent_wallets_most_recent_first() {
+        let recent = RecentWallets::default();
+        recent.record("wallet-a");
+        recent.record("wallet-b");
+        recent.record("wallet-c");
+        recent.record("wallet-a");
+
+        let ids: Vec<String> = recent.most_recent(10).into_iter().map(|w| w.wallet_id).collect();
+        assert_eq!(ids, ["wallet-a", "wallet-c", "wallet-b"]);
+
+        let ids: Vec<String> = recent.most_recent(2).into_iter().map(|w| w.wallet_id).collect();
+        assert_eq!(ids, ["wallet-a", "wallet-c"]);
+        assert!(recent.most_recent(0).is_empty());
+    }
+
+    #[test]
+    fn test_ensure_wallet_exists() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        let wallet_dir = usb.path().join("wallet-1");
+        std::fs::create_dir(&wallet_dir).unwrap();
+        std::fs::write(wallet_dir.join("wallet.json"), "{}").unwrap();
+
+        assert!(ensure_wallet_exists(usb_path, "wallet-1").is_ok());
+    }
+
+    #[test]
+    fn test_ensure_wallet_exists_missing_wallet() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        // No wallet directory at all
+        let err = ensure_wallet_exists(usb_path, "wallet-1").unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletNotFound);
+
+        // Directory without wallet.json
+        std::fs::create_dir(usb.path().join("wallet-2")).unwrap();
+        let err = ensure_wallet_exists(usb_path, "wallet-2").unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletNotFound);
+    }
+
+    /// Stands in for DeriveFirstAddress: the "address" is the phrase as
+    /// received plus the passphrase
+    fn fingerprint_queue() -> LazyWalletQueue {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::ffi::CStr;
+        use std::os::raw::c_char;
+
+        unsafe extern "C" fn derive(params: *const c_char) -> *mut c_char {
+            let params: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+            let address = format!("{}|{}", params["mnemonic"].as_str().unwrap(), params["passphrase"].as_str().unwrap());
+            ffi_string(&json!({"success": true, "data": {"address": address}}).to_string())
+        }
+
+        let mut library = stub_library();
+        library.derive_first_address = Some(derive);
+        LazyWalletQueue::new(std::sync::Arc::new(library))
+    }
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[tokio::test]
+    async fn test_mnemonics_equivalent_ignores_whitespace_and_case() {
+        let queue = fingerprint_queue();
+        let a = Secret::new(MNEMONIC.to_string());
+        let b = Secret::new(format!("  {}\n", MNEMONIC.to_uppercase().replace(' ', "   ")));
+
+        assert!(mnemonics_match(&queue, &a, None, &b, None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mnemonics_equivalent_detects_differences() {
+        let queue = fingerprint_queue();
+        let a = Secret::new(MNEMONIC.to_string());
+        let b = Secret::new(MNEMONIC.replace("about", "abandon"));
+        assert!(!mnemonics_match(&queue, &a, None, &b, None).await.unwrap());
+
+        // Same phrase, different passphrase: a different wallet
+        let passphrase = Secret::new("hunter2".to_string());
+        assert!(!mnemonics_match(&queue, &a, None, &a, Some(&passphrase)).await.unwrap());
+
+        let short = Secret::new("abandon about".to_string());
+        let err = mnemonics_match(&queue, &a, None, &short, None).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidMnemonicLength);
+    }
+
+    #[tokio::test]
+    async fn test_preview_restore_is_stable_and_passphrase_sensitive() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use sha3::{Digest, Keccak256};
+        use std::ffi::CStr;
+        use std::os::raw::c_char;
+
+        // An Ethereum-shaped address hashed from the phrase, passphrase and chain
+        unsafe extern "C" fn derive(params: *const c_char) -> *mut c_char {
+            let params: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+            let digest = Keccak256::digest(format!(
+                "{}|{}|{}",
+                params["mnemonic"].as_str().unwrap(),
+                params["passphrase"].as_str().unwrap(),
+                params["chainId"].as_str().unwrap()
+            ));
+            let address: String = std::iter::once("0x".to_string())
+                .chain(digest[12..].iter().map(|b| format!("{:02x}", b)))
+                .collect();
+            ffi_string(&json!({"success": true, "data": {"address": address}}).to_string())
+        }
+
+        let mut library = stub_library();
+        library.derive_first_address = Some(derive);
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+
+        let mnemonic = Secret::new(MNEMONIC.to_string());
+        let retyped = Secret::new(format!(" {} ", MNEMONIC.to_uppercase()));
+        let first = preview_wallet_restore(&queue, &mnemonic, None, "ethereum").await.unwrap();
+        let second = preview_wallet_restore(&queue, &retyped, None, "ethereum").await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.chain_id, "ethereum");
+        assert!(first.address.starts_with("0x") && first.address.len() == 42);
+
+        let passphrase = Secret::new("hunter2".to_string());
+        let with_passphrase = preview_wallet_restore(&queue, &mnemonic, Some(&passphrase), "ethereum")
+            .await
+            .unwrap();
+        assert_ne!(with_passphrase.address, first.address);
+
+        // A library that ignores chainId doesn't return an Ethereum address
+        let err = preview_wallet_restore(&fingerprint_queue(), &mnemonic, None, "ethereum")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::UnsupportedOperation);
+    }
+
+    #[tokio::test]
+    async fn test_passphrase_variant_is_not_a_duplicate() {
+        const WALLET_ID: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        let usb = tempfile::TempDir::new().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let queue = fingerprint_queue();
+
+        // Wallet restored from MNEMONIC without a passphrase
+        let wallet_dir = usb.path().join(WALLET_ID);
+        std::fs::create_dir_all(&wallet_dir).unwrap();
+        std::fs::write(
+            wallet_dir.join("wallet.json"),
+            json!({"id": WALLET_ID, "name": "Plain", "createdAt": "2025-10-17T14:30:25Z", "usesPassphrase": false})
+                .to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            wallet_dir.join("addresses.json"),
+            json!({"addresses": [{"symbol": "BTC", "derivationPath": "m/44'/0'/0'/0/0", "address": format!("{}|", MNEMONIC)}]})
+                .to_string(),
+        )
+        .unwrap();
+
+        let duplicate = check_duplicate_wallet(&queue, MNEMONIC, None, usb_path).await.unwrap();
+        assert_eq!(duplicate.map(|(id, name, _)| (id, name)), Some((WALLET_ID.to_string(), "Plain".to_string())));
+
+        // Same phrase with a passphrase is a different wallet
+        let duplicate = check_duplicate_wallet(&queue, MNEMONIC, Some("hunter2"), usb_path).await.unwrap();
+        assert_eq!(duplicate, None);
+    }
+
+    fn mnemonic_words(formatted: &str) -> Vec<String> {
+        formatted
+            .split_whitespace()
+            .filter(|token| !token.ends_with('.'))
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_format_mnemonic_styles() {
+        let mnemonic = Secret::new(format!("  {}\n", MNEMONIC.to_uppercase()));
+        let words: Vec<String> = MNEMONIC.split(' ').map(str::to_string).collect();
+
+        let plain = format_mnemonic_words(&mnemonic, MnemonicStyle::Plain).unwrap();
+        assert_eq!(plain.expose(), MNEMONIC);
+
+        let numbered = format_mnemonic_words(&mnemonic, MnemonicStyle::Numbered).unwrap();
+        let lines: Vec<&str> = numbered.expose().lines().collect();
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[0], "1. abandon");
+        assert_eq!(lines[11], "12. about");
+        assert_eq!(mnemonic_words(numbered.expose()), words);
+
+        let grouped = format_mnemonic_words(&mnemonic, MnemonicStyle::Grouped).unwrap();
+        let rows: Vec<&str> = grouped.expose().lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], " 1. abandon    2. abandon    3. abandon    4. abandon");
+        assert!(rows[2].starts_with(" 9. abandon   10. abandon"));
+        assert!(rows[2].ends_with("12. about"));
+        assert_eq!(mnemonic_words(grouped.expose()), words);
+    }
+
+    #[test]
+    fn test_format_mnemonic_rejects_wrong_length() {
+        let short = Secret::new("abandon about".to_string());
+        let err = format_mnemonic_words(&short, MnemonicStyle::Grouped).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidMnemonicLength);
+    }
+
+    #[test]
+    fn test_import_error_mnemonic_codes() {
+        let err = import_error("INVALID_CHECKSUM: mnemonic checksum mismatch".to_string());
+        assert_eq!(err.code, ErrorCode::InvalidMnemonicChecksum);
+
+        let err = import_error("INVALID_MNEMONIC: word 'abandonn' not in wordlist".to_string());
+        assert_eq!(err.code, ErrorCode::InvalidMnemonic);
+
+        let err = import_error("WALLET_ALREADY_EXISTS: wallet exists".to_string());
+        assert_eq!(err.code, ErrorCode::WalletAlreadyExists);
+
+        let err = import_error("STORAGE_ERROR: disk full".to_string());
+        assert_eq!(err.code, ErrorCode::UsbNotFound);
+
+        let err = import_error("LIBRARY_PANIC: nil pointer".to_string());
+        assert_eq!(err.code, ErrorCode::CliExecutionFailed);
+    }
+
+    #[tokio::test]
+    async fn test_reencrypt_all_wallets_continues_past_failures() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::ffi::CStr;
+        use std::os::raw::c_char;
+
+        const SAVINGS: &str = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        const TRADING: &str = "9f1b2c3d-4e5f-4a6b-8c7d-0e1f2a3b4c5d";
+
+        unsafe extern "C" fn list(_params: *const c_char) -> *mut c_char {
+            ffi_string(&format!(
+                r#"{{"success":true,"data":{{"wallets":[{{"walletId":"{}","walletName":"Savings"}},{{"walletId":"{}","walletName":"Trading"}}],"count":2}}}}"#,
+                SAVINGS, TRADING
+            ))
+        }
+
+        /// Savings uses the shared old password; Trading has its own
+        unsafe extern "C" fn change(params: *const c_char) -> *mut c_char {
+            let params: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+            let expected = if params["walletId"] == SAVINGS { "OldPassword123" } else { "OtherPassword456" };
+
+            if params["oldPassword"] != expected {
+                return ffi_string(r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"decryption failed"}}"#);
+            }
+            ffi_string(r#"{"success":true,"data":{"changedAt":"2025-10-17T12:00:00Z"}}"#)
+        }
+
+        let mut library = stub_library();
+        library.list_wallets = list;
+        library.change_wallet_password = Some(change);
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+
+        let old_password = Secret::new("OldPassword123".to_string());
+        let new_password = Secret::new("NewPassword789".to_string());
+
+        let results = reencrypt_usb_wallets(&queue, "/usb", &old_password, &new_password)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].wallet_id, SAVINGS);
+        assert!(results[0].success);
+        assert!(results[0].error.is_none());
+
+        assert_eq!(results[1].wallet_id, TRADING);
+        assert!(!results[1].success);
+        assert_eq!(results[1].error.as_ref().unwrap().code, ErrorCode::InvalidPassword);
+
+        // A weak new password is rejected before any wallet is touched
+        let weak = Secret::new("weak".to_string());
+        let err = reencrypt_usb_wallets(&queue, "/usb", &old_password, &weak).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::PasswordTooWeak);
+    }
+
+    #[tokio::test]
+    async fn test_list_wallets_served_from_cache() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::os::raw::c_char;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        unsafe extern "C" fn list(_params: *const c_char) -> *mut c_char {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            ffi_string(r#"{"success":true,"data":{"wallets":[{"walletId":"w1","walletName":"Savings"}],"count":1}}"#)
+        }
+
+        let mut library = stub_library();
+        library.list_wallets = list;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let cache = TtlCache::new("wallets", Duration::from_secs(60));
+
+        let first = list_usb_wallets_cached(&queue, &cache, "/usb").await.unwrap();
+        let second = list_usb_wallets_cached(&queue, &cache, "/usb").await.unwrap();
+
+        assert_eq!(second.wallets[0].name, first.wallets[0].name);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!((cache.stats().hits, cache.stats().misses), (1, 1));
+
+        cache.invalidate_all();
+        list_usb_wallets_cached(&queue, &cache, "/usb").await.unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_import_retry_after_lost_response_is_not_duplicate() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::ffi::CStr;
+        use std::os::raw::c_char;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static IMPORTS: AtomicUsize = AtomicUsize::new(0);
+
+        /// Writes the wallet, then fails as if the worker died before replying
+        unsafe extern "C" fn import(params: *const c_char) -> *mut c_char {
+            IMPORTS.fetch_add(1, Ordering::SeqCst);
+            let params: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+            let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+            let wallet_dir = std::path::Path::new(params["usbPath"].as_str().unwrap()).join(wallet_id);
+            std::fs::create_dir(&wallet_dir).unwrap();
+            let meta = json!({
+                "id": wallet_id,
+                "name": params["walletName"],
+                "createdAt": chrono::Utc::now().to_rfc3339(),
+                "usesPassphrase": false,
+            });
+            std::fs::write(wallet_dir.join("wallet.json"), meta.to_string()).unwrap();
+            ffi_string(r#"{"success":false,"error":{"code":"INTERNAL_ERROR","message":"worker restarted"}}"#)
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let mut library = stub_library();
+        library.import_wallet = import;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+        let recent_imports = RecentImports::default();
+
+        let cache = AddressCache::default();
+        let input = ImportWalletInput {
+            mnemonic: Secret::new(
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+            ),
+            password: Secret::new("ValidPassword123".to_string()),
+            usb_path: usb_path.to_string(),
+            passphrase: None,
+            name: Some("Savings".to_string()),
+        };
+
+        let err = import_into(&queue, &recent_imports, &cache, &input).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::CliExecutionFailed);
+
+        // Left over from before the wallet was imported
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        cache.0.insert((wallet_id.to_string(), AddressType::Bip44), Vec::new());
+
+        let retry = import_into(&queue, &recent_imports, &cache, &input).await.unwrap();
+        assert!(retry.already_imported);
+        assert!(!retry.is_duplicate);
+        assert_eq!(retry.wallet.id, wallet_id);
+        assert_eq!(retry.wallet.name, "Savings");
+        assert!(cache.0.get(&(wallet_id.to_string(), AddressType::Bip44)).is_none());
+
+        // The retry didn't import the phrase a second time
+        assert_eq!(IMPORTS.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_verify_password_checks_without_caching() {
+        use crate::ffi::stub::{ffi_string, stub_library};
+        use std::ffi::CStr;
+        use std::os::raw::c_char;
+
+        unsafe extern "C" fn unlock(params: *const c_char) -> *mut c_char {
+            let params: serde_json::Value =
+                serde_json::from_str(CStr::from_ptr(params).to_str().unwrap()).unwrap();
+            if params["password"] == "ValidPassword123" {
+                ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+            } else {
+                ffi_string(r#"{"success":false,"error":{"code":"INVALID_PASSWORD","message":"decryption failed"}}"#)
+            }
+        }
+
+        let usb = tempfile::tempdir().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        std::fs::create_dir(usb.path().join(wallet_id)).unwrap();
+        std::fs::write(usb.path().join(wallet_id).join("wallet.json"), "{}").unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+
+        // GenerateAddresses stays NOT_STUBBED: verifying must not derive anything
+        let mut library = stub_library();
+        library.unlock_wallet = unlock;
+        let queue = LazyWalletQueue::new(std::sync::Arc::new(library));
+
+        let correct = Secret::new("ValidPassword123".to_string());
+        let check = check_wallet_password(&queue, wallet_id, &correct, usb_path).await.unwrap();
+        assert_eq!(check, PasswordCheck { valid: true });
+
+        let wrong = Secret::new("WrongPassword456".to_string());
+        let check = check_wallet_password(&queue, wallet_id, &wrong, usb_path).await.unwrap();
+        assert_eq!(check, PasswordCheck { valid: false });
+
+        let err = check_wallet_password(&queue, "a1b2c3d4-0000-0000-0000-000000000000", &correct, usb_path)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::WalletNotFound);
+    }
+
+    #[test]
+    fn test_parse_generated_addresses_uses_reported_category() {
+        let addresses = serde_json::json!([
+            {"blockchain": "bitcoin", "address": "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", "category": "UTXO"},
+            {"blockchain": "ethereum", "address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "category": "EVM_Mainnet"},
+            {"blockchain": "arbitrum", "address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "category": "Layer2"},
+            {"blockchain": "cosmos", "address": "cosmos1abc", "category": "Cosmos_SDK"},
+            {"blockchain": "polkadot", "address": "1abc", "category": "Substrate"},
+            {"blockchain": "legacy", "address": "legacy1abc"},
+        ]);
+
+        let loaded = parse_generated_addresses("3c3e0aba-91e1-44d4-8b29-ec066d5acf0b", addresses.as_array().unwrap());
+        let categories: Vec<Category> = loaded.addresses.iter().map(|a| a.category.clone()).collect();
+
+        assert_eq!(
+            categories,
+            vec![
+                Category::Base,
+                Category::Base,
+                Category::Layer2,
+                Category::Cosmos,
+                Category::Specialized,
+                Category::Specialized
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_generated_addresses_maps_library_entries_by_symbol() {
+        // As GenerateAddresses reports them: coarse categories, coin name as "blockchain"
+        let addresses = serde_json::json!([
+            {"blockchain": "Bitcoin", "symbol": "BTC", "address": "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+             "derivationPath": "m/44'/0'/0'/0/0", "coinType": 0, "category": "UTXO"},
+            {"blockchain": "Klaytn", "symbol": "KLAY", "address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+             "derivationPath": "m/44'/8217'/0'/0/0", "coinType": 8217, "category": "Custom"},
+            {"blockchain": "Fantom", "symbol": "FTM", "address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+             "derivationPath": "m/44'/60'/0'/0/0", "coinType": 60, "category": "EVM_Mainnet"},
+            {"blockchain": "Kusama", "symbol": "KSM", "address": "Fabc",
+             "derivationPath": "m/44'/434'/0'/0'/0'", "coinType": 434, "category": "Substrate"},
+        ]);
+
+        let loaded = parse_generated_addresses("3c3e0aba-91e1-44d4-8b29-ec066d5acf0b", addresses.as_array().unwrap());
+        let summary: Vec<(&str, &str, u32, Category)> = loaded
+            .addresses
+            .iter()
+            .map(|a| (a.symbol.as_str(), a.name.as_str(), a.coin_type, a.category.clone()))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("BTC", "Bitcoin", 0, Category::Base),
+                ("KLAY", "Klaytn", 8217, Category::Regional),
+                ("FTM", "Fantom", 60, Category::AltEvm),
+                ("KSM", "Kusama", 434, Category::Specialized),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_generated_addresses_falls_back_to_reported_coin_type() {
+        let addresses = serde_json::json!([
+            {"blockchain": "Litecoin", "symbol": "LTC", "address": "Labc", "derivationPath": "bogus", "coinType": 2},
+        ]);
+
+        let loaded = parse_generated_addresses("3c3e0aba-91e1-44d4-8b29-ec066d5acf0b", addresses.as_array().unwrap());
+        assert_eq!(loaded.addresses[0].coin_type, 2);
+    }
+
+    #[test]
+    fn test_generated_mnemonic_word_count() {
+        let twelve = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let response = json!({"walletId": "w1", "mnemonic": twelve});
+
+        assert_eq!(generated_mnemonic(&response, 12).unwrap(), twelve);
+
+        // Library ignored wordCount: 12 words back for a 24-word request
+        let err = generated_mnemonic(&response, 24).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InternalError);
+        assert!(err.message.contains("12-word"));
+        assert!(!err.message.contains("abandon"));
+
+        let err = generated_mnemonic(&json!({"walletId": "w1"}), 24).unwrap_err();
+        assert_eq!(err.code, ErrorCode::DeserializationError);
+    }
+
+    #[test]
+    fn test_rejected_mnemonic_deletes_the_created_wallet() {
+        let usb = tempfile::tempdir().unwrap();
+        let usb_path = usb.path().to_str().unwrap();
+        let wallet_id = "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b";
+        let wallet_dir = usb.path().join(wallet_id);
+        std::fs::create_dir(&wallet_dir).unwrap();
+        std::fs::write(wallet_dir.join("wallet.json"), "{}").unwrap();
+        std::fs::write(wallet_dir.join("mnemonic.enc"), [1u8, 2, 3]).unwrap();
+
+        let twelve = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let response = json!({"walletId": wallet_id, "mnemonic": twelve});
+
+        assert_eq!(created_wallet_mnemonic(usb_path, wallet_id, &response, 12).unwrap(), twelve);
+        assert!(wallet_dir.is_dir());
+
+        let err = created_wallet_mnemonic(usb_path, wallet_id, &response, 24).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InternalError);
+        assert!(!wallet_dir.exists());
+        assert!(usb.path().is_dir());
+
+        // An ID that would escape the USB is never deleted
+        let err = created_wallet_mnemonic(usb_path, "..", &response, 24).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InternalError);
+        assert!(usb.path().is_dir());
+    }
 }
@@ -68,12 +68,25 @@ pub enum ErrorCode {
     ScreenshotProtectionFailed,
     MemoryClearFailed,
 
+    // Transaction errors
+    InsufficientBalance,
+    TransactionRejected,
+    FeeTooLow,
+    FeeTooHigh,
+    NonceConflict,
+    InvalidRecipientAddress,
+    AddressChecksumMismatch,
+    TransactionNotReplaceable,
+    BroadcastFailed,
+
     // T053: FFI error codes (matching Go error codes)
     FfiInvalidInput,      // INVALID_INPUT from Go
     FfiInvalidBlockchain, // INVALID_BLOCKCHAIN from Go
     FfiStorageError,      // STORAGE_ERROR from Go
     FfiEncryptionError,   // ENCRYPTION_ERROR from Go
     FfiLibraryPanic,      // LIBRARY_PANIC from Go
+    UnsupportedOperation, // Optional FFI symbol missing from the loaded library
+    QueueBusy,            // Wallet queue full; the command was never run
 
     // Internal errors
     InternalError,
@@ -143,7 +156,7 @@ impl AppError {
     }
 
     /// Sanitize error message to remove sensitive information (SEC-008)
-    fn sanitize_message(message: String) -> String {
+    pub(crate) fn sanitize_message(message: String) -> String {
         // Remove potential file paths
         let sanitized = message
             .lines()
@@ -197,12 +210,24 @@ impl AppError {
             ErrorCode::ScreenshotProtectionFailed => "Failed to enable screenshot protection.",
             ErrorCode::MemoryClearFailed => "Failed to clear sensitive data from memory.",
 
+            ErrorCode::InsufficientBalance => "Insufficient balance to cover the amount and network fee.",
+            ErrorCode::TransactionRejected => "The transaction was rejected. Please review the details and try again.",
+            ErrorCode::FeeTooLow => "Network fee is too low. Please choose a higher fee and try again.",
+            ErrorCode::FeeTooHigh => "Network fee is unusually high. Please double-check the fee rate.",
+            ErrorCode::NonceConflict => "A pending transaction is using the same nonce. Wait for it to confirm or replace it.",
+            ErrorCode::InvalidRecipientAddress => "Recipient address is not valid for the selected blockchain.",
+            ErrorCode::AddressChecksumMismatch => "Address checksum does not match. The address may have been mistyped.",
+            ErrorCode::TransactionNotReplaceable => "This transaction can no longer be replaced with a higher fee.",
+            ErrorCode::BroadcastFailed => "Failed to broadcast the transaction. Please check your network connection.",
+
             // T053: FFI error code messages (user-friendly)
             ErrorCode::FfiInvalidInput => "Invalid input provided. Please check your data and try again.",
             ErrorCode::FfiInvalidBlockchain => "Unsupported blockchain. Please select a supported cryptocurrency.",
             ErrorCode::FfiStorageError => "Storage device not accessible. Please ensure your USB drive is properly connected.",
             ErrorCode::FfiEncryptionError => "Encryption operation failed. Your data is secure, but the operation could not complete.",
             ErrorCode::FfiLibraryPanic => "An unexpected error occurred in the wallet library. Please restart the application.",
+            ErrorCode::UnsupportedOperation => "This operation is not supported by the installed wallet library. Please update the application.",
+            ErrorCode::QueueBusy => "Too many wallet operations are pending. Please wait a moment and try again.",
 
             ErrorCode::InternalError => "An internal error occurred. Please contact support.",
             ErrorCode::SerializationError => "Data serialization error.",
@@ -216,6 +241,7 @@ impl AppError {
             // Input validation errors
             "INVALID_INPUT" => ErrorCode::FfiInvalidInput,
             "INVALID_MNEMONIC" => ErrorCode::InvalidMnemonic,
+            "INVALID_CHECKSUM" => ErrorCode::InvalidMnemonicChecksum,
             "INVALID_PASSWORD" => ErrorCode::InvalidPassword,
             "INVALID_BLOCKCHAIN" => ErrorCode::FfiInvalidBlockchain,
 
@@ -227,6 +253,19 @@ impl AppError {
             "STORAGE_ERROR" => ErrorCode::FfiStorageError,
             "ENCRYPTION_ERROR" => ErrorCode::FfiEncryptionError,
             "LIBRARY_PANIC" => ErrorCode::FfiLibraryPanic,
+            "UNSUPPORTED_OPERATION" => ErrorCode::UnsupportedOperation,
+            "QUEUE_BUSY" => ErrorCode::QueueBusy,
+            "TIMEOUT" => ErrorCode::CliTimeout,
+            "SHUTTING_DOWN" => ErrorCode::InternalError,
+
+            // Transaction errors (library codes and chainadapter ChainError codes)
+            "ERR_INSUFFICIENT_FUNDS" => ErrorCode::InsufficientBalance,
+            "ERR_INVALID_TRANSACTION" | "ERR_TX_REVERTED" => ErrorCode::TransactionRejected,
+            "ERR_FEE_TOO_LOW" => ErrorCode::FeeTooLow,
+            "ERR_RBF_REQUIRED" => ErrorCode::TransactionNotReplaceable,
+            "ERR_NONCE_TOO_LOW" | "ERR_TX_ALREADY_BROADCAST" => ErrorCode::NonceConflict,
+            "ERR_INVALID_ADDRESS" => ErrorCode::InvalidRecipientAddress,
+            "TRANSACTION_BROADCAST_FAILED" | "ERR_BROADCAST_FAILED" => ErrorCode::BroadcastFailed,
 
             // Default to internal error for unknown codes
             _ => {
@@ -358,6 +397,30 @@ mod tests {
         assert!(msg.contains("USB"));
     }
 
+    #[test]
+    fn test_transaction_ffi_codes_mapping() {
+        let cases = [
+            ("ERR_INSUFFICIENT_FUNDS", ErrorCode::InsufficientBalance),
+            ("ERR_INVALID_TRANSACTION", ErrorCode::TransactionRejected),
+            ("ERR_TX_REVERTED", ErrorCode::TransactionRejected),
+            ("ERR_FEE_TOO_LOW", ErrorCode::FeeTooLow),
+            ("ERR_RBF_REQUIRED", ErrorCode::TransactionNotReplaceable),
+            ("ERR_NONCE_TOO_LOW", ErrorCode::NonceConflict),
+            ("ERR_TX_ALREADY_BROADCAST", ErrorCode::NonceConflict),
+            ("ERR_INVALID_ADDRESS", ErrorCode::InvalidRecipientAddress),
+            ("TRANSACTION_BROADCAST_FAILED", ErrorCode::BroadcastFailed),
+            ("ERR_BROADCAST_FAILED", ErrorCode::BroadcastFailed),
+        ];
+
+        for (ffi_code, expected) in cases {
+            assert_eq!(AppError::from_ffi_error_code(ffi_code), expected, "{}", ffi_code);
+            assert!(!AppError::default_message_for_code(expected).is_empty());
+        }
+
+        let json = serde_json::to_string(&ErrorCode::InvalidRecipientAddress).unwrap();
+        assert_eq!(json, "\"INVALID_RECIPIENT_ADDRESS\"");
+    }
+
     #[test]
     fn test_error_from_io_error() {
         let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
@@ -0,0 +1,105 @@
+//! Ethereum address helpers.
+//!
+//! EIP-55 mixed-case checksum verification and normalization for EVM chains.
+
+use crate::error::{AppError, AppResult, ErrorCode};
+use sha3::{Digest, Keccak256};
+
+/// Return the 40 hex digits of a `0x`-prefixed address, or None if malformed
+fn hex_body(address: &str) -> Option<&str> {
+    address
+        .strip_prefix("0x")
+        .filter(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Encode an address with its EIP-55 checksum casing.
+///
+/// Returns None if the input is not `0x` + 40 hex digits.
+pub fn to_checksum_address(address: &str) -> Option<String> {
+    let lower = hex_body(address)?.to_ascii_lowercase();
+    let hash = Keccak256::digest(lower.as_bytes());
+
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            // Uppercase a letter when the matching hash nibble is >= 8
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    Some(format!("0x{}", checksummed))
+}
+
+/// Verify and normalize an address to its EIP-55 checksummed form.
+///
+/// - All-lowercase or all-uppercase input carries no checksum and is
+///   converted to checksummed form.
+/// - Mixed-case input must match its checksum exactly; a mismatch usually
+///   means a mistyped address, so it is rejected rather than corrected.
+pub fn normalize(address: &str) -> AppResult<String> {
+    let hex = hex_body(address).ok_or_else(|| {
+        AppError::new(
+            ErrorCode::InvalidRecipientAddress,
+            "Ethereum address must be 0x followed by 40 hexadecimal characters",
+        )
+    })?;
+
+    let checksummed = to_checksum_address(address).unwrap_or_default();
+
+    let has_lower = hex.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex.chars().any(|c| c.is_ascii_uppercase());
+
+    if has_lower && has_upper && address != checksummed {
+        return Err(AppError::new(
+            ErrorCode::AddressChecksumMismatch,
+            "Address checksum does not match. Please re-copy the address from its source.",
+        ));
+    }
+
+    Ok(checksummed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from EIP-55
+    const CHECKSUMMED: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+    #[test]
+    fn test_valid_checksum_is_accepted() {
+        assert_eq!(normalize(CHECKSUMMED).unwrap(), CHECKSUMMED);
+        assert_eq!(
+            to_checksum_address("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359").unwrap(),
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+        );
+    }
+
+    #[test]
+    fn test_invalid_checksum_is_rejected() {
+        // Last letter's case flipped
+        let err = normalize("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").unwrap_err();
+        assert_eq!(err.code, ErrorCode::AddressChecksumMismatch);
+    }
+
+    #[test]
+    fn test_lowercase_and_uppercase_are_normalized() {
+        assert_eq!(normalize(&CHECKSUMMED.to_lowercase()).unwrap(), CHECKSUMMED);
+
+        let upper = format!("0x{}", CHECKSUMMED[2..].to_uppercase());
+        assert_eq!(normalize(&upper).unwrap(), CHECKSUMMED);
+    }
+
+    #[test]
+    fn test_malformed_address_is_rejected() {
+        let err = normalize("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidRecipientAddress);
+        assert!(to_checksum_address("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_none());
+    }
+}
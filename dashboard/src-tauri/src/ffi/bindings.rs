@@ -9,12 +9,15 @@
 //! Created: 2025-10-25
 
 use libloading::{Library, Symbol};
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
-use std::os::raw::c_char;
-use std::sync::Arc;
+use std::os::raw::{c_char, c_int};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use serde::de::DeserializeOwned;
 
-use super::types::{FFIResponse, FFIError};
+use super::types::{FFIResponse, FFIError, VersionInfo};
 
 // ============================================================================
 // Extern "C" Function Type Definitions (T013)
@@ -87,6 +90,160 @@ type InitializeAppFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
 /// Function signature for UnlockApp: char* UnlockApp(char* params)
 type UnlockAppFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
 
+// Optional progress-reporting function types (not exported by every library build)
+/// Progress callback passed to Go: void (*)(int completed, int total, uintptr_t token)
+pub type ProgressCallbackFn = unsafe extern "C" fn(c_int, c_int, usize);
+
+/// Function signature for CreateWalletWithProgress and ImportWalletWithProgress:
+/// char* CreateWalletWithProgress(char* params, progress_cb callback, uintptr_t token)
+type WalletWithProgressFn = unsafe extern "C" fn(*const c_char, ProgressCallbackFn, usize) -> *mut c_char;
+
+/// Function signature for ValidateTransaction: char* ValidateTransaction(char* params)
+type ValidateTransactionFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for SignMessage: char* SignMessage(char* params)
+type SignMessageFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for VerifyMessage: char* VerifyMessage(char* params)
+type VerifyMessageFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for DeriveFirstAddress: char* DeriveFirstAddress(char* params)
+type DeriveFirstAddressFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for ChangeWalletPassword: char* ChangeWalletPassword(char* params)
+type ChangeWalletPasswordFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for ExportDescriptor: char* ExportDescriptor(char* params)
+type ExportDescriptorFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for BuildTransactionMulti: char* BuildTransactionMulti(char* params)
+type BuildTransactionMultiFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for TestProviderConnection: char* TestProviderConnection(char* params)
+type TestProviderConnectionFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for GetNonce: char* GetNonce(char* params)
+type GetNonceFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+/// Function signature for ResolveProviderEndpoint: char* ResolveProviderEndpoint(char* params)
+type ResolveProviderEndpointFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+
+// ============================================================================
+// Progress Callbacks (Go -> Rust)
+// ============================================================================
+
+/// Rust-side handler for progress reports: (completed, total)
+pub type ProgressFn = Arc<dyn Fn(u32, u32) + Send + Sync>;
+
+/// Handlers for in-flight FFI calls, keyed by the token handed to Go.
+///
+/// Go only ever sees an opaque integer token, never a Rust pointer, so a
+/// callback that arrives late (after the call returned) or from another
+/// thread can't touch freed memory: it simply finds no handler.
+fn progress_registry() -> &'static Mutex<HashMap<usize, ProgressFn>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<usize, ProgressFn>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Registration of a progress handler; unregisters on drop
+struct ProgressRegistration(usize);
+
+impl ProgressRegistration {
+    fn new(handler: ProgressFn) -> Self {
+        static NEXT_TOKEN: AtomicUsize = AtomicUsize::new(1);
+        let token = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
+
+        progress_registry()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(token, handler);
+
+        Self(token)
+    }
+}
+
+impl Drop for ProgressRegistration {
+    fn drop(&mut self) {
+        progress_registry()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.0);
+    }
+}
+
+/// C-compatible trampoline invoked by Go for each progress report.
+///
+/// Thread-safety contract:
+/// - Go may call this from any thread, but only while the originating
+///   FFI call is still running; later calls are ignored.
+/// - The handler runs on the calling (Go) thread, so it must be cheap and
+///   must not call back into the library.
+/// - A panicking handler is caught here: unwinding into Go is undefined
+///   behavior. (Release builds use `panic = "abort"`, so this guard only
+///   matters for unwinding builds such as debug and test.)
+unsafe extern "C" fn progress_trampoline(completed: c_int, total: c_int, token: usize) {
+    let result = std::panic::catch_unwind(|| {
+        // Clone the handler so the registry lock isn't held while it runs
+        let handler = progress_registry()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&token)
+            .cloned();
+
+        match handler {
+            Some(handler) => handler(completed.max(0) as u32, total.max(0) as u32),
+            None => tracing::warn!("Ignoring progress report for inactive token {}", token),
+        }
+    });
+
+    if result.is_err() {
+        tracing::error!("Progress handler panicked; report dropped");
+    }
+}
+
+/// Longest lossy excerpt quoted in an invalid-UTF-8 error
+const MAX_LOSSY_EXCERPT_CHARS: usize = 200;
+
+/// Readable error for FFI output that isn't valid UTF-8.
+///
+/// Decoded lossily (bad bytes become U+FFFD) for diagnostics only. An error
+/// envelope still yields its "CODE: message"; success data is never quoted,
+/// since it may carry wallet material and can't be trusted anyway.
+fn invalid_utf8_error(source: &str, bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+
+    match serde_json::from_str::<FFIResponse<serde_json::Value>>(&text) {
+        Ok(FFIResponse { success: false, error: Some(err), .. }) => {
+            format!("{}: {}", err.code, err.message)
+        }
+        Ok(_) => format!("Invalid UTF-8 in {} response data", source),
+        Err(_) => {
+            let excerpt: String = text.chars().take(MAX_LOSSY_EXCERPT_CHARS).collect();
+            format!("Invalid UTF-8 from {}: {}", source, excerpt)
+        }
+    }
+}
+
+/// Resolve a per-user directory for library lookup.
+///
+/// Prefers `env_value`, the value of `env_var` (so it can be overridden),
+/// then falls back to `dirs`, which also works when the variable is unset
+/// (sandboxes, services). Logs when neither source yields a directory.
+fn user_dir(env_var: &str, env_value: Option<String>, fallback: fn() -> Option<PathBuf>) -> Option<String> {
+    let dir = env_value
+        .filter(|value| !value.is_empty())
+        .or_else(|| fallback().map(|dir| dir.to_string_lossy().into_owned()));
+
+    if dir.is_none() {
+        tracing::warn!(
+            "No user directory could be determined ({} unset); skipping user library path",
+            env_var
+        );
+    }
+
+    dir
+}
+
 // ============================================================================
 // WalletLibrary - Dynamic Library Wrapper (T016, T017)
 // ============================================================================
@@ -102,34 +259,167 @@ type UnlockAppFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
 /// - Library is Send + Sync (wrapped in Arc for sharing)
 /// - Actual wallet operations serialized through WalletQueue
 pub struct WalletLibrary {
-    lib: Arc<Library>,
-    go_free: Symbol<'static, GoFreeFn>,
-    get_version: Symbol<'static, GetVersionFn>,
+    /// Keeps the shared library mapped while its function pointers are in use
+    /// (None for in-process test stubs)
+    pub(crate) lib: Option<Arc<Library>>,
+    /// Search path the library was loaded from (None for test stubs)
+    pub(crate) path: Option<String>,
+    pub(crate) go_free: GoFreeFn,
+    pub(crate) get_version: GetVersionFn,
     // T027: Wallet operation function symbols
-    create_wallet: Symbol<'static, CreateWalletFn>,
-    import_wallet: Symbol<'static, ImportWalletFn>,
-    unlock_wallet: Symbol<'static, UnlockWalletFn>,
-    generate_addresses: Symbol<'static, GenerateAddressesFn>,
-    export_wallet: Symbol<'static, ExportWalletFn>,
-    rename_wallet: Symbol<'static, RenameWalletFn>,
-    list_wallets: Symbol<'static, ListWalletsFn>,
+    pub(crate) create_wallet: CreateWalletFn,
+    pub(crate) import_wallet: ImportWalletFn,
+    pub(crate) unlock_wallet: UnlockWalletFn,
+    pub(crate) generate_addresses: GenerateAddressesFn,
+    pub(crate) export_wallet: ExportWalletFn,
+    pub(crate) rename_wallet: RenameWalletFn,
+    pub(crate) list_wallets: ListWalletsFn,
     // ChainAdapter transaction function symbols
-    build_transaction: Symbol<'static, BuildTransactionFn>,
-    sign_transaction: Symbol<'static, SignTransactionFn>,
-    broadcast_transaction: Symbol<'static, BroadcastTransactionFn>,
-    query_transaction_status: Symbol<'static, QueryTransactionStatusFn>,
-    estimate_fee: Symbol<'static, EstimateFeeFn>,
+    pub(crate) build_transaction: BuildTransactionFn,
+    pub(crate) sign_transaction: SignTransactionFn,
+    pub(crate) broadcast_transaction: BroadcastTransactionFn,
+    pub(crate) query_transaction_status: QueryTransactionStatusFn,
+    pub(crate) estimate_fee: EstimateFeeFn,
     // Provider configuration function symbols
-    set_provider_config: Symbol<'static, SetProviderConfigFn>,
-    get_provider_config: Symbol<'static, GetProviderConfigFn>,
-    list_provider_configs: Symbol<'static, ListProviderConfigsFn>,
-    delete_provider_config: Symbol<'static, DeleteProviderConfigFn>,
+    pub(crate) set_provider_config: SetProviderConfigFn,
+    pub(crate) get_provider_config: GetProviderConfigFn,
+    pub(crate) list_provider_configs: ListProviderConfigsFn,
+    pub(crate) delete_provider_config: DeleteProviderConfigFn,
     // App-level authentication function symbols
-    is_first_time_setup: Symbol<'static, IsFirstTimeSetupFn>,
-    initialize_app: Symbol<'static, InitializeAppFn>,
-    unlock_app: Symbol<'static, UnlockAppFn>,
+    pub(crate) is_first_time_setup: IsFirstTimeSetupFn,
+    pub(crate) initialize_app: InitializeAppFn,
+    pub(crate) unlock_app: UnlockAppFn,
+    // Optional symbols (None if this library build doesn't export them)
+    pub(crate) create_wallet_with_progress: Option<WalletWithProgressFn>,
+    pub(crate) import_wallet_with_progress: Option<WalletWithProgressFn>,
+    pub(crate) validate_transaction: Option<ValidateTransactionFn>,
+    pub(crate) sign_message: Option<SignMessageFn>,
+    pub(crate) verify_message: Option<VerifyMessageFn>,
+    pub(crate) derive_first_address: Option<DeriveFirstAddressFn>,
+    pub(crate) change_wallet_password: Option<ChangeWalletPasswordFn>,
+    pub(crate) export_descriptor: Option<ExportDescriptorFn>,
+    pub(crate) build_transaction_multi: Option<BuildTransactionMultiFn>,
+    pub(crate) test_provider_connection: Option<TestProviderConnectionFn>,
+    pub(crate) get_nonce: Option<GetNonceFn>,
+    pub(crate) resolve_provider_endpoint: Option<ResolveProviderEndpointFn>,
+    /// Leak accounting for returned strings
+    pub(crate) allocations: FfiAllocations,
+}
+
+impl std::fmt::Debug for WalletLibrary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WalletLibrary")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Bytes of Go-allocated strings copied into Rust vs. released with GoFree.
+///
+/// Calls are serialized through the wallet queue, so every string is freed
+/// before the next one arrives and the two totals only differ if a path
+/// skipped GoFree. Counting is compiled into debug builds only.
+#[derive(Debug, Default)]
+pub(crate) struct FfiAllocations {
+    #[cfg(debug_assertions)]
+    received: std::sync::atomic::AtomicU64,
+    #[cfg(debug_assertions)]
+    freed: std::sync::atomic::AtomicU64,
+}
+
+impl FfiAllocations {
+    fn record_received(&self, _len: usize) {
+        #[cfg(debug_assertions)]
+        self.received.fetch_add(_len as u64, Ordering::Relaxed);
+    }
+
+    /// Record a release and warn if anything received was never freed
+    fn record_freed(&self, _len: usize) {
+        #[cfg(debug_assertions)]
+        {
+            self.freed.fetch_add(_len as u64, Ordering::Relaxed);
+            let outstanding = self.outstanding_bytes();
+            if outstanding != 0 {
+                tracing::warn!("FFI strings not freed: {} bytes received but never released", outstanding);
+            }
+        }
+    }
+
+    /// Bytes received but not yet freed
+    #[cfg(debug_assertions)]
+    pub(crate) fn outstanding_bytes(&self) -> u64 {
+        self.received
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.freed.load(Ordering::Relaxed))
+    }
+}
+
+/// A string returned by the Go library; freed with GoFree when dropped, so
+/// no early return (bad UTF-8, bad JSON) can leak it
+struct GoString<'a> {
+    library: &'a WalletLibrary,
+    ptr: *mut c_char,
+    len: usize,
+}
+
+impl<'a> GoString<'a> {
+    /// Safety: `ptr` must be a non-null string allocated by the Go library
+    /// and not used after this guard drops.
+    unsafe fn new(library: &'a WalletLibrary, ptr: *mut c_char) -> Self {
+        let len = CStr::from_ptr(ptr).to_bytes().len();
+        library.allocations.record_received(len);
+        Self { library, ptr, len }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // Safety: `ptr` points at `len` bytes that live until drop
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+impl Drop for GoString<'_> {
+    fn drop(&mut self) {
+        unsafe { (self.library.go_free)(self.ptr) };
+        self.library.allocations.record_freed(self.len);
+    }
+}
+
+/// Loader messages for a library built for another CPU architecture
+const ARCH_MISMATCH_MARKERS: &[&str] = &[
+    // macOS dlopen: "... (mach-o file, but is an incompatible architecture (have 'x86_64', need 'arm64'))"
+    "incompatible architecture",
+    // Linux glibc: "wrong ELF class: ELFCLASS32"
+    "wrong ELF class",
+    // Windows ERROR_BAD_EXE_FORMAT: "%1 is not a valid Win32 application. (os error 193)"
+    "not a valid Win32 application",
+    "os error 193",
+];
+
+/// True if a `Library::new` error means the file is built for another architecture
+fn is_arch_mismatch(error: &str) -> bool {
+    ARCH_MISMATCH_MARKERS.iter().any(|marker| error.contains(marker))
+}
+
+/// Architecture of this app, in the loader's terms ("arm64" rather than "aarch64")
+fn host_arch() -> &'static str {
+    match std::env::consts::ARCH {
+        "aarch64" => "arm64",
+        arch => arch,
+    }
+}
+
+fn arch_mismatch_message(path: &str) -> String {
+    format!(
+        "Wallet library architecture does not match this app (expected {}): {}. \
+         Install the library built for this machine.",
+        host_arch(),
+        path
+    )
 }
 
+/// Library version this build of the dashboard was tested against (T040)
+pub const EXPECTED_LIBRARY_VERSION: &str = "0.2.0";
+
 impl WalletLibrary {
     /// Load the shared library from the platform-specific path.
     ///
@@ -147,36 +437,42 @@ impl WalletLibrary {
     /// - Ok(WalletLibrary) if library loaded and symbols cached successfully
     /// - Err(String) if library not found or symbols missing
     pub fn load() -> Result<Self, String> {
-        unsafe {
-            // Determine library name based on platform
-            #[cfg(target_os = "windows")]
-            let lib_name = "libarcsign.dll";
-
-            #[cfg(target_os = "macos")]
-            let lib_name = "libarcsign.dylib";
-
-            #[cfg(target_os = "linux")]
-            let lib_name = "libarcsign.so";
+        // T047, T048: Platform-specific search paths
+        Self::load_from(&Self::search_paths())
+    }
 
-            // T047, T048: Platform-specific search paths
-            let search_paths = Self::get_search_paths(lib_name);
+    /// Load the shared library from the first of `search_paths` that opens.
+    pub fn load_from(search_paths: &[String]) -> Result<Self, String> {
+        unsafe {
+            let lib_name = Self::library_name();
 
             // Try loading from each search path in order
             let mut last_error = String::new();
-            let lib = 'search: {
-                for path in &search_paths {
+            let mut arch_mismatch = None;
+            let (lib, lib_path) = 'search: {
+                for path in search_paths {
                     match Library::new(path) {
                         Ok(lib) => {
                             tracing::info!("Loaded wallet library from: {}", path);
-                            break 'search lib;
+                            break 'search (lib, path.clone());
                         }
                         Err(e) => {
                             tracing::debug!("Failed to load from {}: {}", path, e);
-                            last_error = format!("{}: {}", path, e);
+                            let error = e.to_string();
+                            if arch_mismatch.is_none() && is_arch_mismatch(&error) {
+                                arch_mismatch = Some(path.clone());
+                            }
+                            last_error = format!("{}: {}", path, error);
                         }
                     }
                 }
 
+                // A wrong-architecture build explains the failure better than
+                // "not found" from the paths searched after it
+                if let Some(path) = arch_mismatch {
+                    return Err(arch_mismatch_message(&path));
+                }
+
                 // If we get here, all paths failed
                 return Err(format!(
                     "Failed to load {} from any search path. Last error: {}",
@@ -273,31 +569,77 @@ impl WalletLibrary {
                 .get(b"UnlockApp")
                 .map_err(|e| format!("UnlockApp symbol not found: {}", e))?;
 
-            // Extend symbol lifetime to 'static (safe because Library lives for program duration)
-            let go_free: Symbol<'static, GoFreeFn> = std::mem::transmute(go_free);
-            let get_version: Symbol<'static, GetVersionFn> = std::mem::transmute(get_version);
-            let create_wallet: Symbol<'static, CreateWalletFn> = std::mem::transmute(create_wallet);
-            let import_wallet: Symbol<'static, ImportWalletFn> = std::mem::transmute(import_wallet);
-            let unlock_wallet: Symbol<'static, UnlockWalletFn> = std::mem::transmute(unlock_wallet);
-            let generate_addresses: Symbol<'static, GenerateAddressesFn> = std::mem::transmute(generate_addresses);
-            let export_wallet: Symbol<'static, ExportWalletFn> = std::mem::transmute(export_wallet);
-            let rename_wallet: Symbol<'static, RenameWalletFn> = std::mem::transmute(rename_wallet);
-            let list_wallets: Symbol<'static, ListWalletsFn> = std::mem::transmute(list_wallets);
-            let build_transaction: Symbol<'static, BuildTransactionFn> = std::mem::transmute(build_transaction);
-            let sign_transaction: Symbol<'static, SignTransactionFn> = std::mem::transmute(sign_transaction);
-            let broadcast_transaction: Symbol<'static, BroadcastTransactionFn> = std::mem::transmute(broadcast_transaction);
-            let query_transaction_status: Symbol<'static, QueryTransactionStatusFn> = std::mem::transmute(query_transaction_status);
-            let estimate_fee: Symbol<'static, EstimateFeeFn> = std::mem::transmute(estimate_fee);
-            let set_provider_config: Symbol<'static, SetProviderConfigFn> = std::mem::transmute(set_provider_config);
-            let get_provider_config: Symbol<'static, GetProviderConfigFn> = std::mem::transmute(get_provider_config);
-            let list_provider_configs: Symbol<'static, ListProviderConfigsFn> = std::mem::transmute(list_provider_configs);
-            let delete_provider_config: Symbol<'static, DeleteProviderConfigFn> = std::mem::transmute(delete_provider_config);
-            let is_first_time_setup: Symbol<'static, IsFirstTimeSetupFn> = std::mem::transmute(is_first_time_setup);
-            let initialize_app: Symbol<'static, InitializeAppFn> = std::mem::transmute(initialize_app);
-            let unlock_app: Symbol<'static, UnlockAppFn> = std::mem::transmute(unlock_app);
-
+            // Copy raw function pointers out of the symbols (valid for as long as `lib` stays loaded,
+            // which the Arc stored in WalletLibrary guarantees)
+            let go_free: GoFreeFn = *go_free;
+            let get_version: GetVersionFn = *get_version;
+            let create_wallet: CreateWalletFn = *create_wallet;
+            let import_wallet: ImportWalletFn = *import_wallet;
+            let unlock_wallet: UnlockWalletFn = *unlock_wallet;
+            let generate_addresses: GenerateAddressesFn = *generate_addresses;
+            let export_wallet: ExportWalletFn = *export_wallet;
+            let rename_wallet: RenameWalletFn = *rename_wallet;
+            let list_wallets: ListWalletsFn = *list_wallets;
+            let build_transaction: BuildTransactionFn = *build_transaction;
+            let sign_transaction: SignTransactionFn = *sign_transaction;
+            let broadcast_transaction: BroadcastTransactionFn = *broadcast_transaction;
+            let query_transaction_status: QueryTransactionStatusFn = *query_transaction_status;
+            let estimate_fee: EstimateFeeFn = *estimate_fee;
+            let set_provider_config: SetProviderConfigFn = *set_provider_config;
+            let get_provider_config: GetProviderConfigFn = *get_provider_config;
+            let list_provider_configs: ListProviderConfigsFn = *list_provider_configs;
+            let delete_provider_config: DeleteProviderConfigFn = *delete_provider_config;
+            let is_first_time_setup: IsFirstTimeSetupFn = *is_first_time_setup;
+            let initialize_app: InitializeAppFn = *initialize_app;
+            let unlock_app: UnlockAppFn = *unlock_app;
+
+            // Optional symbols: older library builds don't export these
+            let create_wallet_with_progress = lib
+                .get::<WalletWithProgressFn>(b"CreateWalletWithProgress")
+                .map(|symbol| *symbol)
+                .ok();
+            let import_wallet_with_progress = lib
+                .get::<WalletWithProgressFn>(b"ImportWalletWithProgress")
+                .map(|symbol| *symbol)
+                .ok();
+            let validate_transaction = lib
+                .get::<ValidateTransactionFn>(b"ValidateTransaction")
+                .map(|symbol| *symbol)
+                .ok();
+
+            let sign_message = lib.get::<SignMessageFn>(b"SignMessage").map(|symbol| *symbol).ok();
+            let verify_message = lib
+                .get::<VerifyMessageFn>(b"VerifyMessage")
+                .map(|symbol| *symbol)
+                .ok();
+            let derive_first_address = lib
+                .get::<DeriveFirstAddressFn>(b"DeriveFirstAddress")
+                .map(|symbol| *symbol)
+                .ok();
+            let change_wallet_password = lib
+                .get::<ChangeWalletPasswordFn>(b"ChangeWalletPassword")
+                .map(|symbol| *symbol)
+                .ok();
+            let export_descriptor = lib
+                .get::<ExportDescriptorFn>(b"ExportDescriptor")
+                .map(|symbol| *symbol)
+                .ok();
+            let build_transaction_multi = lib
+                .get::<BuildTransactionMultiFn>(b"BuildTransactionMulti")
+                .map(|symbol| *symbol)
+                .ok();
+            let test_provider_connection = lib
+                .get::<TestProviderConnectionFn>(b"TestProviderConnection")
+                .map(|symbol| *symbol)
+                .ok();
+            let get_nonce = lib.get::<GetNonceFn>(b"GetNonce").map(|symbol| *symbol).ok();
+            let resolve_provider_endpoint = lib
+                .get::<ResolveProviderEndpointFn>(b"ResolveProviderEndpoint")
+                .map(|symbol| *symbol)
+                .ok();
             Ok(WalletLibrary {
-                lib: Arc::new(lib),
+                lib: Some(Arc::new(lib)),
+                path: Some(lib_path),
                 go_free,
                 get_version,
                 create_wallet,
@@ -319,10 +661,58 @@ impl WalletLibrary {
                 is_first_time_setup,
                 initialize_app,
                 unlock_app,
+                create_wallet_with_progress,
+                import_wallet_with_progress,
+                validate_transaction,
+                sign_message,
+                verify_message,
+                derive_first_address,
+                change_wallet_password,
+                export_descriptor,
+                build_transaction_multi,
+                test_provider_connection,
+                get_nonce,
+                resolve_provider_endpoint,
+                allocations: FfiAllocations::default(),
             })
         }
     }
 
+    /// Platform-specific file name of the wallet library
+    pub fn library_name() -> &'static str {
+        #[cfg(target_os = "windows")]
+        let lib_name = "libarcsign.dll";
+
+        #[cfg(target_os = "macos")]
+        let lib_name = "libarcsign.dylib";
+
+        #[cfg(target_os = "linux")]
+        let lib_name = "libarcsign.so";
+
+        lib_name
+    }
+
+    /// Paths `load()` tries, in order
+    pub fn search_paths() -> Vec<String> {
+        Self::get_search_paths(Self::library_name(), Self::user_library_root().as_deref())
+    }
+
+    /// Per-user directory the user library path is under: APPDATA on
+    /// Windows, the home directory elsewhere
+    fn user_library_root() -> Option<String> {
+        #[cfg(target_os = "windows")]
+        let (env_var, fallback): (&str, fn() -> Option<PathBuf>) = ("APPDATA", dirs::data_dir);
+        #[cfg(not(target_os = "windows"))]
+        let (env_var, fallback): (&str, fn() -> Option<PathBuf>) = ("HOME", dirs::home_dir);
+
+        user_dir(env_var, std::env::var(env_var).ok(), fallback)
+    }
+
+    /// Path the library was loaded from (None for test stubs)
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
     /// Get platform-specific search paths for the wallet library (T047, T048).
     ///
     /// Search order (highest priority first):
@@ -330,8 +720,11 @@ impl WalletLibrary {
     /// 2. User-specific application directory
     /// 3. System-wide application directory
     ///
+    /// `user_root` is the directory from `user_library_root`; the user path
+    /// is skipped when it is None.
+    ///
     /// Returns: Vec<String> of absolute paths to try
-    fn get_search_paths(lib_name: &str) -> Vec<String> {
+    fn get_search_paths(lib_name: &str, user_root: Option<&str>) -> Vec<String> {
         let mut paths = Vec::new();
 
         // Priority 1: Current directory (for development and bundled apps)
@@ -346,7 +739,7 @@ impl WalletLibrary {
         {
             // T047: Windows search paths
             // User AppData: C:\Users\<username>\AppData\Roaming\arcSign
-            if let Ok(appdata) = std::env::var("APPDATA") {
+            if let Some(appdata) = user_root {
                 paths.push(format!("{}\\arcSign\\{}", appdata, lib_name));
             }
 
@@ -365,7 +758,7 @@ impl WalletLibrary {
         {
             // T048: macOS search paths
             // User Library: ~/Library/Application Support/arcSign
-            if let Ok(home) = std::env::var("HOME") {
+            if let Some(home) = user_root {
                 paths.push(format!("{}/Library/Application Support/arcSign/{}", home, lib_name));
             }
 
@@ -380,7 +773,7 @@ impl WalletLibrary {
         {
             // T048: Linux search paths
             // User-specific: ~/.local/lib/arcsign
-            if let Ok(home) = std::env::var("HOME") {
+            if let Some(home) = user_root {
                 paths.push(format!("{}/.local/lib/arcsign/{}", home, lib_name));
             }
 
@@ -417,15 +810,8 @@ impl WalletLibrary {
                 return Err("GetVersion returned null pointer".to_string());
             }
 
-            // Copy C string to Rust (before freeing)
-            let result_cstr = CStr::from_ptr(result_ptr);
-            let result_json = result_cstr
-                .to_str()
-                .map_err(|e| format!("Invalid UTF-8 from GetVersion: {}", e))?
-                .to_string();
-
-            // CRITICAL: Free Go-allocated memory
-            (self.go_free)(result_ptr);
+            // Copy C string to Rust and free Go-allocated memory
+            let result_json = self.take_ffi_string(result_ptr, "GetVersion")?;
 
             // Parse JSON response
             let response: FFIResponse<serde_json::Value> = serde_json::from_str(&result_json)
@@ -443,6 +829,31 @@ impl WalletLibrary {
         }
     }
 
+    /// Check the library reports a readable version.
+    ///
+    /// A version other than `EXPECTED_LIBRARY_VERSION` is only logged; the
+    /// library may still work. Fails if the version can't be read, which
+    /// usually means a corrupted library.
+    pub fn check_version(&self) -> Result<VersionInfo, String> {
+        let version_info = self.version_info()?;
+        if version_info.version != EXPECTED_LIBRARY_VERSION {
+            tracing::warn!(
+                "Library version mismatch: expected {}, got {}",
+                EXPECTED_LIBRARY_VERSION,
+                version_info.version
+            );
+        }
+        Ok(version_info)
+    }
+
+    /// Get the library version as a typed `VersionInfo`.
+    ///
+    /// Fails if the response has no `version` field.
+    pub fn version_info(&self) -> Result<VersionInfo, String> {
+        let data = self.get_version()?;
+        serde_json::from_value(data).map_err(|e| format!("Invalid GetVersion response: {}", e))
+    }
+
     /// Generic helper for calling FFI functions that return JSON.
     ///
     /// This pattern will be reused for all wallet operations in Phase 3.
@@ -464,13 +875,7 @@ impl WalletLibrary {
                 return Err("FFI function returned null pointer".to_string());
             }
 
-            let result_cstr = CStr::from_ptr(result_ptr);
-            let result_json = result_cstr
-                .to_str()
-                .map_err(|e| format!("Invalid UTF-8: {}", e))?
-                .to_string();
-
-            (self.go_free)(result_ptr);
+            let result_json = self.take_ffi_string(result_ptr, "FFI function")?;
 
             // Parse as generic Value first, then extract data
             let response: FFIResponse<serde_json::Value> = serde_json::from_str(&result_json)
@@ -511,33 +916,102 @@ impl WalletLibrary {
 
             let result_ptr = ffi_fn(params_cstr.as_ptr());
 
-            if result_ptr.is_null() {
-                return Err("FFI function returned null pointer".to_string());
-            }
+            self.take_ffi_response(result_ptr)
+        }
+    }
 
-            let result_cstr = CStr::from_ptr(result_ptr);
-            let result_json = result_cstr
-                .to_str()
-                .map_err(|e| format!("Invalid UTF-8: {}", e))?
-                .to_string();
+    /// Helper for FFI operations that report success without a data payload.
+    ///
+    /// Returns `Ok(())` for any `success: true` envelope, with or without
+    /// `data`. Use `call_ffi_with_params` for operations whose result is needed.
+    fn call_ffi_unit(
+        &self,
+        ffi_fn: unsafe extern "C" fn(*const c_char) -> *mut c_char,
+        params_json: &str,
+    ) -> Result<(), String> {
+        unsafe {
+            let params_cstr = CString::new(params_json)
+                .map_err(|e| format!("Invalid params JSON: {}", e))?;
 
-            (self.go_free)(result_ptr);
+            let result_ptr = ffi_fn(params_cstr.as_ptr());
 
-            // Parse as generic Value first, then extract data
-            let response: FFIResponse<serde_json::Value> = serde_json::from_str(&result_json)
-                .map_err(|e| format!("JSON parse error: {}", e))?;
+            self.take_ffi_envelope(result_ptr).map(|_| ())
+        }
+    }
 
-            if response.success {
-                let data_value = response.data.ok_or_else(|| "Success response missing data".to_string())?;
-                serde_json::from_value(data_value)
-                    .map_err(|e| format!("Failed to deserialize data: {}", e))
-            } else {
-                let err = response.error.unwrap_or(FFIError {
-                    code: "UNKNOWN".to_string(),
-                    message: "Unknown error".to_string(),
-                });
-                Err(format!("{}: {}", err.code, err.message))
-            }
+    /// Call an optional symbol, reporting UNSUPPORTED_OPERATION if it wasn't exported.
+    fn call_optional_ffi<T: DeserializeOwned>(
+        &self,
+        ffi_fn: Option<unsafe extern "C" fn(*const c_char) -> *mut c_char>,
+        symbol: &str,
+        params_json: &str,
+    ) -> Result<T, String> {
+        match ffi_fn {
+            Some(ffi_fn) => self.call_ffi_with_params(ffi_fn, params_json),
+            None => Err(format!(
+                "UNSUPPORTED_OPERATION: {} is not available in this wallet library version",
+                symbol
+            )),
+        }
+    }
+
+    /// Parse the FFIResponse envelope returned by a Go function and free it.
+    ///
+    /// Safety:
+    /// - `result_ptr` must be null or a string allocated by the Go library
+    /// - The pointer must not be used after this call
+    unsafe fn take_ffi_response<T: DeserializeOwned>(
+        &self,
+        result_ptr: *mut c_char,
+    ) -> Result<T, String> {
+        let data_value = self
+            .take_ffi_envelope(result_ptr)?
+            .ok_or_else(|| "Success response missing data".to_string())?;
+        serde_json::from_value(data_value)
+            .map_err(|e| format!("Failed to deserialize data: {}", e))
+    }
+
+    /// Copy a non-null FFI result string into Rust and free it.
+    ///
+    /// The JSON must be valid UTF-8, but a bad byte (e.g. from a filesystem
+    /// name embedded in an error) shouldn't hide what the library reported:
+    /// see `invalid_utf8_error`.
+    ///
+    /// The pointer is freed whether or not the conversion succeeds.
+    ///
+    /// Safety: same contract as `take_ffi_response`.
+    unsafe fn take_ffi_string(&self, result_ptr: *mut c_char, source: &str) -> Result<String, String> {
+        let bytes = GoString::new(self, result_ptr).bytes().to_vec();
+
+        String::from_utf8(bytes).map_err(|e| invalid_utf8_error(source, e.as_bytes()))
+    }
+
+    /// Parse and free an FFIResponse envelope, returning its optional `data`
+    /// on success and "CODE: message" on failure.
+    ///
+    /// Safety: same contract as `take_ffi_response`.
+    unsafe fn take_ffi_envelope(
+        &self,
+        result_ptr: *mut c_char,
+    ) -> Result<Option<serde_json::Value>, String> {
+        if result_ptr.is_null() {
+            return Err("FFI function returned null pointer".to_string());
+        }
+
+        let result_json = self.take_ffi_string(result_ptr, "FFI function")?;
+
+        // Parse as generic Value first, then extract data
+        let response: FFIResponse<serde_json::Value> = serde_json::from_str(&result_json)
+            .map_err(|e| format!("JSON parse error: {}", e))?;
+
+        if response.success {
+            Ok(response.data)
+        } else {
+            let err = response.error.unwrap_or(FFIError {
+                code: "UNKNOWN".to_string(),
+                message: "Unknown error".to_string(),
+            });
+            Err(format!("{}: {}", err.code, err.message))
         }
     }
 
@@ -547,37 +1021,206 @@ impl WalletLibrary {
 
     /// Create a new HD wallet from provided mnemonic.
     pub fn create_wallet(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.create_wallet, params_json)
+        self.call_ffi_with_params(self.create_wallet, params_json)
     }
 
     /// Import an existing wallet from mnemonic.
     pub fn import_wallet(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.import_wallet, params_json)
+        self.call_ffi_with_params(self.import_wallet, params_json)
+    }
+
+    /// Create a wallet, reporting progress as each of its addresses is derived.
+    ///
+    /// Uses `CreateWalletWithProgress` when the library exports it and falls
+    /// back to plain `CreateWallet` (no progress) otherwise.
+    pub fn create_wallet_with_progress(
+        &self,
+        params_json: &str,
+        on_progress: ProgressFn,
+    ) -> Result<serde_json::Value, String> {
+        match self.create_wallet_with_progress {
+            Some(ffi_fn) => self.call_ffi_with_progress(ffi_fn, params_json, on_progress),
+            None => self.create_wallet(params_json),
+        }
+    }
+
+    /// Import a wallet, reporting progress as each of its addresses is derived.
+    ///
+    /// Uses `ImportWalletWithProgress` when the library exports it and falls
+    /// back to plain `ImportWallet` (no progress) otherwise.
+    pub fn import_wallet_with_progress(
+        &self,
+        params_json: &str,
+        on_progress: ProgressFn,
+    ) -> Result<serde_json::Value, String> {
+        match self.import_wallet_with_progress {
+            Some(ffi_fn) => self.call_ffi_with_progress(ffi_fn, params_json, on_progress),
+            None => self.import_wallet(params_json),
+        }
+    }
+
+    /// Call a progress-reporting function. `on_progress` receives
+    /// (completed, total) on the Go thread while the call is running; see
+    /// `progress_trampoline` for the contract.
+    fn call_ffi_with_progress(
+        &self,
+        ffi_fn: WalletWithProgressFn,
+        params_json: &str,
+        on_progress: ProgressFn,
+    ) -> Result<serde_json::Value, String> {
+        // Unregistered when this guard drops, i.e. once the call has returned
+        let registration = ProgressRegistration::new(on_progress);
+        let token = registration.0;
+
+        let params_cstr = CString::new(params_json)
+            .map_err(|e| format!("Invalid params JSON: {}", e))?;
+
+        unsafe {
+            let result_ptr = ffi_fn(params_cstr.as_ptr(), progress_trampoline, token);
+            self.take_ffi_response(result_ptr)
+        }
     }
 
     /// Authenticate and load wallet into memory.
     pub fn unlock_wallet(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.unlock_wallet, params_json)
+        self.call_ffi_with_params(self.unlock_wallet, params_json)
     }
 
     /// Derive addresses for specified blockchains.
     pub fn generate_addresses(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.generate_addresses, params_json)
+        self.call_ffi_with_params(self.generate_addresses, params_json)
     }
 
     /// Export wallet metadata without private keys.
     pub fn export_wallet(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.export_wallet, params_json)
+        self.call_ffi_with_params(self.export_wallet, params_json)
     }
 
     /// Change wallet display name.
     pub fn rename_wallet(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.rename_wallet, params_json)
+        self.call_ffi_with_params(self.rename_wallet, params_json)
     }
 
     /// Enumerate all wallets on USB.
     pub fn list_wallets(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.list_wallets, params_json)
+        self.call_ffi_with_params(self.list_wallets, params_json)
+    }
+
+    /// Sign a message with the private key of one of the wallet's addresses.
+    ///
+    /// The library prefixes the message per `scheme` before hashing
+    /// ("\x18Bitcoin Signed Message:\n" for BIP-137, "\x19Ethereum Signed
+    /// Message:\n" for EIP-191), so a signature can never double as a
+    /// transaction signature. The key is zeroized on the Go side after use.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "walletId": "...",
+    ///   "password": "...",
+    ///   "usbPath": "...",
+    ///   "chainId": "bitcoin"|"ethereum",
+    ///   "address": "...",
+    ///   "message": "...",
+    ///   "scheme": "bip137"|"eip191"
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"signature": "..."}` (base64 for BIP-137, 0x-hex for EIP-191)
+    ///
+    /// Optional symbol, and not yet exported by internal/lib (which has no
+    /// message signer); fails with UNSUPPORTED_OPERATION until it is.
+    pub fn sign_message(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.sign_message, "SignMessage", params_json)
+    }
+
+    /// Verify that `signature` over `message` was produced by `address`.
+    /// Needs no wallet: the signer is recovered from the signature.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "chainId": "bitcoin"|"ethereum",
+    ///   "address": "...",
+    ///   "message": "...",
+    ///   "signature": "...",
+    ///   "scheme": "bip137"|"eip191"
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"valid": true|false}`
+    ///
+    /// Optional symbol, and not yet exported by internal/lib; until a
+    /// VerifyMessage export lands this fails with UNSUPPORTED_OPERATION.
+    pub fn verify_message(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.verify_message, "VerifyMessage", params_json)
+    }
+
+    /// Derive the first address of a mnemonic (Bitcoin: m/44'/0'/0'/0/0).
+    /// Needs no wallet; this is the fingerprint used to tell whether two
+    /// phrases (with their passphrases) restore the same wallet.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "mnemonic": "...",
+    ///   "passphrase": "...",
+    ///   "chainId": "bitcoin"|"ethereum"|...
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"address": "..."}`
+    ///
+    /// Optional symbol: fails with UNSUPPORTED_OPERATION on older library builds.
+    pub fn derive_first_address(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.derive_first_address, "DeriveFirstAddress", params_json)
+    }
+
+    /// Re-encrypt a wallet's mnemonic under a new password.
+    ///
+    /// The old password must decrypt the wallet; the file is replaced only
+    /// once the new ciphertext has been written.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "walletId": "...",
+    ///   "oldPassword": "...",
+    ///   "newPassword": "...",
+    ///   "usbPath": "..."
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"walletId": "...", "changedAt": "..."}`
+    ///
+    /// Optional symbol: fails with UNSUPPORTED_OPERATION on older library builds.
+    pub fn change_wallet_password(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.change_wallet_password, "ChangeWalletPassword", params_json)
+    }
+
+    /// Export the public descriptor of one chain's account, for watch-only use.
+    ///
+    /// Bitcoin chains return an output descriptor with the account xpub
+    /// (e.g. `wpkh([fingerprint/84h/0h/0h]xpub.../0/*)#checksum`); other
+    /// chains return the account xpub alone. Never contains private keys.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "walletId": "...",
+    ///   "password": "...",
+    ///   "usbPath": "...",
+    ///   "chainId": "bitcoin"|"ethereum"|...
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"descriptor": "...", "xpub": "...", "derivationPath": "..."}`
+    ///
+    /// Optional symbol, and not yet exported by internal/lib (it stores
+    /// derived addresses, not account xpubs); fails with
+    /// UNSUPPORTED_OPERATION until an ExportDescriptor export exists.
+    pub fn export_descriptor(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.export_descriptor, "ExportDescriptor", params_json)
     }
 
     // ========================================================================
@@ -597,7 +1240,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn build_transaction(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.build_transaction, params_json)
+        self.call_ffi_with_params(self.build_transaction, params_json)
     }
 
     /// Sign an unsigned transaction with the provided private key.
@@ -613,7 +1256,7 @@ impl WalletLibrary {
     ///
     /// Security Note: Private key is zeroed after use on the Go side.
     pub fn sign_transaction(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.sign_transaction, params_json)
+        self.call_ffi_with_params(self.sign_transaction, params_json)
     }
 
     /// Broadcast a signed transaction to the blockchain network.
@@ -628,7 +1271,7 @@ impl WalletLibrary {
     ///
     /// Note: Broadcast is idempotent - duplicate submissions are handled gracefully.
     pub fn broadcast_transaction(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.broadcast_transaction, params_json)
+        self.call_ffi_with_params(self.broadcast_transaction, params_json)
     }
 
     /// Query the status of a transaction by hash.
@@ -641,7 +1284,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn query_transaction_status(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.query_transaction_status, params_json)
+        self.call_ffi_with_params(self.query_transaction_status, params_json)
     }
 
     /// Estimate transaction fees for the specified chain.
@@ -656,7 +1299,114 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn estimate_fee(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.estimate_fee, params_json)
+        self.call_ffi_with_params(self.estimate_fee, params_json)
+    }
+
+    /// Make one request to a provider, without storing anything.
+    ///
+    /// Input JSON format (new credentials):
+    /// ```json
+    /// {
+    ///   "providerType": "alchemy",
+    ///   "apiKey": "...",
+    ///   "chainId": "ethereum",
+    ///   "networkId": "mainnet",
+    ///   "customEndpoint": ""
+    /// }
+    /// ```
+    ///
+    /// Without `apiKey`, the stored config of `chainId`/`providerType` is
+    /// tested instead; `password` and `usbPath` unlock the config store:
+    /// ```json
+    /// { "chainId": "ethereum", "providerType": "alchemy", "password": "...", "usbPath": "/media/usb" }
+    /// ```
+    ///
+    /// The request asks the provider's JSON-RPC endpoint for the latest block.
+    ///
+    /// Output data: `{"reachable": true, "latencyMs": 120}` or
+    /// `{"reachable": false, "error": "..."}`
+    ///
+    /// Optional symbol: fails with UNSUPPORTED_OPERATION on older library builds.
+    pub fn test_provider_connection(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.test_provider_connection, "TestProviderConnection", params_json)
+    }
+
+    /// Next nonce of an account on an account-based chain, counting its
+    /// pending transactions.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// { "chainId": "ethereum", "address": "0x...", "rpcConfig": "" }
+    /// ```
+    ///
+    /// Output data: `{"nonce": 7}`
+    ///
+    /// Optional symbol: fails with UNSUPPORTED_OPERATION on older library builds.
+    pub fn get_nonce(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.get_nonce, "GetNonce", params_json)
+    }
+
+    /// JSON-RPC endpoint of a stored provider config, API key included, to
+    /// pass as `rpcConfig` to the transaction functions.
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "chainId": "ethereum",
+    ///   "providerType": "alchemy",
+    ///   "password": "...",
+    ///   "usbPath": "/media/usb"
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"providerType": "alchemy", "chainId": "ethereum", "rpcEndpoint": "https://..."}`
+    ///
+    /// Optional symbol: fails with UNSUPPORTED_OPERATION on older library builds.
+    pub fn resolve_provider_endpoint(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.resolve_provider_endpoint, "ResolveProviderEndpoint", params_json)
+    }
+
+    /// Build an unsigned transaction paying several outputs at once (Bitcoin).
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "chainId": "bitcoin",
+    ///   "from": "...",
+    ///   "outputs": [{"to": "...", "amount": "..."}],
+    ///   "amount": "...",
+    ///   "feeSpeed": "slow"|"normal"|"fast"|"custom"
+    /// }
+    /// ```
+    ///
+    /// `amount` is the sum of the output amounts, for the balance check.
+    ///
+    /// Optional symbol, and not yet exported by internal/lib: chainadapter's
+    /// Bitcoin builder pays a single recipient. Until it grows multi-output
+    /// support this fails with UNSUPPORTED_OPERATION.
+    pub fn build_transaction_multi(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.build_transaction_multi, "BuildTransactionMulti", params_json)
+    }
+
+    /// Check whether the network would accept a signed transaction, without
+    /// broadcasting it (Bitcoin `testmempoolaccept`, Ethereum call simulation).
+    ///
+    /// Input JSON format:
+    /// ```json
+    /// {
+    ///   "chainId": "bitcoin"|"ethereum",
+    ///   "signedTx": "...",
+    ///   "rpcConfig": "optional-rpc-endpoint"
+    /// }
+    /// ```
+    ///
+    /// Output data: `{"acceptable": true|false, "reason": "..."}`
+    ///
+    /// Optional symbol, and not yet exported by internal/lib: chainadapter
+    /// has no `testmempoolaccept` or `eth_call` path to build it on. Until it
+    /// is, this fails with UNSUPPORTED_OPERATION.
+    pub fn validate_transaction(&self, params_json: &str) -> Result<serde_json::Value, String> {
+        self.call_optional_ffi(self.validate_transaction, "ValidateTransaction", params_json)
     }
 
     // ========================================================================
@@ -679,7 +1429,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn set_provider_config(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.set_provider_config, params_json)
+        self.call_ffi_with_params(self.set_provider_config, params_json)
     }
 
     /// Get a blockchain provider configuration.
@@ -694,7 +1444,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn get_provider_config(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.get_provider_config, params_json)
+        self.call_ffi_with_params(self.get_provider_config, params_json)
     }
 
     /// List all provider configurations.
@@ -708,7 +1458,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn list_provider_configs(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.list_provider_configs, params_json)
+        self.call_ffi_with_params(self.list_provider_configs, params_json)
     }
 
     /// Delete a provider configuration.
@@ -722,8 +1472,10 @@ impl WalletLibrary {
     ///   "usbPath": "/path/to/usb"
     /// }
     /// ```
-    pub fn delete_provider_config(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.delete_provider_config, params_json)
+    ///
+    /// Nothing in the response is needed, so a success without data is fine.
+    pub fn delete_provider_config(&self, params_json: &str) -> Result<(), String> {
+        self.call_ffi_unit(self.delete_provider_config, params_json)
     }
 
     /// Check if this is first-time setup (app_config.enc doesn't exist).
@@ -735,7 +1487,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn is_first_time_setup(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.is_first_time_setup, params_json)
+        self.call_ffi_with_params(self.is_first_time_setup, params_json)
     }
 
     /// Initialize app configuration for first-time setup.
@@ -748,7 +1500,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn initialize_app(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.initialize_app, params_json)
+        self.call_ffi_with_params(self.initialize_app, params_json)
     }
 
     /// Unlock app and load configuration.
@@ -761,7 +1513,7 @@ impl WalletLibrary {
     /// }
     /// ```
     pub fn unlock_app(&self, params_json: &str) -> Result<serde_json::Value, String> {
-        self.call_ffi_with_params(*self.unlock_app, params_json)
+        self.call_ffi_with_params(self.unlock_app, params_json)
     }
 }
 
@@ -772,3 +1524,191 @@ impl WalletLibrary {
 // 3. Actual operations serialized through WalletQueue
 unsafe impl Send for WalletLibrary {}
 unsafe impl Sync for WalletLibrary {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::stub::{ffi_string, stub_library};
+    use std::sync::atomic::AtomicU32;
+
+    #[test]
+    fn test_arch_mismatch_error_is_translated() {
+        let dlopen_error = "dlopen(/Applications/arcSign.app/Contents/Resources/libarcsign.dylib, 0x0005): \
+            tried: '/Applications/arcSign.app/Contents/Resources/libarcsign.dylib' \
+            (mach-o file, but is an incompatible architecture (have 'x86_64', need 'arm64'))";
+        assert!(is_arch_mismatch(dlopen_error));
+        assert!(is_arch_mismatch("libarcsign.so: wrong ELF class: ELFCLASS32"));
+        assert!(!is_arch_mismatch("libarcsign.so: cannot open shared object file: No such file or directory"));
+
+        let message = arch_mismatch_message("/usr/local/lib/arcsign/libarcsign.dylib");
+        assert!(message.starts_with(&format!(
+            "Wallet library architecture does not match this app (expected {})",
+            host_arch()
+        )));
+        assert_ne!(host_arch(), "aarch64");
+    }
+
+    unsafe extern "C" fn success_without_data(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true}"#)
+    }
+
+    unsafe extern "C" fn success_with_data(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":true,"data":{"deleted":true}}"#)
+    }
+
+    unsafe extern "C" fn failure(_params: *const c_char) -> *mut c_char {
+        ffi_string(r#"{"success":false,"error":{"code":"STORAGE_ERROR","message":"disk full"}}"#)
+    }
+
+    /// Error envelope with a Latin-1 byte (0xE9) in a directory name
+    unsafe extern "C" fn invalid_utf8_error_envelope(_params: *const c_char) -> *mut c_char {
+        let mut json = br#"{"success":false,"error":{"code":"STORAGE_ERROR","message":"cannot read /media/caf"#.to_vec();
+        json.extend_from_slice(b"\xE9\"}}");
+        CString::new(json).unwrap().into_raw()
+    }
+
+    unsafe extern "C" fn invalid_utf8_garbage(_params: *const c_char) -> *mut c_char {
+        CString::new(b"panic: bad \xFF\xFE byte".to_vec()).unwrap().into_raw()
+    }
+
+    unsafe extern "C" fn invalid_utf8_success(_params: *const c_char) -> *mut c_char {
+        CString::new(b"{\"success\":true,\"data\":{\"mnemonic\":\"\xFF\"}}".to_vec()).unwrap().into_raw()
+    }
+
+    #[test]
+    fn test_invalid_utf8_surfaces_lossy_error() {
+        let library = stub_library();
+
+        assert_eq!(
+            library.call_ffi_with_params::<serde_json::Value>(invalid_utf8_error_envelope, "{}"),
+            Err("STORAGE_ERROR: cannot read /media/caf\u{FFFD}".to_string())
+        );
+        assert_eq!(
+            library.call_ffi_with_params::<serde_json::Value>(invalid_utf8_garbage, "{}"),
+            Err("Invalid UTF-8 from FFI function: panic: bad \u{FFFD}\u{FFFD} byte".to_string())
+        );
+
+        // Success data is never echoed into the error
+        let err = library
+            .call_ffi_with_params::<serde_json::Value>(invalid_utf8_success, "{}")
+            .unwrap_err();
+        assert_eq!(err, "Invalid UTF-8 in FFI function response data");
+    }
+
+    #[test]
+    fn test_invalid_utf8_response_is_still_freed() {
+        static FREED: AtomicU32 = AtomicU32::new(0);
+
+        unsafe extern "C" fn recording_go_free(ptr: *mut c_char) {
+            FREED.fetch_add(1, Ordering::SeqCst);
+            drop(CString::from_raw(ptr));
+        }
+
+        let mut library = stub_library();
+        library.go_free = recording_go_free;
+
+        assert!(library.call_ffi_with_params::<serde_json::Value>(invalid_utf8_garbage, "{}").is_err());
+        assert!(library.call_ffi_with_params::<serde_json::Value>(invalid_utf8_success, "{}").is_err());
+        assert_eq!(FREED.load(Ordering::SeqCst), 2);
+        #[cfg(debug_assertions)]
+        assert_eq!(library.allocations.outstanding_bytes(), 0);
+    }
+
+    #[test]
+    fn test_call_ffi_unit_accepts_success_without_data() {
+        let mut library = stub_library();
+
+        assert_eq!(library.call_ffi_unit(success_without_data, "{}"), Ok(()));
+        assert_eq!(library.call_ffi_unit(success_with_data, "{}"), Ok(()));
+        assert_eq!(
+            library.call_ffi_unit(failure, "{}"),
+            Err("STORAGE_ERROR: disk full".to_string())
+        );
+
+        library.delete_provider_config = success_without_data;
+        assert_eq!(library.delete_provider_config("{}"), Ok(()));
+    }
+
+    #[test]
+    fn test_call_ffi_with_params_requires_data() {
+        let library = stub_library();
+
+        let data: serde_json::Value = library.call_ffi_with_params(success_with_data, "{}").unwrap();
+        assert_eq!(data["deleted"], true);
+
+        let err = library
+            .call_ffi_with_params::<serde_json::Value>(success_without_data, "{}")
+            .unwrap_err();
+        assert_eq!(err, "Success response missing data");
+    }
+
+    #[test]
+    fn test_user_dir_falls_back_without_env() {
+        fn fallback() -> Option<PathBuf> {
+            Some(PathBuf::from("/home/fallback"))
+        }
+        fn no_fallback() -> Option<PathBuf> {
+            None
+        }
+
+        assert_eq!(user_dir("HOME", Some("/home/env".to_string()), fallback).as_deref(), Some("/home/env"));
+        assert_eq!(user_dir("HOME", None, fallback).as_deref(), Some("/home/fallback"));
+        assert_eq!(user_dir("HOME", Some(String::new()), fallback).as_deref(), Some("/home/fallback"));
+        assert_eq!(user_dir("HOME", None, no_fallback), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_search_paths_without_home_env() {
+        let paths = WalletLibrary::get_search_paths("libarcsign.so", Some("/home/fallback"));
+        assert_eq!(paths[0], "libarcsign.so");
+        assert!(paths.contains(&"/usr/local/lib/arcsign/libarcsign.so".to_string()));
+        assert!(paths.contains(&"/home/fallback/.local/lib/arcsign/libarcsign.so".to_string()));
+
+        // No user directory at all: only the shared paths
+        let paths = WalletLibrary::get_search_paths("libarcsign.so", None);
+        assert!(paths.contains(&"/usr/local/lib/arcsign/libarcsign.so".to_string()));
+        assert!(!paths.iter().any(|path| path.contains(".local")));
+    }
+
+    #[test]
+    fn test_progress_trampoline_dispatches_to_registered_handler() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = calls.clone();
+        let registration = ProgressRegistration::new(Arc::new(move |completed, total| {
+            assert_eq!(total, 3);
+            calls_clone.fetch_add(completed, Ordering::SeqCst);
+        }));
+
+        unsafe {
+            progress_trampoline(1, 3, registration.0);
+            progress_trampoline(2, 3, registration.0);
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_progress_trampoline_ignores_expired_token() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let calls_clone = calls.clone();
+        let registration = ProgressRegistration::new(Arc::new(move |_, _| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+        let token = registration.0;
+        drop(registration);
+
+        // A late report after the FFI call returned must be a no-op
+        unsafe { progress_trampoline(1, 1, token) };
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_progress_trampoline_catches_handler_panic() {
+        let registration = ProgressRegistration::new(Arc::new(|_, _| panic!("handler failed")));
+
+        // Must not unwind across the extern "C" boundary
+        unsafe { progress_trampoline(1, 1, registration.0) };
+    }
+}
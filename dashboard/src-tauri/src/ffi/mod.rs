@@ -25,7 +25,10 @@ pub mod bindings;
 pub mod types;
 pub mod queue;
 
+#[cfg(test)]
+pub(crate) mod stub;
+
 // Re-export main types for convenience
-pub use bindings::WalletLibrary;
-pub use queue::{WalletQueue, LazyWalletQueue};
+pub use bindings::{WalletLibrary, EXPECTED_LIBRARY_VERSION};
+pub use queue::{queue_capacity_from_env, WalletQueue, LazyWalletQueue, MetricThresholds, WalletBackend};
 pub use types::{FFIResponse, FFIError, ErrorCode};
@@ -5,7 +5,10 @@
 //!
 //! Architecture:
 //! - All Tauri commands send requests to the queue
-//! - Single worker thread processes requests sequentially
+//! - Single worker thread processes requests one at a time; quick reads
+//!   take a priority lane, but never pass an earlier write to the wallet,
+//!   chain or config they read, and writes that have waited too long are
+//!   aged ahead of them so they can't starve
 //! - Responses sent back via oneshot channels
 //! - Uses std::sync primitives ONLY (no Tokio)
 //!
@@ -13,11 +16,16 @@
 //! Created: 2025-10-25
 //! Updated: 2025-10-30 - Complete rewrite using std::sync only
 
-use std::sync::{Arc, Mutex, OnceLock, mpsc};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, RwLock, mpsc};
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 use std::thread;
-use super::bindings::WalletLibrary;
+use super::bindings::{ProgressFn, WalletLibrary};
+use super::types::{VersionInfo, WalletProgress};
+use crate::error::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 /// Queue metrics for monitoring performance
 #[derive(Debug, Clone)]
@@ -30,20 +38,118 @@ pub struct QueueMetrics {
     pub peak_depth: Arc<AtomicUsize>,
     /// Total wait time (sum of all operation wait times)
     pub total_wait_time_ms: Arc<AtomicU64>,
+    /// Per-operation counters, keyed by `WalletCommand::operation_name`
+    pub per_operation: Arc<Mutex<HashMap<&'static str, OperationCounters>>>,
+    /// Levels above which `log_metrics` warns
+    pub thresholds: MetricThresholds,
+}
+
+/// Warning levels for queue metrics.
+///
+/// The defaults suit a fast USB drive; raise them on slower media to avoid
+/// constant warnings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricThresholds {
+    /// Average wait time (ms) above which a warning is logged
+    pub max_avg_wait_ms: f64,
+    /// Pending operations above which a warning is logged
+    pub max_depth: usize,
+}
+
+impl Default for MetricThresholds {
+    fn default() -> Self {
+        Self {
+            max_avg_wait_ms: 10.0,
+            max_depth: 10,
+        }
+    }
+}
+
+impl MetricThresholds {
+    /// Defaults, overridden by ARCSIGN_QUEUE_WAIT_WARN_MS and
+    /// ARCSIGN_QUEUE_DEPTH_WARN when they are set to valid numbers
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            max_avg_wait_ms: env_number("ARCSIGN_QUEUE_WAIT_WARN_MS").unwrap_or(defaults.max_avg_wait_ms),
+            max_depth: env_number("ARCSIGN_QUEUE_DEPTH_WARN").unwrap_or(defaults.max_depth),
+        }
+    }
+}
+
+/// Commands that may wait in the queue before enqueueing fails as busy
+pub const DEFAULT_QUEUE_CAPACITY: usize = 32;
+
+/// `DEFAULT_QUEUE_CAPACITY`, overridden by ARCSIGN_QUEUE_CAPACITY when it is
+/// set to a positive number
+pub fn queue_capacity_from_env() -> usize {
+    match env_number::<usize>("ARCSIGN_QUEUE_CAPACITY") {
+        Some(0) => {
+            tracing::warn!("Ignoring ARCSIGN_QUEUE_CAPACITY=0");
+            DEFAULT_QUEUE_CAPACITY
+        }
+        Some(capacity) => capacity,
+        None => DEFAULT_QUEUE_CAPACITY,
+    }
+}
+
+/// How long a normal-lane command may wait before it runs ahead of the
+/// priority lane
+pub const PRIORITY_AGING_THRESHOLD: Duration = Duration::from_millis(500);
+
+pub(crate) fn env_number<T: std::str::FromStr>(name: &str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(number) => Some(number),
+        Err(_) => {
+            tracing::warn!("Ignoring invalid {}={:?}", name, value);
+            None
+        }
+    }
+}
+
+/// Counters for one operation type
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OperationCounters {
+    pub count: u64,
+    pub total_time_ms: u64,
+}
+
+/// Per-operation entry of a metrics snapshot
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationMetrics {
+    pub count: u64,
+    pub total_time_ms: u64,
+    pub average_time_ms: f64,
+}
+
+/// Point-in-time copy of the queue metrics
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueMetricsSnapshot {
+    pub total_operations: u64,
+    pub current_depth: usize,
+    pub peak_depth: usize,
+    pub average_wait_time_ms: f64,
+    /// Sorted by operation name
+    pub operations: BTreeMap<String, OperationMetrics>,
 }
 
 impl QueueMetrics {
-    fn new() -> Self {
+    fn new(thresholds: MetricThresholds) -> Self {
         Self {
             total_operations: Arc::new(AtomicU64::new(0)),
             current_depth: Arc::new(AtomicUsize::new(0)),
             peak_depth: Arc::new(AtomicUsize::new(0)),
             total_wait_time_ms: Arc::new(AtomicU64::new(0)),
+            per_operation: Arc::new(Mutex::new(HashMap::new())),
+            thresholds,
         }
     }
 
-    /// Record operation start (enqueued)
-    fn record_enqueue(&self) {
+    /// Record operation start (enqueued); returns the new depth
+    fn record_enqueue(&self) -> usize {
         let depth = self.current_depth.fetch_add(1, Ordering::SeqCst) + 1;
 
         // Update peak depth if necessary
@@ -59,13 +165,66 @@ impl QueueMetrics {
                 Err(actual) => peak = actual,
             }
         }
+
+        depth
+    }
+
+    /// Undo `record_enqueue` for an operation the full queue turned away,
+    /// so `peak_depth` only counts operations that were actually queued
+    fn record_rejected(&self, depth: usize) {
+        self.current_depth.fetch_sub(1, Ordering::SeqCst);
+        let _ = self.peak_depth.compare_exchange(depth, depth - 1, Ordering::SeqCst, Ordering::SeqCst);
     }
 
     /// Record operation completion (dequeued)
-    fn record_dequeue(&self, wait_time: Duration) {
+    fn record_dequeue(&self, operation: &'static str, wait_time: Duration) {
+        let wait_ms = wait_time.as_millis() as u64;
         self.current_depth.fetch_sub(1, Ordering::SeqCst);
         self.total_operations.fetch_add(1, Ordering::SeqCst);
-        self.total_wait_time_ms.fetch_add(wait_time.as_millis() as u64, Ordering::SeqCst);
+        self.total_wait_time_ms.fetch_add(wait_ms, Ordering::SeqCst);
+
+        let mut per_operation = self.per_operation.lock().unwrap_or_else(|e| e.into_inner());
+        let counters = per_operation.entry(operation).or_default();
+        counters.count += 1;
+        counters.total_time_ms += wait_ms;
+    }
+
+    /// Zero the counters (e.g. between benchmark runs).
+    /// Operations still queued are kept in `current_depth`.
+    pub fn reset(&self) {
+        self.total_operations.store(0, Ordering::SeqCst);
+        self.total_wait_time_ms.store(0, Ordering::SeqCst);
+        self.peak_depth
+            .store(self.current_depth.load(Ordering::SeqCst), Ordering::SeqCst);
+        self.per_operation.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    /// Copy the current counters, including the per-operation breakdown
+    pub fn snapshot(&self) -> QueueMetricsSnapshot {
+        let operations = self
+            .per_operation
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(name, counters)| {
+                (
+                    name.to_string(),
+                    OperationMetrics {
+                        count: counters.count,
+                        total_time_ms: counters.total_time_ms,
+                        average_time_ms: counters.total_time_ms as f64 / counters.count.max(1) as f64,
+                    },
+                )
+            })
+            .collect();
+
+        QueueMetricsSnapshot {
+            total_operations: self.total_operations.load(Ordering::SeqCst),
+            current_depth: self.current_depth.load(Ordering::SeqCst),
+            peak_depth: self.peak_depth.load(Ordering::SeqCst),
+            average_wait_time_ms: self.average_wait_time_ms(),
+            operations,
+        }
     }
 
     /// Get average wait time
@@ -94,19 +253,32 @@ impl QueueMetrics {
         );
 
         // Warn if performance degrading
-        if avg_wait > 10.0 {
-            tracing::warn!(
-                "Queue wait time elevated: {:.2}ms (target <10ms)",
-                avg_wait
-            );
+        for warning in self.threshold_warnings() {
+            tracing::warn!("{}", warning);
+        }
+    }
+
+    /// Warnings for metrics above their configured thresholds
+    pub fn threshold_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let avg_wait = self.average_wait_time_ms();
+        if avg_wait > self.thresholds.max_avg_wait_ms {
+            warnings.push(format!(
+                "Queue wait time elevated: {:.2}ms (target <{}ms)",
+                avg_wait, self.thresholds.max_avg_wait_ms
+            ));
         }
 
-        if current_depth > 10 {
-            tracing::warn!(
+        let current_depth = self.current_depth.load(Ordering::SeqCst);
+        if current_depth > self.thresholds.max_depth {
+            warnings.push(format!(
                 "Queue depth high: {} operations pending",
                 current_depth
-            );
+            ));
         }
+
+        warnings
     }
 }
 
@@ -118,6 +290,127 @@ fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
     mpsc::channel()
 }
 
+/// Error for commands the queue dropped because it is shutting down
+pub const SHUTDOWN_ERROR: &str = "SHUTTING_DOWN: Wallet queue is shutting down";
+
+/// Error prefix for commands rejected because the queue is full
+pub const QUEUE_BUSY_ERROR_CODE: &str = "QUEUE_BUSY";
+
+/// Error prefix for commands that got no response within their timeout
+pub const TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
+
+/// Response budget of a quick read (`get_version`): it should answer in
+/// milliseconds, so anything longer means the library is stuck
+pub const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Response budget of metadata reads and small writes (listing wallets,
+/// provider config, renames)
+pub const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Response budget of operations running one KDF pass or a network round
+/// trip (unlock, signing, transaction building and fee queries)
+pub const UNLOCK_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Response budget of operations deriving every address or re-encrypting
+/// (create, import, address generation, password changes); slow USB drives
+/// and the KDF can legitimately take minutes
+pub const KEY_GENERATION_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How long a caller waits for `command`'s response.
+///
+/// The clock starts when the command is queued, so the budget covers time
+/// spent behind earlier commands as well as the FFI call itself. A timed-out
+/// call keeps running in the worker; only its caller stops waiting.
+pub fn timeout_for(command: &WalletCommand) -> Duration {
+    match command {
+        WalletCommand::GetVersion { .. } => VERSION_TIMEOUT,
+
+        WalletCommand::ListWallets { .. }
+        | WalletCommand::RenameWallet { .. }
+        | WalletCommand::VerifyMessage { .. }
+        | WalletCommand::SetProviderConfig { .. }
+        | WalletCommand::GetProviderConfig { .. }
+        | WalletCommand::ListProviderConfigs { .. }
+        | WalletCommand::DeleteProviderConfig { .. }
+        | WalletCommand::TestProviderConnection { .. }
+        | WalletCommand::ResolveProviderEndpoint { .. }
+        | WalletCommand::IsFirstTimeSetup { .. } => READ_TIMEOUT,
+
+        WalletCommand::UnlockWallet { .. }
+        | WalletCommand::ExportWallet { .. }
+        | WalletCommand::BuildTransaction { .. }
+        | WalletCommand::BuildTransactionMulti { .. }
+        | WalletCommand::EstimateFee { .. }
+        | WalletCommand::QueryTransactionStatus { .. }
+        | WalletCommand::GetNonce { .. }
+        | WalletCommand::BroadcastTransaction { .. }
+        | WalletCommand::ValidateTransaction { .. }
+        | WalletCommand::SignMessage { .. }
+        | WalletCommand::DeriveFirstAddress { .. }
+        | WalletCommand::ExportDescriptor { .. }
+        | WalletCommand::InitializeApp { .. }
+        | WalletCommand::UnlockApp { .. } => UNLOCK_TIMEOUT,
+
+        WalletCommand::CreateWallet { .. }
+        | WalletCommand::ImportWallet { .. }
+        | WalletCommand::GenerateAddresses { .. }
+        | WalletCommand::ChangeWalletPassword { .. }
+        // Waits for everything queued before it
+        | WalletCommand::SwapLibrary { .. } => KEY_GENERATION_TIMEOUT,
+    }
+}
+
+/// What a queued command's caller waits for, and how long
+#[derive(Debug, Clone, Copy)]
+struct ResponseDeadline {
+    operation: &'static str,
+    timeout: Duration,
+}
+
+impl ResponseDeadline {
+    fn for_command(command: &WalletCommand) -> Self {
+        Self {
+            operation: command.operation_name(),
+            timeout: timeout_for(command),
+        }
+    }
+}
+
+/// Wait for a command's response without blocking the async runtime.
+///
+/// A queue shutting down drops the commands it hasn't started, and the
+/// runtime cancels blocking tasks on exit; both surface as SHUTDOWN_ERROR
+/// rather than a closed-channel error that looks like a failure. No response
+/// within the deadline fails with a TIMEOUT error.
+async fn await_response<T: Send + 'static>(
+    receiver: OneshotReceiver<T>,
+    deadline: ResponseDeadline,
+) -> Result<T, String> {
+    tokio::task::spawn_blocking(move || {
+        receiver.recv_timeout(deadline.timeout).map_err(|e| match e {
+            mpsc::RecvTimeoutError::Timeout => format!(
+                "{}: {} did not finish within {}s",
+                TIMEOUT_ERROR_CODE,
+                deadline.operation,
+                deadline.timeout.as_secs()
+            ),
+            mpsc::RecvTimeoutError::Disconnected => SHUTDOWN_ERROR.to_string(),
+        })
+    })
+    .await
+    .map_err(|e| {
+        if e.is_cancelled() {
+            SHUTDOWN_ERROR.to_string()
+        } else {
+            format!("Task join error: {}", e)
+        }
+    })?
+}
+
+/// Parameter JSON of a queued command. It can carry passwords, so it is
+/// zeroized when dropped, right after the worker has made the FFI call.
+pub type FfiParams = Zeroizing<String>;
+
 /// Command types for wallet operations
 #[derive(Debug)]
 pub enum WalletCommand {
@@ -128,82 +421,443 @@ pub enum WalletCommand {
     },
     /// Create a new HD wallet from mnemonic
     CreateWallet {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Import an existing wallet from mnemonic
     ImportWallet {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Authenticate and load wallet into memory
     UnlockWallet {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Derive addresses for specified blockchains
     GenerateAddresses {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Export wallet metadata without private keys
     ExportWallet {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Change wallet display name
     RenameWallet {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Enumerate all wallets on USB
     ListWallets {
-        params_json: String,
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Build an unsigned transaction
+    BuildTransaction {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Build an unsigned transaction with several outputs
+    BuildTransactionMulti {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Estimate the fee of a single transfer
+    EstimateFee {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Query the status of a transaction by hash
+    QueryTransactionStatus {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Next nonce of an account (account-based chains)
+    GetNonce {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Submit a signed transaction to the network
+    BroadcastTransaction {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Check whether the network would accept a signed transaction
+    ValidateTransaction {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Sign a message with one of the wallet's address keys
+    SignMessage {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Verify a signed message against an address
+    VerifyMessage {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Derive the first address of a mnemonic (no wallet needed)
+    DeriveFirstAddress {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Re-encrypt a wallet under a new password
+    ChangeWalletPassword {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// Export a watch-only descriptor for one chain
+    ExportDescriptor {
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Set blockchain provider configuration
     SetProviderConfig {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Get blockchain provider configuration
     GetProviderConfig {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// List all provider configurations
     ListProviderConfigs {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Delete a provider configuration
     DeleteProviderConfig {
-        params_json: String,
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<(), String>>,
+    },
+    /// Try provider credentials without storing them
+    TestProviderConnection {
+        params_json: FfiParams,
+        respond_to: OneshotSender<Result<serde_json::Value, String>>,
+    },
+    /// JSON-RPC endpoint of a stored provider config
+    ResolveProviderEndpoint {
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Check if this is first-time setup
     IsFirstTimeSetup {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Initialize app configuration
     InitializeApp {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
     /// Unlock app and load configuration
     UnlockApp {
-        params_json: String,
+        params_json: FfiParams,
         respond_to: OneshotSender<Result<serde_json::Value, String>>,
     },
+    /// Replace the library used for all later commands
+    SwapLibrary {
+        library: Arc<WalletLibrary>,
+        respond_to: OneshotSender<()>,
+    },
 }
 
+impl WalletCommand {
+    /// Operation name used in metrics and progress events
+    pub fn operation_name(&self) -> &'static str {
+        match self {
+            WalletCommand::GetVersion { .. } => "get_version",
+            WalletCommand::CreateWallet { .. } => "create_wallet",
+            WalletCommand::ImportWallet { .. } => "import_wallet",
+            WalletCommand::UnlockWallet { .. } => "unlock_wallet",
+            WalletCommand::GenerateAddresses { .. } => "generate_addresses",
+            WalletCommand::ExportWallet { .. } => "export_wallet",
+            WalletCommand::RenameWallet { .. } => "rename_wallet",
+            WalletCommand::ListWallets { .. } => "list_wallets",
+            WalletCommand::BuildTransaction { .. } => "build_transaction",
+            WalletCommand::BuildTransactionMulti { .. } => "build_transaction_multi",
+            WalletCommand::EstimateFee { .. } => "estimate_fee",
+            WalletCommand::QueryTransactionStatus { .. } => "query_transaction_status",
+            WalletCommand::BroadcastTransaction { .. } => "broadcast_transaction",
+            WalletCommand::GetNonce { .. } => "get_nonce",
+            WalletCommand::ValidateTransaction { .. } => "validate_transaction",
+            WalletCommand::SignMessage { .. } => "sign_message",
+            WalletCommand::VerifyMessage { .. } => "verify_message",
+            WalletCommand::DeriveFirstAddress { .. } => "derive_first_address",
+            WalletCommand::ChangeWalletPassword { .. } => "change_wallet_password",
+            WalletCommand::ExportDescriptor { .. } => "export_descriptor",
+            WalletCommand::SetProviderConfig { .. } => "set_provider_config",
+            WalletCommand::GetProviderConfig { .. } => "get_provider_config",
+            WalletCommand::ListProviderConfigs { .. } => "list_provider_configs",
+            WalletCommand::DeleteProviderConfig { .. } => "delete_provider_config",
+            WalletCommand::TestProviderConnection { .. } => "test_provider_connection",
+            WalletCommand::ResolveProviderEndpoint { .. } => "resolve_provider_endpoint",
+            WalletCommand::IsFirstTimeSetup { .. } => "is_first_time_setup",
+            WalletCommand::InitializeApp { .. } => "initialize_app",
+            WalletCommand::UnlockApp { .. } => "unlock_app",
+            WalletCommand::SwapLibrary { .. } => "swap_library",
+        }
+    }
+
+    /// Quick read-only commands that take the priority lane.
+    ///
+    /// Everything else (writes, key derivation, library swaps) waits in the
+    /// normal lane in submission order.
+    fn is_priority(&self) -> bool {
+        matches!(
+            self,
+            WalletCommand::GetVersion { .. }
+                | WalletCommand::ListWallets { .. }
+                | WalletCommand::QueryTransactionStatus { .. }
+                | WalletCommand::GetNonce { .. }
+                | WalletCommand::GetProviderConfig { .. }
+                | WalletCommand::ListProviderConfigs { .. }
+                | WalletCommand::IsFirstTimeSetup { .. }
+        )
+    }
+
+    /// What a priority-lane read looks at
+    fn reads(&self) -> Option<Resource> {
+        match self {
+            WalletCommand::GetVersion { .. } => Some(Resource::Library),
+            WalletCommand::ListWallets { .. } => Some(Resource::Wallets(None)),
+            WalletCommand::QueryTransactionStatus { params_json, .. }
+            | WalletCommand::GetNonce { params_json, .. } => {
+                Some(Resource::Chain(ResourceKeys::of(params_json).chain_id))
+            }
+            WalletCommand::GetProviderConfig { params_json, .. } => {
+                Some(Resource::ProviderConfigs(ResourceKeys::of(params_json).chain_id))
+            }
+            WalletCommand::ListProviderConfigs { .. } => Some(Resource::ProviderConfigs(None)),
+            WalletCommand::IsFirstTimeSetup { .. } => Some(Resource::AppConfig),
+            _ => None,
+        }
+    }
+
+    /// What a normal-lane command changes that a priority read could see
+    fn writes(&self) -> Option<Resource> {
+        match self {
+            WalletCommand::CreateWallet { .. } | WalletCommand::ImportWallet { .. } => {
+                Some(Resource::Wallets(None))
+            }
+            WalletCommand::RenameWallet { params_json, .. }
+            | WalletCommand::GenerateAddresses { params_json, .. }
+            | WalletCommand::ChangeWalletPassword { params_json, .. } => {
+                Some(Resource::Wallets(ResourceKeys::of(params_json).wallet_id))
+            }
+            WalletCommand::BroadcastTransaction { params_json, .. } => {
+                Some(Resource::Chain(ResourceKeys::of(params_json).chain_id))
+            }
+            WalletCommand::SetProviderConfig { params_json, .. }
+            | WalletCommand::DeleteProviderConfig { params_json, .. } => {
+                Some(Resource::ProviderConfigs(ResourceKeys::of(params_json).chain_id))
+            }
+            WalletCommand::InitializeApp { .. } => Some(Resource::AppConfig),
+            WalletCommand::SwapLibrary { .. } => Some(Resource::Library),
+            _ => None,
+        }
+    }
+}
+
+/// What a queued command reads or writes. A key of None covers every
+/// wallet or chain, e.g. a wallet listing or a params_json without the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Resource {
+    Wallets(Option<String>),
+    ProviderConfigs(Option<String>),
+    /// Account and transaction state on a chain (nonces, statuses)
+    Chain(Option<String>),
+    AppConfig,
+    Library,
+}
+
+impl Resource {
+    /// Whether the write `self` can change what the read `read` sees
+    fn affects(&self, read: &Resource) -> bool {
+        fn same(a: &Option<String>, b: &Option<String>) -> bool {
+            a.is_none() || b.is_none() || a == b
+        }
+
+        match (self, read) {
+            // Every later command talks to the swapped-in library
+            (Resource::Library, _) => true,
+            (Resource::Wallets(a), Resource::Wallets(b))
+            | (Resource::ProviderConfigs(a), Resource::ProviderConfigs(b))
+            | (Resource::Chain(a), Resource::Chain(b)) => same(a, b),
+            (Resource::AppConfig, Resource::AppConfig) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The params_json fields that name a command's resource. Only these are
+/// deserialized, so secrets in the params are never copied.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceKeys {
+    wallet_id: Option<String>,
+    chain_id: Option<String>,
+}
+
+impl ResourceKeys {
+    fn of(params_json: &str) -> Self {
+        serde_json::from_str(params_json).unwrap_or_default()
+    }
+}
+
+/// A command waiting for the worker
+struct QueuedCommand {
+    cmd: WalletCommand,
+    enqueued_at: Instant,
+    /// What the command reads (priority lane) or writes (normal lane)
+    resource: Option<Resource>,
+}
+
+impl QueuedCommand {
+    fn new(cmd: WalletCommand, enqueued_at: Instant) -> Self {
+        let resource = if cmd.is_priority() { cmd.reads() } else { cmd.writes() };
+        Self { cmd, enqueued_at, resource }
+    }
+
+    /// Whether this priority read must wait for the earlier normal-lane `write`
+    fn waits_for(&self, write: &QueuedCommand) -> bool {
+        // Same instant: the order is unknown, so assume the write came first
+        write.enqueued_at <= self.enqueued_at
+            && match (&write.resource, &self.resource) {
+                (Some(written), Some(read)) => written.affects(read),
+                _ => false,
+            }
+    }
+}
+
+#[derive(Default)]
+struct LaneState {
+    priority: VecDeque<QueuedCommand>,
+    normal: VecDeque<QueuedCommand>,
+    /// Every queue handle is gone; the worker exits after the running command
+    closed: bool,
+}
+
+/// Commands waiting for the worker, split into a priority and a normal lane
+struct CommandLanes {
+    state: Mutex<LaneState>,
+    ready: Condvar,
+    /// Commands that may wait at once, both lanes together
+    capacity: usize,
+    aging_threshold: Duration,
+}
+
+impl CommandLanes {
+    fn new(capacity: usize, aging_threshold: Duration) -> Self {
+        Self {
+            state: Mutex::new(LaneState::default()),
+            ready: Condvar::new(),
+            capacity,
+            aging_threshold,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LaneState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Queue `cmd`, handing it back if `capacity` commands are already waiting
+    fn push(&self, cmd: WalletCommand) -> Result<(), WalletCommand> {
+        let mut state = self.lock();
+        if state.priority.len() + state.normal.len() >= self.capacity {
+            return Err(cmd);
+        }
+
+        let lane = if cmd.is_priority() { &mut state.priority } else { &mut state.normal };
+        lane.push_back(QueuedCommand::new(cmd, Instant::now()));
+        self.ready.notify_one();
+        Ok(())
+    }
+
+    /// Next command to run, blocking while both lanes are empty.
+    /// Returns None once the lanes are closed and drained.
+    fn pop(&self) -> Option<QueuedCommand> {
+        let mut state = self.lock();
+        loop {
+            if let Some(next) = Self::select(&mut state, Instant::now(), self.aging_threshold) {
+                return Some(next);
+            }
+            if state.closed {
+                return None;
+            }
+            state = self.ready.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    /// Priority lane first, unless the oldest normal command has waited
+    /// `aging_threshold` or longer. A read never passes an earlier write to
+    /// what it reads; it runs once that write has.
+    fn select(state: &mut LaneState, now: Instant, aging_threshold: Duration) -> Option<QueuedCommand> {
+        let normal_aged = state
+            .normal
+            .front()
+            .is_some_and(|queued| now.duration_since(queued.enqueued_at) >= aging_threshold);
+
+        if normal_aged {
+            return state.normal.pop_front();
+        }
+
+        let ready_read = state
+            .priority
+            .iter()
+            .position(|read| !state.normal.iter().any(|write| read.waits_for(write)));
+        match ready_read {
+            Some(index) => state.priority.remove(index),
+            None => state.normal.pop_front(),
+        }
+    }
+
+    /// Stop accepting work and drop the commands not started yet, so their
+    /// callers get SHUTDOWN_ERROR instead of waiting on a closing app
+    fn close(&self) {
+        let dropped = {
+            let mut state = self.lock();
+            state.closed = true;
+            let mut dropped: Vec<QueuedCommand> = state.priority.drain(..).collect();
+            dropped.extend(state.normal.drain(..));
+            dropped
+        };
+        self.ready.notify_all();
+
+        if !dropped.is_empty() {
+            tracing::info!("Wallet queue shutting down; dropping {} pending command(s)", dropped.len());
+        }
+        // Responders are dropped outside the lock
+        drop(dropped);
+    }
+}
+
+/// Queue handles' shared link to the worker; closes the lanes when the last
+/// handle is dropped so the worker thread can exit
+struct LaneSender(Arc<CommandLanes>);
+
+impl Drop for LaneSender {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
+/// Receiver for progress reports of long-running operations.
+///
+/// Called on the thread that reports progress (the Go library's thread),
+/// so implementations must be cheap and non-blocking.
+pub type ProgressSink = Arc<dyn Fn(WalletProgress) + Send + Sync>;
+
 /// WalletQueue serializes all wallet operations through a single-threaded queue.
 ///
 /// Uses ONLY std::sync primitives (no Tokio) to avoid macOS thread restrictions.
 #[derive(Clone)]
 pub struct WalletQueue {
-    sender: mpsc::Sender<WalletCommand>,
+    sender: Arc<LaneSender>,
     metrics: QueueMetrics,
 }
 
@@ -212,23 +866,37 @@ impl WalletQueue {
     ///
     /// Spawns a background worker thread using std::thread.
     /// This is safe to call from any context (no Tokio required).
-    pub fn new(library: Arc<WalletLibrary>) -> Self {
-        let (sender, receiver) = mpsc::channel::<WalletCommand>();
-
-        let metrics = QueueMetrics::new();
+    /// Progress reports go to whatever sink is installed in `progress_sink`,
+    /// which may be set before or after the queue is created.
+    /// Metrics warn at `thresholds`.
+    /// At most `capacity` commands wait behind the running one; further
+    /// commands fail with a QUEUE_BUSY error instead of piling up.
+    pub fn new(
+        library: Arc<WalletLibrary>,
+        progress_sink: Arc<OnceLock<ProgressSink>>,
+        thresholds: MetricThresholds,
+        capacity: usize,
+    ) -> Self {
+        let lanes = Arc::new(CommandLanes::new(capacity, PRIORITY_AGING_THRESHOLD));
+        let receiver = lanes.clone();
+
+        let metrics = QueueMetrics::new(thresholds);
         let metrics_clone = metrics.clone();
 
         // Spawn worker thread using std::thread (NOT tokio::spawn)
         thread::Builder::new()
             .name("wallet-queue-worker".to_string())
             .spawn(move || {
-                Self::worker_task(library, receiver, metrics_clone);
+                Self::worker_task(library, receiver, metrics_clone, progress_sink);
             })
             .expect("Failed to spawn wallet queue worker thread");
 
         tracing::info!("✓ Wallet queue worker thread started");
 
-        WalletQueue { sender, metrics }
+        WalletQueue {
+            sender: Arc::new(LaneSender(lanes)),
+            metrics,
+        }
     }
 
     /// Get queue metrics for monitoring
@@ -236,96 +904,213 @@ impl WalletQueue {
         &self.metrics
     }
 
+    /// Queue `cmd` without blocking; fails with QUEUE_BUSY if the queue is full
+    fn enqueue(&self, cmd: WalletCommand) -> Result<ResponseDeadline, String> {
+        let deadline = ResponseDeadline::for_command(&cmd);
+        let depth = self.metrics.record_enqueue();
+
+        match self.sender.0.push(cmd) {
+            Ok(()) => Ok(deadline),
+            Err(cmd) => {
+                self.metrics.record_rejected(depth);
+                tracing::warn!("Wallet queue full; rejecting {}", cmd.operation_name());
+                Err(format!(
+                    "{}: Too many wallet operations pending. Please try again shortly.",
+                    QUEUE_BUSY_ERROR_CODE
+                ))
+            }
+        }
+    }
+
+    /// Build the FFI progress handler for an operation, if a sink is installed
+    fn progress_handler(
+        progress_sink: &OnceLock<ProgressSink>,
+        operation: &'static str,
+    ) -> Option<ProgressFn> {
+        let sink = progress_sink.get()?.clone();
+        Some(Arc::new(move |completed, total| {
+            sink(WalletProgress {
+                operation: operation.to_string(),
+                completed,
+                total,
+            })
+        }))
+    }
+
     /// Background worker task that processes wallet commands sequentially.
     ///
     /// This runs in a dedicated std::thread for the lifetime of the application.
     fn worker_task(
-        library: Arc<WalletLibrary>,
-        receiver: mpsc::Receiver<WalletCommand>,
+        mut library: Arc<WalletLibrary>,
+        receiver: Arc<CommandLanes>,
         metrics: QueueMetrics,
+        progress_sink: Arc<OnceLock<ProgressSink>>,
     ) {
         tracing::info!("Wallet queue worker thread running");
         let mut operations_count = 0u64;
 
         // Block on receiving commands (this is a blocking thread, not async)
-        while let Ok(cmd) = receiver.recv() {
+        while let Some(QueuedCommand { cmd, .. }) = receiver.pop() {
             let operation_start = Instant::now();
+            let operation = cmd.operation_name();
 
             match cmd {
                 WalletCommand::GetVersion { respond_to } => {
                     let result = library.get_version();
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::CreateWallet { params_json, respond_to } => {
-                    let result = library.create_wallet(&params_json);
+                    let result = match Self::progress_handler(&progress_sink, operation) {
+                        Some(handler) => library.create_wallet_with_progress(&params_json, handler),
+                        None => library.create_wallet(&params_json),
+                    };
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::ImportWallet { params_json, respond_to } => {
-                    let result = library.import_wallet(&params_json);
+                    let result = match Self::progress_handler(&progress_sink, operation) {
+                        Some(handler) => library.import_wallet_with_progress(&params_json, handler),
+                        None => library.import_wallet(&params_json),
+                    };
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::UnlockWallet { params_json, respond_to } => {
                     let result = library.unlock_wallet(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::GenerateAddresses { params_json, respond_to } => {
                     let result = library.generate_addresses(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::ExportWallet { params_json, respond_to } => {
                     let result = library.export_wallet(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::RenameWallet { params_json, respond_to } => {
                     let result = library.rename_wallet(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::ListWallets { params_json, respond_to } => {
                     let result = library.list_wallets(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::BuildTransaction { params_json, respond_to } => {
+                    let result = library.build_transaction(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::BuildTransactionMulti { params_json, respond_to } => {
+                    let result = library.build_transaction_multi(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::EstimateFee { params_json, respond_to } => {
+                    let result = library.estimate_fee(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::QueryTransactionStatus { params_json, respond_to } => {
+                    let result = library.query_transaction_status(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::GetNonce { params_json, respond_to } => {
+                    let result = library.get_nonce(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::BroadcastTransaction { params_json, respond_to } => {
+                    let result = library.broadcast_transaction(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::ValidateTransaction { params_json, respond_to } => {
+                    let result = library.validate_transaction(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::SignMessage { params_json, respond_to } => {
+                    let result = library.sign_message(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::VerifyMessage { params_json, respond_to } => {
+                    let result = library.verify_message(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::DeriveFirstAddress { params_json, respond_to } => {
+                    let result = library.derive_first_address(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::ChangeWalletPassword { params_json, respond_to } => {
+                    let result = library.change_wallet_password(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::ExportDescriptor { params_json, respond_to } => {
+                    let result = library.export_descriptor(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::SetProviderConfig { params_json, respond_to } => {
                     let result = library.set_provider_config(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::GetProviderConfig { params_json, respond_to } => {
                     let result = library.get_provider_config(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::ListProviderConfigs { params_json, respond_to } => {
                     let result = library.list_provider_configs(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::DeleteProviderConfig { params_json, respond_to } => {
                     let result = library.delete_provider_config(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::TestProviderConnection { params_json, respond_to } => {
+                    let result = library.test_provider_connection(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(result);
+                }
+                WalletCommand::ResolveProviderEndpoint { params_json, respond_to } => {
+                    let result = library.resolve_provider_endpoint(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::IsFirstTimeSetup { params_json, respond_to } => {
                     let result = library.is_first_time_setup(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::InitializeApp { params_json, respond_to } => {
                     let result = library.initialize_app(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
                 }
                 WalletCommand::UnlockApp { params_json, respond_to } => {
                     let result = library.unlock_app(&params_json);
+                    metrics.record_dequeue(operation, operation_start.elapsed());
                     let _ = respond_to.send(result);
-                    metrics.record_dequeue(operation_start.elapsed());
+                }
+                WalletCommand::SwapLibrary { library: replacement, respond_to } => {
+                    library = replacement;
+                    tracing::info!("Wallet queue switched to reloaded library");
+                    metrics.record_dequeue(operation, operation_start.elapsed());
+                    let _ = respond_to.send(());
                 }
             }
 
@@ -340,313 +1125,471 @@ impl WalletQueue {
         tracing::info!("Wallet queue worker thread exiting");
     }
 
+    /// Switch to `library` once the commands queued before this call are done.
+    pub async fn swap_library(&self, library: Arc<WalletLibrary>) -> Result<(), String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::SwapLibrary {
+            library,
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await
+    }
+
     /// Get library version (blocking wrapper for async context).
     pub async fn get_version(&self) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::GetVersion { respond_to: sender })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::GetVersion { respond_to: sender })?;
 
-        // Use tokio::task::spawn_blocking to await the sync channel
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Create a new HD wallet from provided mnemonic.
     pub async fn create_wallet(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::CreateWallet {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::CreateWallet {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Import an existing wallet from mnemonic.
     pub async fn import_wallet(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::ImportWallet {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::ImportWallet {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Authenticate and load wallet into memory.
     pub async fn unlock_wallet(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::UnlockWallet {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::UnlockWallet {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Derive addresses for specified blockchains.
     pub async fn generate_addresses(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::GenerateAddresses {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::GenerateAddresses {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Export wallet metadata without private keys.
     pub async fn export_wallet(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::ExportWallet {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::ExportWallet {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Change wallet display name.
     pub async fn rename_wallet(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::RenameWallet {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::RenameWallet {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Enumerate all wallets on USB.
     pub async fn list_wallets(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::ListWallets {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::ListWallets {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
+    }
+
+    /// Build an unsigned transaction.
+    pub async fn build_transaction(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::BuildTransaction {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Build an unsigned transaction with several outputs.
+    pub async fn build_transaction_multi(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::BuildTransactionMulti {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Estimate the fee of a single transfer.
+    pub async fn estimate_fee(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::EstimateFee {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Query the status of a transaction by hash.
+    pub async fn query_transaction_status(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::QueryTransactionStatus {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Next nonce of an account (account-based chains).
+    pub async fn get_nonce(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::GetNonce {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Submit a signed transaction to the network.
+    pub async fn broadcast_transaction(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::BroadcastTransaction {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Check whether the network would accept a signed transaction.
+    pub async fn validate_transaction(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::ValidateTransaction {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Sign a message with one of the wallet's address keys.
+    pub async fn sign_message(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::SignMessage {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Verify a signed message against an address.
+    pub async fn verify_message(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::VerifyMessage {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Derive the first address of a mnemonic (no wallet needed).
+    pub async fn derive_first_address(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::DeriveFirstAddress {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Re-encrypt a wallet under a new password.
+    pub async fn change_wallet_password(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::ChangeWalletPassword {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// Export a watch-only descriptor for one chain.
+    pub async fn export_descriptor(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::ExportDescriptor {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
     }
 
     /// Set blockchain provider configuration.
     pub async fn set_provider_config(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::SetProviderConfig {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::SetProviderConfig {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Get blockchain provider configuration.
     pub async fn get_provider_config(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::GetProviderConfig {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::GetProviderConfig {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// List all provider configurations.
     pub async fn list_provider_configs(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::ListProviderConfigs {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::ListProviderConfigs {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Delete a provider configuration.
-    pub async fn delete_provider_config(&self, params_json: String) -> Result<serde_json::Value, String> {
+    pub async fn delete_provider_config(&self, params_json: String) -> Result<(), String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::DeleteProviderConfig {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::DeleteProviderConfig {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
+    }
+
+    /// Try provider credentials without storing them.
+    pub async fn test_provider_connection(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::TestProviderConnection {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
+    }
+
+    /// JSON-RPC endpoint of a stored provider config, API key included.
+    pub async fn resolve_provider_endpoint(&self, params_json: String) -> Result<serde_json::Value, String> {
+        let (sender, receiver) = oneshot();
+
+        let deadline = self.enqueue(WalletCommand::ResolveProviderEndpoint {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
+
+        await_response(receiver, deadline).await?
     }
 
     /// Check if this is first-time setup.
     pub async fn is_first_time_setup(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::IsFirstTimeSetup {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::IsFirstTimeSetup {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Initialize app configuration.
     pub async fn initialize_app(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::InitializeApp {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::InitializeApp {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 
     /// Unlock app and load configuration.
     pub async fn unlock_app(&self, params_json: String) -> Result<serde_json::Value, String> {
         let (sender, receiver) = oneshot();
 
-        self.metrics.record_enqueue();
-        self.sender
-            .send(WalletCommand::UnlockApp {
-                params_json,
-                respond_to: sender,
-            })
-            .map_err(|_| "Queue channel closed".to_string())?;
+        let deadline = self.enqueue(WalletCommand::UnlockApp {
+            params_json: params_json.into(),
+            respond_to: sender,
+        })?;
 
-        tokio::task::spawn_blocking(move || {
-            receiver.recv().map_err(|_| "Response channel closed".to_string())?
-        })
-        .await
-        .map_err(|e| format!("Task join error: {}", e))?
+        await_response(receiver, deadline).await?
     }
 }
 
 /// Lazy-initialized WalletQueue wrapper
 /// Initializes the queue on first use
 pub struct LazyWalletQueue {
-    library: Arc<WalletLibrary>,
+    library: RwLock<Arc<WalletLibrary>>,
+    /// GetVersion of `library`, fetched on first use
+    version_info: RwLock<Option<VersionInfo>>,
     queue: OnceLock<WalletQueue>,
+    progress_sink: Arc<OnceLock<ProgressSink>>,
+    thresholds: MetricThresholds,
+    capacity: usize,
 }
 
 impl LazyWalletQueue {
     /// Create a new lazy wallet queue
     pub fn new(library: Arc<WalletLibrary>) -> Self {
         Self {
-            library,
+            library: RwLock::new(library),
+            version_info: RwLock::new(None),
             queue: OnceLock::new(),
+            progress_sink: Arc::new(OnceLock::new()),
+            thresholds: MetricThresholds::default(),
+            capacity: DEFAULT_QUEUE_CAPACITY,
+        }
+    }
+
+    /// Set the metric warning thresholds used once the queue starts
+    pub fn with_metric_thresholds(mut self, thresholds: MetricThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Set how many commands may wait in the queue once it starts
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// The loaded wallet library
+    pub fn library(&self) -> Arc<WalletLibrary> {
+        self.library.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the wallet library, e.g. after the user repaired an install.
+    ///
+    /// Commands already queued finish on the old library. The old library
+    /// stays mapped: a Go runtime can't be unloaded safely.
+    pub async fn swap_library(&self, library: Arc<WalletLibrary>) -> Result<(), String> {
+        let previous = std::mem::replace(
+            &mut *self.library.write().unwrap_or_else(|e| e.into_inner()),
+            library.clone(),
+        );
+        std::mem::forget(previous);
+        *self.version_info.write().unwrap_or_else(|e| e.into_inner()) = None;
+
+        match self.queue.get() {
+            Some(queue) => queue.swap_library(library).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Version info of the current library (cached after the first call)
+    pub async fn version_info(&self) -> Result<VersionInfo, String> {
+        if let Some(info) = self.version_info.read().unwrap_or_else(|e| e.into_inner()).clone() {
+            return Ok(info);
         }
+
+        let data = self.get_version().await?;
+        let info: VersionInfo = serde_json::from_value(data)
+            .map_err(|e| format!("Invalid GetVersion response: {}", e))?;
+        *self.version_info.write().unwrap_or_else(|e| e.into_inner()) = Some(info.clone());
+        Ok(info)
     }
 
     /// Get or initialize the queue
     fn get_or_init(&self) -> &WalletQueue {
         self.queue.get_or_init(|| {
-            WalletQueue::new(self.library.clone())
+            WalletQueue::new(
+                self.library(),
+                self.progress_sink.clone(),
+                self.thresholds,
+                self.capacity,
+            )
         })
     }
 
+    /// Snapshot of the queue metrics (empty if the worker hasn't started)
+    pub fn metrics_snapshot(&self) -> QueueMetricsSnapshot {
+        self.queue
+            .get()
+            .map(|queue| queue.metrics().snapshot())
+            .unwrap_or_default()
+    }
+
+    /// Reset the queue metrics, if the worker has started
+    pub fn reset_metrics(&self) {
+        if let Some(queue) = self.queue.get() {
+            queue.metrics().reset();
+        }
+    }
+
+    /// Install the receiver for progress reports without starting the worker
+    pub fn set_progress_sink(&self, sink: ProgressSink) {
+        if self.progress_sink.set(sink).is_err() {
+            tracing::warn!("Progress sink already installed; ignoring");
+        }
+    }
+
     /// Create a new HD wallet from provided mnemonic
     pub async fn create_wallet(&self, params_json: String) -> Result<serde_json::Value, String> {
         self.get_or_init().create_wallet(params_json).await
@@ -682,6 +1625,66 @@ impl LazyWalletQueue {
         self.get_or_init().list_wallets(params_json).await
     }
 
+    /// Build an unsigned transaction
+    pub async fn build_transaction(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().build_transaction(params_json).await
+    }
+
+    /// Build an unsigned transaction with several outputs
+    pub async fn build_transaction_multi(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().build_transaction_multi(params_json).await
+    }
+
+    /// Estimate the fee of a single transfer
+    pub async fn estimate_fee(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().estimate_fee(params_json).await
+    }
+
+    /// Query the status of a transaction by hash
+    pub async fn query_transaction_status(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().query_transaction_status(params_json).await
+    }
+
+    /// Next nonce of an account (account-based chains)
+    pub async fn get_nonce(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().get_nonce(params_json).await
+    }
+
+    /// Submit a signed transaction to the network
+    pub async fn broadcast_transaction(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().broadcast_transaction(params_json).await
+    }
+
+    /// Check whether the network would accept a signed transaction
+    pub async fn validate_transaction(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().validate_transaction(params_json).await
+    }
+
+    /// Sign a message with one of the wallet's address keys
+    pub async fn sign_message(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().sign_message(params_json).await
+    }
+
+    /// Verify a signed message against an address
+    pub async fn verify_message(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().verify_message(params_json).await
+    }
+
+    /// Derive the first address of a mnemonic (no wallet needed)
+    pub async fn derive_first_address(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().derive_first_address(params_json).await
+    }
+
+    /// Re-encrypt a wallet under a new password
+    pub async fn change_wallet_password(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().change_wallet_password(params_json).await
+    }
+
+    /// Export a watch-only descriptor for one chain
+    pub async fn export_descriptor(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().export_descriptor(params_json).await
+    }
+
     /// Set blockchain provider configuration
     pub async fn set_provider_config(&self, params_json: String) -> Result<serde_json::Value, String> {
         self.get_or_init().set_provider_config(params_json).await
@@ -698,10 +1701,20 @@ impl LazyWalletQueue {
     }
 
     /// Delete a provider configuration
-    pub async fn delete_provider_config(&self, params_json: String) -> Result<serde_json::Value, String> {
+    pub async fn delete_provider_config(&self, params_json: String) -> Result<(), String> {
         self.get_or_init().delete_provider_config(params_json).await
     }
 
+    /// Try provider credentials without storing them
+    pub async fn test_provider_connection(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().test_provider_connection(params_json).await
+    }
+
+    /// JSON-RPC endpoint of a stored provider config, API key included
+    pub async fn resolve_provider_endpoint(&self, params_json: String) -> Result<serde_json::Value, String> {
+        self.get_or_init().resolve_provider_endpoint(params_json).await
+    }
+
     /// Check if this is first-time setup
     pub async fn is_first_time_setup(&self, params_json: String) -> Result<serde_json::Value, String> {
         self.get_or_init().is_first_time_setup(params_json).await
@@ -722,3 +1735,409 @@ impl LazyWalletQueue {
         self.get_or_init().get_version().await
     }
 }
+
+/// Wallet backend registered as Tauri state.
+///
+/// Always managed, so commands get a clear error instead of Tauri's
+/// "state not managed" panic when the FFI library isn't loaded.
+pub struct WalletBackend(Option<LazyWalletQueue>);
+
+impl WalletBackend {
+    pub fn new(queue: Option<LazyWalletQueue>) -> Self {
+        Self(queue)
+    }
+
+    /// The FFI queue, or CliNotFound if FFI is disabled or failed to load
+    pub fn queue(&self) -> AppResult<&LazyWalletQueue> {
+        self.0.as_ref().ok_or_else(|| {
+            AppError::new(
+                crate::error::ErrorCode::CliNotFound,
+                "FFI disabled; CLI backend not configured",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::bindings::ProgressCallbackFn;
+    use crate::ffi::stub::{ffi_string, stub_library};
+    use std::os::raw::c_char;
+
+    const TOTAL: i32 = 54;
+
+    /// Reports every address and finishes with the new wallet
+    unsafe extern "C" fn create_with_progress(
+        _params: *const c_char,
+        callback: ProgressCallbackFn,
+        token: usize,
+    ) -> *mut c_char {
+        for completed in 1..=TOTAL {
+            callback(completed, TOTAL, token);
+        }
+        ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+    }
+
+    fn collecting_sink() -> (ProgressSink, Arc<Mutex<Vec<WalletProgress>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_clone = events.clone();
+        let sink: ProgressSink = Arc::new(move |progress| {
+            events_clone.lock().unwrap().push(progress);
+        });
+        (sink, events)
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_per_operation_type() {
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        assert_eq!(queue.metrics_snapshot().total_operations, 0);
+
+        // Stubbed calls fail, but still go through the queue
+        let _ = queue.list_wallets("{}".to_string()).await;
+        let _ = queue.list_wallets("{}".to_string()).await;
+        let _ = queue.create_wallet("{}".to_string()).await;
+        let _ = queue.get_version().await;
+
+        let snapshot = queue.metrics_snapshot();
+        assert_eq!(snapshot.total_operations, 4);
+        assert_eq!(snapshot.operations["list_wallets"].count, 2);
+        assert_eq!(snapshot.operations["create_wallet"].count, 1);
+        assert_eq!(snapshot.operations["get_version"].count, 1);
+        assert!(!snapshot.operations.contains_key("import_wallet"));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_reset_zeroes_counters() {
+        let queue = LazyWalletQueue::new(Arc::new(stub_library()));
+        let _ = queue.list_wallets("{}".to_string()).await;
+        let _ = queue.unlock_wallet("{}".to_string()).await;
+
+        queue.reset_metrics();
+
+        let snapshot = queue.metrics_snapshot();
+        assert_eq!(snapshot.total_operations, 0);
+        assert_eq!(snapshot.peak_depth, 0);
+        assert_eq!(snapshot.average_wait_time_ms, 0.0);
+        assert!(snapshot.operations.is_empty());
+
+        let _ = queue.list_wallets("{}".to_string()).await;
+        assert_eq!(queue.metrics_snapshot().operations["list_wallets"].count, 1);
+    }
+
+    #[test]
+    fn test_metrics_warn_above_custom_thresholds() {
+        let metrics = QueueMetrics::new(MetricThresholds {
+            max_avg_wait_ms: 50.0,
+            max_depth: 2,
+        });
+
+        // 40ms average and depth 2: below both thresholds
+        for _ in 0..4 {
+            metrics.record_enqueue();
+        }
+        metrics.record_dequeue("list_wallets", Duration::from_millis(30));
+        metrics.record_dequeue("list_wallets", Duration::from_millis(50));
+        assert!(metrics.threshold_warnings().is_empty());
+
+        // 60ms average and depth 3: above both
+        metrics.record_enqueue();
+        metrics.record_dequeue("unlock_wallet", Duration::from_millis(100));
+        metrics.record_enqueue();
+
+        let warnings = metrics.threshold_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("wait time elevated: 60.00ms"));
+        assert!(warnings[1].contains("3 operations pending"));
+
+        // The defaults would already have warned at 40ms
+        assert!(MetricThresholds::default().max_avg_wait_ms < 40.0);
+    }
+
+    #[test]
+    fn test_backend_without_queue_returns_cli_not_found() {
+        let backend = WalletBackend::new(None);
+        let err = backend.queue().err().unwrap();
+        assert_eq!(err.code, crate::error::ErrorCode::CliNotFound);
+        assert!(err.message.contains("FFI disabled"));
+
+        let backend = WalletBackend::new(Some(LazyWalletQueue::new(Arc::new(stub_library()))));
+        assert!(backend.queue().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_and_import_forward_progress_events() {
+        let mut library = stub_library();
+        library.create_wallet_with_progress = Some(create_with_progress);
+        library.import_wallet_with_progress = Some(create_with_progress);
+
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let (sink, events) = collecting_sink();
+        queue.set_progress_sink(sink);
+
+        let result = queue.create_wallet("{}".to_string()).await.unwrap();
+        assert_eq!(result["walletId"], "w");
+        queue.import_wallet("{}".to_string()).await.unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2 * TOTAL as usize);
+        let (created, imported) = events.split_at(TOTAL as usize);
+        assert!(created.iter().all(|e| e.operation == "create_wallet" && e.total == 54));
+        assert!(imported.iter().all(|e| e.operation == "import_wallet" && e.total == 54));
+        let completed: Vec<u32> = created.iter().map(|e| e.completed).collect();
+        assert_eq!(completed, (1..=54).collect::<Vec<u32>>());
+    }
+
+    #[tokio::test]
+    async fn test_create_without_progress_symbol_falls_back() {
+        unsafe extern "C" fn create(_params: *const c_char) -> *mut c_char {
+            ffi_string(r#"{"success":true,"data":{"walletId":"w"}}"#)
+        }
+
+        let mut library = stub_library();
+        library.create_wallet = create;
+
+        let queue = LazyWalletQueue::new(Arc::new(library));
+        let (sink, events) = collecting_sink();
+        queue.set_progress_sink(sink);
+
+        assert!(queue.create_wallet("{}".to_string()).await.is_ok());
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_rejects_with_busy_error() {
+        use std::sync::atomic::AtomicBool;
+
+        static STARTED: AtomicBool = AtomicBool::new(false);
+        static RELEASE: AtomicBool = AtomicBool::new(false);
+
+        /// Holds the worker until RELEASE is set
+        unsafe extern "C" fn blocking_list(_params: *const c_char) -> *mut c_char {
+            STARTED.store(true, Ordering::SeqCst);
+            while !RELEASE.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            ffi_string(r#"{"success":true,"data":{"wallets":[]}}"#)
+        }
+
+        let mut library = stub_library();
+        library.list_wallets = blocking_list;
+        let queue = Arc::new(LazyWalletQueue::new(Arc::new(library)).with_capacity(1));
+
+        // One command running in the worker, one waiting: the queue is full
+        let running = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.list_wallets("{}".to_string()).await }
+        });
+        while !STARTED.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        let waiting = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.list_wallets("{}".to_string()).await }
+        });
+        while queue.metrics_snapshot().current_depth < 2 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        let err = queue.list_wallets("{}".to_string()).await.unwrap_err();
+        let code = err.split_once(':').map(|(code, _)| AppError::from_ffi_error_code(code)).unwrap();
+        assert_eq!(code, crate::error::ErrorCode::QueueBusy);
+
+        // The rejected command never counted as queued
+        let snapshot = queue.metrics_snapshot();
+        assert_eq!(snapshot.current_depth, 2);
+        assert_eq!(snapshot.peak_depth, 2);
+
+        RELEASE.store(true, Ordering::SeqCst);
+        assert!(running.await.unwrap().is_ok());
+        assert!(waiting.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_dropping_queue_fails_pending_calls_with_shutdown_error() {
+        use std::sync::atomic::AtomicBool;
+
+        static STARTED: AtomicBool = AtomicBool::new(false);
+        static RELEASE: AtomicBool = AtomicBool::new(false);
+
+        /// Holds the worker until RELEASE is set
+        unsafe extern "C" fn blocking_list(_params: *const c_char) -> *mut c_char {
+            STARTED.store(true, Ordering::SeqCst);
+            while !RELEASE.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            ffi_string(r#"{"success":true,"data":{"wallets":[]}}"#)
+        }
+
+        let mut library = stub_library();
+        library.list_wallets = blocking_list;
+        let queue = WalletQueue::new(Arc::new(library), Arc::new(OnceLock::new()), MetricThresholds::default(), 4);
+
+        // One command running in the worker, one waiting behind it
+        let (running_sender, running_receiver) = oneshot();
+        let running_deadline = queue
+            .enqueue(WalletCommand::ListWallets { params_json: "{}".to_string().into(), respond_to: running_sender })
+            .unwrap();
+        while !STARTED.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        let (pending_sender, pending_receiver) = oneshot();
+        let pending_deadline = queue.enqueue(WalletCommand::GetVersion { respond_to: pending_sender }).unwrap();
+
+        drop(queue);
+
+        let err = await_response(pending_receiver, pending_deadline).await.unwrap_err();
+        assert_eq!(err, SHUTDOWN_ERROR);
+        let code = err.split_once(':').map(|(code, _)| AppError::from_ffi_error_code(code)).unwrap();
+        assert_eq!(code, crate::error::ErrorCode::InternalError);
+
+        // The command already running still gets its answer
+        RELEASE.store(true, Ordering::SeqCst);
+        assert!(await_response(running_receiver, running_deadline).await.unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_timeouts_are_tuned_per_operation() {
+        let version = WalletCommand::GetVersion { respond_to: oneshot().0 };
+        let list = WalletCommand::ListWallets { params_json: "{}".to_string().into(), respond_to: oneshot().0 };
+        let unlock = WalletCommand::UnlockWallet { params_json: "{}".to_string().into(), respond_to: oneshot().0 };
+        let create = WalletCommand::CreateWallet { params_json: "{}".to_string().into(), respond_to: oneshot().0 };
+
+        assert_eq!(timeout_for(&version), VERSION_TIMEOUT);
+        assert_eq!(timeout_for(&create), KEY_GENERATION_TIMEOUT);
+        assert_ne!(timeout_for(&version), timeout_for(&create));
+        assert!(timeout_for(&version) < timeout_for(&list));
+        assert!(timeout_for(&list) < timeout_for(&unlock));
+        assert!(timeout_for(&unlock) < timeout_for(&create));
+    }
+
+    #[tokio::test]
+    async fn test_missing_response_times_out() {
+        let (_sender, receiver) = oneshot::<Result<VersionInfo, String>>();
+        let deadline = ResponseDeadline { operation: "get_version", timeout: Duration::from_millis(20) };
+
+        let err = await_response(receiver, deadline).await.unwrap_err();
+        assert!(err.starts_with("TIMEOUT: get_version did not finish within"));
+        let code = err.split_once(':').map(|(code, _)| AppError::from_ffi_error_code(code)).unwrap();
+        assert_eq!(code, crate::error::ErrorCode::CliTimeout);
+    }
+
+    #[test]
+    fn test_aged_write_runs_despite_continuous_reads() {
+        fn queued(cmd: WalletCommand, enqueued_at: Instant) -> QueuedCommand {
+            QueuedCommand::new(cmd, enqueued_at)
+        }
+        fn list_wallets() -> WalletCommand {
+            WalletCommand::ListWallets { params_json: "{}".to_string().into(), respond_to: oneshot().0 }
+        }
+        fn export_wallet() -> WalletCommand {
+            WalletCommand::ExportWallet { params_json: "{}".to_string().into(), respond_to: oneshot().0 }
+        }
+
+        let threshold = Duration::from_millis(500);
+        let start = Instant::now();
+        let mut state = LaneState::default();
+
+        // A write the reads don't depend on
+        let set_provider = WalletCommand::SetProviderConfig {
+            params_json: r#"{"chainId":"ethereum"}"#.to_string().into(),
+            respond_to: oneshot().0,
+        };
+        assert!(!set_provider.is_priority());
+        state.normal.push_back(queued(set_provider, start));
+
+        // A new read arrives every 10ms and the worker takes one every 10ms
+        let mut now = start;
+        let mut reads_first = 0;
+        loop {
+            state.priority.push_back(queued(list_wallets(), now));
+            let next = CommandLanes::select(&mut state, now, threshold).unwrap();
+            if next.cmd.operation_name() == "set_provider_config" {
+                break;
+            }
+            reads_first += 1;
+            assert!(reads_first <= 50, "write was starved");
+            now += Duration::from_millis(10);
+        }
+
+        // Reads ran ahead only until the write had waited the threshold
+        assert_eq!(reads_first, 50);
+        assert_eq!(now.duration_since(start), threshold);
+
+        // A fresh write still waits behind the pending read
+        state.normal.push_back(queued(export_wallet(), now));
+        let next = CommandLanes::select(&mut state, now, threshold).unwrap();
+        assert_eq!(next.cmd.operation_name(), "list_wallets");
+        let next = CommandLanes::select(&mut state, now, threshold).unwrap();
+        assert_eq!(next.cmd.operation_name(), "export_wallet");
+    }
+
+    #[test]
+    fn test_read_waits_for_earlier_write_to_what_it_reads() {
+        fn command(operation: &str, params: &str) -> WalletCommand {
+            let params_json: FfiParams = params.to_string().into();
+            match operation {
+                "rename_wallet" => WalletCommand::RenameWallet { params_json, respond_to: oneshot().0 },
+                "list_wallets" => WalletCommand::ListWallets { params_json, respond_to: oneshot().0 },
+                "get_nonce" => WalletCommand::GetNonce { params_json, respond_to: oneshot().0 },
+                "set_provider_config" => WalletCommand::SetProviderConfig { params_json, respond_to: oneshot().0 },
+                "get_provider_config" => WalletCommand::GetProviderConfig { params_json, respond_to: oneshot().0 },
+                _ => unreachable!(),
+            }
+        }
+        fn push(state: &mut LaneState, cmd: WalletCommand, enqueued_at: Instant) {
+            let lane = if cmd.is_priority() { &mut state.priority } else { &mut state.normal };
+            lane.push_back(QueuedCommand::new(cmd, enqueued_at));
+        }
+        fn run_order(state: &mut LaneState, now: Instant) -> Vec<(&'static str, String)> {
+            std::iter::from_fn(|| CommandLanes::select(state, now, Duration::from_secs(60)))
+                .map(|queued| {
+                    let params = match &queued.cmd {
+                        WalletCommand::RenameWallet { params_json, .. }
+                        | WalletCommand::ListWallets { params_json, .. }
+                        | WalletCommand::GetNonce { params_json, .. }
+                        | WalletCommand::SetProviderConfig { params_json, .. }
+                        | WalletCommand::GetProviderConfig { params_json, .. } => params_json.to_string(),
+                        _ => unreachable!(),
+                    };
+                    (queued.cmd.operation_name(), params)
+                })
+                .collect()
+        }
+
+        let start = Instant::now();
+        let later = start + Duration::from_millis(1);
+        let mut state = LaneState::default();
+
+        // Listed before the rename was queued: it may still go first
+        push(&mut state, command("list_wallets", "{}"), start);
+        push(&mut state, command("rename_wallet", r#"{"walletId":"a","newName":"x"}"#), later);
+        push(&mut state, command("set_provider_config", r#"{"chainId":"ethereum","apiKey":"k"}"#), later);
+        push(&mut state, command("get_nonce", r#"{"chainId":"ethereum"}"#), later);
+        push(&mut state, command("list_wallets", r#"{"usbPath":"/usb"}"#), later);
+        push(&mut state, command("get_provider_config", r#"{"chainId":"bitcoin"}"#), later);
+        push(&mut state, command("get_provider_config", r#"{"chainId":"ethereum"}"#), later);
+
+        let order = run_order(&mut state, later);
+        let operations: Vec<&str> = order.iter().map(|(operation, _)| *operation).collect();
+        assert_eq!(
+            operations,
+            vec![
+                "list_wallets",
+                // Nonces and other chains' providers don't depend on the writes
+                "get_nonce",
+                "get_provider_config",
+                // The second listing waits for the rename
+                "rename_wallet",
+                "list_wallets",
+                // Ethereum's provider is read after it was set
+                "set_provider_config",
+                "get_provider_config",
+            ]
+        );
+        assert!(order[2].1.contains("bitcoin"));
+        assert!(order[6].1.contains("ethereum"));
+    }
+}
@@ -0,0 +1,70 @@
+//! In-process stand-in for the Go shared library (tests only).
+//!
+//! Every function returns a "not stubbed" FFIResponse error unless a test
+//! overrides its field. Strings are allocated with `CString::into_raw` and
+//! released by `stub_go_free`, mirroring the Go C.CString/GoFree contract.
+
+use super::bindings::{FfiAllocations, WalletLibrary};
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+/// Allocate a response string the way the Go library would
+pub(crate) fn ffi_string(json: &str) -> *mut c_char {
+    CString::new(json).expect("stub JSON contains NUL").into_raw()
+}
+
+unsafe extern "C" fn stub_go_free(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+unsafe extern "C" fn stub_get_version() -> *mut c_char {
+    ffi_string(r#"{"success":true,"data":{"version":"0.0.0-stub"}}"#)
+}
+
+unsafe extern "C" fn not_stubbed(_params: *const c_char) -> *mut c_char {
+    ffi_string(r#"{"success":false,"error":{"code":"NOT_STUBBED","message":"not stubbed"}}"#)
+}
+
+/// Library whose functions all fail with NOT_STUBBED
+pub(crate) fn stub_library() -> WalletLibrary {
+    WalletLibrary {
+        lib: None,
+        path: None,
+        go_free: stub_go_free,
+        get_version: stub_get_version,
+        create_wallet: not_stubbed,
+        import_wallet: not_stubbed,
+        unlock_wallet: not_stubbed,
+        generate_addresses: not_stubbed,
+        export_wallet: not_stubbed,
+        rename_wallet: not_stubbed,
+        list_wallets: not_stubbed,
+        build_transaction: not_stubbed,
+        sign_transaction: not_stubbed,
+        broadcast_transaction: not_stubbed,
+        query_transaction_status: not_stubbed,
+        estimate_fee: not_stubbed,
+        set_provider_config: not_stubbed,
+        get_provider_config: not_stubbed,
+        list_provider_configs: not_stubbed,
+        delete_provider_config: not_stubbed,
+        is_first_time_setup: not_stubbed,
+        initialize_app: not_stubbed,
+        unlock_app: not_stubbed,
+        create_wallet_with_progress: None,
+        import_wallet_with_progress: None,
+        validate_transaction: None,
+        sign_message: None,
+        verify_message: None,
+        derive_first_address: None,
+        change_wallet_password: None,
+        export_descriptor: None,
+        build_transaction_multi: None,
+        test_provider_connection: None,
+        get_nonce: None,
+        resolve_provider_endpoint: None,
+        allocations: FfiAllocations::default(),
+    }
+}
@@ -25,6 +25,39 @@ pub struct FFIError {
     pub message: String,
 }
 
+/// Data of a GetVersion response.
+///
+/// `version` is required so a malformed response fails the startup version
+/// check instead of comparing as "unknown".
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionInfo {
+    pub version: String,
+    /// Build timestamp (Go sends it as `buildTime`)
+    #[serde(default, alias = "buildTime")]
+    pub build_date: Option<String>,
+    #[serde(default)]
+    pub git_commit: Option<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Chain IDs this build can handle (empty if the build doesn't say)
+    #[serde(default)]
+    pub supported_chains: Vec<String>,
+}
+
+/// Progress report for a long-running FFI operation
+///
+/// Emitted to the frontend as the `wallet-progress` event.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct WalletProgress {
+    /// Operation name ("create_wallet" or "import_wallet")
+    pub operation: String,
+    /// Items completed so far
+    pub completed: u32,
+    /// Total items (e.g. 54 addresses)
+    pub total: u32,
+}
+
 /// Error codes matching Go ErrorCode constants
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorCode {
@@ -56,3 +89,31 @@ impl From<String> for ErrorCode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_info_full_payload() {
+        let info: VersionInfo = serde_json::from_str(
+            r#"{"version":"0.2.0","buildTime":"2025-10-25T10:00:00Z","gitCommit":"a1b2c3d","features":["ffi","progress"],"goVersion":"1.21+"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(info.version, "0.2.0");
+        assert_eq!(info.build_date.as_deref(), Some("2025-10-25T10:00:00Z"));
+        assert_eq!(info.git_commit.as_deref(), Some("a1b2c3d"));
+        assert_eq!(info.features, vec!["ffi", "progress"]);
+    }
+
+    #[test]
+    fn test_version_info_requires_version() {
+        let err = serde_json::from_str::<VersionInfo>(r#"{"buildTime":"2025-10-25T10:00:00Z"}"#).unwrap_err();
+        assert!(err.to_string().contains("missing field `version`"));
+
+        let info: VersionInfo = serde_json::from_str(r#"{"version":"0.2.0"}"#).unwrap();
+        assert_eq!(info.build_date, None);
+        assert!(info.features.is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+/**
+ * Filesystem path helpers for USB file operations
+ * Feature: User Dashboard for Wallet Management
+ * Windows long-path (MAX_PATH) and UNC network path support, and atomic
+ * file replacement so a pulled drive never leaves a truncated file
+ */
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Classic Windows path length limit (including the drive prefix)
+#[cfg(windows)]
+const MAX_PATH: usize = 260;
+
+/// Prepare a path for `std::fs` operations.
+///
+/// On Windows, absolute paths that reach `MAX_PATH` are rewritten to the
+/// extended-length form so deeply nested USB directories don't fail with
+/// "path not found":
+/// - `E:\dir\file` -> `\\?\E:\dir\file`
+/// - `\\server\share\file` -> `\\?\UNC\server\share\file`
+///
+/// The rewritten path has `.` and `..` components resolved, since Windows
+/// takes them literally in extended-length paths. Relative paths and paths
+/// that are already extended-length are returned unchanged. On other
+/// platforms this is a no-op.
+pub fn long_path(path: &Path) -> PathBuf {
+    #[cfg(windows)]
+    {
+        match extended_length_path(&path.to_string_lossy()) {
+            Some(prefixed) => PathBuf::from(prefixed),
+            None => path.to_path_buf(),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        path.to_path_buf()
+    }
+}
+
+/// Build the `\\?\` form of an absolute Windows path if it needs one.
+///
+/// Returns None when the path is short enough, relative, or already prefixed.
+#[cfg(windows)]
+fn extended_length_path(path: &str) -> Option<String> {
+    if path.len() < MAX_PATH || path.starts_with(r"\\?\") || path.starts_with(r"\\.\") {
+        return None;
+    }
+
+    // Extended-length paths are passed to the kernel verbatim, so they
+    // must use backslashes only
+    let normalized = path.replace('/', "\\");
+
+    let bytes = normalized.as_bytes();
+    let is_drive_absolute = bytes.len() >= 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && bytes[2] == b'\\';
+
+    // The prefixed root, and the components below it
+    let (root, rest) = if let Some(unc) = normalized.strip_prefix(r"\\") {
+        // UNC network path: \\server\share\... -> \\?\UNC\server\share\...
+        let mut parts = unc.splitn(3, '\\');
+        let server = parts.next().filter(|server| !server.is_empty())?;
+        let share = parts.next().filter(|share| !share.is_empty())?;
+        (format!(r"\\?\UNC\{}\{}", server, share), parts.next().unwrap_or(""))
+    } else if is_drive_absolute {
+        (format!(r"\\?\{}", &normalized[..2]), &normalized[3..])
+    } else {
+        return None;
+    };
+
+    // Nor does the kernel resolve `.` and `..`, which it would otherwise look
+    // up as literal names; resolve them the way Win32 does for short paths,
+    // never climbing above the drive or share
+    let mut components: Vec<&str> = Vec::new();
+    for component in rest.split('\\') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            name => components.push(name),
+        }
+    }
+
+    Some(components.iter().fold(root, |path, component| path + "\\" + component))
+}
+
+/// Write `contents` to `path` so readers see either the old file or the
+/// complete new one, never a partial write.
+///
+/// The data goes to a temporary file in the same directory, is fsynced, and
+/// is then renamed over `path`. The file is readable only by the owner
+/// (0600 on unix).
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_atomic_with(path, |file| file.write_all(contents))
+}
+
+/// `write_atomic` with the file contents produced by `write`
+pub fn write_atomic_with(path: &Path, write: impl FnOnce(&mut File) -> io::Result<()>) -> io::Result<()> {
+    let temp = write_temp_sibling(path, write)?;
+    if let Err(e) = replace_with(&temp, path) {
+        let _ = fs::remove_file(long_path(&temp));
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// `write_atomic_with` for a file that must not exist yet: fails with
+/// `AlreadyExists` instead of replacing one.
+///
+/// The name is claimed with an exclusive create before the contents are
+/// renamed over it, so of two concurrent writers only one succeeds. A crash
+/// in between leaves an empty file at `path`.
+pub fn write_new_atomic_with(path: &Path, write: impl FnOnce(&mut File) -> io::Result<()>) -> io::Result<()> {
+    private_options().open(long_path(path))?;
+
+    let result = write_temp_sibling(path, write).and_then(|temp| {
+        replace_with(&temp, path).inspect_err(|_| {
+            let _ = fs::remove_file(long_path(&temp));
+        })
+    });
+    if result.is_err() {
+        // Only the empty placeholder claimed above can be here
+        let _ = fs::remove_file(long_path(path));
+    }
+    result
+}
+
+/// Options creating a new file readable only by the owner (0600 on unix)
+fn private_options() -> fs::OpenOptions {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options
+}
+
+/// Write and fsync a private temporary file next to `path`; returns its path
+fn write_temp_sibling(path: &Path, write: impl FnOnce(&mut File) -> io::Result<()>) -> io::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+    // Unique per write, so a temp file left by a crash never blocks a retry
+    static NEXT_TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+    let temp = path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = private_options().open(long_path(&temp)).and_then(|mut file| {
+        write(&mut file)?;
+        file.sync_all()
+    });
+
+    match result {
+        Ok(()) => Ok(temp),
+        Err(e) => {
+            let _ = fs::remove_file(long_path(&temp));
+            Err(e)
+        }
+    }
+}
+
+/// Rename `temp` over `path` and make the rename durable
+fn replace_with(temp: &Path, path: &Path) -> io::Result<()> {
+    fs::rename(long_path(temp), long_path(path))?;
+
+    // The rename is only durable once the directory entry is flushed
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(windows)]
+    #[test]
+    fn test_long_drive_path_is_prefixed() {
+        let long = format!(r"E:\{}\addresses.json", "a".repeat(300));
+        let result = long_path(Path::new(&long));
+        assert_eq!(result, PathBuf::from(format!(r"\\?\{}", long)));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_long_unc_path_is_prefixed() {
+        let long = format!(r"\\server\share\{}\wallet.json", "b".repeat(300));
+        let result = long_path(Path::new(&long));
+        assert_eq!(
+            result,
+            PathBuf::from(format!(r"\\?\UNC\server\share\{}\wallet.json", "b".repeat(300)))
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_long_path_resolves_dot_components() {
+        let dir = "d".repeat(300);
+        let long = format!(r"E:\{}\.\tmp\..\\exports\addresses.json", dir);
+        assert_eq!(
+            long_path(Path::new(&long)),
+            PathBuf::from(format!(r"\\?\E:\{}\exports\addresses.json", dir))
+        );
+
+        // `..` stops at the share root
+        let long = format!(r"\\server\share\..\..\{}\wallet.json", dir);
+        assert_eq!(
+            long_path(Path::new(&long)),
+            PathBuf::from(format!(r"\\?\UNC\server\share\{}\wallet.json", dir))
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_short_and_prefixed_paths_unchanged() {
+        assert_eq!(long_path(Path::new(r"E:\wallet")), PathBuf::from(r"E:\wallet"));
+
+        let prefixed = format!(r"\\?\E:\{}", "c".repeat(300));
+        assert_eq!(long_path(Path::new(&prefixed)), PathBuf::from(&prefixed));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_long_path_is_noop_off_windows() {
+        let long = format!("/media/usb/{}", "a".repeat(300));
+        assert_eq!(long_path(Path::new(&long)), PathBuf::from(&long));
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wallet.json");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, b"new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1, "temporary file left behind");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_interrupted_write_leaves_original_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wallet.json");
+        fs::write(&path, "old").unwrap();
+
+        // Written and synced, but the process dies before the rename
+        let temp = write_temp_sibling(&path, |file| file.write_all(b"new")).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+
+        // A failed write never touches the target either
+        let err = write_atomic_with(&path, |_| Err(io::Error::other("drive removed")));
+        assert!(err.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+
+        fs::remove_file(temp).unwrap();
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_write_new_atomic_never_replaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("addresses.csv");
+
+        write_new_atomic_with(&path, |file| file.write_all(b"first")).unwrap();
+        let err = write_new_atomic_with(&path, |file| file.write_all(b"second")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first");
+
+        // A failed write releases the name it claimed
+        let other = dir.path().join("addresses.json");
+        assert!(write_new_atomic_with(&other, |_| Err(io::Error::other("drive removed"))).is_err());
+        assert!(!other.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1, "temporary file left behind");
+    }
+}
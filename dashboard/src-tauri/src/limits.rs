@@ -0,0 +1,76 @@
+//! Upper bounds on command string inputs.
+//!
+//! IPC arguments arrive as unbounded Strings. These caps are checked before
+//! any normalization, FFI or filesystem work so an oversized argument is
+//! rejected without further allocation.
+
+use crate::error::{AppError, AppResult, ErrorCode};
+
+/// Wallet display name (see `Wallet::validate_name`)
+pub const MAX_WALLET_NAME_LEN: usize = 50;
+
+/// Wallet or app password
+pub const MAX_PASSWORD_LEN: usize = 1024;
+
+/// Optional BIP39 passphrase ("25th word")
+pub const MAX_PASSPHRASE_LEN: usize = 1024;
+
+/// Mnemonic phrase; 24 words of at most 8 letters is 215, the rest is
+/// slack for extra whitespace in pasted input
+pub const MAX_MNEMONIC_LEN: usize = 512;
+
+/// USB mount path
+pub const MAX_PATH_LEN: usize = 4096;
+
+/// Hex-encoded signed transaction (Bitcoin standardness caps a
+/// transaction at 400,000 weight units, i.e. at most 400 kB)
+pub const MAX_SIGNED_TX_LEN: usize = 800_000;
+
+/// Message to sign or verify
+pub const MAX_MESSAGE_LEN: usize = 4096;
+
+/// Encoded message signature (65 bytes as base64 or 0x-hex, with slack)
+pub const MAX_SIGNATURE_LEN: usize = 256;
+
+/// Blockchain address (bech32 tops out at 90 characters, with slack)
+pub const MAX_ADDRESS_LEN: usize = 128;
+
+/// Provider API key or project ID
+pub const MAX_API_KEY_LEN: usize = 256;
+
+/// Reject `value` if it is longer than `max` bytes
+pub fn check_length(field: &str, value: &str, max: usize) -> AppResult<()> {
+    if value.len() > max {
+        return Err(AppError::new(
+            ErrorCode::FfiInvalidInput,
+            format!("{} is too long (maximum {} bytes)", field, max),
+        ));
+    }
+    Ok(())
+}
+
+/// `check_length` for optional arguments
+pub fn check_optional_length(field: &str, value: Option<&str>, max: usize) -> AppResult<()> {
+    value.map_or(Ok(()), |v| check_length(field, v, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_length_boundary() {
+        assert!(check_length("Password", &"a".repeat(MAX_PASSWORD_LEN), MAX_PASSWORD_LEN).is_ok());
+
+        let err = check_length("Password", &"a".repeat(MAX_PASSWORD_LEN + 1), MAX_PASSWORD_LEN).unwrap_err();
+        assert_eq!(err.code, ErrorCode::FfiInvalidInput);
+        assert!(err.message.contains("Password"));
+    }
+
+    #[test]
+    fn test_check_optional_length() {
+        assert!(check_optional_length("Passphrase", None, 4).is_ok());
+        assert!(check_optional_length("Passphrase", Some("abcd"), 4).is_ok());
+        assert!(check_optional_length("Passphrase", Some("abcde"), 4).is_err());
+    }
+}
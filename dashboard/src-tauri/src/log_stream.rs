@@ -0,0 +1,319 @@
+//! Log forwarding for the in-app diagnostics view.
+//!
+//! Besides the stdout formatter, `init_tracing` installs a layer that hands
+//! every event at or above the chosen level to a sink. The sink is attached
+//! once the Tauri app exists and emits each event as `app-log`. Values of
+//! secret-bearing fields (see `SECRET_FIELDS`) are redacted and messages then
+//! pass through the same sanitizer as error messages before leaving Rust.
+
+use chrono::Utc;
+use serde::Serialize;
+use std::cell::Cell;
+use std::fmt::Write;
+use std::sync::{Arc, OnceLock, RwLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::error::AppError;
+
+/// Tauri event carrying forwarded log lines
+pub const LOG_EVENT: &str = "app-log";
+
+/// Level forwarded until the frontend picks another one
+pub const DEFAULT_FORWARD_LEVEL: Level = Level::WARN;
+
+/// Field names whose values never leave Rust, compared case-insensitively
+/// with `_` ignored (so `apiKey`, `api_key` and `APIKEY` all match). A name
+/// containing one of these (`newPassword`) matches too.
+const SECRET_FIELDS: [&str; 6] = ["mnemonic", "password", "passphrase", "apikey", "privatekey", "seed"];
+
+/// Replacement for a redacted value
+const REDACTED: &str = "[REDACTED]";
+
+/// One forwarded log line
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LogEvent {
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    /// RFC 3339 timestamp of when the event was recorded
+    pub timestamp: String,
+}
+
+/// Receiver of forwarded log lines
+pub type LogSink = Arc<dyn Fn(LogEvent) + Send + Sync>;
+
+/// Shared handle to the forwarding layer: its sink and level (Tauri managed
+/// state, so the level can be changed at runtime)
+#[derive(Clone)]
+pub struct LogForwarder {
+    sink: Arc<OnceLock<LogSink>>,
+    /// None turns forwarding off
+    level: Arc<RwLock<Option<Level>>>,
+}
+
+impl Default for LogForwarder {
+    fn default() -> Self {
+        Self {
+            sink: Arc::new(OnceLock::new()),
+            level: Arc::new(RwLock::new(Some(DEFAULT_FORWARD_LEVEL))),
+        }
+    }
+}
+
+impl LogForwarder {
+    /// Install the receiver; events before this are not forwarded
+    pub fn set_sink(&self, sink: LogSink) {
+        if self.sink.set(sink).is_err() {
+            tracing::warn!("Log sink already installed; ignoring");
+        }
+    }
+
+    /// Forward events at or above `level`, or none with `None`
+    pub fn set_level(&self, level: Option<Level>) {
+        *self.level.write().unwrap_or_else(|e| e.into_inner()) = level;
+    }
+
+    pub fn level(&self) -> Option<Level> {
+        *self.level.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn layer(&self) -> ForwardingLayer {
+        ForwardingLayer { forwarder: self.clone() }
+    }
+}
+
+thread_local! {
+    /// Set while a sink runs, so events it logs aren't forwarded back into it
+    static FORWARDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// `tracing_subscriber` layer feeding a `LogForwarder`'s sink
+pub struct ForwardingLayer {
+    forwarder: LogForwarder,
+}
+
+impl<S: Subscriber> Layer<S> for ForwardingLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        // Levels compare by verbosity: TRACE > DEBUG > INFO > WARN > ERROR
+        match self.forwarder.level() {
+            Some(level) if *metadata.level() <= level => {}
+            _ => return,
+        }
+        let Some(sink) = self.forwarder.sink.get() else {
+            return;
+        };
+        if FORWARDING.with(|forwarding| forwarding.replace(true)) {
+            return;
+        }
+
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+        sink(LogEvent {
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: AppError::sanitize_message(redact_secrets(&message.0)),
+            timestamp: Utc::now().to_rfc3339(),
+        });
+
+        FORWARDING.with(|forwarding| forwarding.set(false));
+    }
+}
+
+/// The event's message followed by its other fields as `name=value`
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        if field.name() == "message" {
+            let _ = write!(self.0, "{:?}", value);
+        } else if is_secret_field(field.name()) {
+            let _ = write!(self.0, "{}={}", field.name(), REDACTED);
+        } else {
+            let _ = write!(self.0, "{}={:?}", field.name(), value);
+        }
+    }
+}
+
+fn is_secret_field(name: &str) -> bool {
+    let name: String = name.chars().filter(|c| *c != '_').collect::<String>().to_ascii_lowercase();
+    SECRET_FIELDS.iter().any(|secret| name.contains(secret))
+}
+
+/// Redact the values of secret fields written into a message, as JSON
+/// (`"mnemonic": "..."`), `Debug` of a `serde_json::Value`
+/// (`"mnemonic": String("...")`) or `name=value`.
+fn redact_secrets(message: &str) -> String {
+    let bytes = message.as_bytes();
+    let mut out = String::with_capacity(message.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_alphabetic() || (i > 0 && bytes[i - 1].is_ascii_alphanumeric()) {
+            i += 1;
+            continue;
+        }
+        // A whole identifier, so `has_password` and `newPassword` are seen as one name
+        let name_end = i + bytes[i..].iter().take_while(|b| b.is_ascii_alphanumeric() || **b == b'_').count();
+        let Some(value_start) = value_after_separator(bytes, name_end) else {
+            i = name_end;
+            continue;
+        };
+        if !is_secret_field(&message[i..name_end]) {
+            i = name_end;
+            continue;
+        }
+        let value_end = value_end(bytes, value_start);
+        out.push_str(&message[copied..value_start]);
+        out.push_str(REDACTED);
+        copied = value_end;
+        i = value_end;
+    }
+    out.push_str(&message[copied..]);
+    out
+}
+
+/// Start of the value when `bytes[at..]` is an optional closing quote then
+/// `:` or `=`
+fn value_after_separator(bytes: &[u8], mut at: usize) -> Option<usize> {
+    if bytes.get(at) == Some(&b'"') {
+        at += 1;
+    }
+    if !matches!(bytes.get(at), Some(b':') | Some(b'=')) {
+        return None;
+    }
+    at += 1;
+    while bytes.get(at) == Some(&b' ') {
+        at += 1;
+    }
+    Some(at)
+}
+
+/// End of a value: a quoted string (optionally wrapped as `String(...)`)
+/// or a bare token
+fn value_end(bytes: &[u8], start: usize) -> usize {
+    let mut at = start;
+    if bytes[at..].starts_with(b"String(") {
+        at += "String(".len();
+    }
+    if bytes.get(at) == Some(&b'"') {
+        at += 1;
+        while at < bytes.len() && bytes[at] != b'"' {
+            at += if bytes[at] == b'\\' { 2 } else { 1 };
+        }
+        at = (at + 1).min(bytes.len());
+        if bytes.get(at) == Some(&b')') && at > start && bytes[start] == b'S' {
+            at += 1;
+        }
+        return at;
+    }
+    at + bytes[at..]
+        .iter()
+        .take_while(|b| !matches!(b, b' ' | b',' | b'}' | b')'))
+        .count()
+}
+
+/// Install the global subscriber: stdout formatting plus log forwarding
+pub fn init_tracing() -> LogForwarder {
+    let forwarder = LogForwarder::default();
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(forwarder.layer())
+        .init();
+    forwarder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tracing_subscriber::Registry;
+
+    fn capture(forwarder: &LogForwarder) -> Arc<Mutex<Vec<LogEvent>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink_events = events.clone();
+        forwarder.set_sink(Arc::new(move |event| sink_events.lock().unwrap().push(event)));
+        events
+    }
+
+    #[test]
+    fn test_info_event_is_forwarded() {
+        let forwarder = LogForwarder::default();
+        let events = capture(&forwarder);
+        let subscriber = Registry::default().with(forwarder.layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("below the default level");
+            forwarder.set_level(Some(Level::INFO));
+            tracing::info!(target: "arcsign::wallet", wallet_count = 2, "Listed wallets");
+            tracing::debug!("below the forwarded level");
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].level, "INFO");
+        assert_eq!(events[0].target, "arcsign::wallet");
+        assert_eq!(events[0].message, "Listed wallets wallet_count=2");
+        assert!(chrono::DateTime::parse_from_rfc3339(&events[0].timestamp).is_ok());
+    }
+
+    #[test]
+    fn test_forwarded_messages_are_sanitized_and_level_filtered() {
+        let forwarder = LogForwarder::default();
+        let events = capture(&forwarder);
+        let subscriber = Registry::default().with(forwarder.layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("Failed to read /media/usb/wallet.json");
+            forwarder.set_level(Some(Level::ERROR));
+            tracing::warn!("filtered out");
+            forwarder.set_level(None);
+            tracing::error!("forwarding is off");
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].message, "Error occurred (path details hidden for security)");
+    }
+
+    #[test]
+    fn test_wallet_creation_response_secrets_are_redacted() {
+        let forwarder = LogForwarder::default();
+        let events = capture(&forwarder);
+        let subscriber = Registry::default().with(forwarder.layer());
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let response = serde_json::json!({
+            "walletId": "w1",
+            "mnemonic": mnemonic,
+            "password": "hunter2",
+            "apiKey": "key-123",
+        });
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("Wallet creation FFI response: {:?}", response);
+            tracing::warn!("Wallet creation FFI response: {}", response);
+            tracing::warn!(private_key = "0xabc", word_count = 12, "Derived key");
+            tracing::warn!("Retrying with privateKey=0xdef, attempt=2");
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 4);
+        for event in events.iter() {
+            for secret in ["abandon", "hunter2", "key-123", "0xabc", "0xdef"] {
+                assert!(!event.message.contains(secret), "{} leaked in {}", secret, event.message);
+            }
+        }
+        assert!(events[0].message.contains("\"walletId\": String(\"w1\")"));
+        assert!(events[1].message.contains("\"mnemonic\":[REDACTED]"));
+        assert_eq!(events[2].message, "Derived key private_key=[REDACTED] word_count=12");
+        assert_eq!(events[3].message, "Retrying with privateKey=[REDACTED], attempt=2");
+    }
+}
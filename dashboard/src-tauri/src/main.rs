@@ -17,30 +17,75 @@
 const USE_FFI: bool = true;
 
 // Module declarations
+mod amount;
+mod cache;
 mod commands;
 mod error;
+mod eth_address;
 mod ffi;  // T017: Add FFI module
+mod fs_utils;
+mod limits;
+mod log_stream;
 mod models;
+mod secret;
+mod signing_payload;
 
+use cache::ReadCache;
+use log_stream::LOG_EVENT;
 use commands::app::{is_first_time_setup, initialize_app, unlock_app};
+use commands::bundle::{export_wallet_bundle, import_wallet_bundle};
+use commands::derivation::{address_belongs_to_wallet, get_derivation_tree, parse_derivation_path};
+use commands::diagnostics::{
+    cache_debug, cache_stats, capabilities, check_clock, get_diagnostics, get_library_info,
+    get_queue_metrics, is_chain_supported, list_supported_chains, reload_library, reset_queue_metrics,
+    set_log_stream_level,
+};
+use commands::export::{
+    cleanup_partial_exports, delete_export, export_addresses, export_combined_csv, list_exports,
+    secure_delete_export, verify_export,
+};
+use commands::descriptor::export_descriptor;
+use commands::message::{sign_message, verify_message};
+use commands::maintenance::{
+    get_kdf_params, portfolio_summary, reconcile_wallet_directories, wallet_provenance,
+    wallet_storage_usage,
+};
+use commands::receive::get_receive_payload;
+use commands::transaction::{
+    broadcast_transaction, build_transaction, build_transaction_multi, decode_transaction,
+    estimate_batch_fee, query_transaction_status, query_transaction_status_all, validate_transaction,
+};
 use commands::security::{
-    clear_sensitive_memory, disable_screenshot_protection, enable_screenshot_protection,
+    auto_lock_if_idle, clear_sensitive_memory, disable_screenshot_protection, enable_screenshot_protection,
+    heartbeat, ActivityTracker, AUTO_LOCK_EVENT, AUTO_LOCK_POLL_INTERVAL,
 };
-use commands::usb::detect_usb;
-use commands::wallet::{create_wallet, import_wallet, list_wallets, load_addresses, rename_wallet, AddressCache};
-use commands::provider::{set_provider_config, get_provider_config, list_provider_configs, delete_provider_config};
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use commands::usb::{check_usb_security, detect_usb, estimate_required_space, usb_has_wallets};
+use commands::wallet::{
+    change_wallet_password, create_wallet, format_mnemonic, import_wallet, list_unlocked_wallets,
+    list_wallets, list_wallets_page, load_addresses, load_addresses_grouped, lock_wallet,
+    mnemonics_equivalent, preview_restore, recent_wallets, reencrypt_all_wallets, rename_wallet,
+    verify_password,
+    AddressCache, RecentImports, RecentWallets, UnlockedWallets,
+};
+use commands::ranking::set_address_ranking;
+use commands::provider::{
+    delete_provider_config, export_provider_configs, get_provider_config, import_provider_configs,
+    list_provider_configs, rotate_provider_key, set_provider_config, test_all_providers,
+};
+use std::sync::Arc;
 use std::time::Instant; // T045: Startup time logging
-use ffi::{WalletLibrary, LazyWalletQueue};  // T017: Import FFI types (use LazyWalletQueue)
+use ffi::{
+    queue_capacity_from_env, WalletLibrary, LazyWalletQueue, EXPECTED_LIBRARY_VERSION, MetricThresholds,
+    WalletBackend,
+};  // T017: Import FFI types (use LazyWalletQueue)
 use tauri::Manager;  // For app.manage() in setup hook
 
 fn main() {
     // T045: Start startup timer
     let startup_start = Instant::now();
 
-    // Initialize tracing for logging
-    tracing_subscriber::fmt::init();
+    // Initialize tracing for logging (stdout, plus forwarding to the frontend)
+    let log_forwarder = log_stream::init_tracing();
 
     tracing::info!("=== arcSign Dashboard Starting ===");
 
@@ -67,12 +112,9 @@ fn main() {
 
             // T040: Verify library version compatibility
             let version_check_start = Instant::now();
-            match lib.get_version() {
-                Ok(version_data) => {
-                    let version = version_data
-                        .get("version")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("unknown");
+            match lib.version_info() {
+                Ok(version_info) => {
+                    let version = version_info.version.as_str();
 
                     tracing::info!(
                         "Wallet library version: {} (verified in {:?})",
@@ -80,16 +122,17 @@ fn main() {
                         version_check_start.elapsed()
                     );
 
-                    // T040: Check version compatibility (expecting 0.2.0)
-                    if version != "0.2.0" {
+                    // T040: Check version compatibility
+                    if version != EXPECTED_LIBRARY_VERSION {
                         tracing::warn!(
-                            "Library version mismatch: expected 0.2.0, got {}",
+                            "Library version mismatch: expected {}, got {}",
+                            EXPECTED_LIBRARY_VERSION,
                             version
                         );
                         eprintln!("========================================");
                         eprintln!("WARNING: Library Version Mismatch");
                         eprintln!("========================================");
-                        eprintln!("Expected: 0.2.0");
+                        eprintln!("Expected: {}", EXPECTED_LIBRARY_VERSION);
                         eprintln!("Found: {}", version);
                         eprintln!("The application may not function correctly.");
                         eprintln!("Consider reinstalling the application.");
@@ -161,20 +204,59 @@ fn main() {
     let library_for_setup = library.clone();
 
     tauri::Builder::default()
-        .manage(AddressCache(Mutex::new(HashMap::new())))
+        .manage(AddressCache::default())
+        .manage(ActivityTracker::from_env())
+        .manage(RecentWallets::default())
+        .manage(RecentImports::default())
+        .manage(UnlockedWallets::default())
+        .manage(ReadCache::default())
+        .manage(log_forwarder.clone())
         .setup(move |app| {
+            // Forward log lines to the in-app diagnostics view
+            let handle = app.handle();
+            log_forwarder.set_sink(Arc::new(move |event| {
+                // Not logged: the failure would be forwarded right back here
+                let _ = handle.emit_all(LOG_EVENT, event);
+            }));
+
             // T018: Initialize LazyWalletQueue (defers actual initialization until first use)
             // T042: Symbol caching is already implemented in WalletLibrary::load()
             // T068: Only create queue if library loaded successfully
             if let Some(lib) = library_for_setup {
                 // Create lazy queue - actual WalletQueue will be initialized on first use from async context
-                let queue = LazyWalletQueue::new(lib);
-                app.manage(queue);
+                let queue = LazyWalletQueue::new(lib)
+                    .with_metric_thresholds(MetricThresholds::from_env())
+                    .with_capacity(queue_capacity_from_env());
+
+                // Forward FFI progress reports (e.g. "k of 54 addresses") to the frontend
+                let handle = app.handle();
+                queue.set_progress_sink(Arc::new(move |progress| {
+                    if let Err(e) = handle.emit_all("wallet-progress", progress) {
+                        tracing::warn!("Failed to emit wallet-progress event: {}", e);
+                    }
+                }));
+
+                app.manage(WalletBackend::new(Some(queue)));
                 tracing::info!("✓ Lazy queue registered (will initialize on first use from async context)");
             } else {
+                // Still managed, so wallet commands report CliNotFound instead of panicking
+                app.manage(WalletBackend::new(None));
                 tracing::warn!("⚠ FFI queue not available - commands will use CLI fallback");
             }
 
+            // Lock unlocked wallets once the user has been idle too long
+            let handle = app.handle();
+            std::thread::spawn(move || loop {
+                std::thread::sleep(AUTO_LOCK_POLL_INTERVAL);
+                let activity = handle.state::<ActivityTracker>();
+                let unlocked = handle.state::<UnlockedWallets>();
+                if auto_lock_if_idle(activity.inner(), unlocked.inner()) {
+                    if let Err(e) = handle.emit_all(AUTO_LOCK_EVENT, ()) {
+                        tracing::warn!("Failed to emit {} event: {}", AUTO_LOCK_EVENT, e);
+                    }
+                }
+            });
+
             // T045: Log total startup time
             let startup_duration = startup_start.elapsed();
             tracing::info!(
@@ -195,6 +277,9 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             // USB commands
             detect_usb,
+            estimate_required_space,
+            usb_has_wallets,
+            check_usb_security,
             // App-level authentication commands
             is_first_time_setup,
             initialize_app,
@@ -203,17 +288,81 @@ fn main() {
             create_wallet,
             import_wallet,
             list_wallets,
+            list_wallets_page,
             load_addresses,
+            load_addresses_grouped,
+            set_address_ranking,
             rename_wallet,
+            recent_wallets,
+            mnemonics_equivalent,
+            preview_restore,
+            format_mnemonic,
+            change_wallet_password,
+            reencrypt_all_wallets,
+            verify_password,
+            list_unlocked_wallets,
+            lock_wallet,
+            get_derivation_tree,
+            parse_derivation_path,
+            address_belongs_to_wallet,
+            // Export commands
+            list_exports,
+            export_addresses,
+            export_combined_csv,
+            delete_export,
+            secure_delete_export,
+            verify_export,
+            cleanup_partial_exports,
+            export_descriptor,
+            export_wallet_bundle,
+            import_wallet_bundle,
+            // Maintenance commands
+            reconcile_wallet_directories,
+            wallet_storage_usage,
+            portfolio_summary,
+            wallet_provenance,
+            get_kdf_params,
+            // Message signing commands
+            sign_message,
+            verify_message,
+            // Receive commands
+            get_receive_payload,
             // Security commands
             enable_screenshot_protection,
             disable_screenshot_protection,
             clear_sensitive_memory,
+            heartbeat,
+            // Diagnostics commands
+            get_diagnostics,
+            get_queue_metrics,
+            reset_queue_metrics,
+            reload_library,
+            is_chain_supported,
+            get_library_info,
+            list_supported_chains,
+            cache_stats,
+            cache_debug,
+            check_clock,
+            capabilities,
+            set_log_stream_level,
             // Provider configuration commands
             set_provider_config,
             get_provider_config,
             list_provider_configs,
             delete_provider_config,
+            rotate_provider_key,
+            export_provider_configs,
+            import_provider_configs,
+            test_all_providers,
+            // Transaction commands
+            build_transaction,
+            build_transaction_multi,
+            broadcast_transaction,
+            query_transaction_status,
+            query_transaction_status_all,
+            validate_transaction,
+            estimate_batch_fee,
+            decode_transaction,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
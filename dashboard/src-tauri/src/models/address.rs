@@ -7,6 +7,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{AppError, AppResult, ErrorCode};
+
 /// Blockchain category classification
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -20,6 +22,72 @@ pub enum Category {
     Specialized,
 }
 
+impl Category {
+    /// Display order of the category groups: base chains first, then
+    /// layer 2, regional, Cosmos, alternative EVM and specialized chains
+    pub const ALL: [Category; 6] = [
+        Category::Base,
+        Category::Layer2,
+        Category::Regional,
+        Category::Cosmos,
+        Category::AltEvm,
+        Category::Specialized,
+    ];
+
+    /// Dashboard category of a chain the wallet library derives, by symbol
+    /// (case-insensitive); None for chains missing from `CHAIN_CATEGORIES`
+    pub fn of_symbol(symbol: &str) -> Option<Category> {
+        CHAIN_CATEGORIES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(symbol))
+            .map(|(_, category)| category.clone())
+    }
+}
+
+/// Category of each chain in the wallet library's coin registry.
+///
+/// The library's own categories are coarser: alternative EVM chains are
+/// "EVM_Mainnet" like Ethereum, and regional chains are "Custom".
+const CHAIN_CATEGORIES: &[(&str, Category)] = &[
+    ("BTC", Category::Base),
+    ("ETH", Category::Base),
+    ("BNB", Category::Base),
+    ("SOL", Category::Base),
+    ("XRP", Category::Base),
+    ("DOGE", Category::Base),
+    ("TRX", Category::Base),
+    ("AVAX", Category::Base),
+    ("MATIC", Category::Base),
+    ("LTC", Category::Base),
+    ("BCH", Category::Base),
+    ("XLM", Category::Base),
+    ("UNI", Category::Base),
+    ("ETC", Category::Base),
+    ("VET", Category::Base),
+    ("ZEC", Category::Base),
+    ("DASH", Category::Base),
+    ("ARB", Category::Layer2),
+    ("OP", Category::Layer2),
+    ("BASE", Category::Layer2),
+    ("ZKS", Category::Layer2),
+    ("LINEA", Category::Layer2),
+    ("STRK", Category::Layer2),
+    ("KLAY", Category::Regional),
+    ("CRO", Category::Regional),
+    ("HT", Category::Regional),
+    ("ONE", Category::AltEvm),
+    ("FTM", Category::AltEvm),
+    ("CELO", Category::AltEvm),
+    ("GLMR", Category::AltEvm),
+    ("METIS", Category::AltEvm),
+    ("GNO", Category::AltEvm),
+    ("WAN", Category::AltEvm),
+    ("KSM", Category::Specialized),
+    ("ICX", Category::Specialized),
+    ("XTZ", Category::Specialized),
+    ("ZIL", Category::Specialized),
+];
+
 /// Cryptographic key type for address derivation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -30,6 +98,92 @@ pub enum KeyType {
     Schnorr,
 }
 
+/// Bitcoin address type, selecting the purpose level of the derivation path.
+///
+/// Only applies to chains that support it (Bitcoin and its forks); other
+/// chains always derive with BIP44. The wallet library only derives BIP44
+/// addresses so far, so loading the other types fails with
+/// `UnsupportedOperation`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressType {
+    /// Legacy P2PKH, m/44'/...
+    #[default]
+    Bip44,
+    /// P2SH-wrapped SegWit, m/49'/...
+    Bip49,
+    /// Native SegWit (bech32), m/84'/...
+    Bip84,
+}
+
+impl AddressType {
+    /// BIP43 purpose field of the derivation path
+    pub fn purpose(self) -> u32 {
+        match self {
+            AddressType::Bip44 => 44,
+            AddressType::Bip49 => 49,
+            AddressType::Bip84 => 84,
+        }
+    }
+}
+
+/// Parameters of the library's GenerateAddresses call.
+///
+/// The library reads an empty `blockchains` array as "every supported
+/// chain". That convention stays inside `to_params`: `None` asks for every
+/// chain, and an explicit empty filter is rejected instead of widening to
+/// all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerateAddressesRequest {
+    pub wallet_id: String,
+    /// Lets the library load the wallet's metadata
+    pub usb_path: String,
+    /// Chain symbols to derive; None derives every supported chain
+    pub blockchains: Option<Vec<String>>,
+    pub address_type: AddressType,
+}
+
+impl GenerateAddressesRequest {
+    /// Every supported chain
+    pub fn all_chains(wallet_id: &str, usb_path: &str, address_type: AddressType) -> Self {
+        Self {
+            wallet_id: wallet_id.to_string(),
+            usb_path: usb_path.to_string(),
+            blockchains: None,
+            address_type,
+        }
+    }
+
+    /// Params JSON as the library expects it
+    pub fn to_params(&self) -> AppResult<serde_json::Value> {
+        let blockchains = match &self.blockchains {
+            None => Vec::new(),
+            Some(blockchains) if blockchains.is_empty() => {
+                return Err(AppError::new(ErrorCode::FfiInvalidInput, "No chains requested"));
+            }
+            Some(blockchains) => blockchains.clone(),
+        };
+
+        // The library returns the BIP44 addresses stored at wallet creation
+        // and has no way to derive under another purpose
+        if self.address_type != AddressType::Bip44 {
+            return Err(AppError::new(
+                ErrorCode::UnsupportedOperation,
+                format!(
+                    "The installed wallet library only derives BIP44 addresses, not BIP{}",
+                    self.address_type.purpose()
+                ),
+            ));
+        }
+
+        Ok(serde_json::json!({
+            "walletId": self.wallet_id,
+            "usbPath": self.usb_path,
+            "blockchains": blockchains,
+        }))
+    }
+}
+
 /// Derived cryptocurrency address for a specific blockchain
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Address {
@@ -63,6 +217,11 @@ pub struct Address {
     /// Optional testnet indicator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_testnet: Option<bool>,
+
+    /// Library rank when a user ranking reordered the list (`rank` is then
+    /// the position in the user's order)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_rank: Option<u32>,
 }
 
 /// Response from load_addresses command
@@ -76,6 +235,103 @@ pub struct AddressListResponse {
 
     /// Total address count (always 54 for v0.3.0)
     pub total_count: u32,
+
+    /// Blockchains the library failed to derive an address for
+    #[serde(default)]
+    pub failed: Vec<FailedAddress>,
+
+    /// Time the command took, in milliseconds (rounded up)
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// A blockchain whose address could not be derived
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FailedAddress {
+    pub blockchain: String,
+    pub error: String,
+}
+
+/// Address export file format.
+///
+/// Wire names are part of the IPC contract with the frontend `ExportFormat`
+/// enum: "json", "csv", "encrypted_json", "pdf".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    #[serde(rename = "encrypted_json")]
+    EncryptedJson,
+    Pdf,
+}
+
+impl ExportFormat {
+    /// All export formats
+    pub const ALL: [ExportFormat; 4] = [
+        ExportFormat::Json,
+        ExportFormat::Csv,
+        ExportFormat::EncryptedJson,
+        ExportFormat::Pdf,
+    ];
+
+    /// Name on the IPC wire, as the frontend sends it
+    pub fn wire_name(self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::EncryptedJson => "encrypted_json",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+
+    /// Extension used in export filenames (without the dot)
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::EncryptedJson => "enc",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// Options for export_addresses (all default to off)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ExportOptions {
+    /// gzip the export (JSON only), written as `.json.gz`
+    pub compress: bool,
+    /// Append new rows to the latest CSV export instead of writing a new
+    /// file (CSV only); addresses already in it are skipped
+    pub append: bool,
+    /// Write a `<file>.sha256` sidecar that `verify_export` checks against
+    pub checksum: bool,
+}
+
+/// Response from export_addresses command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportResponse {
+    /// Full path to the written export file
+    pub file_path: String,
+
+    /// Number of addresses written
+    pub exported_count: usize,
+
+    /// Format of the export
+    pub format: ExportFormat,
+
+    /// Export timestamp (ISO 8601, UTC)
+    pub exported_at: String,
+
+    /// SHA-256 of the file as written (hex), if a checksum sidecar was kept
+    #[serde(default)]
+    pub checksum: Option<String>,
+
+    /// Blockchains the library failed to derive an address for; the file
+    /// has no address for them
+    #[serde(default)]
+    pub failed: Vec<FailedAddress>,
 }
 
 impl Address {
@@ -102,6 +358,7 @@ impl Address {
             category,
             key_type,
             is_testnet: None,
+            default_rank: None,
         }
     }
 
@@ -112,6 +369,52 @@ impl Address {
     }
 }
 
+/// Addresses of one category, in rank order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryGroup {
+    pub category: Category,
+    pub addresses: Vec<Address>,
+}
+
+/// Response from load_addresses_grouped command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupedAddressResponse {
+    pub wallet_id: String,
+
+    /// One group per category in `Category::ALL` order, empty ones included
+    pub groups: Vec<CategoryGroup>,
+
+    /// Addresses across all groups
+    pub total_count: u32,
+
+    /// Blockchains the library failed to derive an address for
+    #[serde(default)]
+    pub failed: Vec<FailedAddress>,
+}
+
+impl GroupedAddressResponse {
+    /// Group an address list by category, keeping each group in list order
+    pub fn from_list(response: AddressListResponse) -> Self {
+        let mut groups: Vec<CategoryGroup> = Category::ALL
+            .into_iter()
+            .map(|category| CategoryGroup { category, addresses: Vec::new() })
+            .collect();
+
+        for address in response.addresses {
+            if let Some(group) = groups.iter_mut().find(|g| g.category == address.category) {
+                group.addresses.push(address);
+            }
+        }
+
+        Self {
+            wallet_id: response.wallet_id,
+            groups,
+            total_count: response.total_count,
+            failed: response.failed,
+        }
+    }
+}
+
 impl AddressListResponse {
     /// Create new address list response
     pub fn new(wallet_id: String, addresses: Vec<Address>) -> Self {
@@ -120,8 +423,16 @@ impl AddressListResponse {
             wallet_id,
             addresses,
             total_count,
+            failed: Vec::new(),
+            duration_ms: None,
         }
     }
+
+    /// Report blockchains that failed to derive alongside the addresses
+    pub fn with_failed(mut self, failed: Vec<FailedAddress>) -> Self {
+        self.failed = failed;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -178,6 +489,57 @@ mod tests {
         assert_eq!(json, "\"alt_evm\"");
     }
 
+    #[test]
+    fn test_category_of_symbol() {
+        assert_eq!(Category::of_symbol("BTC"), Some(Category::Base));
+        assert_eq!(Category::of_symbol("arb"), Some(Category::Layer2));
+        // The library reports these as "Custom" and "EVM_Mainnet"
+        assert_eq!(Category::of_symbol("KLAY"), Some(Category::Regional));
+        assert_eq!(Category::of_symbol("FTM"), Some(Category::AltEvm));
+        assert_eq!(Category::of_symbol("ATOM"), None);
+    }
+
+    #[test]
+    fn test_grouping_keeps_all_categories_in_order() {
+        let address = |rank: u32, symbol: &str, category: Category| {
+            Address::new(
+                "wallet-1".to_string(),
+                rank,
+                symbol.to_string(),
+                symbol.to_lowercase(),
+                0,
+                "m/44'/0'/0'/0/0".to_string(),
+                format!("{}-address", symbol),
+                category,
+                KeyType::Secp256k1,
+            )
+        };
+        let list = AddressListResponse::new(
+            "wallet-1".to_string(),
+            vec![
+                address(1, "BTC", Category::Base),
+                address(2, "ARB", Category::Layer2),
+                address(3, "ETH", Category::Base),
+                address(4, "ATOM", Category::Cosmos),
+                address(5, "DOT", Category::Specialized),
+            ],
+        );
+
+        let grouped = GroupedAddressResponse::from_list(list);
+
+        let categories: Vec<Category> = grouped.groups.iter().map(|g| g.category.clone()).collect();
+        assert_eq!(categories, Category::ALL.to_vec());
+        assert!(grouped.groups[2].addresses.is_empty()); // regional
+        assert!(grouped.groups[4].addresses.is_empty()); // alt_evm
+
+        let base: Vec<&str> = grouped.groups[0].addresses.iter().map(|a| a.symbol.as_str()).collect();
+        assert_eq!(base, ["BTC", "ETH"]);
+
+        let total: usize = grouped.groups.iter().map(|g| g.addresses.len()).sum();
+        assert_eq!(total, 5);
+        assert_eq!(grouped.total_count, 5);
+    }
+
     #[test]
     fn test_key_type_serialization() {
         let key_type = KeyType::Secp256k1;
@@ -189,6 +551,30 @@ mod tests {
         assert_eq!(json, "\"ed25519\"");
     }
 
+    #[test]
+    fn test_export_format_file_extension() {
+        assert_eq!(ExportFormat::Json.file_extension(), "json");
+        assert_eq!(ExportFormat::Csv.file_extension(), "csv");
+        assert_eq!(ExportFormat::EncryptedJson.file_extension(), "enc");
+        assert_eq!(ExportFormat::Pdf.file_extension(), "pdf");
+    }
+
+    #[test]
+    fn test_export_format_serialization() {
+        let expected = [
+            (ExportFormat::Json, "\"json\""),
+            (ExportFormat::Csv, "\"csv\""),
+            (ExportFormat::EncryptedJson, "\"encrypted_json\""),
+            (ExportFormat::Pdf, "\"pdf\""),
+        ];
+
+        for (format, json) in expected {
+            assert_eq!(serde_json::to_string(&format).unwrap(), json);
+            assert_eq!(serde_json::from_str::<ExportFormat>(json).unwrap(), format);
+            assert_eq!(format!("\"{}\"", format.wire_name()), json);
+        }
+    }
+
     #[test]
     fn test_address_list_response() {
         let addresses = vec![
@@ -222,4 +608,33 @@ mod tests {
         assert_eq!(response.addresses.len(), 2);
         assert_eq!(response.total_count, 2);
     }
+
+    #[test]
+    fn test_generate_request_distinguishes_all_from_empty() {
+        let all = GenerateAddressesRequest::all_chains("wallet-1", "/media/usb", AddressType::Bip44);
+        let params = all.to_params().unwrap();
+        assert_eq!(params["blockchains"], serde_json::json!([]));
+
+        let some = GenerateAddressesRequest {
+            blockchains: Some(vec!["BTC".to_string(), "ETH".to_string()]),
+            ..all.clone()
+        };
+        let params = some.to_params().unwrap();
+        assert_eq!(params["blockchains"], serde_json::json!(["BTC", "ETH"]));
+        assert!(params.get("purpose").is_none());
+
+        let empty = GenerateAddressesRequest {
+            blockchains: Some(Vec::new()),
+            ..all
+        };
+        assert_eq!(empty.to_params().unwrap_err().code, ErrorCode::FfiInvalidInput);
+    }
+
+    #[test]
+    fn test_generate_request_rejects_purposes_the_library_cannot_derive() {
+        for address_type in [AddressType::Bip49, AddressType::Bip84] {
+            let request = GenerateAddressesRequest::all_chains("wallet-1", "/media/usb", address_type);
+            assert_eq!(request.to_params().unwrap_err().code, ErrorCode::UnsupportedOperation);
+        }
+    }
 }
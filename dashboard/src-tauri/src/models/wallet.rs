@@ -5,6 +5,7 @@
  * Generated: 2025-10-17
  */
 
+use crate::limits::MAX_WALLET_NAME_LEN;
 use serde::{Deserialize, Serialize};
 
 /// Hierarchical Deterministic Wallet
@@ -29,6 +30,71 @@ pub struct Wallet {
     pub address_count: u32,
 }
 
+/// Fields the app reads from a wallet.json or a ListWallets entry.
+///
+/// The Go library writes camelCase while older CLI builds wrote snake_case,
+/// so each field accepts every spelling either layer has used. A field that
+/// is missing, empty or of the wrong type reads as None instead of failing
+/// the whole file.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(from = "serde_json::Value")]
+pub struct WalletFileMetadata {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub created_at: Option<String>,
+    /// Library version that created the wallet
+    pub created_by_version: Option<String>,
+    pub mnemonic_word_count: Option<u32>,
+    pub uses_passphrase: bool,
+}
+
+impl WalletFileMetadata {
+    /// Parse wallet.json contents; None unless they are a JSON object
+    pub fn parse(contents: &str) -> Option<Self> {
+        serde_json::from_str::<serde_json::Value>(contents)
+            .ok()
+            .filter(serde_json::Value::is_object)
+            .map(Self::from)
+    }
+}
+
+/// Value of the first of `names` present in `metadata`
+fn metadata_field<'a>(metadata: &'a serde_json::Value, names: &[&str]) -> Option<&'a serde_json::Value> {
+    names.iter().find_map(|name| metadata.get(name))
+}
+
+/// First non-empty string among `names`
+fn metadata_text(metadata: &serde_json::Value, names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .filter_map(|name| metadata.get(name)?.as_str())
+        .find(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+impl From<&serde_json::Value> for WalletFileMetadata {
+    fn from(metadata: &serde_json::Value) -> Self {
+        Self {
+            id: metadata_text(metadata, &["id", "walletId", "wallet_id"]),
+            name: metadata_text(metadata, &["name", "walletName", "wallet_name"]),
+            created_at: metadata_text(metadata, &["createdAt", "created_at"]),
+            created_by_version: metadata_text(metadata, &["createdByVersion", "created_by_version"]),
+            mnemonic_word_count: metadata_field(metadata, &["mnemonicWordCount", "mnemonic_word_count"])
+                .and_then(|v| v.as_u64())
+                .and_then(|count| u32::try_from(count).ok()),
+            uses_passphrase: metadata_field(metadata, &["usesPassphrase", "uses_passphrase"])
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl From<serde_json::Value> for WalletFileMetadata {
+    fn from(metadata: serde_json::Value) -> Self {
+        Self::from(&metadata)
+    }
+}
+
 /// Wallet creation response (includes mnemonic)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletCreateResponse {
@@ -38,6 +104,10 @@ pub struct WalletCreateResponse {
     /// BIP39 mnemonic phrase (12 or 24 words, space-separated)
     /// SECURITY: Never store in persistent state, only display once
     pub mnemonic: String,
+
+    /// Time the command took, in milliseconds (rounded up)
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
 }
 
 /// Wallet import/restore response
@@ -48,6 +118,106 @@ pub struct WalletImportResponse {
 
     /// True if wallet with same ID already exists (FR-031)
     pub is_duplicate: bool,
+
+    /// True if this call retried an import that had already completed; the
+    /// wallet is the one the earlier attempt created
+    #[serde(default)]
+    pub already_imported: bool,
+
+    /// Time the command took, in milliseconds (rounded up)
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// Response from list_wallets command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletListResponse {
+    /// Wallets found on the USB drive
+    pub wallets: Vec<Wallet>,
+
+    /// Time the command took, in milliseconds (rounded up)
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// Default page size of list_wallets_page
+pub const DEFAULT_WALLET_PAGE_SIZE: usize = 20;
+
+/// Largest page list_wallets_page returns
+pub const MAX_WALLET_PAGE_SIZE: usize = 100;
+
+/// Order of a wallet page
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletSortKey {
+    /// Case-insensitive name, A to Z
+    #[default]
+    Name,
+    /// Creation time, oldest first; unknown dates last
+    CreatedAt,
+}
+
+/// Response from list_wallets_page command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletPage {
+    /// Wallets on this page
+    pub wallets: Vec<Wallet>,
+
+    /// Wallets on the USB drive across all pages
+    pub total: usize,
+
+    /// Index of the first wallet on this page
+    pub offset: usize,
+
+    /// Page size used (after clamping)
+    pub limit: usize,
+
+    /// Time the command took, in milliseconds (rounded up)
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+impl WalletPage {
+    /// Sort `wallets` by `sort_by` and cut out one page. `limit` is clamped
+    /// to 1..=MAX_WALLET_PAGE_SIZE; an offset past the end gives an empty page.
+    pub fn from_list(mut wallets: Vec<Wallet>, sort_by: WalletSortKey, offset: usize, limit: usize) -> Self {
+        let limit = limit.clamp(1, MAX_WALLET_PAGE_SIZE);
+
+        match sort_by {
+            WalletSortKey::Name => wallets.sort_by(|a, b| {
+                a.name
+                    .to_lowercase()
+                    .cmp(&b.name.to_lowercase())
+                    .then_with(|| a.id.cmp(&b.id))
+            }),
+            WalletSortKey::CreatedAt => wallets.sort_by_key(|w| {
+                let created = chrono::DateTime::parse_from_rfc3339(&w.created_at).ok();
+                (created.is_none(), created, w.id.clone())
+            }),
+        }
+
+        let total = wallets.len();
+        let wallets = wallets.into_iter().skip(offset).take(limit).collect();
+
+        Self {
+            wallets,
+            total,
+            offset,
+            limit,
+            duration_ms: None,
+        }
+    }
+}
+
+/// Response from rename_wallet command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletRenameResponse {
+    /// Wallet metadata with the new name
+    pub wallet: Wallet,
+
+    /// Time the command took, in milliseconds (rounded up)
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
 }
 
 impl Wallet {
@@ -76,7 +246,7 @@ impl Wallet {
     /// Validate wallet name (1-50 chars)
     pub fn validate_name(name: &str) -> bool {
         let trimmed = name.trim();
-        !trimmed.is_empty() && trimmed.len() <= 50
+        !trimmed.is_empty() && trimmed.len() <= MAX_WALLET_NAME_LEN
     }
 
     /// Update wallet name
@@ -148,6 +318,87 @@ mod tests {
         assert_ne!(wallet.updated_at, old_updated_at);
     }
 
+    fn wallet(id: &str, name: &str, created_at: &str) -> Wallet {
+        Wallet::new(id.to_string(), name.to_string(), created_at.to_string(), false)
+    }
+
+    fn ids(page: &WalletPage) -> Vec<&str> {
+        page.wallets.iter().map(|w| w.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_wallet_page_boundaries() {
+        let wallets: Vec<Wallet> = (0..5)
+            .map(|i| wallet(&format!("w{}", i), &format!("Wallet {}", i), "2025-10-17T12:00:00Z"))
+            .collect();
+
+        let first = WalletPage::from_list(wallets.clone(), WalletSortKey::Name, 0, 2);
+        assert_eq!((ids(&first), first.total, first.limit), (vec!["w0", "w1"], 5, 2));
+
+        let last = WalletPage::from_list(wallets.clone(), WalletSortKey::Name, 4, 2);
+        assert_eq!(ids(&last), vec!["w4"]);
+
+        let past_end = WalletPage::from_list(wallets.clone(), WalletSortKey::Name, 5, 2);
+        assert!(past_end.wallets.is_empty());
+        assert_eq!(past_end.total, 5);
+
+        // Limits are clamped rather than rejected
+        assert_eq!(WalletPage::from_list(wallets.clone(), WalletSortKey::Name, 0, 0).limit, 1);
+        assert_eq!(WalletPage::from_list(wallets, WalletSortKey::Name, 0, 10_000).limit, MAX_WALLET_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_wallet_page_sort_order() {
+        let wallets = vec![
+            wallet("a", "savings", "2025-03-01T00:00:00Z"),
+            wallet("b", "Cold", "unknown"),
+            wallet("c", "business", "2025-01-01T08:00:00+08:00"),
+            wallet("d", "Travel", "2025-02-01T00:00:00Z"),
+        ];
+
+        let by_name = WalletPage::from_list(wallets.clone(), WalletSortKey::Name, 0, 10);
+        assert_eq!(ids(&by_name), vec!["c", "b", "a", "d"]);
+
+        let by_created = WalletPage::from_list(wallets, WalletSortKey::CreatedAt, 0, 10);
+        assert_eq!(ids(&by_created), vec!["c", "d", "a", "b"]);
+    }
+
+    #[test]
+    fn test_wallet_file_metadata_accepts_both_casings() {
+        let camel = r#"{
+            "id": "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b",
+            "name": "Savings",
+            "createdAt": "2025-10-17T12:00:00Z",
+            "createdByVersion": "0.3.0",
+            "mnemonicWordCount": 24,
+            "usesPassphrase": true
+        }"#;
+        let snake = r#"{
+            "id": "3c3e0aba-91e1-44d4-8b29-ec066d5acf0b",
+            "name": "Savings",
+            "created_at": "2025-10-17T12:00:00Z",
+            "created_by_version": "0.3.0",
+            "mnemonic_word_count": 24,
+            "uses_passphrase": true
+        }"#;
+
+        let from_camel: WalletFileMetadata = serde_json::from_str(camel).unwrap();
+        let from_snake: WalletFileMetadata = serde_json::from_str(snake).unwrap();
+        assert_eq!(from_camel, from_snake);
+        assert_eq!(from_camel.created_at.as_deref(), Some("2025-10-17T12:00:00Z"));
+        assert_eq!(from_camel.mnemonic_word_count, Some(24));
+        assert!(from_camel.uses_passphrase);
+
+        // ListWallets entries name the same fields differently again
+        let listed = WalletFileMetadata::parse(r#"{"walletId":"w1","walletName":"","createdAt":"x"}"#).unwrap();
+        assert_eq!((listed.id.as_deref(), listed.name), (Some("w1"), None));
+
+        // Wrong types degrade to None instead of failing the file
+        let odd = WalletFileMetadata::parse(r#"{"id":7,"mnemonicWordCount":"24","usesPassphrase":"yes"}"#).unwrap();
+        assert_eq!(odd, WalletFileMetadata::default());
+        assert_eq!(WalletFileMetadata::parse("[1,2]"), None);
+    }
+
     #[test]
     fn test_wallet_serialization() {
         let wallet = Wallet::new(
@@ -175,6 +426,7 @@ mod tests {
         let response = WalletCreateResponse {
             wallet: wallet.clone(),
             mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+            duration_ms: None,
         };
 
         assert_eq!(response.wallet, wallet);
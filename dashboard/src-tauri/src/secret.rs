@@ -0,0 +1,132 @@
+//! Secret string wrapper.
+//!
+//! Passwords, mnemonics and passphrases are held in `Secret`, which zeroizes
+//! its buffer on drop. That covers every exit path of a command, including
+//! `?` early returns and panics, without manual `.zeroize()` calls.
+//!
+//! `Secret` deliberately doesn't implement `Serialize`: secrets are only
+//! written out through `ffi_params`, so a struct holding one can't leak it
+//! into a response or log by deriving `Serialize`.
+
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use zeroize::Zeroize;
+
+/// A string that is zeroized when dropped and redacted in Debug output
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    /// Borrow the secret value. Avoid copying it into long-lived Strings.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Zero the buffer in place (called from Drop)
+    fn wipe(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+/// Deserializes from a plain JSON string, so commands can take `Secret` arguments
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Secret::new)
+    }
+}
+
+/// FFI parameter JSON: the object `fields` plus each `(name, secret)`, with
+/// the secrets borrowed from their `Secret`s (`Secret::expose`).
+///
+/// Secrets are escaped straight into the returned buffer, which is sized
+/// up front so it never reallocates and leaves no partial copy behind.
+/// Hand the result to the queue, which zeroizes it after the call.
+pub fn ffi_params(fields: serde_json::Value, secrets: &[(&str, &str)]) -> serde_json::Result<String> {
+    if !fields.is_object() {
+        return Err(serde::ser::Error::custom("FFI parameters must be a JSON object"));
+    }
+    let plain = serde_json::to_vec(&fields)?;
+    // Escaping widens a byte to at most 6 ("\u001f")
+    let reserved: usize = secrets.iter().map(|(name, secret)| name.len() + secret.len() * 6 + 6).sum();
+
+    let mut json = Vec::with_capacity(plain.len() + reserved);
+    json.extend_from_slice(&plain[..plain.len() - 1]);
+    for (name, secret) in secrets {
+        if json.len() > 1 {
+            json.push(b',');
+        }
+        serde_json::to_writer(&mut json, name)?;
+        json.push(b':');
+        serde_json::to_writer(&mut json, secret)?;
+    }
+    json.push(b'}');
+    Ok(String::from_utf8(json).expect("serde_json writes UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wipe_zeroes_buffer() {
+        let mut secret = Secret::new("correct horse battery staple".to_string());
+        let ptr = secret.0.as_ptr();
+        let capacity = secret.0.capacity();
+
+        secret.wipe();
+
+        // The allocation is still owned by `secret`, so reading it is sound
+        let bytes = unsafe { std::slice::from_raw_parts(ptr, capacity) };
+        assert!(bytes.iter().all(|&b| b == 0));
+        assert!(secret.expose().is_empty());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = Secret::new("ValidPassword123".to_string());
+        assert_eq!(format!("{:?}", secret), "Secret(***)");
+    }
+
+    #[test]
+    fn test_ffi_params_include_escaped_secrets() {
+        let password = Secret::new("p\"ss\u{1}word".to_string());
+        let params = ffi_params(serde_json::json!({ "walletId": "w1" }), &[("password", password.expose())]).unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&params).unwrap();
+        assert_eq!(value, serde_json::json!({ "walletId": "w1", "password": "p\"ss\u{1}word" }));
+
+        let only_secret = ffi_params(serde_json::json!({}), &[("password", password.expose())]).unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&only_secret).unwrap()["password"], "p\"ss\u{1}word");
+        assert!(ffi_params(serde_json::json!([]), &[]).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_from_string() {
+        let secret: Secret = serde_json::from_str("\"hunter2\"").unwrap();
+        assert_eq!(secret.expose(), "hunter2");
+
+        let missing: Option<Secret> = serde_json::from_str("null").unwrap();
+        assert!(missing.is_none());
+    }
+}
@@ -0,0 +1,418 @@
+//! Decoding of the wallet library's signing payloads.
+//!
+//! An unsigned transaction travels through the webview before it is
+//! signed, so its `to`/`amount`/`fee` fields are only claims. These helpers
+//! read what is actually signed: Bitcoin payloads are the serialized
+//! transaction, Ethereum payloads the EIP-1559 signing hash, which is
+//! recomputed from the transaction's parameters.
+
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_ALPHABET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Checksum constants of bech32 (BIP173, witness v0) and bech32m (BIP350, v1+)
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// First byte of an OP_RETURN (data carrier) output script
+const OP_RETURN: u8 = 0x6a;
+
+/// Decode standard padded base64, as Go's `encoding/base64.StdEncoding` writes it
+pub fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.trim().as_bytes();
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+    let padding = encoded.iter().rev().take_while(|&&c| c == b'=').count();
+    if padding > 2 {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+    for chunk in encoded.chunks(4) {
+        let mut word = 0u32;
+        for &c in chunk {
+            let value = match c {
+                b'=' => 0,
+                _ => BASE64_ALPHABET.iter().position(|&a| a == c)? as u32,
+            };
+            word = word << 6 | value;
+        }
+        decoded.extend_from_slice(&word.to_be_bytes()[1..]);
+    }
+    decoded.truncate(decoded.len() - padding);
+    Some(decoded)
+}
+
+/// One output of a Bitcoin transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxOutput {
+    /// Satoshis
+    pub value: u64,
+    pub script: Vec<u8>,
+}
+
+impl TxOutput {
+    /// OP_RETURN output carrying a memo
+    pub fn is_null_data(&self) -> bool {
+        self.script.first() == Some(&OP_RETURN)
+    }
+}
+
+/// Reads a serialized transaction front to back
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    fn u64_le(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    /// Bitcoin CompactSize integer
+    fn compact_size(&mut self) -> Option<u64> {
+        let prefix = self.take(1)?[0];
+        let width = match prefix {
+            0xfd => 2,
+            0xfe => 4,
+            0xff => 8,
+            _ => return Some(prefix as u64),
+        };
+        let mut bytes = [0u8; 8];
+        bytes[..width].copy_from_slice(self.take(width)?);
+        Some(u64::from_le_bytes(bytes))
+    }
+
+    fn var_bytes(&mut self) -> Option<&'a [u8]> {
+        let len = usize::try_from(self.compact_size()?).ok()?;
+        self.take(len)
+    }
+}
+
+/// Outputs of a serialized Bitcoin transaction (legacy or segwit encoding)
+pub fn bitcoin_outputs(tx: &[u8]) -> Option<Vec<TxOutput>> {
+    let mut reader = Reader { bytes: tx };
+    reader.take(4)?; // version
+
+    let mut input_count = reader.compact_size()?;
+    if input_count == 0 {
+        // Segwit marker 0x00 followed by flag 0x01
+        if reader.take(1)? != [0x01] {
+            return None;
+        }
+        input_count = reader.compact_size()?;
+    }
+    for _ in 0..input_count {
+        reader.take(36)?; // previous output
+        reader.var_bytes()?; // signature script
+        reader.take(4)?; // sequence
+    }
+
+    let output_count = reader.compact_size()?;
+    let mut outputs = Vec::new();
+    for _ in 0..output_count {
+        let value = reader.u64_le()?;
+        let script = reader.var_bytes()?.to_vec();
+        outputs.push(TxOutput { value, script });
+    }
+    Some(outputs)
+}
+
+/// Base58Check payload (version byte included), if the checksum holds
+fn decode_base58check(address: &str) -> Option<Vec<u8>> {
+    // Big-endian base-256 digits of the base58 number
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in address.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    // Leading '1's stand for leading zero bytes
+    let zeros = address.bytes().take_while(|&c| c == b'1').count();
+    let mut decoded = vec![0u8; zeros];
+    decoded.extend(bytes);
+
+    if decoded.len() < 4 {
+        return None;
+    }
+    let (payload, checksum) = decoded.split_at(decoded.len() - 4);
+    let hash = Sha256::digest(Sha256::digest(payload));
+    (hash[..4] == *checksum).then(|| payload.to_vec())
+}
+
+fn bech32_polymod(values: impl IntoIterator<Item = u8>) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = (checksum & 0x1ff_ffff) << 5 ^ value as u32;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// Witness version and program of a segwit address, if its checksum holds
+fn decode_segwit(address: &str) -> Option<(u8, Vec<u8>)> {
+    let lower = address.to_ascii_lowercase();
+    if address != lower && address != address.to_ascii_uppercase() {
+        return None;
+    }
+    let (hrp, data) = lower.rsplit_once('1')?;
+    if hrp.is_empty() || data.len() < 7 {
+        return None;
+    }
+    let data: Vec<u8> = data
+        .bytes()
+        .map(|c| BECH32_ALPHABET.iter().position(|&a| a == c).map(|v| v as u8))
+        .collect::<Option<_>>()?;
+
+    let expanded = hrp.bytes().map(|c| c >> 5).chain([0]).chain(hrp.bytes().map(|c| c & 31));
+    let checksum = bech32_polymod(expanded.chain(data.iter().copied()));
+    let version = data[0];
+    let expected = if version == 0 { BECH32_CONST } else { BECH32M_CONST };
+    if checksum != expected || version > 16 {
+        return None;
+    }
+
+    // Regroup the 5-bit program into bytes; leftover bits must be zero padding
+    let mut program = Vec::new();
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for &value in &data[1..data.len() - 6] {
+        acc = acc << 5 | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            program.push((acc >> bits) as u8);
+        }
+    }
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 || !(2..=40).contains(&program.len()) {
+        return None;
+    }
+    Some((version, program))
+}
+
+/// Output script paying a Bitcoin address (P2PKH, P2SH or segwit), on any network
+pub fn bitcoin_script(address: &str) -> Option<Vec<u8>> {
+    if let Some((version, program)) = decode_segwit(address) {
+        let opcode = if version == 0 { 0x00 } else { 0x50 + version };
+        let mut script = vec![opcode, program.len() as u8];
+        script.extend(program);
+        return Some(script);
+    }
+
+    let payload = decode_base58check(address)?;
+    let (version, hash) = payload.split_first()?;
+    if hash.len() != 20 {
+        return None;
+    }
+    let script = match version {
+        // OP_DUP OP_HASH160 <hash> OP_EQUALVERIFY OP_CHECKSIG
+        0x00 | 0x6f => [&[0x76, 0xa9, 0x14][..], hash, &[0x88, 0xac]].concat(),
+        // OP_HASH160 <hash> OP_EQUAL
+        0x05 | 0xc4 => [&[0xa9, 0x14][..], hash, &[0x87]].concat(),
+        _ => return None,
+    };
+    Some(script)
+}
+
+/// RLP encoding of a byte string
+fn rlp_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        out.push(bytes[0]);
+    } else {
+        rlp_length(bytes.len(), 0x80, out);
+        out.extend_from_slice(bytes);
+    }
+}
+
+/// RLP encoding of an integer: big-endian without leading zeros
+fn rlp_uint(value: u128, out: &mut Vec<u8>) {
+    let bytes = value.to_be_bytes();
+    let first = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    rlp_bytes(&bytes[first..], out);
+}
+
+/// RLP length prefix; `offset` is 0x80 for strings, 0xc0 for lists
+fn rlp_length(len: usize, offset: u8, out: &mut Vec<u8>) {
+    if len <= 55 {
+        out.push(offset + len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let first = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        out.push(offset + 55 + (bytes.len() - first) as u8);
+        out.extend_from_slice(&bytes[first..]);
+    }
+}
+
+/// Parameters of an EIP-1559 transaction
+#[derive(Debug, Clone)]
+pub struct Eip1559Transaction<'a> {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub gas_limit: u64,
+    pub to: [u8; 20],
+    /// Wei
+    pub value: u128,
+    pub data: &'a [u8],
+}
+
+impl Eip1559Transaction<'_> {
+    /// Hash the sender signs: keccak256(0x02 || rlp([chainId, nonce,
+    /// maxPriorityFeePerGas, maxFeePerGas, gasLimit, to, value, data, accessList]))
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut fields = Vec::new();
+        rlp_uint(self.chain_id.into(), &mut fields);
+        rlp_uint(self.nonce.into(), &mut fields);
+        rlp_uint(self.max_priority_fee_per_gas, &mut fields);
+        rlp_uint(self.max_fee_per_gas, &mut fields);
+        rlp_uint(self.gas_limit.into(), &mut fields);
+        rlp_bytes(&self.to, &mut fields);
+        rlp_uint(self.value, &mut fields);
+        rlp_bytes(self.data, &mut fields);
+        rlp_length(0, 0xc0, &mut fields); // empty access list
+
+        let mut encoded = vec![0x02];
+        rlp_length(fields.len(), 0xc0, &mut encoded);
+        encoded.extend(fields);
+        Keccak256::digest(&encoded).into()
+    }
+}
+
+/// The 20 bytes of a `0x`-prefixed Ethereum address
+pub fn ethereum_address_bytes(address: &str) -> Option<[u8; 20]> {
+    let hex = address.strip_prefix("0x")?;
+    if hex.len() != 40 {
+        return None;
+    }
+    let mut bytes = [0u8; 20];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(2 * i..2 * i + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(decode_base64("AQID").unwrap(), vec![1, 2, 3]);
+        assert_eq!(decode_base64("q83vAA==").unwrap(), vec![0xab, 0xcd, 0xef, 0x00]);
+        assert_eq!(decode_base64("aGk=").unwrap(), b"hi");
+        assert_eq!(decode_base64("").unwrap(), Vec::<u8>::new());
+        assert!(decode_base64("AQI").is_none());
+        assert!(decode_base64("A?ID").is_none());
+    }
+
+    #[test]
+    fn test_rlp_encoding() {
+        let encode = |value: &[u8]| {
+            let mut out = Vec::new();
+            rlp_bytes(value, &mut out);
+            hex(&out)
+        };
+        assert_eq!(encode(b"dog"), "83646f67");
+        assert_eq!(encode(b""), "80");
+        assert_eq!(encode(&[0x0f]), "0f");
+        let lorem = b"Lorem ipsum dolor sit amet, consectetur adipisicing elit";
+        assert_eq!(encode(lorem), format!("b838{}", hex(lorem)));
+
+        let mut out = Vec::new();
+        rlp_uint(0, &mut out);
+        rlp_uint(1024, &mut out);
+        assert_eq!(hex(&out), "80820400");
+    }
+
+    #[test]
+    fn test_bitcoin_scripts() {
+        let cases = [
+            // BIP173 / BIP350 test vectors
+            (
+                "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+                "0014751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+            (
+                "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+                "76a91477bff20c60e522dfaa3350c39b030a5d004e839a88ac",
+            ),
+            (
+                "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                "a914b472a266d0bd89c13706a4132ccfb16f7c3b9fcb87",
+            ),
+        ];
+        for (address, script) in cases {
+            assert_eq!(bitcoin_script(address).map(|s| hex(&s)).as_deref(), Some(script), "{}", address);
+        }
+
+        // Bad checksums
+        assert_eq!(bitcoin_script("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"), None);
+        assert_eq!(bitcoin_script("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"), None);
+        // A v1 program with a bech32 (not bech32m) checksum
+        assert_eq!(bitcoin_script("bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7k7grplx"), None);
+    }
+
+    #[test]
+    fn test_bitcoin_outputs() {
+        // Version 2, one input, outputs paying 150000 and 0 (OP_RETURN "hi")
+        let tx = [
+            "02000000",
+            "01",
+            &"00".repeat(32),
+            "00000000",
+            "00",
+            "ffffffff",
+            "02",
+            "f049020000000000",
+            "16",
+            "0014751e76e8199196d454941c45d1b3a323f1433bd6",
+            "0000000000000000",
+            "04",
+            "6a026869",
+            "00000000",
+        ]
+        .concat();
+        let bytes: Vec<u8> = (0..tx.len()).step_by(2).map(|i| u8::from_str_radix(&tx[i..i + 2], 16).unwrap()).collect();
+
+        let outputs = bitcoin_outputs(&bytes).unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].value, 150_000);
+        assert_eq!(hex(&outputs[0].script), "0014751e76e8199196d454941c45d1b3a323f1433bd6");
+        assert!(outputs[1].is_null_data());
+
+        assert!(bitcoin_outputs(&bytes[..bytes.len() - 10]).is_none());
+    }
+}
@@ -21,6 +21,8 @@ export interface ProviderConfigResponse {
   providerType: string;
   chainId: string;
   networkId?: string;
+  /** Masked: tokens, query strings and user info are replaced by "****" */
+  customEndpoint?: string;
   priority: number;
   enabled: boolean;
   hasApiKey: boolean;
@@ -124,6 +126,149 @@ export async function deleteProviderConfig(
   }
 }
 
+export interface RotatedProviderKey {
+  providerType: string;
+  chainId: string;
+  networkId: string;
+  priority: number;
+  enabled: boolean;
+  /** Last 4 characters only, e.g. "****abcd" */
+  maskedApiKey: string;
+  /** The new key passed a connection test before it was stored */
+  tested: boolean;
+}
+
+/**
+ * Replace a provider's API key, keeping priority, enabled flag and endpoint
+ */
+export async function rotateProviderKey(
+  chainId: string,
+  providerType: string,
+  newApiKey: string,
+  password: string,
+  usbPath: string,
+  testConnection = false
+): Promise<RotatedProviderKey> {
+  try {
+    return await invoke<RotatedProviderKey>('rotate_provider_key', {
+      input: {
+        chainId,
+        providerType,
+        newApiKey,
+        testConnection,
+        password,
+        usbPath,
+      },
+    });
+  } catch (error) {
+    console.error('Failed to rotate provider key:', error);
+    throw error;
+  }
+}
+
+/** A stored provider config without its API key */
+export interface ProviderConfigBackup {
+  providerType: string;
+  chainId: string;
+  networkId: string;
+  /** Masked in exports; imports take the full URL via ProviderApiKey */
+  customEndpoint: string;
+  priority: number;
+  enabled: boolean;
+  hasApiKey: boolean;
+}
+
+/** Parsed form of the JSON returned by exportProviderConfigs */
+export interface ProviderConfigExport {
+  version: number;
+  exportedAt: string;
+  providers: ProviderConfigBackup[];
+}
+
+export interface ProviderApiKey {
+  chainId: string;
+  providerType: string;
+  apiKey: string;
+  /** Full endpoint, for configs whose exported endpoint contains "****" */
+  customEndpoint?: string;
+}
+
+/** Entries are "chainId/providerType" */
+export interface ProviderImportSummary {
+  imported: string[];
+  /** Skipped because no API key was given for them */
+  missingKeys: string[];
+  /** Skipped because their endpoint was masked and no full one was given */
+  missingEndpoints: string[];
+}
+
+/**
+ * Export all provider configs as JSON, without API keys
+ */
+export async function exportProviderConfigs(password: string, usbPath: string): Promise<string> {
+  try {
+    return await invoke<string>('export_provider_configs', {
+      input: {
+        password,
+        usbPath,
+      },
+    });
+  } catch (error) {
+    console.error('Failed to export provider configs:', error);
+    throw error;
+  }
+}
+
+/**
+ * Restore provider configs from an export; keys are entered separately
+ */
+export async function importProviderConfigs(
+  contents: string,
+  apiKeys: ProviderApiKey[],
+  password: string,
+  usbPath: string
+): Promise<ProviderImportSummary> {
+  try {
+    return await invoke<ProviderImportSummary>('import_provider_configs', {
+      input: {
+        contents,
+        apiKeys,
+        password,
+        usbPath,
+      },
+    });
+  } catch (error) {
+    console.error('Failed to import provider configs:', error);
+    throw error;
+  }
+}
+
+/** Result of checking one provider */
+export interface ProviderHealth {
+  chainId: string;
+  providerType: string;
+  reachable: boolean;
+  latencyMs: number;
+  error: string | null;
+}
+
+/**
+ * Check that every enabled provider answers with its stored key
+ */
+export async function testAllProviders(password: string, usbPath: string): Promise<ProviderHealth[]> {
+  try {
+    return await invoke<ProviderHealth[]>('test_all_providers', {
+      input: {
+        password,
+        usbPath,
+      },
+    });
+  } catch (error) {
+    console.error('Failed to test providers:', error);
+    throw error;
+  }
+}
+
 /**
  * Provider types supported by the system
  */
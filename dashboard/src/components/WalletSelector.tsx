@@ -81,7 +81,7 @@ export const WalletSelector: React.FC<WalletSelectorProps> = ({
     setRenameError(null);
 
     try {
-      const updatedWallet = await tauriApi.renameWallet({
+      const { wallet: updatedWallet } = await tauriApi.renameWallet({
         wallet_id: walletId,
         new_name: newName.trim(),
         usb_path: usbPath,
@@ -137,6 +137,8 @@ export function useInactivityLogout(options: UseInactivityLogoutOptions = {}) {
   const handleActivity = () => {
     if (enabled) {
       resetTimers();
+      // The backend locks unlocked wallets on its own idle timer
+      tauriApi.heartbeat().catch((error) => console.error('Heartbeat failed:', error));
     }
   };
 
@@ -75,7 +75,7 @@ export function Dashboard() {
         setUsbPath(usbDevice.path);
 
         // Load wallets from USB
-        const walletList = await tauriApi.listWallets(usbDevice.path);
+        const { wallets: walletList } = await tauriApi.listWallets(usbDevice.path);
         setWallets(walletList);
 
         // Auto-select first wallet if none selected
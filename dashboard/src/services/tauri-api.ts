@@ -7,9 +7,12 @@
 
 import { invoke } from '@tauri-apps/api';
 import type {
-  Wallet,
   WalletCreateResponse,
   WalletImportResponse,
+  WalletListResponse,
+  WalletPage,
+  WalletSortKey,
+  WalletRenameResponse,
   WalletCreateParams,
   WalletImportParams,
   LoadAddressesParams,
@@ -17,6 +20,7 @@ import type {
 } from '@/types/wallet';
 import type {
   AddressListResponse,
+  GroupedAddressResponse,
 } from '@/types/address';
 
 /**
@@ -77,6 +81,25 @@ export async function detectUsb(): Promise<UsbDevice[]> {
   }
 }
 
+/** What a USB filesystem can enforce (null where unknown) */
+export interface UsbSecurityReport {
+  /** Filesystem type, e.g. "vfat", "exfat", "apfs" */
+  filesystem: string | null;
+  preserves_permissions: boolean | null;
+  case_sensitive: boolean | null;
+  /** Messages to show the user; empty if nothing to warn about */
+  warnings: string[];
+}
+
+/** Check whether a USB can restrict file permissions and distinguishes file name case */
+export async function checkUsbSecurity(usbPath: string): Promise<UsbSecurityReport> {
+  try {
+    return await invoke<UsbSecurityReport>('check_usb_security', { usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
 /**
  * Wallet Management
  */
@@ -99,28 +122,43 @@ export async function createWallet(params: WalletCreateParams): Promise<WalletCr
 export async function importWallet(params: WalletImportParams): Promise<WalletImportResponse> {
   try {
     return await invoke<WalletImportResponse>('import_wallet', {
-      mnemonic: params.mnemonic,
-      password: params.password,
-      usbPath: params.usb_path,
-      passphrase: params.passphrase,
-      name: params.name,
+      input: {
+        mnemonic: params.mnemonic,
+        password: params.password,
+        usbPath: params.usb_path,
+        passphrase: params.passphrase,
+        name: params.name,
+      },
     });
   } catch (error) {
     throw parseError(error);
   }
 }
 
-export async function listWallets(usbPath: string): Promise<Wallet[]> {
+export async function listWallets(usbPath: string): Promise<WalletListResponse> {
+  try {
+    return await invoke<WalletListResponse>('list_wallets', { usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+export async function listWalletsPage(
+  usbPath: string,
+  offset?: number,
+  limit?: number,
+  sortBy?: WalletSortKey
+): Promise<WalletPage> {
   try {
-    return await invoke<Wallet[]>('list_wallets', { usbPath });
+    return await invoke<WalletPage>('list_wallets_page', { usbPath, offset, limit, sortBy });
   } catch (error) {
     throw parseError(error);
   }
 }
 
-export async function renameWallet(params: RenameWalletParams): Promise<Wallet> {
+export async function renameWallet(params: RenameWalletParams): Promise<WalletRenameResponse> {
   try {
-    return await invoke<Wallet>('rename_wallet', {
+    return await invoke<WalletRenameResponse>('rename_wallet', {
       walletId: params.wallet_id,
       newName: params.new_name,
       usbPath: params.usb_path,
@@ -130,6 +168,260 @@ export async function renameWallet(params: RenameWalletParams): Promise<Wallet>
   }
 }
 
+/**
+ * Wallet whose addresses were loaded this session
+ */
+export interface RecentWallet {
+  wallet_id: string;
+  /** ISO 8601 timestamp of the last access */
+  last_accessed: string;
+}
+
+/** Recently accessed wallets, most recent first (reset on restart) */
+export async function recentWallets(limit: number): Promise<RecentWallet[]> {
+  try {
+    return await invoke<RecentWallet[]>('recent_wallets', { limit });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/**
+ * Check that a re-entered backup phrase restores the same wallet.
+ * Whitespace and case are ignored; a different passphrase is a different wallet.
+ */
+export async function mnemonicsEquivalent(
+  a: string,
+  b: string,
+  passphraseA?: string,
+  passphraseB?: string
+): Promise<boolean> {
+  try {
+    return await invoke<boolean>('mnemonics_equivalent', { a, b, passphraseA, passphraseB });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Address a backup phrase restores on one chain */
+export interface RestorePreview {
+  chainId: string;
+  address: string;
+}
+
+/** Derive the first address a mnemonic (and passphrase) restores, without creating a wallet */
+export async function previewRestore(
+  mnemonic: string,
+  chainId: string,
+  passphrase?: string
+): Promise<RestorePreview> {
+  try {
+    return await invoke<RestorePreview>('preview_restore', { mnemonic, passphrase, chainId });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Mnemonic layout on the backup screen */
+export type MnemonicStyle = 'plain' | 'numbered' | 'grouped';
+
+/** Format a mnemonic for display: one line, one numbered word per line, or numbered rows of 4 */
+export async function formatMnemonic(mnemonic: string, style: MnemonicStyle): Promise<string> {
+  try {
+    return await invoke<string>('format_mnemonic', { mnemonic, style });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Change the password protecting one wallet */
+export async function changeWalletPassword(
+  walletId: string,
+  oldPassword: string,
+  newPassword: string,
+  usbPath: string
+): Promise<void> {
+  try {
+    await invoke('change_wallet_password', { walletId, oldPassword, newPassword, usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Check a wallet password without loading its addresses */
+export async function verifyPassword(
+  walletId: string,
+  password: string,
+  usbPath: string
+): Promise<{ valid: boolean }> {
+  try {
+    return await invoke<{ valid: boolean }>('verify_password', {
+      walletId,
+      password,
+      usbPath,
+    });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/**
+ * Outcome of re-encrypting one wallet in a bulk password change
+ */
+export interface WalletReencryptResult {
+  wallet_id: string;
+  name: string;
+  success: boolean;
+  error: AppError | null;
+}
+
+/** Re-encrypt every wallet on a USB that the old password unlocks */
+export async function reencryptAllWallets(
+  usbPath: string,
+  oldPassword: string,
+  newPassword: string
+): Promise<WalletReencryptResult[]> {
+  try {
+    return await invoke<WalletReencryptResult[]>('reencrypt_all_wallets', {
+      usbPath,
+      oldPassword,
+      newPassword,
+    });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/**
+ * Public description of one chain account, for watch-only wallets
+ */
+export interface WatchOnlyDescriptor {
+  chainId: string;
+  /** Output descriptor (Bitcoin) or the account xpub (other chains) */
+  descriptor: string;
+  xpub: string;
+  derivationPath: string;
+}
+
+/** Export a watch-only descriptor (no private keys) for one chain */
+export async function exportDescriptor(
+  walletId: string,
+  password: string,
+  usbPath: string,
+  chainId: string
+): Promise<WatchOnlyDescriptor> {
+  try {
+    return await invoke<WatchOnlyDescriptor>('export_descriptor', {
+      walletId,
+      password,
+      usbPath,
+      chainId,
+    });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/**
+ * Wallet in the portfolio summary
+ */
+export interface PortfolioWallet {
+  id: string;
+  name: string;
+  address_count: number;
+}
+
+/**
+ * Wallet and address totals across a USB
+ */
+export interface PortfolioSummary {
+  wallet_count: number;
+  total_addresses: number;
+  wallets: PortfolioWallet[];
+}
+
+/** Summarize the wallets on a USB from stored metadata (no unlock needed) */
+export async function portfolioSummary(usbPath: string): Promise<PortfolioSummary> {
+  try {
+    return await invoke<PortfolioSummary>('portfolio_summary', { usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/**
+ * How a wallet was created, from its metadata (nothing secret)
+ */
+export interface WalletProvenance {
+  wallet_id: string;
+  created_at: string | null;
+  /** Library version that created the wallet (null for older wallets) */
+  created_by_version: string | null;
+  /** Mnemonic length in words (null for older wallets) */
+  mnemonic_word_count: number | null;
+  uses_passphrase: boolean;
+}
+
+/** Read a wallet's creation provenance for audits */
+export async function walletProvenance(walletId: string, usbPath: string): Promise<WalletProvenance> {
+  try {
+    return await invoke<WalletProvenance>('wallet_provenance', { walletId, usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Argon2id parameters a wallet's mnemonic is encrypted with */
+export interface KdfParams {
+  wallet_id: string;
+  algorithm: string;
+  format_version: number;
+  memory_kib: number;
+  iterations: number;
+  parallelism: number;
+  /** At least as strong as the settings used for new wallets */
+  recommended: boolean;
+}
+
+/** Read a wallet's key-derivation parameters (explains unlock speed) */
+export async function getKdfParams(walletId: string, usbPath: string): Promise<KdfParams> {
+  try {
+    return await invoke<KdfParams>('get_kdf_params', { walletId, usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Result of writing or restoring a wallet bundle */
+export interface WalletBundleResult {
+  wallet_id: string;
+  /** Bundle file (export) or restored wallet directory (import) */
+  path: string;
+  file_count: number;
+}
+
+/** Package a wallet's encrypted files into a bundle for moving it to another USB */
+export async function exportWalletBundle(
+  walletId: string,
+  usbPath: string,
+  destPath: string
+): Promise<WalletBundleResult> {
+  try {
+    return await invoke<WalletBundleResult>('export_wallet_bundle', { walletId, usbPath, destPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Verify a wallet bundle's checksums and restore it onto a USB */
+export async function importWalletBundle(bundlePath: string, usbPath: string): Promise<WalletBundleResult> {
+  try {
+    return await invoke<WalletBundleResult>('import_wallet_bundle', { bundlePath, usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
 /**
  * Address Management
  */
@@ -137,15 +429,46 @@ export async function renameWallet(params: RenameWalletParams): Promise<Wallet>
 export async function loadAddresses(params: LoadAddressesParams): Promise<AddressListResponse> {
   try {
     return await invoke<AddressListResponse>('load_addresses', {
-      walletId: params.wallet_id,
-      password: params.password,
-      usbPath: params.usb_path,
+      input: {
+        walletId: params.wallet_id,
+        password: params.password,
+        usbPath: params.usb_path,
+        applyRanking: params.apply_ranking,
+      },
+    });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Load a wallet's addresses grouped by category, in a fixed category order */
+export async function loadAddressesGrouped(params: LoadAddressesParams): Promise<GroupedAddressResponse> {
+  try {
+    return await invoke<GroupedAddressResponse>('load_addresses_grouped', {
+      input: {
+        walletId: params.wallet_id,
+        password: params.password,
+        usbPath: params.usb_path,
+      },
     });
   } catch (error) {
     throw parseError(error);
   }
 }
 
+/** Store the chains to pin to the top of a wallet's address list (empty list resets) */
+export async function setAddressRanking(
+  walletId: string,
+  preferredSymbols: string[],
+  usbPath: string
+): Promise<string[]> {
+  try {
+    return await invoke<string[]>('set_address_ranking', { walletId, preferredSymbols, usbPath });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
 /**
  * Security Commands
  */
@@ -174,6 +497,55 @@ export async function clearSensitiveMemory(): Promise<void> {
   }
 }
 
+/** Ids of the wallets currently held unlocked in memory */
+export async function listUnlockedWallets(): Promise<string[]> {
+  try {
+    return await invoke<string[]>('list_unlocked_wallets');
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Lock one wallet, leaving the others unlocked */
+export async function lockWallet(walletId: string): Promise<void> {
+  try {
+    await invoke('lock_wallet', { walletId });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Reset the backend idle timer; call on user interaction */
+export async function heartbeat(): Promise<void> {
+  try {
+    await invoke('heartbeat');
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/**
+ * Diagnostics
+ */
+
+/** Payload of the `app-log` event */
+export interface LogEvent {
+  level: 'ERROR' | 'WARN' | 'INFO' | 'DEBUG' | 'TRACE';
+  target: string;
+  message: string;
+  /** RFC 3339 timestamp */
+  timestamp: string;
+}
+
+/** Choose which log lines are streamed as `app-log` events ('off' stops them) */
+export async function setLogStreamLevel(level: 'error' | 'warn' | 'info' | 'debug' | 'trace' | 'off'): Promise<void> {
+  try {
+    await invoke('set_log_stream_level', { level });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
 /**
  * App-level Authentication
  */
@@ -240,6 +612,242 @@ export async function unlockApp(password: string, usbPath: string): Promise<AppC
   }
 }
 
+/**
+ * Transactions
+ */
+
+export interface BatchRecipient {
+  to: string;
+  /** Main units ("1", "0.5"), or with a unit ("20 gwei", "1500 sat") */
+  amount: string;
+}
+
+export interface BuildTransactionInput {
+  chainId: string;
+  from: string;
+  to: string;
+  asset?: string;
+  /** Main units ("1", "0.5"), or with a unit ("20 gwei", "1500 sat") */
+  amount: string;
+  feeSpeed?: 'slow' | 'normal' | 'fast' | 'custom';
+  feeRate?: string;
+  memo?: string;
+  rpcConfig?: string;
+  /** Account nonce (EVM chains); fetched from the node when omitted */
+  nonce?: number;
+}
+
+/** Build an unsigned transaction */
+export async function buildTransaction(
+  input: BuildTransactionInput
+): Promise<Record<string, unknown>> {
+  try {
+    return await invoke<Record<string, unknown>>('build_transaction', { input });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** One payment of a transaction summary */
+export interface SummaryOutput {
+  to: string;
+  /** Main units ("0.5") */
+  amount: string;
+}
+
+/** What an unsigned transaction does, for the confirmation screen */
+export interface TransactionSummary {
+  chain: string;
+  /** Unit of amount, fee and total ("BTC", "ETH") */
+  symbol: string;
+  from: string;
+  /** The recipient, or the first of several (see outputs) */
+  to: string;
+  outputs: SummaryOutput[];
+  amount: string;
+  fee: string;
+  total: string;
+  nonce: number | null;
+}
+
+/**
+ * Summarize a transaction returned by buildTransaction(Multi) before signing.
+ * Fails if the signing payload does not match its recipients, amount or fee,
+ * and for token transfers.
+ */
+export async function decodeTransaction(
+  chainId: string,
+  unsignedTx: Record<string, unknown>
+): Promise<TransactionSummary> {
+  try {
+    return await invoke<TransactionSummary>('decode_transaction', { chainId, unsignedTx });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+export interface BuildMultiTransactionInput {
+  chainId: string;
+  from: string;
+  asset?: string;
+  /** Several outputs are only supported on Bitcoin */
+  outputs: BatchRecipient[];
+  feeSpeed?: 'slow' | 'normal' | 'fast' | 'custom';
+  feeRate?: string;
+  memo?: string;
+  rpcConfig?: string;
+}
+
+/** Build an unsigned transaction paying several outputs at once */
+export async function buildTransactionMulti(
+  input: BuildMultiTransactionInput
+): Promise<Record<string, unknown>> {
+  try {
+    return await invoke<Record<string, unknown>>('build_transaction_multi', { input });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Fee estimate in the chain's base unit (satoshi, wei, ...) */
+export interface FeeEstimate {
+  minFee: string;
+  recommendedFee: string;
+  maxFee: string;
+  confidence: number;
+  estimatedBlocks: number;
+}
+
+export interface BatchFeeEstimate extends FeeEstimate {
+  chainId: string;
+  /** True when all recipients are paid by one multi-output transaction */
+  combined: boolean;
+  /** Per-recipient estimates (empty when combined) */
+  recipients: FeeEstimate[];
+}
+
+/** Estimate the total fee of paying several recipients ("pay many") */
+export async function estimateBatchFee(
+  chainId: string,
+  from: string,
+  recipients: BatchRecipient[],
+  rpcConfig?: string
+): Promise<BatchFeeEstimate> {
+  try {
+    return await invoke<BatchFeeEstimate>('estimate_batch_fee', {
+      chainId,
+      from,
+      recipients,
+      rpcConfig,
+    });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+export interface QueryStatusInput {
+  chainId: string;
+  txHash: string;
+  /** Unlocks the provider config store */
+  password: string;
+  usbPath: string;
+}
+
+/** A provider that failed before another one answered */
+export interface ProviderFailure {
+  providerType: string;
+  error: string;
+}
+
+export interface BroadcastTransactionInput {
+  chainId: string;
+  /** Signed transaction as returned by the signer */
+  signedTx: Record<string, unknown>;
+  /** Unlocks the provider config store */
+  password: string;
+  usbPath: string;
+}
+
+/** A submitted transaction and the provider that accepted it */
+export interface BroadcastReceipt {
+  txHash: string;
+  chainId: string;
+  status: string;
+  submittedAt: string | null;
+  statusUrl: string | null;
+  /** null: the library's default endpoint (no provider configured) */
+  providerType: string | null;
+  /** Providers that failed first, highest priority first */
+  failedProviders: ProviderFailure[];
+}
+
+/** A transaction's status and the provider that reported it */
+export interface TransactionStatusReport {
+  txHash: string;
+  status: string;
+  confirmations: number | null;
+  blockNumber: number | null;
+  /** null: the library's default endpoint (no provider configured) */
+  providerType: string | null;
+  /** Providers that failed first, highest priority first */
+  failedProviders: ProviderFailure[];
+}
+
+/** Broadcast a signed transaction, failing over between enabled providers */
+export async function broadcastTransaction(
+  input: BroadcastTransactionInput
+): Promise<BroadcastReceipt> {
+  try {
+    return await invoke<BroadcastReceipt>('broadcast_transaction', { input });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** Query a transaction's status, failing over between enabled providers */
+export async function queryTransactionStatus(
+  input: QueryStatusInput
+): Promise<TransactionStatusReport> {
+  try {
+    return await invoke<TransactionStatusReport>('query_transaction_status', { input });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
+/** One provider's answer to a status query */
+export interface ProviderTransactionStatus {
+  providerType: string;
+  priority: number;
+  /** null if the query failed */
+  status: string | null;
+  confirmations: number | null;
+  error: string | null;
+}
+
+/** A transaction's status as seen by every enabled provider of its chain */
+export interface ConsolidatedTransactionStatus {
+  chainId: string;
+  txHash: string;
+  /** Answer of the highest-priority provider that responded */
+  status: string | null;
+  /** Providers reported different statuses: one is lagging or misconfigured */
+  disagreement: boolean;
+  /** Highest priority first */
+  providers: ProviderTransactionStatus[];
+}
+
+/** Query a transaction through every enabled provider of its chain */
+export async function queryTransactionStatusAll(
+  input: QueryStatusInput
+): Promise<ConsolidatedTransactionStatus> {
+  try {
+    return await invoke<ConsolidatedTransactionStatus>('query_transaction_status_all', { input });
+  } catch (error) {
+    throw parseError(error);
+  }
+}
+
 /**
  * Typed Tauri API wrapper
  * Provides type-safe access to all Tauri commands
@@ -247,6 +855,7 @@ export async function unlockApp(password: string, usbPath: string): Promise<AppC
 export const tauriApi = {
   // USB
   detectUsb,
+  checkUsbSecurity,
 
   // App Authentication
   isFirstTimeSetup,
@@ -257,15 +866,44 @@ export const tauriApi = {
   createWallet,
   importWallet,
   listWallets,
+  listWalletsPage,
   renameWallet,
+  recentWallets,
+  portfolioSummary,
+  walletProvenance,
+  getKdfParams,
+  exportWalletBundle,
+  importWalletBundle,
+  mnemonicsEquivalent,
+  previewRestore,
+  formatMnemonic,
+  changeWalletPassword,
+  reencryptAllWallets,
+  verifyPassword,
+  exportDescriptor,
 
   // Address
   loadAddresses,
+  loadAddressesGrouped,
+  setAddressRanking,
+
+  // Transactions
+  buildTransaction,
+  buildTransactionMulti,
+  decodeTransaction,
+  estimateBatchFee,
+  broadcastTransaction,
+  queryTransactionStatus,
+  queryTransactionStatusAll,
 
   // Security
   enableScreenshotProtection,
   disableScreenshotProtection,
   clearSensitiveMemory,
+  listUnlockedWallets,
+  lockWallet,
+  heartbeat,
+  setLogStreamLevel,
 };
 
 export default tauriApi;
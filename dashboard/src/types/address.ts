@@ -44,6 +44,22 @@ export enum KeyType {
   SCHNORR = 'schnorr',
 }
 
+/**
+ * Bitcoin address type (purpose level of the derivation path).
+ * Other chains always derive with BIP44. The wallet library only derives
+ * BIP44 addresses so far; other types fail with UNSUPPORTED_OPERATION.
+ */
+export enum AddressType {
+  /** Legacy P2PKH, m/44'/... (default) */
+  BIP44 = 'bip44',
+
+  /** P2SH-wrapped SegWit, m/49'/... */
+  BIP49 = 'bip49',
+
+  /** Native SegWit (bech32), m/84'/... */
+  BIP84 = 'bip84',
+}
+
 /**
  * Represents a derived cryptocurrency address for a specific blockchain
  * Each wallet contains exactly 54 addresses (one per supported blockchain)
@@ -78,6 +94,9 @@ export interface Address {
 
   /** Optional testnet indicator (true for testnet addresses) */
   is_testnet?: boolean;
+
+  /** Library rank, set when a user ranking reordered the list */
+  default_rank?: number;
 }
 
 /**
@@ -93,6 +112,45 @@ export interface AddressListResponse {
 
   /** Total address count (always 54 for v0.3.0) */
   total_count: number;
+
+  /** Blockchains the library failed to derive an address for */
+  failed: FailedAddress[];
+
+  /** Time the command took, in milliseconds */
+  duration_ms?: number;
+}
+
+/**
+ * Addresses of one category, in rank order
+ */
+export interface CategoryGroup {
+  category: Category;
+  addresses: Address[];
+}
+
+/**
+ * Response from load_addresses_grouped command
+ */
+export interface GroupedAddressResponse {
+  /** Wallet identifier */
+  wallet_id: string;
+
+  /** One group per category (base, layer2, regional, cosmos, alt_evm, specialized), empty ones included */
+  groups: CategoryGroup[];
+
+  /** Addresses across all groups */
+  total_count: number;
+
+  /** Blockchains the library failed to derive an address for */
+  failed: FailedAddress[];
+}
+
+/**
+ * A blockchain whose address could not be derived
+ */
+export interface FailedAddress {
+  blockchain: string;
+  error: string;
 }
 
 /**
@@ -107,6 +165,9 @@ export interface LoadAddressesParams {
 
   /** USB mount point */
   usb_path: string;
+
+  /** Put the wallet's preferred chains first (see setAddressRanking) */
+  apply_ranking?: boolean;
 }
 
 /**
@@ -135,6 +196,24 @@ export enum ExportFormat {
 
   /** CSV format (symbol, address, derivation_path) */
   CSV = 'csv',
+
+  /** Password-encrypted JSON (.enc) */
+  ENCRYPTED_JSON = 'encrypted_json',
+
+  /** Printable PDF */
+  PDF = 'pdf',
+}
+
+/**
+ * Options for exporting addresses
+ */
+export interface ExportOptions {
+  /** gzip the export (JSON only), written as .json.gz */
+  compress: boolean;
+  /** Append new rows to the latest CSV export instead of writing a new file (CSV only) */
+  append?: boolean;
+  /** Write a <file>.sha256 sidecar that verify_export checks against */
+  checksum?: boolean;
 }
 
 /**
@@ -149,6 +228,9 @@ export interface ExportAddressesParams {
 
   /** Optional filter to export subset of addresses */
   filter?: AddressFilter;
+
+  /** Optional export options */
+  options?: ExportOptions;
 }
 
 /**
@@ -166,4 +248,25 @@ export interface ExportResponse {
 
   /** Export timestamp (ISO 8601) */
   exported_at: string;
+
+  /** SHA-256 of the written file (hex), when a checksum sidecar was kept */
+  checksum?: string | null;
+
+  /** Blockchains the library failed to derive an address for; the file has no address for them */
+  failed: FailedAddress[];
+}
+
+/**
+ * Response from verify_export Tauri command
+ */
+export interface ExportVerification {
+  file_path: string;
+
+  /** Hash recorded when the export was written */
+  expected: string;
+
+  /** Hash of the file as it is now */
+  actual: string;
+
+  valid: boolean;
 }
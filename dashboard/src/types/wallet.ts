@@ -39,6 +39,9 @@ export interface WalletCreateResponse {
    *  SECURITY: Never store in state, only display in secure component
    */
   mnemonic: string;
+
+  /** Time the command took, in milliseconds */
+  duration_ms?: number;
 }
 
 /**
@@ -49,6 +52,57 @@ export interface WalletImportResponse {
 
   /** True if wallet with same ID already exists (FR-031) */
   is_duplicate: boolean;
+
+  /** True if this call retried an import that had already completed */
+  already_imported?: boolean;
+
+  /** Time the command took, in milliseconds */
+  duration_ms?: number;
+}
+
+/**
+ * Response from list_wallets Tauri command
+ */
+export interface WalletListResponse {
+  wallets: Wallet[];
+
+  /** Time the command took, in milliseconds */
+  duration_ms?: number;
+}
+
+/**
+ * Order of a wallet page
+ */
+export type WalletSortKey = 'name' | 'created_at';
+
+/**
+ * Response from list_wallets_page Tauri command
+ */
+export interface WalletPage {
+  /** Wallets on this page */
+  wallets: Wallet[];
+
+  /** Wallets on the USB drive across all pages */
+  total: number;
+
+  /** Index of the first wallet on this page */
+  offset: number;
+
+  /** Page size used (after clamping to 1-100) */
+  limit: number;
+
+  /** Time the command took, in milliseconds */
+  duration_ms?: number;
+}
+
+/**
+ * Response from rename_wallet Tauri command
+ */
+export interface WalletRenameResponse {
+  wallet: Wallet;
+
+  /** Time the command took, in milliseconds */
+  duration_ms?: number;
 }
 
 /**
@@ -103,6 +157,9 @@ export interface LoadAddressesParams {
 
   /** USB mount point */
   usb_path: string;
+
+  /** Put the wallet's preferred chains first (see setAddressRanking) */
+  apply_ranking?: boolean;
 }
 
 /**
@@ -17,7 +17,16 @@
 package main
 
 /*
+#include <stdint.h>
 #include <stdlib.h>
+
+// Progress callback supplied by the caller: completed, total, caller token
+typedef void (*progress_cb)(int completed, int total, uintptr_t token);
+
+// cgo cannot call C function pointers directly
+static inline void call_progress(progress_cb callback, int completed, int total, uintptr_t token) {
+	callback(completed, total, token);
+}
 */
 import "C"
 import (
@@ -32,10 +41,14 @@ import (
 	"github.com/arcsign/chainadapter"
 	"github.com/yourusername/arcsign/internal/app"
 	"github.com/yourusername/arcsign/internal/provider"
+	_ "github.com/yourusername/arcsign/internal/provider/alchemy" // registers the "alchemy" provider
+	"github.com/yourusername/arcsign/internal/services/address"
 	"github.com/yourusername/arcsign/internal/services/bip39service"
 	chainadapterService "github.com/yourusername/arcsign/internal/services/chainadapter"
+	"github.com/yourusername/arcsign/internal/services/coinregistry"
 	"github.com/yourusername/arcsign/internal/services/hdkey"
 	"github.com/yourusername/arcsign/internal/services/wallet"
+	"github.com/yourusername/arcsign/internal/utils"
 )
 
 // Global ChainAdapter service instance (initialized on first use)
@@ -63,6 +76,16 @@ func zeroString(s *string) {
 	*s = ""
 }
 
+// progressReporter adapts a caller's C progress callback; nil when none was given
+func progressReporter(callback C.progress_cb, token C.uintptr_t) func(completed, total int) {
+	if callback == nil {
+		return nil
+	}
+	return func(completed, total int) {
+		C.call_progress(callback, C.int(completed), C.int(total), token)
+	}
+}
+
 //export GoFree
 // GoFree frees memory allocated by Go and returned to Rust.
 // CRITICAL: Rust MUST call this function on every pointer returned by FFI exports.
@@ -85,11 +108,20 @@ func GoFree(ptr *C.char) {
 	}
 }
 
+// LibraryVersion is reported by GetVersion and recorded in new wallets' metadata
+const LibraryVersion = "0.2.0"
+
+// LibraryFeatures is reported by GetVersion for features the exported
+// symbols alone don't reveal. "transactions": building, signing and
+// broadcasting work. A "bip84" entry would mean native SegWit derivation;
+// this build only derives BIP44 addresses.
+var LibraryFeatures = []string{"transactions"}
+
 //export GetVersion
 // GetVersion returns library version information as JSON.
 // This is the simplest FFI function, useful for testing library loading.
 //
-// Returns: {"success": true, "data": {"version": "0.2.0", "buildTime": "...", "goVersion": "..."}}
+// Returns: {"success": true, "data": {"version": "0.2.0", "buildTime": "...", "goVersion": "...", "features": [...], "supportedChains": [...]}}
 // Caller MUST call GoFree() on the returned pointer.
 func GetVersion() *C.char {
 	start := time.Now()
@@ -106,10 +138,12 @@ func GetVersion() *C.char {
 		}
 	}()
 
-	data := map[string]string{
-		"version":   "0.2.0",
-		"buildTime": time.Now().Format(time.RFC3339),
-		"goVersion": "1.21+",
+	data := map[string]interface{}{
+		"version":         LibraryVersion,
+		"buildTime":       time.Now().Format(time.RFC3339),
+		"goVersion":       "1.21+",
+		"features":        LibraryFeatures,
+		"supportedChains": chainadapterService.SupportedChains,
 	}
 
 	response := NewSuccessResponse(data)
@@ -126,6 +160,20 @@ func GetVersion() *C.char {
 //
 // Caller MUST call GoFree() on the returned pointer.
 func CreateWallet(params *C.char) *C.char {
+	return createWallet(params, nil)
+}
+
+//export CreateWalletWithProgress
+// CreateWalletWithProgress is CreateWallet, calling callback(completed, total, token)
+// after each coin address is derived. Callbacks run before this function returns.
+//
+// Caller MUST call GoFree() on the returned pointer.
+func CreateWalletWithProgress(params *C.char, callback C.progress_cb, token C.uintptr_t) *C.char {
+	return createWallet(params, progressReporter(callback, token))
+}
+
+// createWallet implements CreateWallet and CreateWalletWithProgress; progress may be nil
+func createWallet(params *C.char, progress func(completed, total int)) *C.char {
 	start := time.Now()
 	defer func() {
 		elapsed := time.Since(start)
@@ -169,7 +217,7 @@ func CreateWallet(params *C.char) *C.char {
 	}()
 
 	// Create wallet service
-	svc := wallet.NewWalletService(input.USBPath)
+	svc := wallet.NewWalletService(input.USBPath).WithLibraryVersion(LibraryVersion).WithProgress(progress)
 
 	usesPassphrase := input.Passphrase != ""
 
@@ -212,6 +260,20 @@ func CreateWallet(params *C.char) *C.char {
 // Input JSON: {"walletName": "...", "mnemonic": "...", "password": "...", "usbPath": "...", "passphrase": "..."}
 // Output JSON: {"success": true, "data": {"walletId": "...", "walletName": "...", "importedAt": "..."}}
 func ImportWallet(params *C.char) *C.char {
+	return importWallet(params, nil)
+}
+
+//export ImportWalletWithProgress
+// ImportWalletWithProgress is ImportWallet, calling callback(completed, total, token)
+// after each coin address is derived. Callbacks run before this function returns.
+//
+// Caller MUST call GoFree() on the returned pointer.
+func ImportWalletWithProgress(params *C.char, callback C.progress_cb, token C.uintptr_t) *C.char {
+	return importWallet(params, progressReporter(callback, token))
+}
+
+// importWallet implements ImportWallet and ImportWalletWithProgress; progress may be nil
+func importWallet(params *C.char, progress func(completed, total int)) *C.char {
 	start := time.Now()
 	defer func() {
 		elapsed := time.Since(start)
@@ -252,7 +314,7 @@ func ImportWallet(params *C.char) *C.char {
 	}()
 
 	// Create wallet service
-	svc := wallet.NewWalletService(input.USBPath)
+	svc := wallet.NewWalletService(input.USBPath).WithLibraryVersion(LibraryVersion).WithProgress(progress)
 
 	// Determine word count (12 or 24)
 	words := len(input.Mnemonic) / 8 // Approximate: 12 words ≈ 96 chars, 24 words ≈ 192 chars
@@ -367,6 +429,68 @@ func UnlockWallet(params *C.char) *C.char {
 	return C.CString(string(jsonBytes))
 }
 
+//export ChangeWalletPassword
+// ChangeWalletPassword re-encrypts a wallet's mnemonic under a new password.
+// The old password is verified first; the wallet is unchanged if it is wrong.
+//
+// Input JSON: {"walletId": "...", "oldPassword": "...", "newPassword": "...", "usbPath": "..."}
+// Output JSON: {"success": true, "data": {"walletId": "...", "changedAt": "..."}}
+func ChangeWalletPassword(params *C.char) *C.char {
+	defer func() {
+		if r := recover(); r != nil {
+			debug.PrintStack()
+			response := NewErrorResponse(ErrLibraryPanic, fmt.Sprintf("Library panic: %v", r))
+			jsonBytes, _ := json.Marshal(response)
+			ptr := C.CString(string(jsonBytes))
+			_ = ptr
+		}
+	}()
+
+	paramsJSON := C.GoString(params)
+	var input struct {
+		WalletID    string `json:"walletId"`
+		OldPassword string `json:"oldPassword"`
+		NewPassword string `json:"newPassword"`
+		USBPath     string `json:"usbPath"`
+	}
+
+	if err := json.Unmarshal([]byte(paramsJSON), &input); err != nil {
+		response := NewErrorResponse(ErrInvalidInput, fmt.Sprintf("Invalid JSON: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	// T026: Ensure sensitive data is zeroed before function returns
+	defer func() {
+		zeroString(&input.OldPassword)
+		zeroString(&input.NewPassword)
+	}()
+
+	// A weak new password is bad input, not a wrong old password
+	if err := utils.ValidatePassword(input.NewPassword); err != nil {
+		response := NewErrorResponse(ErrInvalidInput, err.Error())
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	svc := wallet.NewWalletService(input.USBPath)
+	if err := svc.ChangePassword(input.WalletID, input.OldPassword, input.NewPassword); err != nil {
+		code := MapWalletError(err)
+		response := NewErrorResponse(code, err.Error())
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	data := map[string]interface{}{
+		"walletId":  input.WalletID,
+		"changedAt": time.Now().Format(time.RFC3339),
+	}
+
+	response := NewSuccessResponse(data)
+	jsonBytes, _ := json.Marshal(response)
+	return C.CString(string(jsonBytes))
+}
+
 //export GenerateAddresses
 // GenerateAddresses derives addresses for specified blockchains from wallet's AddressBook.
 // T024: Implement GenerateAddresses export function (returns all addresses from wallet metadata)
@@ -445,6 +569,7 @@ func GenerateAddresses(params *C.char) *C.char {
 			"address":        addr.Address,
 			"derivationPath": addr.DerivationPath,
 			"coinType":       addr.CoinType,
+			"category":       addr.Category,
 		})
 	}
 
@@ -459,6 +584,95 @@ func GenerateAddresses(params *C.char) *C.char {
 	return C.CString(string(jsonBytes))
 }
 
+//export DeriveFirstAddress
+// DeriveFirstAddress derives the first receiving address (m/44'/coin_type'/0'/0/0)
+// of a mnemonic and passphrase on one chain, without creating a wallet.
+// Used to detect duplicate imports and to preview a restore.
+//
+// Input JSON: {"chainId": "bitcoin", "mnemonic": "...", "passphrase": "..."}
+// Output JSON: {"success": true, "data": {"chainId": "bitcoin", "address": "...", "derivationPath": "m/44'/0'/0'/0/0"}}
+func DeriveFirstAddress(params *C.char) *C.char {
+	defer func() {
+		if r := recover(); r != nil {
+			debug.PrintStack()
+			response := NewErrorResponse(ErrLibraryPanic, fmt.Sprintf("Library panic: %v", r))
+			jsonBytes, _ := json.Marshal(response)
+			ptr := C.CString(string(jsonBytes))
+			_ = ptr
+		}
+	}()
+
+	paramsJSON := C.GoString(params)
+	var input struct {
+		ChainID    string `json:"chainId"`
+		Mnemonic   string `json:"mnemonic"`
+		Passphrase string `json:"passphrase"` // BIP39 passphrase (optional)
+	}
+
+	if err := json.Unmarshal([]byte(paramsJSON), &input); err != nil {
+		response := NewErrorResponse(ErrInvalidInput, fmt.Sprintf("Invalid JSON: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	// T026: Ensure sensitive data is zeroed before function returns
+	defer func() {
+		zeroString(&input.Mnemonic)
+		zeroString(&input.Passphrase)
+	}()
+
+	coin, err := coinregistry.NewRegistry().GetCoinByChainID(input.ChainID)
+	if err != nil {
+		response := NewErrorResponse(ErrInvalidBlockchain, fmt.Sprintf("Unsupported chain: %s", input.ChainID))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	bip39Svc := bip39service.NewBIP39Service()
+	if err := bip39Svc.ValidateMnemonic(input.Mnemonic); err != nil {
+		response := NewErrorResponse(ErrInvalidMnemonic, err.Error())
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	// Mnemonic → Seed (empty passphrase if not used)
+	seed, err := bip39Svc.MnemonicToSeed(input.Mnemonic, input.Passphrase)
+	if err != nil {
+		response := NewErrorResponse(ErrEncryptionError, fmt.Sprintf("Failed to derive seed: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+	defer func() {
+		for i := range seed {
+			seed[i] = 0
+		}
+	}()
+
+	masterKey, err := hdkey.NewHDKeyService().NewMasterKey(seed)
+	if err != nil {
+		response := NewErrorResponse(ErrEncryptionError, fmt.Sprintf("Failed to create master key: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	firstAddress, err := address.NewAddressService().DeriveFirstCoinAddress(masterKey, *coin)
+	if err != nil {
+		response := NewErrorResponse(ErrEncryptionError, fmt.Sprintf("Failed to derive %s address: %v", coin.Name, err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	data := map[string]interface{}{
+		"chainId":        input.ChainID,
+		"address":        firstAddress,
+		"derivationPath": fmt.Sprintf("m/44'/%d'/0'/0/0", coin.CoinType),
+	}
+
+	response := NewSuccessResponse(data)
+	jsonBytes, _ := json.Marshal(response)
+	return C.CString(string(jsonBytes))
+}
+
 //export ExportWallet
 // ExportWallet exports wallet metadata without private keys.
 // T024.1: Implement ExportWallet export function
@@ -629,7 +843,8 @@ func ListWallets(params *C.char) *C.char {
 //   "asset": "BTC" | "ETH",
 //   "amount": "1000000",  // string representation of big.Int
 //   "feeSpeed": "slow" | "normal" | "fast",
-//   "memo": "optional"
+//   "memo": "optional",
+//   "nonce": 7  // optional, Ethereum only; defaults to the next pending nonce
 // }
 //
 // Output JSON: {
@@ -639,10 +854,12 @@ func ListWallets(params *C.char) *C.char {
 //     "chainId": "bitcoin",
 //     "from": "address",
 //     "to": "address",
+//     "asset": "BTC",
 //     "amount": "1000000",
 //     "fee": "5000",
 //     "signingPayload": "base64-encoded-bytes",
-//     "humanReadable": "JSON representation for audit"
+//     "humanReadable": "JSON representation for audit",
+//     "chainSpecific": {...}  // e.g. nonce, gas_limit and fee caps on Ethereum
 //   }
 // }
 func BuildTransaction(params *C.char) *C.char {
@@ -664,14 +881,15 @@ func BuildTransaction(params *C.char) *C.char {
 
 	paramsJSON := C.GoString(params)
 	var input struct {
-		ChainID   string `json:"chainId"`
-		From      string `json:"from"`
-		To        string `json:"to"`
-		Asset     string `json:"asset"`
-		Amount    string `json:"amount"`    // string representation of big.Int
-		FeeSpeed  string `json:"feeSpeed"`  // "slow", "normal", "fast"
-		Memo      string `json:"memo"`      // optional
-		RPCConfig string `json:"rpcConfig"` // optional RPC endpoint
+		ChainID   string  `json:"chainId"`
+		From      string  `json:"from"`
+		To        string  `json:"to"`
+		Asset     string  `json:"asset"`
+		Amount    string  `json:"amount"`    // string representation of big.Int
+		FeeSpeed  string  `json:"feeSpeed"`  // "slow", "normal", "fast"
+		Memo      string  `json:"memo"`      // optional
+		Nonce     *uint64 `json:"nonce"`     // optional account nonce (Ethereum)
+		RPCConfig string  `json:"rpcConfig"` // optional RPC endpoint
 	}
 
 	if err := json.Unmarshal([]byte(paramsJSON), &input); err != nil {
@@ -712,6 +930,7 @@ func BuildTransaction(params *C.char) *C.char {
 		Amount:   amount,
 		FeeSpeed: feeSpeed,
 		Memo:     input.Memo,
+		Nonce:    input.Nonce,
 	}
 
 	// Build unsigned transaction
@@ -732,12 +951,17 @@ func BuildTransaction(params *C.char) *C.char {
 		"chainId":         unsigned.ChainID,
 		"from":            unsigned.From,
 		"to":              unsigned.To,
+		"asset":           input.Asset,
 		"amount":          unsigned.Amount.String(),
 		"fee":             unsigned.Fee.String(),
 		"signingPayload":  signingPayloadB64,
 		"humanReadable":   unsigned.HumanReadable,
+		"chainSpecific":   unsigned.ChainSpecific, // lets the payload be checked before signing
 		"buildTimestamp":  time.Now().Format(time.RFC3339),
 	}
+	if unsigned.Nonce != nil {
+		data["nonce"] = *unsigned.Nonce
+	}
 
 	response := NewSuccessResponse(data)
 	jsonBytes, _ := json.Marshal(response)
@@ -1290,6 +1514,7 @@ func SetProviderConfig(params *C.char) *C.char {
 //     "providerType": "alchemy",
 //     "chainId": "ethereum",
 //     "networkId": "mainnet",
+//     "customEndpoint": "",
 //     "priority": 100,
 //     "enabled": true,
 //     "hasApiKey": true,
@@ -1357,14 +1582,15 @@ func GetProviderConfig(params *C.char) *C.char {
 
 	// Return config without exposing API key
 	data := map[string]interface{}{
-		"providerType": config.ProviderType,
-		"chainId":      config.ChainID,
-		"networkId":    config.NetworkID,
-		"priority":     config.Priority,
-		"enabled":      config.Enabled,
-		"hasApiKey":    config.APIKey != "",
-		"createdAt":    config.CreatedAt.Format(time.RFC3339),
-		"updatedAt":    config.UpdatedAt.Format(time.RFC3339),
+		"providerType":   config.ProviderType,
+		"chainId":        config.ChainID,
+		"networkId":      config.NetworkID,
+		"customEndpoint": config.CustomEndpoint,
+		"priority":       config.Priority,
+		"enabled":        config.Enabled,
+		"hasApiKey":      config.APIKey != "",
+		"createdAt":      config.CreatedAt.Format(time.RFC3339),
+		"updatedAt":      config.UpdatedAt.Format(time.RFC3339),
 	}
 
 	response := NewSuccessResponse(data)
@@ -1560,6 +1786,278 @@ func DeleteProviderConfig(params *C.char) *C.char {
 	return C.CString(string(jsonBytes))
 }
 
+//export ResolveProviderEndpoint
+// ResolveProviderEndpoint returns the JSON-RPC endpoint of a stored provider
+// configuration, API key included, to pass as "rpcConfig" to
+// BroadcastTransaction / QueryTransactionStatus / EstimateFee.
+// Feature: Provider Registry System - Provider Failover
+//
+// Input JSON: {
+//   "chainId": "ethereum",
+//   "providerType": "alchemy",
+//   "password": "wallet-password",
+//   "usbPath": "/path/to/usb"
+// }
+//
+// Output JSON: {
+//   "success": true,
+//   "data": {
+//     "providerType": "alchemy",
+//     "chainId": "ethereum",
+//     "rpcEndpoint": "https://eth-mainnet.g.alchemy.com/v2/<api-key>"
+//   }
+// }
+func ResolveProviderEndpoint(params *C.char) *C.char {
+	start := time.Now()
+	defer func() {
+		elapsed := time.Since(start)
+		_ = elapsed
+	}()
+
+	defer func() {
+		if r := recover(); r != nil {
+			debug.PrintStack()
+			response := NewErrorResponse(ErrLibraryPanic, fmt.Sprintf("Library panic: %v", r))
+			jsonBytes, _ := json.Marshal(response)
+			ptr := C.CString(string(jsonBytes))
+			_ = ptr
+		}
+	}()
+
+	paramsJSON := C.GoString(params)
+	var input struct {
+		ChainID      string `json:"chainId"`
+		ProviderType string `json:"providerType"`
+		Password     string `json:"password"`
+		USBPath      string `json:"usbPath"`
+	}
+
+	if err := json.Unmarshal([]byte(paramsJSON), &input); err != nil {
+		response := NewErrorResponse(ErrInvalidInput, fmt.Sprintf("Invalid JSON: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	// Zero sensitive data after function returns
+	defer zeroString(&input.Password)
+
+	// Create provider config store
+	configPath := input.USBPath + "/provider_config.enc"
+	store, err := provider.NewProviderConfigStore(configPath, input.Password)
+	if err != nil {
+		response := NewErrorResponse(ErrStorageError, fmt.Sprintf("Failed to open config store: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	config, err := store.Get(input.ChainID, input.ProviderType)
+	if err != nil {
+		response := NewErrorResponse(ErrStorageError, fmt.Sprintf("Provider config not found: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	endpoint, err := provider.GetRegistry().ResolveRPCEndpoint(config)
+	if err != nil {
+		response := NewErrorResponse(ErrInvalidInput, fmt.Sprintf("Failed to resolve provider endpoint: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	data := map[string]interface{}{
+		"providerType": config.ProviderType,
+		"chainId":      config.ChainID,
+		"rpcEndpoint":  endpoint,
+	}
+
+	response := NewSuccessResponse(data)
+	jsonBytes, _ := json.Marshal(response)
+	return C.CString(string(jsonBytes))
+}
+
+//export GetNonce
+// GetNonce returns the next nonce of an account on an account-based chain,
+// counting its pending transactions.
+// Feature: 006-chain-adapter - ChainAdapter Transaction FFI
+//
+// Input JSON: {
+//   "chainId": "ethereum",
+//   "address": "0x...",
+//   "rpcConfig": "optional-rpc-endpoint"
+// }
+//
+// Output JSON: {
+//   "success": true,
+//   "data": {
+//     "nonce": 7
+//   }
+// }
+func GetNonce(params *C.char) *C.char {
+	start := time.Now()
+	defer func() {
+		elapsed := time.Since(start)
+		_ = elapsed
+	}()
+
+	defer func() {
+		if r := recover(); r != nil {
+			debug.PrintStack()
+			response := NewErrorResponse(ErrLibraryPanic, fmt.Sprintf("Library panic: %v", r))
+			jsonBytes, _ := json.Marshal(response)
+			ptr := C.CString(string(jsonBytes))
+			_ = ptr
+		}
+	}()
+
+	paramsJSON := C.GoString(params)
+	var input struct {
+		ChainID   string `json:"chainId"`
+		Address   string `json:"address"`
+		RPCConfig string `json:"rpcConfig"`
+	}
+
+	if err := json.Unmarshal([]byte(paramsJSON), &input); err != nil {
+		response := NewErrorResponse(ErrInvalidInput, fmt.Sprintf("Invalid JSON: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	// Initialize ChainAdapter service
+	svc := initChainAdapterService()
+
+	ctx := context.Background()
+	nonce, err := svc.GetNonce(ctx, input.ChainID, input.Address, input.RPCConfig)
+	if err != nil {
+		response := NewErrorResponse(ErrTransactionBuildFailed, fmt.Sprintf("Failed to get nonce: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	data := map[string]interface{}{
+		"nonce": nonce,
+	}
+
+	response := NewSuccessResponse(data)
+	jsonBytes, _ := json.Marshal(response)
+	return C.CString(string(jsonBytes))
+}
+
+//export TestProviderConnection
+// TestProviderConnection checks provider credentials without storing them,
+// by asking the provider's JSON-RPC endpoint for the latest block.
+// Feature: Provider Registry System - Key Rotation
+//
+// Input JSON: {
+//   "providerType": "alchemy",
+//   "apiKey": "...",
+//   "chainId": "ethereum",
+//   "networkId": "mainnet",
+//   "customEndpoint": ""
+// }
+//
+// Without "apiKey", the stored config of chainId/providerType is tested:
+// {"chainId": "ethereum", "providerType": "alchemy", "password": "...", "usbPath": "/path/to/usb"}
+//
+// Output JSON: {
+//   "success": true,
+//   "data": {
+//     "reachable": true,
+//     "latencyMs": 120,
+//     "error": ""        // Why the provider is unreachable
+//   }
+// }
+func TestProviderConnection(params *C.char) *C.char {
+	start := time.Now()
+	defer func() {
+		elapsed := time.Since(start)
+		_ = elapsed
+	}()
+
+	defer func() {
+		if r := recover(); r != nil {
+			debug.PrintStack()
+			response := NewErrorResponse(ErrLibraryPanic, fmt.Sprintf("Library panic: %v", r))
+			jsonBytes, _ := json.Marshal(response)
+			ptr := C.CString(string(jsonBytes))
+			_ = ptr
+		}
+	}()
+
+	paramsJSON := C.GoString(params)
+	var input struct {
+		ProviderType   string `json:"providerType"`
+		APIKey         string `json:"apiKey"`
+		ChainID        string `json:"chainId"`
+		NetworkID      string `json:"networkId"`
+		CustomEndpoint string `json:"customEndpoint"`
+		Password       string `json:"password"`
+		USBPath        string `json:"usbPath"`
+	}
+
+	if err := json.Unmarshal([]byte(paramsJSON), &input); err != nil {
+		response := NewErrorResponse(ErrInvalidInput, fmt.Sprintf("Invalid JSON: %v", err))
+		jsonBytes, _ := json.Marshal(response)
+		return C.CString(string(jsonBytes))
+	}
+
+	// Zero sensitive data after function returns
+	defer zeroString(&input.APIKey)
+	defer zeroString(&input.Password)
+
+	config := &provider.ProviderConfig{
+		ProviderType:   input.ProviderType,
+		APIKey:         input.APIKey,
+		ChainID:        input.ChainID,
+		NetworkID:      input.NetworkID,
+		CustomEndpoint: input.CustomEndpoint,
+	}
+
+	if input.APIKey == "" {
+		// Test the stored config instead
+		configPath := input.USBPath + "/provider_config.enc"
+		store, err := provider.NewProviderConfigStore(configPath, input.Password)
+		if err != nil {
+			response := NewErrorResponse(ErrStorageError, fmt.Sprintf("Failed to open config store: %v", err))
+			jsonBytes, _ := json.Marshal(response)
+			return C.CString(string(jsonBytes))
+		}
+
+		config, err = store.Get(input.ChainID, input.ProviderType)
+		if err != nil {
+			response := NewErrorResponse(ErrStorageError, fmt.Sprintf("Provider config not found: %v", err))
+			jsonBytes, _ := json.Marshal(response)
+			return C.CString(string(jsonBytes))
+		}
+	}
+
+	response := NewSuccessResponse(testProviderConnection(config))
+	jsonBytes, _ := json.Marshal(response)
+	return C.CString(string(jsonBytes))
+}
+
+// testProviderConnection probes a provider's endpoint and reports whether it
+// answered, for TestProviderConnection. Errors never include the endpoint.
+func testProviderConnection(config *provider.ProviderConfig) map[string]interface{} {
+	probeStart := time.Now()
+
+	endpoint, err := provider.GetRegistry().ResolveRPCEndpoint(config)
+	if err == nil {
+		defer zeroString(&endpoint)
+		ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+		defer cancel()
+		err = provider.ProbeRPCEndpoint(ctx, endpoint, config.ChainID)
+	}
+
+	data := map[string]interface{}{
+		"reachable": err == nil,
+		"latencyMs": time.Since(probeStart).Milliseconds(),
+	}
+	if err != nil {
+		data["error"] = err.Error()
+	}
+	return data
+}
+
 //export EstimateFee
 // EstimateFee calculates fee estimates with confidence bounds.
 // Feature: 006-chain-adapter - ChainAdapter Transaction FFI
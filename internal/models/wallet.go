@@ -13,7 +13,9 @@ type Wallet struct {
 	LastAccessedAt         time.Time     `json:"lastAccessedAt"`
 	EncryptedMnemonicPath  string        `json:"encryptedMnemonicPath"`
 	UsesPassphrase         bool          `json:"usesPassphrase"`
-	AddressBook            *AddressBook  `json:"addressBook,omitempty"` // T016: v0.2.0+ multi-coin addresses (optional for backwards compatibility)
+	AddressBook            *AddressBook  `json:"addressBook,omitempty"`       // T016: v0.2.0+ multi-coin addresses (optional for backwards compatibility)
+	MnemonicWordCount      int           `json:"mnemonicWordCount,omitempty"` // Provenance: BIP39 word count (absent in older wallets)
+	CreatedByVersion       string        `json:"createdByVersion,omitempty"`  // Provenance: library version that created the wallet
 }
 
 // ValidateWalletName validates the wallet name length and characters
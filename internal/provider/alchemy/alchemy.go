@@ -83,12 +83,17 @@ func (a *AlchemyProvider) SupportedChains() []string {
 	return []string{"ethereum", "polygon", "arbitrum", "optimism", "base"}
 }
 
+// RPCEndpoint returns the JSON-RPC URL, API key included
+func (a *AlchemyProvider) RPCEndpoint() string {
+	return fmt.Sprintf("%s/%s", a.baseURL, a.apiKey)
+}
+
 // --- JSON-RPC Helper Methods ---
 
 // rpcCall performs a JSON-RPC call to Alchemy
 func (a *AlchemyProvider) rpcCall(ctx context.Context, method string, params interface{}) (json.RawMessage, error) {
 	// Build request URL
-	url := fmt.Sprintf("%s/%s", a.baseURL, a.apiKey)
+	url := a.RPCEndpoint()
 
 	// Build JSON-RPC request
 	reqBody := map[string]interface{}{
@@ -220,6 +220,13 @@ type BlockchainProvider interface {
 	Close() error
 }
 
+// EndpointProvider is implemented by providers that are reached over plain
+// JSON-RPC, so chain adapters can talk to them directly.
+type EndpointProvider interface {
+	// RPCEndpoint returns the provider's JSON-RPC URL, API key included
+	RPCEndpoint() string
+}
+
 // TransactionInfo represents generic transaction information
 type TransactionInfo struct {
 	Hash        string
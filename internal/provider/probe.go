@@ -0,0 +1,81 @@
+package provider
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+)
+
+// maxProbeResponseSize bounds how much of a probe response is read
+const maxProbeResponseSize = 64 * 1024
+
+// ProbeRPCEndpoint checks that a JSON-RPC endpoint answers a cheap request
+// for the latest block (getblockcount on Bitcoin chains, eth_blockNumber
+// otherwise).
+//
+// Errors never include the endpoint, as it may embed an API key.
+//
+// Parameters:
+// - ctx: Context for timeout and cancellation
+// - endpoint: JSON-RPC URL, API key included
+// - chainID: Chain identifier (e.g., "ethereum", "bitcoin-testnet")
+//
+// Returns:
+// - Error if the endpoint is unreachable or answers with an error
+func ProbeRPCEndpoint(ctx context.Context, endpoint, chainID string) error {
+	method := "eth_blockNumber"
+	if strings.HasPrefix(chainID, "bitcoin") {
+		method = "getblockcount"
+	}
+	body := fmt.Sprintf(`{"jsonrpc":"2.0","id":1,"method":"%s","params":[]}`, method)
+
+	req, err := http.NewRequestWithContext(ctx, "POST", endpoint, strings.NewReader(body))
+	if err != nil {
+		return fmt.Errorf("invalid endpoint")
+	}
+	req.Header.Set("Content-Type", "application/json")
+
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		// url.Error repeats the URL; keep only the cause
+		var urlErr *url.Error
+		if errors.As(err, &urlErr) {
+			err = urlErr.Err
+		}
+		return fmt.Errorf("request failed: %v", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("HTTP %d", resp.StatusCode)
+	}
+
+	respBody, err := io.ReadAll(io.LimitReader(resp.Body, maxProbeResponseSize))
+	if err != nil {
+		return fmt.Errorf("failed to read response: %v", err)
+	}
+
+	var rpcResp struct {
+		Result json.RawMessage `json:"result"`
+		Error  *struct {
+			Code    int    `json:"code"`
+			Message string `json:"message"`
+		} `json:"error"`
+	}
+	if err := json.Unmarshal(respBody, &rpcResp); err != nil {
+		return fmt.Errorf("not a JSON-RPC response")
+	}
+	if rpcResp.Error != nil {
+		return fmt.Errorf("RPC error %d: %s", rpcResp.Error.Code, rpcResp.Error.Message)
+	}
+	if len(rpcResp.Result) == 0 || string(rpcResp.Result) == "null" {
+		return fmt.Errorf("empty JSON-RPC result")
+	}
+
+	return nil
+}
@@ -201,6 +201,47 @@ func (r *ProviderRegistry) GetProviderWithFallback(ctx context.Context, chainID
 	return nil, fmt.Errorf("all providers unhealthy for chain %s: %w", chainID, lastErr)
 }
 
+// ResolveRPCEndpoint returns the JSON-RPC URL of a configured provider,
+// API key included, for chain adapters to connect to directly.
+//
+// Registered providers that implement EndpointProvider build the URL from
+// their network table; any other provider must have a custom endpoint.
+// Providers are created uncached, so a rotated key takes effect at once.
+//
+// Parameters:
+// - config: Provider configuration
+//
+// Returns:
+// - JSON-RPC endpoint URL
+// - Error if the provider can't be created or has no JSON-RPC endpoint
+func (r *ProviderRegistry) ResolveRPCEndpoint(config *ProviderConfig) (string, error) {
+	if config == nil {
+		return "", fmt.Errorf("provider config is nil")
+	}
+
+	r.mutex.RLock()
+	factory, ok := r.factories[config.ProviderType]
+	r.mutex.RUnlock()
+
+	if ok {
+		provider, err := factory(config)
+		if err != nil {
+			return "", fmt.Errorf("failed to create provider %s: %w", config.ProviderType, err)
+		}
+		defer provider.Close()
+
+		if endpointProvider, ok := provider.(EndpointProvider); ok {
+			return endpointProvider.RPCEndpoint(), nil
+		}
+	}
+
+	if config.CustomEndpoint != "" {
+		return config.CustomEndpoint, nil
+	}
+
+	return "", fmt.Errorf("provider %s has no JSON-RPC endpoint for %s; set a custom endpoint", config.ProviderType, config.ChainID)
+}
+
 // ClearCache removes all cached provider instances
 //
 // This is useful when:
@@ -18,6 +18,7 @@ import (
 // AddressService handles cryptocurrency address derivation
 type AddressService struct {
 	btcParams *chaincfg.Params
+	progress  func(completed, total int)
 }
 
 // NewAddressService creates a new address service
@@ -27,6 +28,12 @@ func NewAddressService() *AddressService {
 	}
 }
 
+// WithProgress reports each coin finished by GenerateMultiCoinAddresses to progress
+func (s *AddressService) WithProgress(progress func(completed, total int)) *AddressService {
+	s.progress = progress
+	return s
+}
+
 // DeriveBitcoinAddress derives a Bitcoin P2PKH address from an extended key
 // Returns a base58-encoded Bitcoin address (e.g., 1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa)
 func (s *AddressService) DeriveBitcoinAddress(key *hdkeychain.ExtendedKey) (string, error) {
@@ -122,38 +129,12 @@ func (s *AddressService) GenerateMultiCoinAddresses(masterKey *hdkeychain.Extend
 	}
 
 	// Generate address for each coin
-	for _, coin := range coins {
+	for i, coin := range coins {
 		chainStart := time.Now()
 
 		// Wrap address generation in retry-once logic
 		address, attempts, err := retryOnce(func() (string, error) {
-			// Derive BIP44 path: m/44'/coin_type'/0'/0/0
-			purpose, err := masterKey.Derive(hdkeychain.HardenedKeyStart + 44)
-			if err != nil {
-				return "", fmt.Errorf("failed to derive purpose: %w", err)
-			}
-
-			coinTypeKey, err := purpose.Derive(hdkeychain.HardenedKeyStart + coin.CoinType)
-			if err != nil {
-				return "", fmt.Errorf("failed to derive coin type: %w", err)
-			}
-
-			accountKey, err := coinTypeKey.Derive(hdkeychain.HardenedKeyStart + 0)
-			if err != nil {
-				return "", fmt.Errorf("failed to derive account: %w", err)
-			}
-
-			externalKey, err := accountKey.Derive(0)
-			if err != nil {
-				return "", fmt.Errorf("failed to derive external chain: %w", err)
-			}
-
-			addressKey, err := externalKey.Derive(0)
-			if err != nil {
-				return "", fmt.Errorf("failed to derive address key: %w", err)
-			}
-
-			return s.deriveAddressByFormatter(addressKey, coin.FormatterID)
+			return s.DeriveFirstCoinAddress(masterKey, coin)
 		})
 
 		chainDuration := time.Since(chainStart)
@@ -197,6 +178,10 @@ func (s *AddressService) GenerateMultiCoinAddresses(masterKey *hdkeychain.Extend
 		}
 
 		metrics.PerChainMetrics[coin.Symbol] = chainMetric
+
+		if s.progress != nil {
+			s.progress(i+1, len(coins))
+		}
 	}
 
 	metrics.TotalDuration = time.Since(startTime)
@@ -209,6 +194,37 @@ func (s *AddressService) GenerateMultiCoinAddresses(masterKey *hdkeychain.Extend
 	}, metrics, nil
 }
 
+// DeriveFirstCoinAddress derives a coin's first receiving address at the
+// BIP44 path m/44'/coin_type'/0'/0/0
+func (s *AddressService) DeriveFirstCoinAddress(masterKey *hdkeychain.ExtendedKey, coin coinregistry.CoinMetadata) (string, error) {
+	purpose, err := masterKey.Derive(hdkeychain.HardenedKeyStart + 44)
+	if err != nil {
+		return "", fmt.Errorf("failed to derive purpose: %w", err)
+	}
+
+	coinTypeKey, err := purpose.Derive(hdkeychain.HardenedKeyStart + coin.CoinType)
+	if err != nil {
+		return "", fmt.Errorf("failed to derive coin type: %w", err)
+	}
+
+	accountKey, err := coinTypeKey.Derive(hdkeychain.HardenedKeyStart + 0)
+	if err != nil {
+		return "", fmt.Errorf("failed to derive account: %w", err)
+	}
+
+	externalKey, err := accountKey.Derive(0)
+	if err != nil {
+		return "", fmt.Errorf("failed to derive external chain: %w", err)
+	}
+
+	addressKey, err := externalKey.Derive(0)
+	if err != nil {
+		return "", fmt.Errorf("failed to derive address key: %w", err)
+	}
+
+	return s.deriveAddressByFormatter(addressKey, coin.FormatterID)
+}
+
 // deriveAddressByFormatter calls the appropriate formatter method based on FormatterID
 func (s *AddressService) deriveAddressByFormatter(key *hdkeychain.ExtendedKey, formatterID string) (string, error) {
 	switch formatterID {
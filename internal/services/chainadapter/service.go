@@ -26,7 +26,7 @@ import (
 // - Uses mutex for adapter cache access
 // - Adapter instances are immutable after creation
 type Service struct {
-	adapters map[string]chainadapter.ChainAdapter // cache: chainId -> adapter
+	adapters map[string]chainadapter.ChainAdapter // cache: chainId|rpcEndpoint -> adapter
 	txStore  storage.TransactionStateStore
 	mu       sync.RWMutex
 }
@@ -47,6 +47,12 @@ func NewService(txStore storage.TransactionStateStore) *Service {
 	}
 }
 
+// SupportedChains lists the chainIds GetAdapter accepts.
+var SupportedChains = []string{
+	"bitcoin", "bitcoin-testnet", "bitcoin-regtest",
+	"ethereum", "ethereum-goerli", "ethereum-sepolia",
+}
+
 // GetAdapter returns a ChainAdapter instance for the specified chainId.
 //
 // Supported chainIds:
@@ -57,13 +63,21 @@ func NewService(txStore storage.TransactionStateStore) *Service {
 // - chainId: Blockchain identifier
 // - rpcEndpoint: Optional RPC endpoint URL (uses default if empty)
 //
+// Adapters are cached per chainId and endpoint, so calls naming different
+// providers' endpoints each reach their own provider.
+//
 // Returns:
 // - ChainAdapter instance
 // - Error if chainId not supported or adapter initialization fails
 func (s *Service) GetAdapter(ctx context.Context, chainId string, rpcEndpoint string) (chainadapter.ChainAdapter, error) {
+	if rpcEndpoint == "" {
+		rpcEndpoint = getDefaultRPCEndpoint(chainId)
+	}
+	cacheKey := chainId + "|" + rpcEndpoint
+
 	// Check cache first (read lock)
 	s.mu.RLock()
-	if adapter, exists := s.adapters[chainId]; exists {
+	if adapter, exists := s.adapters[cacheKey]; exists {
 		s.mu.RUnlock()
 		return adapter, nil
 	}
@@ -74,7 +88,7 @@ func (s *Service) GetAdapter(ctx context.Context, chainId string, rpcEndpoint st
 	defer s.mu.Unlock()
 
 	// Double-check after acquiring write lock (another goroutine might have created it)
-	if adapter, exists := s.adapters[chainId]; exists {
+	if adapter, exists := s.adapters[cacheKey]; exists {
 		return adapter, nil
 	}
 
@@ -82,10 +96,6 @@ func (s *Service) GetAdapter(ctx context.Context, chainId string, rpcEndpoint st
 	var rpcClient rpc.RPCClient
 	var err error
 
-	if rpcEndpoint == "" {
-		rpcEndpoint = getDefaultRPCEndpoint(chainId)
-	}
-
 	// Create HTTP RPC client with default timeout and nil health tracker
 	rpcClient, err = rpc.NewHTTPRPCClient([]string{rpcEndpoint}, 30*time.Second, nil)
 	if err != nil {
@@ -116,7 +126,7 @@ func (s *Service) GetAdapter(ctx context.Context, chainId string, rpcEndpoint st
 	}
 
 	// Cache the adapter
-	s.adapters[chainId] = adapter
+	s.adapters[cacheKey] = adapter
 
 	return adapter, nil
 }
@@ -171,6 +181,27 @@ func (s *Service) QueryTransactionStatus(ctx context.Context, chainId string, tx
 	return adapter.QueryStatus(ctx, txHash)
 }
 
+// nonceSource is implemented by adapters of account-based chains.
+type nonceSource interface {
+	NextNonce(ctx context.Context, address string) (uint64, error)
+}
+
+// GetNonce returns the next nonce of an address, counting its pending
+// transactions. Only account-based chains (Ethereum) have nonces.
+func (s *Service) GetNonce(ctx context.Context, chainId string, address string, rpcEndpoint string) (uint64, error) {
+	adapter, err := s.GetAdapter(ctx, chainId, rpcEndpoint)
+	if err != nil {
+		return 0, err
+	}
+
+	source, ok := adapter.(nonceSource)
+	if !ok {
+		return 0, fmt.Errorf("%s does not use account nonces", chainId)
+	}
+
+	return source.NextNonce(ctx, address)
+}
+
 // getDefaultRPCEndpoint returns the default RPC endpoint for a chainId.
 func getDefaultRPCEndpoint(chainId string) string {
 	defaults := map[string]string{
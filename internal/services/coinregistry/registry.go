@@ -510,6 +510,19 @@ func (r *Registry) GetCoinBySymbol(symbol string) (*CoinMetadata, error) {
 	return &r.coins[index], nil
 }
 
+// GetCoinByChainID retrieves coin metadata by chain identifier (e.g.
+// "bitcoin", "bitcoin-cash"): the coin name, lowercased with spaces as
+// hyphens, or the symbol (case-insensitive)
+func (r *Registry) GetCoinByChainID(chainID string) (*CoinMetadata, error) {
+	for i := range r.coins {
+		name := strings.ReplaceAll(strings.ToLower(r.coins[i].Name), " ", "-")
+		if name == strings.ToLower(chainID) {
+			return &r.coins[i], nil
+		}
+	}
+	return r.GetCoinBySymbol(chainID)
+}
+
 // GetAllCoinsSortedByMarketCap returns all coins sorted by market capitalization rank
 // (rank 1 = highest market cap, comes first)
 func (r *Registry) GetAllCoinsSortedByMarketCap() []CoinMetadata {
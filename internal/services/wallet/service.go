@@ -21,9 +21,11 @@ import (
 
 // WalletService handles wallet creation and management operations
 type WalletService struct {
-	storagePath  string
-	bip39Service *bip39service.BIP39Service
-	rateLimiter  *ratelimit.RateLimiter
+	storagePath    string
+	bip39Service   *bip39service.BIP39Service
+	rateLimiter    *ratelimit.RateLimiter
+	libraryVersion string
+	progress       func(completed, total int)
 }
 
 // NewWalletService creates a new wallet service instance
@@ -36,6 +38,18 @@ func NewWalletService(storagePath string) *WalletService {
 	}
 }
 
+// WithLibraryVersion records version as the creator of wallets made by this service
+func (s *WalletService) WithLibraryVersion(version string) *WalletService {
+	s.libraryVersion = version
+	return s
+}
+
+// WithProgress reports per-coin address derivation during CreateWallet to progress
+func (s *WalletService) WithProgress(progress func(completed, total int)) *WalletService {
+	s.progress = progress
+	return s
+}
+
 // CreateWallet creates a new HD wallet with encrypted mnemonic storage
 //
 // Parameters:
@@ -108,6 +122,8 @@ func (s *WalletService) CreateWallet(
 		EncryptedMnemonicPath: mnemonicPath,
 		UsesPassphrase:        usesPassphrase,
 		AddressBook:           addressBook, // T054: Include generated addresses
+		MnemonicWordCount:     wordCount,
+		CreatedByVersion:      s.libraryVersion,
 	}
 
 	// 9. T056: Save wallet metadata as JSON (includes AddressBook automatically)
@@ -247,6 +263,61 @@ func (s *WalletService) RestoreWallet(walletID string, password string) (string,
 	return mnemonic, nil
 }
 
+// ChangePassword re-encrypts a wallet's mnemonic under newPassword
+// oldPassword is verified by RestoreWallet, so the same rate limiting and
+// audit logging apply. The new file replaces mnemonic.enc atomically: a
+// pulled drive leaves the wallet under either the old or the new password.
+func (s *WalletService) ChangePassword(walletID string, oldPassword string, newPassword string) error {
+	// 1. Validate the new password before touching the wallet
+	if err := utils.ValidatePassword(newPassword); err != nil {
+		return err
+	}
+
+	// 2. Verify the old password by decrypting the mnemonic
+	mnemonic, err := s.RestoreWallet(walletID, oldPassword)
+	if err != nil {
+		return err
+	}
+
+	wallet, err := s.LoadWallet(walletID)
+	if err != nil {
+		return err
+	}
+
+	// 3. Re-encrypt with a fresh salt and nonce
+	encryptedMnemonic, err := crypto.EncryptMnemonic(mnemonic, newPassword)
+	if err != nil {
+		return fmt.Errorf("failed to encrypt mnemonic: %w", err)
+	}
+
+	// 4. Replace the encrypted mnemonic
+	encryptedData := crypto.SerializeEncryptedData(encryptedMnemonic)
+	if err := storage.AtomicWriteFile(wallet.EncryptedMnemonicPath, encryptedData, 0600); err != nil {
+		return fmt.Errorf("failed to save encrypted mnemonic: %w", err)
+	}
+
+	s.logAuditPasswordChange(walletID)
+	return nil
+}
+
+// logAuditPasswordChange logs a successful password change
+func (s *WalletService) logAuditPasswordChange(walletID string) {
+	auditPath := filepath.Join(s.storagePath, walletID, "audit.log")
+	auditLogger, err := audit.NewAuditLogger(auditPath)
+	if err != nil {
+		return // Non-fatal
+	}
+
+	entry := audit.AuditLogEntry{
+		ID:        walletID + "-password-" + fmt.Sprintf("%d", time.Now().Unix()),
+		WalletID:  walletID,
+		Timestamp: time.Now(),
+		Operation: "PASSWORD_CHANGE",
+		Status:    "SUCCESS",
+	}
+	auditLogger.LogOperation(entry)
+}
+
 // logAuditSuccess logs successful wallet access
 func (s *WalletService) logAuditSuccess(walletID string) {
 	auditPath := filepath.Join(s.storagePath, walletID, "audit.log")
@@ -323,7 +394,7 @@ func (s *WalletService) generateMultiCoinAddresses(mnemonic string, passphrase s
 	registry := coinregistry.NewRegistry()
 
 	// 4. Initialize address service
-	addressService := address.NewAddressService()
+	addressService := address.NewAddressService().WithProgress(s.progress)
 
 	// 5. Generate addresses for all coins in registry
 	addressBook, metrics, err := addressService.GenerateMultiCoinAddresses(masterKey, registry)
@@ -123,6 +123,7 @@ type TransactionRequest struct {
 	MaxFee    *big.Int // Optional maximum acceptable fee
 	ConfirmBy *time.Time
 	FeeSpeed  FeeSpeed // "slow", "normal", "fast"
+	Nonce     *uint64  // Optional account nonce (Ethereum); nil = next pending nonce
 
 	// Chain-specific extensions (e.g., {"gas_limit": 21000})
 	ChainSpecific map[string]interface{}
@@ -85,6 +85,12 @@ func (e *EthereumAdapter) Capabilities() *chainadapter.Capabilities {
 	}
 }
 
+// NextNonce returns the next nonce of an address, counting its pending
+// transactions.
+func (e *EthereumAdapter) NextNonce(ctx context.Context, address string) (uint64, error) {
+	return e.rpcHelper.GetTransactionCount(ctx, address)
+}
+
 // Build constructs an unsigned Ethereum transaction from a standardized request.
 //
 // Contract:
@@ -92,7 +98,7 @@ func (e *EthereumAdapter) Capabilities() *chainadapter.Capabilities {
 // - MUST return NonRetryable error for invalid addresses or amounts
 // - MUST populate UnsignedTransaction.SigningPayload for offline signing
 // - MUST be deterministic (same request → same unsigned tx)
-// - MUST query current nonce and estimate gas
+// - MUST query current nonce (unless req.Nonce is set) and estimate gas
 func (e *EthereumAdapter) Build(ctx context.Context, req *chainadapter.TransactionRequest) (result *chainadapter.UnsignedTransaction, err error) {
 	// Record metrics
 	start := time.Now()
@@ -104,10 +110,15 @@ func (e *EthereumAdapter) Build(ctx context.Context, req *chainadapter.Transacti
 		}
 	}()
 
-	// Step 1: Get nonce for the from address
-	nonce, err := e.rpcHelper.GetTransactionCount(ctx, req.From)
-	if err != nil {
-		return nil, err
+	// Step 1: Get nonce for the from address, unless the caller picked one
+	var nonce uint64
+	if req.Nonce != nil {
+		nonce = *req.Nonce
+	} else {
+		nonce, err = e.rpcHelper.GetTransactionCount(ctx, req.From)
+		if err != nil {
+			return nil, err
+		}
 	}
 
 	// Step 2: Estimate gas for the transaction
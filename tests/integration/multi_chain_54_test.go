@@ -368,3 +368,40 @@ func TestPhase3_AlternativeEVM_6Chains(t *testing.T) {
 
 	t.Logf("✓ Alternative EVM chains: %d/6 successful (100%%)", altEvmSuccessCount)
 }
+
+// TestGenerateMultiCoinAddresses_ReportsProgress tests that progress is reported once per coin, in order
+func TestGenerateMultiCoinAddresses_ReportsProgress(t *testing.T) {
+	registry := coinregistry.NewRegistry()
+
+	mnemonic := "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+	seed := bip39.NewSeed(mnemonic, "")
+
+	masterKey, err := hdkeychain.NewMaster(seed, &chaincfg.MainNetParams)
+	if err != nil {
+		t.Fatalf("Failed to create master key: %v", err)
+	}
+
+	var completedCalls []int
+	total := 0
+	service := address.NewAddressService().WithProgress(func(completed, coins int) {
+		completedCalls = append(completedCalls, completed)
+		total = coins
+	})
+
+	if _, _, err := service.GenerateMultiCoinAddresses(masterKey, registry); err != nil {
+		t.Fatalf("GenerateMultiCoinAddresses failed: %v", err)
+	}
+
+	coinCount := len(registry.GetAllCoinsSortedByMarketCap())
+	if total != coinCount {
+		t.Errorf("Expected total %d, got %d", coinCount, total)
+	}
+	if len(completedCalls) != coinCount {
+		t.Fatalf("Expected %d progress calls, got %d", coinCount, len(completedCalls))
+	}
+	for i, completed := range completedCalls {
+		if completed != i+1 {
+			t.Errorf("Progress call %d reported %d completed, want %d", i, completed, i+1)
+		}
+	}
+}
@@ -151,6 +151,30 @@ func TestRegistry_GetCoinBySymbol(t *testing.T) {
 	})
 }
 
+func TestRegistry_GetCoinByChainID(t *testing.T) {
+	registry := coinregistry.NewRegistry()
+
+	cases := map[string]string{
+		"bitcoin":  "BTC",
+		"ethereum": "ETH",
+		"Ethereum": "ETH",
+		"sol":      "SOL",
+	}
+	for chainID, symbol := range cases {
+		coin, err := registry.GetCoinByChainID(chainID)
+		if err != nil {
+			t.Fatalf("Expected %s for chain ID '%s', got error %v", symbol, chainID, err)
+		}
+		if coin.Symbol != symbol {
+			t.Errorf("Expected Symbol '%s' for chain ID '%s', got '%s'", symbol, chainID, coin.Symbol)
+		}
+	}
+
+	if _, err := registry.GetCoinByChainID("bitcoin-testnet"); err == nil {
+		t.Error("Expected error for unknown chain ID, got nil")
+	}
+}
+
 // T008: Test for Registry.GetAllCoinsSortedByMarketCap
 // RED/GREEN phase - test implementation exists
 func TestRegistry_GetAllCoinsSortedByMarketCap(t *testing.T) {
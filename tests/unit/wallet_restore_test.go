@@ -277,3 +277,46 @@ func TestWalletRestoreIntegration(t *testing.T) {
 		}
 	})
 }
+
+// Test for ChangePassword() - re-encrypt mnemonic under a new password
+func TestChangePassword(t *testing.T) {
+	tempDir := t.TempDir()
+	service := wallet.NewWalletService(tempDir)
+
+	walletData, originalMnemonic, err := service.CreateWallet("Password Change", "SecurePass@123", 12, false, "")
+	if err != nil {
+		t.Fatalf("Failed to create wallet: %v", err)
+	}
+
+	t.Run("rejects wrong old password", func(t *testing.T) {
+		err := service.ChangePassword(walletData.ID, "WrongPass@123", "NewSecure@Pass456")
+		if err == nil {
+			t.Fatal("Expected error for wrong old password")
+		}
+	})
+
+	t.Run("rejects weak new password", func(t *testing.T) {
+		err := service.ChangePassword(walletData.ID, "SecurePass@123", "weak")
+		if err == nil {
+			t.Fatal("Expected error for weak new password")
+		}
+	})
+
+	t.Run("re-encrypts with new password", func(t *testing.T) {
+		if err := service.ChangePassword(walletData.ID, "SecurePass@123", "NewSecure@Pass456"); err != nil {
+			t.Fatalf("ChangePassword failed: %v", err)
+		}
+
+		mnemonic, err := service.RestoreWallet(walletData.ID, "NewSecure@Pass456")
+		if err != nil {
+			t.Fatalf("Restore with new password failed: %v", err)
+		}
+		if mnemonic != originalMnemonic {
+			t.Error("Mnemonic changed when the password did")
+		}
+
+		if _, err := service.RestoreWallet(walletData.ID, "SecurePass@123"); err == nil {
+			t.Error("Old password still decrypts the wallet")
+		}
+	})
+}